@@ -5,7 +5,11 @@ use crate::{
 	kusama_test_net::*,
 	prelude::*,
 };
-use composable_traits::defi::Sell;
+use composable_traits::{
+	defi::{Sell, Take},
+	time::{LinearDecrease, TimeReleaseFunction},
+	xcm::{CumulusMethodId, XcmSellOrderState, XcmSellRequest, XcmSellStatusQuery},
+};
 use frame_system::EventRecord;
 use orml_traits::currency::MultiCurrency;
 use primitives::currency::CurrencyId;
@@ -178,3 +182,265 @@ fn dex() {
 		assert!(result);
 	});
 }
+
+#[test]
+fn xcm_sell_reports_status_to_sender() {
+	simtest();
+	let this_native_asset = CurrencyId::PICA;
+	let some_native_amount = 1_000_000_000;
+	let some_enough_weight = UnitWeightCost::get() * 10;
+	let configuration_id = 42;
+	let remote_order_id = 13;
+	let sibling = sibling_account(SIBLING_PARA_ID);
+	let sell_amount = 100_000_000_000;
+	let request = XcmSellRequest {
+		order_id: remote_order_id,
+		from_to: sibling.clone().into(),
+		order: Sell::new(
+			CurrencyId::PICA.0,
+			CurrencyId::kUSD.0,
+			sell_amount,
+			FixedU128::saturating_from_integer(42_u64),
+		),
+		configuration: configuration_id,
+	};
+	let sell = this_runtime::RuntimeCall::DutchAuction(
+		dutch_auction::Call::<this_runtime::Runtime>::xcm_sell { request },
+	);
+	let status = this_runtime::RuntimeCall::DutchAuction(
+		dutch_auction::Call::<this_runtime::Runtime>::xcm_sell_status {
+			query: XcmSellStatusQuery { order_id: remote_order_id },
+		},
+	);
+
+	This::execute_with(|| {
+		assert_ok!(this_runtime::DutchAuction::add_configuration(
+			this_runtime::RuntimeOrigin::root(),
+			configuration_id,
+			TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 }),
+		));
+		assert_ok!(this_runtime::DutchAuction::add_xcm_callback_location(
+			this_runtime::RuntimeOrigin::root(),
+			SIBLING_PARA_ID.into(),
+			CumulusMethodId { pallet_instance: 61, method_id: 42 },
+		));
+		let _ = <balances::Pallet<this_runtime::Runtime> as frame_support::traits::Currency<
+			AccountId,
+		>>::deposit_creating(&sibling, enough_weight() * 1_000_000_000_000);
+	});
+
+	let transact = |call: Vec<u8>| {
+		let assets: MultiAsset = (
+			(Parent, X2(Parachain(THIS_PARA_ID), GeneralIndex(this_native_asset.into()))),
+			some_native_amount,
+		)
+			.into();
+		Xcm(vec![
+			WithdrawAsset(assets.clone().into()),
+			BuyExecution { fees: assets, weight_limit: Unlimited },
+			Transact {
+				origin_type: OriginKind::Native,
+				require_weight_at_most: some_enough_weight,
+				call: call.into(),
+			},
+		])
+	};
+
+	Sibling::execute_with(|| {
+		assert_ok!(pallet_xcm::Pallet::<sibling_runtime::Runtime>::send_xcm(
+			Here,
+			(Parent, Parachain(THIS_PARA_ID)),
+			transact(sell.encode()),
+		));
+	});
+
+	This::execute_with(|| {
+		assert!(this_runtime::System::events().iter().any(|x| {
+			match_this_event!(x, DutchAuction, dutch_auction::Event::<_>::OrderAdded { .. })
+		}));
+		let status = this_runtime::DutchAuction::xcm_sell_order_statuses(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.expect("order status is tracked");
+		assert_eq!(status.state, XcmSellOrderState::Open);
+		assert_eq!(status.remaining_amount, sell_amount);
+	});
+
+	Sibling::execute_with(|| {
+		assert_ok!(pallet_xcm::Pallet::<sibling_runtime::Runtime>::send_xcm(
+			Here,
+			(Parent, Parachain(THIS_PARA_ID)),
+			transact(status.encode()),
+		));
+	});
+
+	This::execute_with(|| {
+		assert!(this_runtime::System::events().iter().any(|x| {
+			match_this_event!(
+				x,
+				DutchAuction,
+				dutch_auction::Event::<_>::XcmSellResponseSent {
+					remote_order_id: 13,
+					state: XcmSellOrderState::Open,
+					..
+				}
+			)
+		}));
+		let order_id = this_runtime::DutchAuction::xcm_sell_orders(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.expect("remote order is mapped to local");
+		assert_ok!(this_runtime::DutchAuction::liquidate(
+			this_runtime::RuntimeOrigin::signed(sibling.clone()),
+			order_id,
+		));
+		assert!(this_runtime::System::events().iter().any(|x| {
+			match_this_event!(
+				x,
+				DutchAuction,
+				dutch_auction::Event::<_>::XcmSellResponseSent {
+					remote_order_id: 13,
+					state: XcmSellOrderState::Expired,
+					..
+				}
+			)
+		}));
+		assert!(this_runtime::DutchAuction::xcm_sell_order_statuses(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.is_none());
+	});
+}
+
+#[test]
+fn xcm_sell_reports_fills_with_proceeds_to_sender() {
+	simtest();
+	let this_native_asset = CurrencyId::PICA;
+	let some_native_amount = 1_000_000_000;
+	let some_enough_weight = UnitWeightCost::get() * 10;
+	let configuration_id = 42;
+	let remote_order_id = 14;
+	let sibling = sibling_account(SIBLING_PARA_ID);
+	let taker = AccountId::from(ALICE);
+	let sell_amount = 100_000_000_000;
+	let price: u128 = 42;
+	let request = XcmSellRequest {
+		order_id: remote_order_id,
+		from_to: sibling.clone().into(),
+		order: Sell::new(
+			CurrencyId::PICA.0,
+			CurrencyId::kUSD.0,
+			sell_amount,
+			FixedU128::saturating_from_integer(price),
+		),
+		configuration: configuration_id,
+	};
+	let sell = this_runtime::RuntimeCall::DutchAuction(
+		dutch_auction::Call::<this_runtime::Runtime>::xcm_sell { request },
+	);
+
+	This::execute_with(|| {
+		assert_ok!(this_runtime::DutchAuction::add_configuration(
+			this_runtime::RuntimeOrigin::root(),
+			configuration_id,
+			TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 }),
+		));
+		assert_ok!(this_runtime::DutchAuction::add_xcm_callback_location(
+			this_runtime::RuntimeOrigin::root(),
+			SIBLING_PARA_ID.into(),
+			CumulusMethodId { pallet_instance: 61, method_id: 42 },
+		));
+		let _ = <balances::Pallet<this_runtime::Runtime> as frame_support::traits::Currency<
+			AccountId,
+		>>::deposit_creating(&sibling, enough_weight() * 1_000_000_000_000);
+		assert_ok!(this_runtime::Tokens::deposit(
+			CurrencyId::kUSD,
+			&taker,
+			sell_amount * price
+		));
+	});
+
+	Sibling::execute_with(|| {
+		let assets: MultiAsset = (
+			(Parent, X2(Parachain(THIS_PARA_ID), GeneralIndex(this_native_asset.into()))),
+			some_native_amount,
+		)
+			.into();
+		assert_ok!(pallet_xcm::Pallet::<sibling_runtime::Runtime>::send_xcm(
+			Here,
+			(Parent, Parachain(THIS_PARA_ID)),
+			Xcm(vec![
+				WithdrawAsset(assets.clone().into()),
+				BuyExecution { fees: assets, weight_limit: Unlimited },
+				Transact {
+					origin_type: OriginKind::Native,
+					require_weight_at_most: some_enough_weight,
+					call: sell.encode().into(),
+				},
+			]),
+		));
+	});
+
+	let take_and_finalize = |amount: u128| {
+		let order_id = this_runtime::DutchAuction::xcm_sell_orders(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.expect("remote order is mapped to local");
+		assert_ok!(this_runtime::DutchAuction::take(
+			this_runtime::RuntimeOrigin::signed(taker.clone()),
+			order_id,
+			Take::new(amount, FixedU128::saturating_from_integer(price)),
+		));
+		<this_runtime::DutchAuction as frame_support::traits::Hooks<_>>::on_finalize(
+			this_runtime::System::block_number(),
+		);
+	};
+
+	This::execute_with(|| {
+		take_and_finalize(sell_amount / 2);
+
+		assert!(this_runtime::System::events().iter().any(|x| {
+			match_this_event!(
+				x,
+				DutchAuction,
+				dutch_auction::Event::<_>::XcmSellResponseSent {
+					remote_order_id: 14,
+					state: XcmSellOrderState::PartiallyFilled,
+					..
+				}
+			)
+		}));
+		let status = this_runtime::DutchAuction::xcm_sell_order_statuses(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.expect("status of partially filled order is kept");
+		assert_eq!(status.state, XcmSellOrderState::PartiallyFilled);
+		assert_eq!(status.total_amount_taken, sell_amount / 2);
+		assert_eq!(status.total_amount_received, sell_amount / 2 * price);
+		assert_eq!(status.remaining_amount, sell_amount / 2);
+
+		take_and_finalize(sell_amount / 2);
+
+		assert!(this_runtime::System::events().iter().any(|x| {
+			match_this_event!(
+				x,
+				DutchAuction,
+				dutch_auction::Event::<_>::XcmSellResponseSent {
+					remote_order_id: 14,
+					state: XcmSellOrderState::Filled,
+					..
+				}
+			)
+		}));
+		assert!(this_runtime::DutchAuction::xcm_sell_order_statuses(
+			ParaId::from(SIBLING_PARA_ID),
+			remote_order_id,
+		)
+		.is_none());
+	});
+}
@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+use composable_traits::airdrop::{AirdropFunds, RecipientClaimStatus};
+
+// Airdrop Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait AirdropRuntimeApi<AirdropId, Balance, Identity>
+	where
+		AirdropId: Codec,
+		Balance: Codec,
+		Identity: Codec,
+	{
+		/// Retrieve the claimed and unclaimed funds of an Airdrop.
+		fn airdrop_funds(airdrop_id: AirdropId) -> Option<AirdropFunds<SafeRpcWrapper<Balance>>>;
+
+		/// Retrieve the claim status of a recipient of an Airdrop.
+		fn recipient_claim_status(
+			airdrop_id: AirdropId,
+			identity: Identity,
+		) -> Option<RecipientClaimStatus<SafeRpcWrapper<Balance>>>;
+	}
+}
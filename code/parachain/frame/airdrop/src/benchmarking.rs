@@ -2,15 +2,17 @@
 
 use super::*;
 use crate::{
-	models::Proof, AccountIdOf, Call, Config, IdentityOf, Pallet as Airdrop, Pallet, ProofOf,
+	merkle::{self, MerkleTree},
+	models::{MerkleDistribution, MerkleLeaf, MerkleProof, PayoutMode, Proof},
+	AccountIdOf, Call, Config, IdentityOf, MerkleLeafOf, Pallet as Airdrop, Pallet, ProofOf,
 };
 use composable_support::{
 	signature_verification,
 	types::{CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress},
 };
-use composable_traits::airdrop::Airdropper;
+use composable_traits::{airdrop::Airdropper, time::ONE_MONTH};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::Time};
 use frame_system::{Pallet as System, RawOrigin};
 use multihash::{Hasher, Keccak256, Sha2_256};
 use p256::ecdsa::{signature::Signer, SigningKey, VerifyingKey};
@@ -187,18 +189,19 @@ benchmarks! {
 		where
 			T: Config<RelayChainAccountId = [u8; 32]>,
 			BalanceOf<T>: From<u128>,
+			T::RewardPoolId: From<u128>,
 	}
 
 	create_airdrop_benchmark {
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-	}: create_airdrop(RawOrigin::Signed(creator), None, VESTING_STEP.into())
+	}: create_airdrop(RawOrigin::Signed(creator), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)
 
 	add_recipient_benchmark {
 		let x in 100..1000;
 		let accounts: Vec<(IdentityOf<T>, BalanceOf<T>, MomentOf<T>,bool)> = generate_accounts::<T>(x as _).into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
 		let airdrop_id = T::AirdropId::one();
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into())?;
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
 	}: add_recipient(RawOrigin::Signed(creator), airdrop_id, accounts)
 
 	remove_recipient_benchmark {
@@ -206,7 +209,7 @@ benchmarks! {
 		let accounts: Vec<(IdentityOf<T>, BalanceOf<T>, MomentOf<T>,bool)> = generate_accounts::<T>(x as _).into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
 		let airdrop_id = T::AirdropId::one();
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into())?;
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
 		<Airdrop<T> as Airdropper>::add_recipient(creator.clone(), airdrop_id, accounts.clone())?;
 	}: remove_recipient(RawOrigin::Signed(creator), airdrop_id, accounts[0].0.clone())
 
@@ -215,7 +218,7 @@ benchmarks! {
 		let accounts: Vec<(IdentityOf<T>, BalanceOf<T>, MomentOf<T>,bool)> = generate_accounts::<T>(x as _).into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
 		let airdrop_id = T::AirdropId::one();
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into())?;
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
 		<Airdrop<T> as Airdropper>::add_recipient(creator.clone(), airdrop_id, accounts)?;
 	}: enable_airdrop(RawOrigin::Signed(creator), airdrop_id)
 
@@ -224,7 +227,7 @@ benchmarks! {
 		let accounts: Vec<(IdentityOf<T>, BalanceOf<T>, MomentOf<T>,bool)> = generate_accounts::<T>(x as _).into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
 		let airdrop_id = T::AirdropId::one();
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into())?;
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
 		<Airdrop<T> as Airdropper>::add_recipient(creator.clone(), airdrop_id, accounts)?;
 	}: disable_airdrop(RawOrigin::Signed(creator), airdrop_id)
 
@@ -234,11 +237,87 @@ benchmarks! {
 		let remote_accounts = accounts.clone().into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
 		let airdrop_id = T::AirdropId::one();
 		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
-		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into())?;
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
 		<Airdrop<T> as Airdropper>::add_recipient(creator, airdrop_id, remote_accounts)?;
 		let reward_account = accounts[0].0.clone();
 		System::<T>::set_block_number(VESTING_PERIOD.into());
-	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()))
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), None)
+
+	claim_staked_benchmark {
+		let x in 100..1000;
+		let accounts = generate_accounts::<T>(x as _);
+		let remote_accounts = accounts.clone().into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Stake { pool_id: 1_u128.into(), duration_preset: ONE_MONTH })?;
+		<Airdrop<T> as Airdropper>::add_recipient(creator, airdrop_id, remote_accounts)?;
+		let reward_account = accounts[0].0.clone();
+		System::<T>::set_block_number(VESTING_PERIOD.into());
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), None)
+
+	claim_vested_benchmark {
+		let x in 100..1000;
+		let accounts = generate_accounts::<T>(x as _);
+		let remote_accounts = accounts.clone().into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Vest { period: VESTING_STEP.into(), period_count: 4 })?;
+		<Airdrop<T> as Airdropper>::add_recipient(creator, airdrop_id, remote_accounts)?;
+		let reward_account = accounts[0].0.clone();
+		System::<T>::set_block_number(VESTING_PERIOD.into());
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), None)
+
+	sweep_benchmark {
+		let x in 100..1000;
+		let accounts: Vec<(IdentityOf<T>, BalanceOf<T>, MomentOf<T>,bool)> = generate_accounts::<T>(x as _).into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
+		<Airdrop<T> as Airdropper>::add_recipient(creator.clone(), airdrop_id, accounts)?;
+		Airdrops::<T>::mutate(airdrop_id, |airdrop| if let Some(airdrop) = airdrop { airdrop.expire_at = Some(T::Time::now()) });
+	}: sweep(RawOrigin::Signed(creator), airdrop_id)
+
+	claim_with_merkle_proof_benchmark {
+		let x in 100..1000;
+		let accounts = generate_accounts::<T>(x as _);
+		let leaves: Vec<MerkleLeafOf<T>> = accounts.iter().enumerate().map(|(index, (_, a))| MerkleLeaf { index: index as u32, identity: a.as_remote_public::<T>(), total: T::Balance::from(1_000_000_000_000), vesting_period: VESTING_PERIOD.into(), funded_claim: false }).collect();
+		let tree = MerkleTree::new(leaves.iter().map(merkle::leaf_hash).collect());
+		let merkle_distribution = MerkleDistribution { root: tree.root().unwrap(), total_funds: T::Balance::from(1_000_000_000_000 * x as u128), total_recipients: x };
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator, None, VESTING_STEP.into(), Some(merkle_distribution), None, PayoutMode::Liquid)?;
+		let merkle_proof = MerkleProof { index: 0, total: T::Balance::from(1_000_000_000_000), vesting_period: VESTING_PERIOD.into(), funded_claim: false, path: tree.proof(0).unwrap().try_into().unwrap() };
+		let reward_account = accounts[0].0.clone();
+		System::<T>::set_block_number(VESTING_PERIOD.into());
+	}: claim(RawOrigin::None, airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), Some(merkle_proof))
+
+	relay_claim_benchmark {
+		let x in 100..1000;
+		let accounts = generate_accounts::<T>(x as _);
+		let remote_accounts = accounts.clone().into_iter().map(|(_, a)| (a.as_remote_public::<T>(), T::Balance::from(1_000_000_000_000), VESTING_PERIOD.into(), false)).collect();
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		let relayer: AccountIdOf<T> = account("relayer", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator.clone(), None, VESTING_STEP.into(), None, None, PayoutMode::Liquid)?;
+		<Airdrop<T> as Airdropper>::add_recipient(creator, airdrop_id, remote_accounts)?;
+		let reward_account = accounts[0].0.clone();
+		System::<T>::set_block_number(VESTING_PERIOD.into());
+	}: relay_claim(RawOrigin::Signed(relayer), airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), None)
+
+	relay_claim_with_merkle_proof_benchmark {
+		let x in 100..1000;
+		let accounts = generate_accounts::<T>(x as _);
+		let leaves: Vec<MerkleLeafOf<T>> = accounts.iter().enumerate().map(|(index, (_, a))| MerkleLeaf { index: index as u32, identity: a.as_remote_public::<T>(), total: T::Balance::from(1_000_000_000_000), vesting_period: VESTING_PERIOD.into(), funded_claim: false }).collect();
+		let tree = MerkleTree::new(leaves.iter().map(merkle::leaf_hash).collect());
+		let merkle_distribution = MerkleDistribution { root: tree.root().unwrap(), total_funds: T::Balance::from(1_000_000_000_000 * x as u128), total_recipients: x };
+		let airdrop_id = T::AirdropId::one();
+		let creator: AccountIdOf<T> = account("creator", 0, 0xCAFEBABE);
+		let relayer: AccountIdOf<T> = account("relayer", 0, 0xCAFEBABE);
+		<Airdrop<T> as Airdropper>::create_airdrop(creator, None, VESTING_STEP.into(), Some(merkle_distribution), None, PayoutMode::Liquid)?;
+		let merkle_proof = MerkleProof { index: 0, total: T::Balance::from(1_000_000_000_000), vesting_period: VESTING_PERIOD.into(), funded_claim: false, path: tree.proof(0).unwrap().try_into().unwrap() };
+		let reward_account = accounts[0].0.clone();
+		System::<T>::set_block_number(VESTING_PERIOD.into());
+	}: relay_claim(RawOrigin::Signed(relayer), airdrop_id, reward_account, accounts[0].1.clone().proof::<T>(accounts[0].0.clone()), Some(merkle_proof))
 }
 
 impl_benchmark_test_suite!(
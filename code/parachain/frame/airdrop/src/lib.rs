@@ -3,6 +3,7 @@
 
 pub use pallet::*;
 
+pub mod merkle;
 pub mod models;
 pub mod weights;
 
@@ -15,7 +16,11 @@ mod tests;
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::{
-		models::{Airdrop, AirdropState, Identity, Proof, RecipientFund},
+		merkle,
+		models::{
+			Airdrop, AirdropState, Identity, MerkleDistribution, MerkleLeaf, MerkleProof,
+			PayoutMode, Proof, RecipientFund,
+		},
 		weights::WeightInfo,
 	};
 	use codec::{Codec, FullCodec, MaxEncodedLen};
@@ -27,10 +32,18 @@ pub mod pallet {
 				start_at::ZeroInit,
 			},
 		},
+		claims::{
+			identity::IdentityVerifier,
+			relay::RelayedClaimQuota,
+			vesting::{ClaimAccount, VestingSchedule},
+		},
 		math::safe::{SafeAdd, SafeSub},
-		signature_verification,
 	};
-	use composable_traits::airdrop::Airdropper;
+	use composable_traits::{
+		airdrop::{AirdropFunds, Airdropper, RecipientClaimStatus},
+		staking::{ProtocolStaking, Staking},
+		vesting::{VestedTransfer, VestingCurve, VestingScheduleInfo, VestingWindow},
+	};
 	use frame_support::{
 		dispatch::PostDispatchInfo,
 		pallet_prelude::*,
@@ -42,12 +55,13 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use scale_info::TypeInfo;
+	use sp_core::H256;
 	use sp_runtime::{
 		traits::{
 			AccountIdConversion, AtLeast32Bit, AtLeast32BitUnsigned, CheckedAdd, CheckedMul,
 			CheckedSub, Convert, One, Saturating, Zero,
 		},
-		AccountId32, DispatchErrorWithPostInfo,
+		AccountId32, DispatchErrorWithPostInfo, Perbill,
 	};
 	use sp_std::{fmt::Debug, vec::Vec};
 
@@ -60,6 +74,7 @@ pub mod pallet {
 		<T as frame_system::Config>::AccountId,
 		<T as Config>::Balance,
 		<T as Config>::Moment,
+		<T as Config>::RewardPoolId,
 	>;
 	/// [`Balance`](Config::Balance) as configured by the pallet.
 	pub type BalanceOf<T> = <T as Config>::Balance;
@@ -70,6 +85,15 @@ pub mod pallet {
 	/// ['Proof'](crate::models::Proof) as configured by the pallet
 	pub type ProofOf<T> = Proof<<T as Config>::RelayChainAccountId>;
 	pub type IdentityOf<T> = Identity<<T as Config>::RelayChainAccountId>;
+	/// [`MerkleDistribution`](crate::models::MerkleDistribution) as configured by the pallet.
+	pub type MerkleDistributionOf<T> = MerkleDistribution<<T as Config>::Balance>;
+	/// [`MerkleProof`](crate::models::MerkleProof) as configured by the pallet.
+	pub type MerkleProofOf<T> = MerkleProof<<T as Config>::Balance, <T as Config>::Moment>;
+	/// [`MerkleLeaf`](crate::models::MerkleLeaf) as configured by the pallet.
+	pub type MerkleLeafOf<T> =
+		MerkleLeaf<IdentityOf<T>, <T as Config>::Balance, <T as Config>::Moment>;
+	/// [`PayoutMode`](crate::models::PayoutMode) as configured by the pallet.
+	pub type PayoutModeOf<T> = PayoutMode<<T as Config>::RewardPoolId, <T as Config>::Moment>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -78,6 +102,12 @@ pub mod pallet {
 			airdrop_id: T::AirdropId,
 			by: T::AccountId,
 		},
+		MerkleRootCommitted {
+			airdrop_id: T::AirdropId,
+			root: H256,
+			total_funds: T::Balance,
+			total_recipients: u32,
+		},
 		RecipientsAdded {
 			airdrop_id: T::AirdropId,
 			number: u32,
@@ -96,11 +126,21 @@ pub mod pallet {
 			airdrop_id: T::AirdropId,
 			at: T::Moment,
 		},
+		AirdropSwept {
+			airdrop_id: T::AirdropId,
+			unclaimed_funds: T::Balance,
+		},
 		Claimed {
 			identity: IdentityOf<T>,
 			recipient_account: T::AccountId,
 			amount: T::Balance,
 		},
+		ClaimRelayed {
+			airdrop_id: T::AirdropId,
+			relayer: T::AccountId,
+			recipient_account: T::AccountId,
+			fee: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -117,6 +157,15 @@ pub mod pallet {
 		RecipientNotFound,
 		InvalidProof,
 		UnclaimedFundsRemaining,
+		InvalidMerkleProof,
+		MerkleLeafAlreadyClaimed,
+		NotMerkleAirdrop,
+		RecipientsCommittedByMerkleRoot,
+		AirdropNotExpired,
+		ExpiresBeforeStart,
+		InvalidPayoutMode,
+		RelayedClaimQuotaExceeded,
+		SponsorshipNotFunded,
 	}
 
 	#[pallet::config]
@@ -163,6 +212,13 @@ pub mod pallet {
 			+ Into<AccountId32>
 			+ Ord;
 
+		/// Verifier of the proofs associating recipient accounts with remote identities.
+		type IdentityVerifier: IdentityVerifier<
+			Self::AccountId,
+			Proof = ProofOf<Self>,
+			Identity = IdentityOf<Self>,
+		>;
+
 		/// The asset type Recipients will claim from the Airdrops.
 		type RecipientFundAsset: Inspect<Self::AccountId, Balance = Self::Balance>
 			+ Transfer<Self::AccountId, Balance = Self::Balance>;
@@ -170,6 +226,31 @@ pub mod pallet {
 		/// Time provider
 		type Time: Time<Moment = Self::Moment>;
 
+		/// ID of the reward pools recipients can be staked in.
+		type RewardPoolId: Parameter + MaxEncodedLen;
+
+		/// Staking used to pay out claims of Airdrops in [`PayoutMode::Stake`].
+		type ProtocolStaking: Staking<
+				AccountId = Self::AccountId,
+				Balance = Self::Balance,
+				RewardPoolId = Self::RewardPoolId,
+			> + ProtocolStaking<
+				AccountId = Self::AccountId,
+				AssetId = <Self::Vesting as VestedTransfer>::AssetId,
+				Balance = Self::Balance,
+				RewardPoolId = Self::RewardPoolId,
+			>;
+
+		/// Vesting used to pay out claims of Airdrops in [`PayoutMode::Vest`].
+		type Vesting: VestedTransfer<
+			AccountId = Self::AccountId,
+			Balance = Self::Balance,
+			Moment = Self::Moment,
+		>;
+
+		/// ID of the `RecipientFundAsset` in `Vesting` and `ProtocolStaking`.
+		type RecipientFundAssetId: Get<<Self::Vesting as VestedTransfer>::AssetId>;
+
 		/// The pallet ID required for creating sub-accounts used by Airdrops.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
@@ -182,6 +263,17 @@ pub mod pallet {
 		#[pallet::constant]
 		type Stake: Get<BalanceOf<Self>>;
 
+		/// The origin allowed to relay claims on behalf of recipients.
+		type RelayerOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The fee paid from the Airdrop account to the relayer of a claim.
+		#[pallet::constant]
+		type RelayerFee: Get<BalanceOf<Self>>;
+
+		/// The maximum number of claims that can be relayed in a block.
+		#[pallet::constant]
+		type MaxRelayedClaimsPerBlock: Get<u32>;
+
 		/// The implementation of extrinsic weights.
 		type WeightInfo: WeightInfo;
 	}
@@ -234,6 +326,25 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Bitmaps of the leaves of Merkle trees that have been claimed, by words of 128 leaves.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // Allow `frame_support::pallet_prelude::ValueQuery` because default of 0 is correct
+	pub type ClaimedMerkleLeaves<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AirdropId,
+		Blake2_128Concat,
+		u32,
+		u128,
+		ValueQuery,
+	>;
+
+	/// The block of the last relayed claim and the number of claims relayed in it.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)] // Allow `frame_support::pallet_prelude::ValueQuery` because default of no claims is correct
+	pub type RelayedClaims<T: Config> =
+		StorageValue<_, RelayedClaimQuota<T::BlockNumber>, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new Airdrop. This requires that the user puts down a stake in PICA.
@@ -241,31 +352,61 @@ pub mod pallet {
 		/// If `start_at` is `Some(MomentOf<T>)` and the `MomentOf<T>` is greater than the current
 		/// block, the Airdrop will be scheduled to start automatically.
 		///
+		/// If `merkle_distribution` is provided, recipients are committed by its Merkle root
+		/// instead of being added with `add_recipient`, and its total funds are transferred from
+		/// the creator.
+		///
+		/// If `expire_at` is provided, recipients can no longer claim from that moment, and the
+		/// unclaimed funds can be returned to the creator with `sweep`.
+		///
+		/// `payout_mode` sets how claimed funds are paid out. Unless it is `Liquid`, recipients
+		/// claim their whole fund at once, and receive it as a staking position or a vesting
+		/// schedule instead.
+		///
 		/// Can be called by any signed origin.
 		///
 		/// # Parameter Sources
 		/// * `start_at` - user provided, optional
 		/// * `vesting_schedule` - user provided
+		/// * `merkle_distribution` - user provided, optional
+		/// * `expire_at` - user provided, optional
+		/// * `payout_mode` - user provided
 		///
 		/// # Emits
 		/// * `AirdropCreated`
+		/// * `MerkleRootCommitted`
 		/// * `AirdropStarted`
 		///
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `AirdropAlreadyStarted` - The Airdrop has already started or has been scheduled to
 		/// start
-		/// * `BackToTheFuture` - The provided `start` has already passed
+		/// * `BackToTheFuture` - The provided `start` or `expire_at` has already passed
+		/// * `ExpiresBeforeStart` - The provided `expire_at` is not after `start`
+		/// * `InvalidPayoutMode` - The provided `payout_mode` vests over no time
+		/// * Any error of `ProtocolStaking::ensure_stakeable` - The provided `payout_mode` stakes
+		///   in a pool that doesn't exist, doesn't stake the `RecipientFundAsset` or has no such
+		///   lock
 		#[pallet::weight(<T as Config>::WeightInfo::create_airdrop())]
 		#[transactional]
 		pub fn create_airdrop(
 			origin: OriginFor<T>,
 			start_at: Option<MomentOf<T>>,
 			vesting_schedule: MomentOf<T>,
+			merkle_distribution: Option<MerkleDistributionOf<T>>,
+			expire_at: Option<MomentOf<T>>,
+			payout_mode: PayoutModeOf<T>,
 		) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
 
-			<Self as Airdropper>::create_airdrop(creator, start_at, vesting_schedule)
+			<Self as Airdropper>::create_airdrop(
+				creator,
+				start_at,
+				vesting_schedule,
+				merkle_distribution,
+				expire_at,
+				payout_mode,
+			)
 		}
 
 		/// Add one or more recipients to the Airdrop, specifying the token amount that each
@@ -283,6 +424,7 @@ pub mod pallet {
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
+		/// * `RecipientsCommittedByMerkleRoot` - The recipients are committed by a Merkle root
 		#[pallet::weight(<T as Config>::WeightInfo::add_recipient(recipients.len() as u32))]
 		#[transactional]
 		pub fn add_recipient(
@@ -312,6 +454,7 @@ pub mod pallet {
 		/// * `NotAirdropCreator` - Signer of the origin is not the creator of the Airdrop
 		/// * `RecipientAlreadyClaimed` - The recipient has already began claiming their funds.
 		/// * `RecipientNotFound` - No recipient associated with the `identity` could be found.
+		/// * `RecipientsCommittedByMerkleRoot` - The recipients are committed by a Merkle root
 		#[pallet::weight(<T as Config>::WeightInfo::remove_recipient())]
 		#[transactional]
 		pub fn remove_recipient(
@@ -374,12 +517,16 @@ pub mod pallet {
 		///
 		/// If no more funds are left to claim, the Airdrop will be removed.
 		///
+		/// For Airdrops committing to their recipients with a Merkle root, the first claim of a
+		/// recipient must provide a `merkle_proof` of its fund. Later claims don't need one.
+		///
 		/// Callable by any unsigned origin.
 		///
 		/// # Parameter Sources
 		/// * `airdrop_id` - user selected, provided by the system
 		/// * `reward_account` - user provided
 		/// * `proof` - calculated by the system (requires applicable signing)
+		/// * `merkle_proof` - provided by the Airdrop creator, optional
 		///
 		/// # Emits
 		/// * `AirdropEnded`
@@ -390,16 +537,126 @@ pub mod pallet {
 		/// * `AssociatedWithAnotherAccount` - Associated with a different account
 		/// * `ArithmeticError` - Overflow while totaling claimed funds
 		/// * `InvalidProof`
+		/// * `InvalidMerkleProof` - The fund is not a leaf of the Merkle tree of the Airdrop
+		/// * `MerkleLeafAlreadyClaimed` - The leaf of the Merkle proof has already been claimed
+		/// * `NotMerkleAirdrop` - The Airdrop doesn't commit to its recipients with a Merkle root
 		/// * `RecipientNotFound` - No recipient associated with the `identity` could be found.
-		#[pallet::weight(<T as Config>::WeightInfo::claim(TotalAirdropRecipients::<T>::get(airdrop_id)))]
+		#[pallet::weight(match merkle_proof {
+			Some(_) => <T as Config>::WeightInfo::claim_with_merkle_proof(TotalAirdropRecipients::<T>::get(airdrop_id)),
+			None => <T as Config>::WeightInfo::claim(TotalAirdropRecipients::<T>::get(airdrop_id)),
+		}.saturating_add(Pallet::<T>::pay_out_weight(airdrop_id)))]
 		#[transactional]
 		pub fn claim(
 			origin: OriginFor<T>,
 			airdrop_id: T::AirdropId,
 			reward_account: T::AccountId,
 			proof: ProofOf<T>,
+			merkle_proof: Option<MerkleProofOf<T>>,
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
+			Self::claim_with_proof(airdrop_id, reward_account, proof, merkle_proof)
+		}
+
+		/// Claim recipient funds from an Airdrop on behalf of the recipient, see `claim`.
+		///
+		/// The relayer pays the transaction fees, whether the claim succeeds or not, and is paid
+		/// the `RelayerFee` from the Airdrop account when it does. The fee is only paid from
+		/// funds of the Airdrop account exceeding its unclaimed funds and the creation stake.
+		///
+		/// Callable by the `RelayerOrigin`.
+		///
+		/// # Parameter Sources
+		/// * `airdrop_id` - user selected, provided by the system
+		/// * `reward_account` - user provided
+		/// * `proof` - calculated by the system (requires applicable signing)
+		/// * `merkle_proof` - provided by the Airdrop creator, optional
+		///
+		/// # Emits
+		/// * `ClaimRelayed`
+		/// * `AirdropEnded`
+		///
+		/// # Errors
+		/// * `RelayedClaimQuotaExceeded` - `MaxRelayedClaimsPerBlock` claims have already been
+		///   relayed in this block
+		/// * `SponsorshipNotFunded` - The Airdrop account can not pay the `RelayerFee` without
+		///   dipping into the unclaimed funds or the creation stake
+		/// * Any error of `claim`
+		#[pallet::weight(match merkle_proof {
+			Some(_) => <T as Config>::WeightInfo::relay_claim_with_merkle_proof(TotalAirdropRecipients::<T>::get(airdrop_id)),
+			None => <T as Config>::WeightInfo::relay_claim(TotalAirdropRecipients::<T>::get(airdrop_id)),
+		}.saturating_add(Pallet::<T>::pay_out_weight(airdrop_id)))]
+		#[transactional]
+		pub fn relay_claim(
+			origin: OriginFor<T>,
+			airdrop_id: T::AirdropId,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+			merkle_proof: Option<MerkleProofOf<T>>,
+		) -> DispatchResult {
+			let relayer = T::RelayerOrigin::ensure_origin(origin)?;
+
+			Self::use_relayed_claim_quota()?;
+			// The relayer is paid first, as the last claim of an Airdrop returns the remaining
+			// funds to its creator.
+			let fee = Self::pay_relayer(airdrop_id, &relayer)?;
+			Self::claim_with_proof(airdrop_id, reward_account.clone(), proof, merkle_proof)?;
+
+			Self::deposit_event(Event::ClaimRelayed {
+				airdrop_id,
+				relayer,
+				recipient_account: reward_account,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Return the unclaimed funds of an Airdrop that reached its claim deadline, along with
+		/// the creation stake, to its creator and remove the Airdrop.
+		///
+		/// Can be called by any signed origin.
+		///
+		/// # Parameter Sources
+		/// * `airdrop_id` - user selected, provided by the system
+		///
+		/// # Emits
+		/// * `AirdropSwept`
+		/// * `AirdropEnded`
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `AirdropNotExpired` - The Airdrop has no deadline or hasn't reached it yet
+		#[pallet::weight(<T as Config>::WeightInfo::sweep(TotalAirdropRecipients::<T>::get(airdrop_id)))]
+		#[transactional]
+		pub fn sweep(origin: OriginFor<T>, airdrop_id: T::AirdropId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			<Self as Airdropper>::sweep(airdrop_id)?;
+			Ok(())
+		}
+	}
+
+	#[pallet::extra_constants]
+	impl<T: Config> Pallet<T> {
+		/// The AccountId of this pallet.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Claims recipient funds after associating the `reward_account` with the identity
+		/// proven by `proof`, registering the recipient of a Merkle proof if provided.
+		///
+		/// # Errors
+		/// * `AssociatedWithAnotherAccount` - Associated with a different account
+		/// * `InvalidProof`
+		/// * Any error of `register_merkle_recipient` and `Airdropper::claim`
+		pub(crate) fn claim_with_proof(
+			airdrop_id: T::AirdropId,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+			merkle_proof: Option<MerkleProofOf<T>>,
+		) -> DispatchResultWithPostInfo {
 			let identity = Self::get_identity(proof, &reward_account, T::Prefix::get())?;
 
 			match Associations::<T>::get(airdrop_id, reward_account.clone()) {
@@ -416,21 +673,58 @@ pub mod pallet {
 				},
 			}
 
+			if let Some(merkle_proof) = merkle_proof {
+				Self::register_merkle_recipient(airdrop_id, &identity, merkle_proof)?;
+			}
+
 			<Self as Airdropper>::claim(airdrop_id, identity, reward_account)
 		}
-	}
 
-	#[pallet::extra_constants]
-	impl<T: Config> Pallet<T> {
-		/// The AccountId of this pallet.
-		pub fn account_id() -> T::AccountId {
-			T::PalletId::get().into_account_truncating()
+		/// Counts a relayed claim against the `MaxRelayedClaimsPerBlock` of the current block.
+		///
+		/// # Errors
+		/// * `RelayedClaimQuotaExceeded` - The quota of the current block has been reached
+		pub(crate) fn use_relayed_claim_quota() -> DispatchResult {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				RelayedClaims::<T>::mutate(|quota| {
+					quota.try_use(current_block, T::MaxRelayedClaimsPerBlock::get())
+				}),
+				Error::<T>::RelayedClaimQuotaExceeded
+			);
+			Ok(())
+		}
+
+		/// Pays the `RelayerFee` to `relayer` from the funds of the Airdrop account exceeding
+		/// its unclaimed funds and the creation stake.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `SponsorshipNotFunded` - The Airdrop account can not pay the fee
+		pub(crate) fn pay_relayer(
+			airdrop_id: T::AirdropId,
+			relayer: &T::AccountId,
+		) -> Result<T::Balance, DispatchError> {
+			let airdrop = Self::get_airdrop(&airdrop_id)?;
+			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
+			let reserved_funds = airdrop
+				.total_funds
+				.saturating_sub(airdrop.claimed_funds)
+				.saturating_add(T::Stake::get());
+			let fee = T::RelayerFee::get();
+
+			ensure!(
+				T::RecipientFundAsset::balance(&airdrop_account).saturating_sub(reserved_funds) >=
+					fee,
+				Error::<T>::SponsorshipNotFunded
+			);
+			T::RecipientFundAsset::transfer(&airdrop_account, relayer, fee, false)?;
+
+			Ok(fee)
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
 		/// Gets the account ID to be used by the Airdrop.
-		pub(crate) fn get_airdrop_account_id(airdrop_id: T::AirdropId) -> AccountIdOf<T> {
+		pub fn get_airdrop_account_id(airdrop_id: T::AirdropId) -> AccountIdOf<T> {
 			T::PalletId::get().into_sub_account_truncating(airdrop_id)
 		}
 
@@ -451,7 +745,7 @@ pub mod pallet {
 		) -> Result<AirdropState, Error<T>> {
 			let airdrop = Self::get_airdrop(&airdrop_id)?;
 
-			if airdrop.disabled {
+			if airdrop.disabled || Self::is_expired(&airdrop) {
 				return Ok(AirdropState::Disabled)
 			}
 
@@ -464,6 +758,11 @@ pub mod pallet {
 			})
 		}
 
+		/// Whether an Airdrop has reached its claim deadline.
+		pub(crate) fn is_expired(airdrop: &AirdropOf<T>) -> bool {
+			airdrop.expire_at.map_or(false, |expire_at| expire_at <= T::Time::now())
+		}
+
 		/// Gets the [`RecipientFund`](crate::models::RecipientFund) of an Airdrop that is
 		/// associated with the `identity`.
 		///
@@ -486,44 +785,8 @@ pub mod pallet {
 			reward_account: &<T as frame_system::Config>::AccountId,
 			prefix: &[u8],
 		) -> Result<IdentityOf<T>, DispatchErrorWithPostInfo<PostDispatchInfo>> {
-			let identity = match proof {
-				Proof::Ethereum(eth_proof) => {
-					let reward_account_encoded =
-						reward_account.using_encoded(signature_verification::get_encoded_vec);
-					let eth_address = signature_verification::ethereum_recover(
-						prefix,
-						&reward_account_encoded,
-						&eth_proof,
-					)
-					.map_err(|_| Error::<T>::InvalidProof)?;
-					Result::<_, DispatchError>::Ok(Identity::Ethereum(eth_address))
-				},
-				Proof::RelayChain(relay_account, relay_proof) => {
-					ensure!(
-						signature_verification::verify_relay(
-							prefix,
-							reward_account.clone(),
-							relay_account.clone().into(),
-							&relay_proof
-						),
-						Error::<T>::InvalidProof
-					);
-					Ok(Identity::RelayChain(relay_account))
-				},
-				Proof::Cosmos(cosmos_address, cosmos_proof) => {
-					let reward_account_encoded =
-						reward_account.using_encoded(signature_verification::get_encoded_vec);
-					let cosmos_address = signature_verification::cosmos_recover(
-						prefix,
-						&reward_account_encoded,
-						cosmos_address,
-						&cosmos_proof,
-					)
-					.map_err(|_| Error::<T>::InvalidProof)?;
-					Result::<_, DispatchError>::Ok(Identity::Cosmos(cosmos_address))
-				},
-			}?;
-			Ok(identity)
+			T::IdentityVerifier::verify(prefix, reward_account, proof)
+				.map_err(|_| Error::<T>::InvalidProof.into())
 		}
 
 		/// Start an Airdrop at a given moment.
@@ -561,7 +824,8 @@ pub mod pallet {
 		/// Calculates the amount of the total fund that a recipient should have claimed.
 		///
 		/// The amount that should have been claimed is proportional to the number of **full**
-		/// vesting steps passed.
+		/// vesting steps passed. Airdrops not paying out liquid funds release the whole fund as
+		/// soon as they start.
 		///
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
@@ -573,29 +837,220 @@ pub mod pallet {
 			let airdrop = Airdrops::<T>::get(airdrop_id).ok_or(Error::<T>::AirdropDoesNotExist)?;
 			let airdrop_state = Self::get_airdrop_state(airdrop_id)?;
 			match (airdrop_state, airdrop.start) {
+				(AirdropState::Enabled, _) if airdrop.payout_mode != PayoutMode::Liquid =>
+					Ok(fund.total),
 				(AirdropState::Enabled, Some(start)) => {
-					let now = T::Time::now();
-					let vesting_point = now.saturating_sub(start);
+					let schedule = VestingSchedule {
+						start,
+						step: airdrop.schedule,
+						initial_payment: Perbill::zero(),
+					};
+					Ok(schedule.vested::<_, T::Convert>(
+						fund.total,
+						fund.vesting_period,
+						T::Time::now(),
+					))
+				},
+				_ => Err(Error::<T>::AirdropIsNotEnabled),
+			}
+		}
 
-					// If the vesting period is over, the recipient should receive the remainder of
-					// the fund
-					if vesting_point >= fund.vesting_period {
-						return Ok(fund.total)
-					}
+		/// Checks that the recipient fund of a Merkle proof is an unclaimed leaf of the Merkle tree
+		/// of an Airdrop, returning the leaf.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `InvalidMerkleProof` - The fund is not a leaf of the Merkle tree of the Airdrop
+		/// * `MerkleLeafAlreadyClaimed` - The leaf of the Merkle proof has already been claimed
+		/// * `NotMerkleAirdrop` - The Airdrop doesn't commit to its recipients with a Merkle root
+		/// * `RecipientAlreadyClaimed` - The identity is already a recipient of the Airdrop
+		pub(crate) fn check_merkle_proof(
+			airdrop_id: T::AirdropId,
+			identity: &IdentityOf<T>,
+			merkle_proof: MerkleProofOf<T>,
+		) -> Result<MerkleLeafOf<T>, Error<T>> {
+			let airdrop = Self::get_airdrop(&airdrop_id)?;
+			let root = airdrop.merkle_root.ok_or(Error::<T>::NotMerkleAirdrop)?;
+			ensure!(merkle_proof.index < airdrop.total_recipients, Error::<T>::InvalidMerkleProof);
+
+			let leaf = MerkleLeafOf::<T> {
+				index: merkle_proof.index,
+				identity: identity.clone(),
+				total: merkle_proof.total,
+				vesting_period: merkle_proof.vesting_period,
+				funded_claim: merkle_proof.funded_claim,
+			};
+			ensure!(
+				merkle::verify(&root, merkle::leaf_hash(&leaf), &merkle_proof.path),
+				Error::<T>::InvalidMerkleProof
+			);
+			ensure!(
+				!Self::is_merkle_leaf_claimed(airdrop_id, leaf.index),
+				Error::<T>::MerkleLeafAlreadyClaimed
+			);
+			ensure!(
+				!RecipientFunds::<T>::contains_key(airdrop_id, identity),
+				Error::<T>::RecipientAlreadyClaimed
+			);
 
-					// The current vesting window rounded to the previous window
-					let vesting_window =
-						vesting_point.saturating_sub(vesting_point % airdrop.schedule);
+			Ok(leaf)
+		}
 
-					let claimable = fund.total.saturating_mul(T::Convert::convert(vesting_window)) /
-						T::Convert::convert(fund.vesting_period);
+		/// Adds the recipient fund of a Merkle proof to an Airdrop, marking its leaf as claimed.
+		///
+		/// # Errors
+		/// See [`check_merkle_proof`](Self::check_merkle_proof).
+		pub(crate) fn register_merkle_recipient(
+			airdrop_id: T::AirdropId,
+			identity: &IdentityOf<T>,
+			merkle_proof: MerkleProofOf<T>,
+		) -> DispatchResult {
+			let leaf = Self::check_merkle_proof(airdrop_id, identity, merkle_proof)?;
 
-					Ok(claimable)
+			ClaimedMerkleLeaves::<T>::mutate(airdrop_id, leaf.index / 128, |word| {
+				*word |= 1_u128 << (leaf.index % 128);
+			});
+			RecipientFunds::<T>::insert(
+				airdrop_id,
+				identity,
+				RecipientFundOf::<T> {
+					total: leaf.total,
+					claimed: T::Balance::zero(),
+					vesting_period: leaf.vesting_period,
+					funded_claim: leaf.funded_claim,
 				},
-				_ => Err(Error::<T>::AirdropIsNotEnabled),
+			);
+
+			Ok(())
+		}
+
+		/// Pays `amount` claimed from an Airdrop out to `reward_account` as set by the payout
+		/// mode of the Airdrop.
+		///
+		/// Vested payouts release an equal share every period, the remainder of dividing `amount`
+		/// by the number of periods is paid out liquid. So is the whole `amount` if it would vest
+		/// less than the `MinVestedTransfer` of `Vesting`.
+		pub(crate) fn pay_out(
+			payout_mode: &PayoutModeOf<T>,
+			airdrop_account: &AccountIdOf<T>,
+			reward_account: &AccountIdOf<T>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			match payout_mode {
+				PayoutMode::Liquid => {
+					T::RecipientFundAsset::transfer(
+						airdrop_account,
+						reward_account,
+						amount,
+						false,
+					)?;
+				},
+				PayoutMode::Stake { pool_id, duration_preset } => {
+					T::RecipientFundAsset::transfer(
+						airdrop_account,
+						reward_account,
+						amount,
+						false,
+					)?;
+					T::ProtocolStaking::stake(
+						reward_account,
+						pool_id,
+						amount,
+						*duration_preset,
+						false,
+					)?;
+				},
+				PayoutMode::Vest { period, period_count } => {
+					let per_period = amount / T::Balance::from(*period_count);
+					let mut vested = per_period.saturating_mul((*period_count).into());
+					if vested.is_zero() ||
+						vested < <T::Vesting as VestedTransfer>::MinVestedTransfer::get()
+					{
+						vested = T::Balance::zero();
+					}
+					let remainder = amount.saturating_sub(vested);
+
+					if !vested.is_zero() {
+						T::Vesting::vested_transfer(
+							T::RecipientFundAssetId::get(),
+							airdrop_account,
+							reward_account,
+							VestingScheduleInfo {
+								window: VestingWindow::MomentBased {
+									start: T::Time::now(),
+									period: *period,
+								},
+								period_count: *period_count,
+								per_period,
+								cliff: 0,
+								curve: VestingCurve::Linear,
+								revoker: None,
+							},
+						)?;
+					}
+
+					if !remainder.is_zero() {
+						T::RecipientFundAsset::transfer(
+							airdrop_account,
+							reward_account,
+							remainder,
+							false,
+						)?;
+					}
+				},
+			}
+
+			Ok(())
+		}
+
+		/// Extra weight of claiming from an Airdrop staking or vesting the claimed funds, over
+		/// claiming liquid funds.
+		pub(crate) fn pay_out_weight(airdrop_id: &T::AirdropId) -> Weight {
+			let total_recipients = TotalAirdropRecipients::<T>::get(airdrop_id);
+			let liquid = <T as Config>::WeightInfo::claim(total_recipients);
+			match Airdrops::<T>::get(airdrop_id).map(|airdrop| airdrop.payout_mode) {
+				Some(PayoutMode::Stake { .. }) =>
+					<T as Config>::WeightInfo::claim_staked(total_recipients).saturating_sub(liquid),
+				Some(PayoutMode::Vest { .. }) =>
+					<T as Config>::WeightInfo::claim_vested(total_recipients).saturating_sub(liquid),
+				Some(PayoutMode::Liquid) | None => Weight::zero(),
 			}
 		}
 
+		/// Claimed and unclaimed funds of an Airdrop, `None` if the Airdrop doesn't exist.
+		pub fn airdrop_funds(airdrop_id: T::AirdropId) -> Option<AirdropFunds<T::Balance>> {
+			let airdrop = Airdrops::<T>::get(airdrop_id)?;
+
+			Some(AirdropFunds {
+				total: airdrop.total_funds,
+				claimed: airdrop.claimed_funds,
+				unclaimed: airdrop.total_funds.saturating_sub(airdrop.claimed_funds),
+			})
+		}
+
+		/// Claim status of a recipient of an Airdrop, `None` if the Airdrop doesn't exist or
+		/// `identity` isn't one of its recipients.
+		///
+		/// Recipients of Airdrops committed by a Merkle root are only known after their first
+		/// claim.
+		pub fn recipient_claim_status(
+			airdrop_id: T::AirdropId,
+			identity: IdentityOf<T>,
+		) -> Option<RecipientClaimStatus<T::Balance>> {
+			let fund = RecipientFunds::<T>::get(airdrop_id, identity)?;
+			let claimable = Self::claimable(airdrop_id, &fund).map_or_else(
+				|_| T::Balance::zero(),
+				|claimable| claimable.saturating_sub(fund.claimed),
+			);
+
+			Some(RecipientClaimStatus { total: fund.total, claimed: fund.claimed, claimable })
+		}
+
+		/// Whether the leaf at `index` of the Merkle tree of an Airdrop has been claimed.
+		pub fn is_merkle_leaf_claimed(airdrop_id: T::AirdropId, index: u32) -> bool {
+			ClaimedMerkleLeaves::<T>::get(airdrop_id, index / 128) & (1_u128 << (index % 128)) != 0
+		}
+
 		/// Removes an Airdrop and associated data from the pallet iff all funds have been recorded
 		/// as claimed.
 		///
@@ -626,6 +1081,8 @@ pub mod pallet {
 			RecipientFunds::<T>::remove_prefix(airdrop_id, None);
 			#[allow(deprecated)]
 			Associations::<T>::remove_prefix(airdrop_id, None);
+			#[allow(deprecated)]
+			ClaimedMerkleLeaves::<T>::remove_prefix(airdrop_id, None);
 			Airdrops::<T>::remove(airdrop_id);
 
 			Ok(true)
@@ -642,21 +1099,59 @@ pub mod pallet {
 		type RecipientCollection = Vec<(Self::Recipient, BalanceOf<T>, MomentOf<T>, bool)>;
 		type Identity = IdentityOf<T>;
 		type VestingSchedule = MomentOf<T>;
+		type MerkleDistribution = MerkleDistributionOf<T>;
+		type PayoutMode = PayoutModeOf<T>;
 
 		/// Create a new Airdrop.
 		///
 		/// Provide `None` for `start` if starting the Airdrop manually is desired.
 		///
+		/// If a `merkle_distribution` is provided, the creator funds the Airdrop with its total
+		/// funds, and recipients are added on their first claim.
+		///
+		/// Provide `None` for `expire_at` if recipients should be able to claim indefinitely.
+		///
+		/// Provide [`PayoutMode::Liquid`] for `payout_mode` to pay claims out along the vesting
+		/// `schedule` of the Airdrop.
+		///
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `AirdropAlreadyStarted` - The Airdrop has already started or has been scheduled to
 		/// start
-		/// * `BackToTheFuture` - The provided `start` has already passed
+		/// * `BackToTheFuture` - The provided `start` or `expire_at` has already passed
+		/// * `ExpiresBeforeStart` - The provided `expire_at` is not after `start`
+		/// * `InvalidPayoutMode` - The provided `payout_mode` vests over no time
+		/// * Any error of `ProtocolStaking::ensure_stakeable` - The provided `payout_mode` stakes
+		///   in a pool that doesn't exist, doesn't stake the `RecipientFundAsset` or has no such
+		///   lock
 		fn create_airdrop(
 			creator_id: Self::AccountId,
 			start: Option<Self::AirdropStart>,
 			schedule: Self::VestingSchedule,
+			merkle_distribution: Option<Self::MerkleDistribution>,
+			expire_at: Option<Self::AirdropStart>,
+			payout_mode: Self::PayoutMode,
 		) -> DispatchResult {
+			match &payout_mode {
+				PayoutMode::Liquid => {},
+				PayoutMode::Stake { pool_id, duration_preset } =>
+					T::ProtocolStaking::ensure_stakeable(
+						pool_id,
+						T::RecipientFundAssetId::get(),
+						*duration_preset,
+					)?,
+				PayoutMode::Vest { period, period_count } =>
+					ensure!(!period.is_zero() && *period_count > 0, Error::<T>::InvalidPayoutMode),
+			}
+
+			if let Some(expire_at) = expire_at {
+				ensure!(expire_at > T::Time::now(), Error::<T>::BackToTheFuture);
+				ensure!(
+					start.map_or(true, |start| expire_at > start),
+					Error::<T>::ExpiresBeforeStart
+				);
+			}
+
 			let airdrop_id = AirdropCount::<T>::increment()?;
 			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
 
@@ -671,13 +1166,41 @@ pub mod pallet {
 					start: None,
 					schedule,
 					disabled: false,
+					merkle_root: None,
+					expire_at,
+					payout_mode,
 				},
 			);
 
 			// Transfer stake into airdrop specific account.
 			T::RecipientFundAsset::transfer(&creator_id, &airdrop_account, T::Stake::get(), false)?;
 
-			Self::deposit_event(Event::AirdropCreated { airdrop_id, by: creator_id });
+			Self::deposit_event(Event::AirdropCreated { airdrop_id, by: creator_id.clone() });
+
+			if let Some(MerkleDistribution { root, total_funds, total_recipients }) =
+				merkle_distribution
+			{
+				// Fund all the recipients committed by the root upfront
+				T::RecipientFundAsset::transfer(&creator_id, &airdrop_account, total_funds, false)?;
+
+				Airdrops::<T>::try_mutate(airdrop_id, |airdrop| match airdrop.as_mut() {
+					Some(airdrop) => {
+						airdrop.total_funds = total_funds;
+						airdrop.total_recipients = total_recipients;
+						airdrop.merkle_root = Some(root);
+						Ok(())
+					},
+					None => Err(Error::<T>::AirdropDoesNotExist),
+				})?;
+				TotalAirdropRecipients::<T>::insert(airdrop_id, total_recipients);
+
+				Self::deposit_event(Event::MerkleRootCommitted {
+					airdrop_id,
+					root,
+					total_funds,
+					total_recipients,
+				});
+			}
 
 			if let Some(moment) = start {
 				Self::start_airdrop_at(airdrop_id, moment)?;
@@ -704,6 +1227,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let airdrop = Self::get_airdrop(&airdrop_id)?;
 			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(airdrop.merkle_root.is_none(), Error::<T>::RecipientsCommittedByMerkleRoot);
 
 			// Calculate total funds and recipients local to this transaction
 			let (transaction_funds, transaction_recipients) = recipients.iter().try_fold(
@@ -789,6 +1313,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let airdrop = Self::get_airdrop(&airdrop_id)?;
 			ensure!(airdrop.creator == origin_id, Error::<T>::NotAirdropCreator);
+			ensure!(airdrop.merkle_root.is_none(), Error::<T>::RecipientsCommittedByMerkleRoot);
 
 			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
 			let recipient_fund = Self::get_recipient_fund(airdrop_id, recipient.clone())?;
@@ -895,8 +1420,40 @@ pub mod pallet {
 			unclaimed_funds
 		}
 
+		/// Return the unclaimed funds of an expired Airdrop to its creator.
+		///
+		/// Returns the amount of unclaimed funds from the airdrop upon success.
+		///
+		/// # Errors
+		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
+		/// * `AirdropNotExpired` - The Airdrop has no deadline or hasn't reached it yet
+		fn sweep(airdrop_id: Self::AirdropId) -> Result<Self::Balance, DispatchError> {
+			let airdrop = Self::get_airdrop(&airdrop_id)?;
+			ensure!(Self::is_expired(&airdrop), Error::<T>::AirdropNotExpired);
+
+			let unclaimed_funds = airdrop.total_funds.saturating_sub(airdrop.claimed_funds);
+			Airdrops::<T>::try_mutate(airdrop_id, |airdrop| match airdrop.as_mut() {
+				Some(airdrop) => {
+					// Sets claimed funds equal to total funds so the airdrop can be pruned
+					airdrop.disabled = true;
+					airdrop.claimed_funds = airdrop.total_funds;
+					Ok(())
+				},
+				None => Err(Error::<T>::AirdropDoesNotExist),
+			})?;
+
+			Self::prune_airdrop(airdrop_id)?;
+
+			Self::deposit_event(Event::AirdropSwept { airdrop_id, unclaimed_funds });
+			Self::deposit_event(Event::AirdropEnded { airdrop_id, at: T::Time::now() });
+
+			Ok(unclaimed_funds)
+		}
+
 		/// Claim a recipient reward from an Airdrop.
 		///
+		/// The claimed funds are paid out as set by the payout mode of the Airdrop.
+		///
 		/// # Errors
 		/// * `AirdropDoesNotExist` - No Airdrop exist that is associated 'airdrop_id'
 		/// * `AirdropIsNotEnabled` - The Airdrop has not been enabled
@@ -907,6 +1464,7 @@ pub mod pallet {
 			identity: Self::Identity,
 			reward_account: Self::AccountId,
 		) -> DispatchResultWithPostInfo {
+			let airdrop = Self::get_airdrop(&airdrop_id)?;
 			let airdrop_account = Self::get_airdrop_account_id(airdrop_id);
 			let (available_to_claim, recipient_fund) =
 				RecipientFunds::<T>::try_mutate(airdrop_id, identity, |fund| {
@@ -921,7 +1479,7 @@ pub mod pallet {
 							);
 
 							// Update Airdrop and fund status
-							fund.claimed = fund.claimed.saturating_add(available_to_claim);
+							fund.record_claim(available_to_claim);
 
 							Ok((available_to_claim, *fund))
 						},
@@ -929,11 +1487,11 @@ pub mod pallet {
 					}
 				})?;
 
-			T::RecipientFundAsset::transfer(
+			Self::pay_out(
+				&airdrop.payout_mode,
 				&airdrop_account,
 				&reward_account,
 				available_to_claim,
-				false,
 			)?;
 
 			Airdrops::<T>::try_mutate(airdrop_id, |airdrop| match airdrop.as_mut() {
@@ -965,13 +1523,13 @@ pub mod pallet {
 	/// * The Airdrop has been enabled / has started
 	/// * The provided proof is valid
 	/// * If an association has been created for the reward account, it matches the remote account
-	/// * The recipient has funds to claim
+	/// * The recipient has funds to claim, or a valid Merkle proof of unclaimed funds
 	#[pallet::validate_unsigned]
 	impl<T: Config> ValidateUnsigned for Pallet<T> {
 		type Call = Call<T>;
 
 		fn validate_unsigned(_: TransactionSource, call: &Self::Call) -> TransactionValidity {
-			if let Call::claim { airdrop_id, reward_account, proof } = call {
+			if let Call::claim { airdrop_id, reward_account, proof, merkle_proof } = call {
 				// Validity Error if the airdrop does not exist
 				let airdrop_state = Self::get_airdrop_state(*airdrop_id).map_err(|_| {
 					Into::<TransactionValidityError>::into(InvalidTransaction::Custom(
@@ -1001,11 +1559,33 @@ pub mod pallet {
 					}
 				}
 
+				// Validity Error if the Merkle proof doesn't prove unclaimed funds
+				if let Some(merkle_proof) = merkle_proof {
+					return match Self::check_merkle_proof(
+						*airdrop_id,
+						&identity,
+						merkle_proof.clone(),
+					) {
+						Ok(leaf) if !leaf.total.is_zero() =>
+							ValidTransaction::with_tag_prefix("AirdropAssociationCheck")
+								.and_provides(identity)
+								.build(),
+						Ok(_) => InvalidTransaction::Custom(ValidityError::NoFunds as u8).into(),
+						Err(_) =>
+							InvalidTransaction::Custom(ValidityError::InvalidProof as u8).into(),
+					}
+				}
+
 				// Validity Error if there are no funds for this recipient
 				match RecipientFunds::<T>::get(airdrop_id, identity.clone()) {
 					None => InvalidTransaction::Custom(ValidityError::NoFunds as u8).into(),
 					Some(fund) if fund.total.is_zero() =>
 						InvalidTransaction::Custom(ValidityError::NoFunds as u8).into(),
+					// Free claims must pay something out
+					Some(fund)
+						if Self::claimable(*airdrop_id, &fund)
+							.map_or(true, |claimable| claimable <= fund.claimed) =>
+						InvalidTransaction::Custom(ValidityError::NotClaimable as u8).into(),
 					Some(_) => ValidTransaction::with_tag_prefix("AirdropAssociationCheck")
 						.and_provides(identity)
 						.build(),
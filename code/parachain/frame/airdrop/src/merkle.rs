@@ -0,0 +1,76 @@
+//! Merkle trees committing to the recipients of an Airdrop.
+//!
+//! Leaves are hashed as `blake2_256(0x00 ++ SCALE(leaf))` and nodes as
+//! `blake2_256(0x01 ++ min(a, b) ++ max(a, b))`. As the children of a node are sorted, proofs
+//! don't need to record on which side each sibling is. A node without a sibling is promoted to
+//! the next level of the tree unchanged.
+
+use codec::Encode;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::{vec, vec::Vec};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// Hashes a leaf of the tree.
+pub fn leaf_hash<Leaf: Encode>(leaf: &Leaf) -> H256 {
+	let mut input = vec![LEAF_PREFIX];
+	leaf.encode_to(&mut input);
+	blake2_256(&input).into()
+}
+
+/// Hashes two sibling nodes into their parent node.
+pub fn node_hash(a: &H256, b: &H256) -> H256 {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	blake2_256(&[&[NODE_PREFIX][..], left.as_bytes(), right.as_bytes()].concat()).into()
+}
+
+/// Checks that `proof` proves the inclusion of `leaf` in the tree of `root`.
+pub fn verify(root: &H256, leaf: H256, proof: &[H256]) -> bool {
+	proof.iter().fold(leaf, |node, sibling| node_hash(&node, sibling)) == *root
+}
+
+/// A Merkle tree, built off-chain to commit to the recipients of an Airdrop.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleTree {
+	/// Levels of the tree, from the leaves up to the root.
+	levels: Vec<Vec<H256>>,
+}
+
+impl MerkleTree {
+	/// Builds the tree of the hashed `leaves`.
+	pub fn new(leaves: Vec<H256>) -> Self {
+		let mut levels = vec![leaves];
+		while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+			let parents = level
+				.chunks(2)
+				.filter_map(|pair| pair.iter().copied().reduce(|a, b| node_hash(&a, &b)))
+				.collect();
+			levels.push(parents);
+		}
+
+		Self { levels }
+	}
+
+	/// The root of the tree, `None` if the tree has no leaves.
+	pub fn root(&self) -> Option<H256> {
+		self.levels.last().and_then(|level| level.first()).copied()
+	}
+
+	/// The proof of inclusion of the leaf at `index`, `None` if there is no such leaf.
+	pub fn proof(&self, index: usize) -> Option<Vec<H256>> {
+		self.levels.first().filter(|leaves| index < leaves.len())?;
+
+		let mut index = index;
+		let mut proof = Vec::new();
+		for level in &self.levels {
+			if let Some(sibling) = level.get(index ^ 1) {
+				proof.push(*sibling);
+			}
+			index /= 2;
+		}
+
+		Some(proof)
+	}
+}
@@ -2,23 +2,34 @@
 use crate::{self as pallet_airdrop, models::Proof};
 use codec::Encode;
 use composable_support::{
+	claims::identity::MultiChainVerifier,
 	signature_verification,
-	types::{EcdsaSignature, EthereumAddress},
+	types::{EcdsaSignature, Ed25519PublicKey, Ed25519Signature, EthereumAddress},
+};
+use composable_traits::{
+	staking::{ProtocolStaking, Staking},
+	time::{DurationSeconds, ONE_MONTH},
+	vesting::{VestedTransfer, VestingScheduleInfo},
 };
 use frame_support::{
-	construct_runtime, dispatch::DispatchResultWithPostInfo, parameter_types, traits::Everything,
+	construct_runtime,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	parameter_types,
+	traits::{fungible::Transfer, ConstU128, Everything},
 	PalletId,
 };
 use frame_system as system;
 use sp_core::{ed25519, keccak_256, Pair, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	AccountId32,
+	AccountId32, DispatchError, Perbill, Permill,
 };
-use sp_std::vec::Vec;
+use sp_std::{cell::RefCell, vec::Vec};
+use system::EnsureSigned;
 
 pub type EthereumKey = libsecp256k1::SecretKey;
 pub type RelayChainKey = ed25519::Pair;
+pub type Ed25519Key = ed25519::Pair;
 
 pub type AccountId = AccountId32;
 pub type AirdropId = u64;
@@ -26,9 +37,18 @@ pub type Balance = u128;
 pub type BlockNumber = u32;
 pub type Moment = u64;
 pub type RelayChainAccountId = [u8; 32];
+pub type AssetId = u128;
+pub type RewardPoolId = u128;
+pub type PositionId = u128;
 
 pub const PROOF_PREFIX: &[u8] = b"picasso-";
 pub const STAKE: Balance = 10_000;
+pub const STAKING_ACCOUNT: AccountId = AccountId32::new([0xff_u8; 32]);
+pub const RECIPIENT_FUND_ASSET_ID: AssetId = 1;
+pub const MIN_VESTED_TRANSFER: Balance = 1_000;
+pub const RELAYER: AccountId = AccountId32::new([0xfe_u8; 32]);
+pub const RELAYER_FEE: Balance = 10;
+pub const MAX_RELAYED_CLAIMS_PER_BLOCK: u32 = 10;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MockRuntime>;
 type Block = frame_system::mocking::MockBlock<MockRuntime>;
@@ -83,6 +103,9 @@ parameter_types! {
 	pub const AirdropPalletId: PalletId = PalletId(*b"pal_aird");
 	pub const Prefix: &'static [u8] = PROOF_PREFIX;
 	pub const Stake: Balance = STAKE;
+	pub const RelayerFee: Balance = RELAYER_FEE;
+	pub const MaxRelayedClaimsPerBlock: u32 = MAX_RELAYED_CLAIMS_PER_BLOCK;
+	pub const RecipientFundAssetId: AssetId = RECIPIENT_FUND_ASSET_ID;
 }
 
 impl pallet_airdrop::Config for MockRuntime {
@@ -92,11 +115,19 @@ impl pallet_airdrop::Config for MockRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type Moment = Moment;
 	type RelayChainAccountId = RelayChainAccountId;
+	type IdentityVerifier = MultiChainVerifier<RelayChainAccountId>;
 	type RecipientFundAsset = Balances;
 	type Time = Timestamp;
+	type RewardPoolId = RewardPoolId;
+	type ProtocolStaking = MockStaking;
+	type Vesting = MockVesting;
+	type RecipientFundAssetId = RecipientFundAssetId;
 	type PalletId = AirdropPalletId;
 	type Prefix = Prefix;
 	type Stake = Stake;
+	type RelayerOrigin = EnsureSigned<AccountId>;
+	type RelayerFee = RelayerFee;
+	type MaxRelayedClaimsPerBlock = MaxRelayedClaimsPerBlock;
 	type WeightInfo = ();
 }
 
@@ -124,6 +155,168 @@ construct_runtime!(
 	}
 );
 
+/// A staked position, as recorded by [`MockStaking`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockPosition {
+	pub owner: AccountId,
+	pub pool_id: RewardPoolId,
+	pub amount: Balance,
+	pub duration_preset: DurationSeconds,
+}
+
+/// A vesting schedule, as recorded by [`MockVesting`].
+pub type MockSchedule = (AccountId, VestingScheduleInfo<BlockNumber, Moment, Balance, AccountId>);
+
+thread_local! {
+	pub static POSITIONS: RefCell<Vec<MockPosition>> = RefCell::new(Vec::new());
+	pub static SCHEDULES: RefCell<Vec<MockSchedule>> = RefCell::new(Vec::new());
+}
+
+/// Staking that moves the stake to [`STAKING_ACCOUNT`] and records the position.
+///
+/// Pools `1` and `2` exist, stake the asset they are identified by and lock for [`ONE_MONTH`].
+pub struct MockStaking;
+
+impl ProtocolStaking for MockStaking {
+	type AccountId = AccountId;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type RewardPoolId = RewardPoolId;
+
+	fn transfer_reward(
+		_from: &Self::AccountId,
+		_pool_id: &Self::RewardPoolId,
+		_reward_currency: Self::AssetId,
+		_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn slash(
+		_pool_id: &Self::RewardPoolId,
+		_ratio: Perbill,
+		_beneficiary: &Self::AccountId,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn ensure_stakeable(
+		pool_id: &Self::RewardPoolId,
+		asset: Self::AssetId,
+		duration_preset: DurationSeconds,
+	) -> DispatchResult {
+		match *pool_id {
+			1 | 2 if *pool_id != asset => Err(DispatchError::Other("staked asset mismatch")),
+			1 | 2 if duration_preset != ONE_MONTH =>
+				Err(DispatchError::Other("duration preset not found")),
+			1 | 2 => Ok(()),
+			_ => Err(DispatchError::Other("pool not found")),
+		}
+	}
+}
+
+impl Staking for MockStaking {
+	type AccountId = AccountId;
+	type RewardPoolId = RewardPoolId;
+	type Balance = Balance;
+	type PositionId = PositionId;
+
+	fn stake(
+		who: &Self::AccountId,
+		pool_id: &Self::RewardPoolId,
+		amount: Self::Balance,
+		duration_preset: DurationSeconds,
+		keep_alive: bool,
+	) -> Result<Self::PositionId, DispatchError> {
+		<Balances as Transfer<AccountId>>::transfer(who, &STAKING_ACCOUNT, amount, keep_alive)?;
+		Ok(POSITIONS.with(|positions| {
+			let mut positions = positions.borrow_mut();
+			positions.push(MockPosition {
+				owner: who.clone(),
+				pool_id: *pool_id,
+				amount,
+				duration_preset,
+			});
+			positions.len() as PositionId - 1
+		}))
+	}
+
+	fn extend(
+		_who: &Self::AccountId,
+		_position: Self::PositionId,
+		_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn unstake(_who: &Self::AccountId, _position: &Self::PositionId) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn split(
+		_who: &Self::AccountId,
+		_position: &Self::PositionId,
+		_ratio: Permill,
+	) -> Result<Self::PositionId, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn merge(
+		_who: &Self::AccountId,
+		_position: &Self::PositionId,
+		_other: &Self::PositionId,
+	) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn claim(_who: &Self::AccountId, _position: &Self::PositionId) -> DispatchResult {
+		Err(DispatchError::Other("unsupported"))
+	}
+}
+
+/// Vesting that transfers the vested amount and records the schedule.
+pub struct MockVesting;
+
+impl VestedTransfer for MockVesting {
+	type AccountId = AccountId;
+	type AssetId = AssetId;
+	type BlockNumber = BlockNumber;
+	type Moment = Moment;
+	type Balance = Balance;
+	type MinVestedTransfer = ConstU128<MIN_VESTED_TRANSFER>;
+	type VestingScheduleId = u128;
+	type VestingScheduleNonce = u64;
+
+	fn vested_transfer(
+		_asset: Self::AssetId,
+		from: &Self::AccountId,
+		to: &Self::AccountId,
+		schedule: VestingScheduleInfo<
+			Self::BlockNumber,
+			Self::Moment,
+			Self::Balance,
+			Self::AccountId,
+		>,
+	) -> DispatchResult {
+		let total = schedule.per_period * schedule.period_count as Balance;
+		if total < MIN_VESTED_TRANSFER {
+			return Err(DispatchError::Other("amount low"))
+		}
+		<Balances as Transfer<AccountId>>::transfer(from, to, total, false)?;
+		SCHEDULES.with(|schedules| schedules.borrow_mut().push((to.clone(), schedule)));
+		Ok(())
+	}
+
+	fn claim(
+		_asset: Self::AssetId,
+		_who: &Self::AccountId,
+	) -> Result<Self::Balance, DispatchError> {
+		Ok(0)
+	}
+}
+
 #[derive(Default)]
 pub struct ExtBuilder {
 	pub(crate) balances: Vec<(AccountId, Balance)>,
@@ -145,6 +338,8 @@ impl ExtBuilder {
 pub enum Identity {
 	Relay(RelayChainKey),
 	Eth(EthereumKey),
+	Ed25519(Ed25519Key),
+	EthTypedData(EthereumKey),
 }
 
 impl Identity {
@@ -152,8 +347,10 @@ impl Identity {
 		match self {
 			Identity::Relay(relay_account) =>
 				crate::models::Identity::RelayChain(relay_account.public().into()),
-			Identity::Eth(eth_account) =>
+			Identity::Eth(eth_account) | Identity::EthTypedData(eth_account) =>
 				crate::models::Identity::Ethereum(ethereum_address(eth_account)),
+			Identity::Ed25519(ed25519_account) =>
+				crate::models::Identity::Ed25519(Ed25519PublicKey(ed25519_account.public().0)),
 		}
 	}
 
@@ -161,6 +358,8 @@ impl Identity {
 		match self {
 			Identity::Relay(relay) => relay_proof(&relay, reward_account),
 			Identity::Eth(eth) => ethereum_proof(&eth, reward_account),
+			Identity::Ed25519(ed25519) => ed25519_proof(&ed25519, reward_account),
+			Identity::EthTypedData(eth) => ethereum_typed_data_proof(&eth, reward_account),
 		}
 	}
 
@@ -169,13 +368,21 @@ impl Identity {
 		airdrop_id: AirdropId,
 		reward_account: AccountId,
 	) -> DispatchResultWithPostInfo {
-		let proof = match self {
-			Identity::Relay(relay_account) => relay_proof(relay_account, reward_account.clone()),
-			Identity::Eth(ethereum_account) =>
-				ethereum_proof(ethereum_account, reward_account.clone()),
-		};
+		let proof = self.clone().proof(reward_account.clone());
+
+		Airdrop::claim(RuntimeOrigin::none(), airdrop_id, reward_account, proof, None)
+	}
 
-		Airdrop::claim(RuntimeOrigin::none(), airdrop_id, reward_account, proof)
+	pub fn relay_claim(&self, airdrop_id: AirdropId, reward_account: AccountId) -> DispatchResult {
+		let proof = self.clone().proof(reward_account.clone());
+
+		Airdrop::relay_claim(
+			RuntimeOrigin::signed(RELAYER),
+			airdrop_id,
+			reward_account,
+			proof,
+			None,
+		)
 	}
 }
 
@@ -209,6 +416,35 @@ pub fn ethereum_proof(
 	Proof::Ethereum(EcdsaSignature(recovered_signature))
 }
 
+fn ed25519_proof(
+	ed25519_account: &Ed25519Key,
+	reward_account: AccountId,
+) -> Proof<RelayChainAccountId> {
+	let mut msg = PROOF_PREFIX.to_vec();
+	msg.append(&mut reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()));
+	Proof::Ed25519(
+		Ed25519PublicKey(ed25519_account.public().0),
+		Ed25519Signature(ed25519_account.sign(&msg).0),
+	)
+}
+
+pub fn ethereum_typed_data_proof(
+	ethereum_account: &EthereumKey,
+	reward_account: AccountId,
+) -> Proof<RelayChainAccountId> {
+	let msg = signature_verification::ethereum_typed_data_hash(
+		PROOF_PREFIX,
+		&reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()),
+	);
+	let (sig, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), ethereum_account);
+	let mut recovered_signature = [0_u8; 65];
+
+	recovered_signature[0..64].copy_from_slice(&sig.serialize()[..]);
+	recovered_signature[64] = recovery_id.serialize();
+	Proof::EthereumTypedData(EcdsaSignature(recovered_signature))
+}
+
 pub fn ethereum_public(secret: &EthereumKey) -> libsecp256k1::PublicKey {
 	libsecp256k1::PublicKey::from_secret_key(secret)
 }
@@ -252,6 +488,41 @@ pub fn ethereum_generate(count: u64) -> Vec<(AccountId, Identity)> {
 		.collect()
 }
 
+#[allow(clippy::disallowed_methods)] // Allow unwrap
+pub fn ed25519_generate(count: u64) -> Vec<(AccountId, Identity)> {
+	(0..count)
+		.map(|i| {
+			let account_id =
+				[[1_u8; 16], (&(i as u128 + 1)).to_le_bytes()].concat().try_into().unwrap();
+			(
+				AccountId::new(account_id),
+				Identity::Ed25519(ed25519::Pair::from_seed(&keccak_256(
+					&[b"ed25519".to_vec(), i.to_le_bytes().to_vec()].concat(),
+				))),
+			)
+		})
+		.collect()
+}
+
+#[allow(clippy::disallowed_methods)] // Allow unwrap
+pub fn ethereum_typed_data_generate(count: u64) -> Vec<(AccountId, Identity)> {
+	(0..count)
+		.map(|i| {
+			let account_id =
+				[(&(i as u128 + 1)).to_le_bytes(), [1_u8; 16]].concat().try_into().unwrap();
+			(
+				AccountId::new(account_id),
+				Identity::EthTypedData(
+					EthereumKey::parse(&keccak_256(
+						&[b"eip712".to_vec(), i.to_le_bytes().to_vec()].concat(),
+					))
+					.unwrap(),
+				),
+			)
+		})
+		.collect()
+}
+
 /// `count % 2 == 0` should hold for all x
 pub fn generate_accounts(count: u64) -> Vec<(AccountId, Identity)> {
 	assert!(count % 2 == 0, "`x % 2 == 0` should hold for all x");
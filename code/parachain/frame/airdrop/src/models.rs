@@ -1,13 +1,24 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use composable_support::types::{
-	CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress,
-};
+use composable_support::claims::vesting::ClaimAccount;
+use composable_traits::time::DurationSeconds;
+use frame_support::{traits::ConstU32, BoundedVec};
 use scale_info::TypeInfo;
-use sp_runtime::{MultiSignature, RuntimeDebug};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{AtLeast32Bit, AtLeast32BitUnsigned, Saturating},
+	RuntimeDebug,
+};
+
+/// Proof that a remote account owns a local recipient account, and the remote account it
+/// proves.
+pub use composable_support::claims::identity::{Proof, RemoteAccount as Identity};
+
+/// Maximum length of a Merkle proof, supporting up to `2^32` recipients.
+pub type MaxMerkleProofLength = ConstU32<32>;
 
 /// A single Airdrop.
 #[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, TypeInfo, MaxEncodedLen)]
-pub struct Airdrop<AccountId, Balance, Moment> {
+pub struct Airdrop<AccountId, Balance, Moment, RewardPoolId> {
 	/// Creator of the Airdrop.
 	pub creator: AccountId,
 	/// Total funds committed to the Airdrop.
@@ -22,6 +33,70 @@ pub struct Airdrop<AccountId, Balance, Moment> {
 	pub schedule: Moment,
 	/// Set `true` if an airdrop has been explicitly disabled.
 	pub disabled: bool,
+	/// Root of the Merkle tree of recipient funds, if recipients are committed by a root instead
+	/// of being added on-chain.
+	pub merkle_root: Option<H256>,
+	/// Deadline of the Airdrop, after which recipients can no longer claim and unclaimed funds
+	/// can be swept back to the creator.
+	pub expire_at: Option<Moment>,
+	/// How claimed funds are paid out to recipients.
+	pub payout_mode: PayoutMode<RewardPoolId, Moment>,
+}
+
+/// How the funds claimed from an Airdrop are paid out to its recipients.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum PayoutMode<RewardPoolId, Moment> {
+	/// Funds are transferred to the recipient as they vest with the Airdrop schedule.
+	Liquid,
+	/// The whole recipient fund is claimed at once and staked in `pool_id` on behalf of the
+	/// recipient, locked for `duration_preset` to earn the reward multiplier of that lock.
+	Stake { pool_id: RewardPoolId, duration_preset: DurationSeconds },
+	/// The whole recipient fund is claimed at once as a vesting schedule of the recipient,
+	/// releasing an equal share every `period` over `period_count` periods.
+	Vest { period: Moment, period_count: u32 },
+}
+
+/// Recipients of an Airdrop committed by the root of a Merkle tree of [`MerkleLeaf`]s.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct MerkleDistribution<Balance> {
+	/// Root of the Merkle tree.
+	pub root: H256,
+	/// Sum of the funds of all the leaves.
+	pub total_funds: Balance,
+	/// Number of leaves.
+	pub total_recipients: u32,
+}
+
+/// Leaf of the Merkle tree of an Airdrop, committing to the fund of a recipient.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct MerkleLeaf<Identity, Balance, Period> {
+	/// Position of the leaf in the tree, used to track claimed leaves.
+	pub index: u32,
+	/// Remote account of the recipient.
+	pub identity: Identity,
+	/// Total funds committed for this recipient.
+	pub total: Balance,
+	/// The minimum time, in blocks, between recipient claims.
+	pub vesting_period: Period,
+	/// If claims by this user will be funded by an external pool.
+	pub funded_claim: bool,
+}
+
+/// Proof that a recipient fund is a leaf of the Merkle tree of an Airdrop.
+///
+/// The identity of the leaf is the one proven by the accompanying [`Proof`].
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct MerkleProof<Balance, Period> {
+	/// Position of the leaf in the tree.
+	pub index: u32,
+	/// Total funds committed for this recipient.
+	pub total: Balance,
+	/// The minimum time, in blocks, between recipient claims.
+	pub vesting_period: Period,
+	/// If claims by this user will be funded by an external pool.
+	pub funded_claim: bool,
+	/// Siblings of the nodes on the path from the leaf to the root.
+	pub path: BoundedVec<H256, MaxMerkleProofLength>,
 }
 
 /// Funds, and related information, to be claimed by an Airdrop recipient.
@@ -37,6 +112,31 @@ pub struct RecipientFund<Balance, Period> {
 	pub funded_claim: bool,
 }
 
+impl<Balance, Period> ClaimAccount for RecipientFund<Balance, Period>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	Period: AtLeast32Bit + Copy,
+{
+	type Balance = Balance;
+	type Moment = Period;
+
+	fn total(&self) -> Balance {
+		self.total
+	}
+
+	fn claimed(&self) -> Balance {
+		self.claimed
+	}
+
+	fn vesting_period(&self) -> Period {
+		self.vesting_period
+	}
+
+	fn record_claim(&mut self, amount: Balance) {
+		self.claimed = self.claimed.saturating_add(amount);
+	}
+}
+
 /// Current State of an [`Airdrop`](Airdrop).
 #[derive(Debug, Encode, Decode, PartialEq, Eq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub enum AirdropState {
@@ -44,22 +144,7 @@ pub enum AirdropState {
 	Created,
 	/// The Airdrop has started. Recipients can claim funds.
 	Enabled,
-	/// The Airdrop has ended. Recipients can **NOT** claim funds.
+	/// The Airdrop has ended, either explicitly or by reaching its deadline. Recipients can
+	/// **NOT** claim funds.
 	Disabled,
 }
-
-/// Proof that a remote account owns a local recipient account.
-#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum Proof<AccountId> {
-	RelayChain(AccountId, MultiSignature),
-	Ethereum(EcdsaSignature),
-	Cosmos(CosmosPublicKey, CosmosEcdsaSignature),
-}
-
-/// Remote account that is associated with a local account.
-#[derive(Hash, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum Identity<AccountId> {
-	RelayChain(AccountId),
-	Ethereum(EthereumAddress),
-	Cosmos(CosmosPublicKey),
-}
@@ -1,10 +1,11 @@
 use crate::{
 	mocks::{
-		ethereum_address, generate_accounts, AccountId, Airdrop, AirdropId, Balance, Balances,
-		EthereumKey, ExtBuilder, Identity, MockRuntime, Moment, RuntimeOrigin, System, Timestamp,
-		PROOF_PREFIX, STAKE,
+		ed25519_generate, ethereum_address, ethereum_generate, ethereum_typed_data_generate,
+		generate_accounts, AccountId, Airdrop, AirdropId, Balance, Balances, EthereumKey,
+		ExtBuilder, Identity, MockRuntime, Moment, RuntimeOrigin, System, Timestamp, PROOF_PREFIX,
+		STAKE,
 	},
-	models::AirdropState,
+	models::{AirdropState, PayoutMode},
 	Error,
 };
 use codec::Encode;
@@ -15,6 +16,7 @@ use composable_support::{
 use composable_tests_helpers::prop_assert_ok;
 use frame_support::{
 	assert_noop, assert_ok,
+	dispatch::DispatchResultWithPostInfo,
 	traits::{fungible::Inspect, Currency},
 };
 use hex_literal::hex;
@@ -47,7 +49,25 @@ fn with_recipients<R>(
 	vesting_period: Moment,
 	execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
 ) -> R {
-	let accounts = generate_accounts(count as _);
+	with_accounts(
+		generate_accounts(count as _),
+		reward,
+		funded_claim,
+		vesting_schedule,
+		vesting_period,
+		execute,
+	)
+}
+
+fn with_accounts<R>(
+	accounts: Vec<(AccountId, Identity)>,
+	reward: Balance,
+	funded_claim: bool,
+	vesting_schedule: Moment,
+	vesting_period: Moment,
+	execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
+) -> R {
+	let count = accounts.len() as u128;
 	let recipients = accounts
 		.iter()
 		.map(|(_, account)| (account.as_remote_public(), reward, vesting_period, funded_claim))
@@ -61,7 +81,14 @@ fn with_recipients<R>(
 
 		Balances::make_free_balance_be(&CREATOR, STAKE + reward * count);
 
-		assert_ok!(Airdrop::create_airdrop(creator.clone(), Some(start_moment), vesting_schedule));
+		assert_ok!(Airdrop::create_airdrop(
+			creator.clone(),
+			Some(start_moment),
+			vesting_schedule,
+			None,
+			None,
+			PayoutMode::Liquid
+		));
 		assert_ok!(Airdrop::add_recipient(creator, AirdropId::from(1_u32), recipients));
 
 		execute(&set_moment, accounts)
@@ -95,7 +122,14 @@ mod create_airdrop {
 		ExtBuilder::default().build().execute_with(|| {
 			Balances::make_free_balance_be(&CREATOR, STAKE);
 
-			assert_ok!(Airdrop::create_airdrop(creator, start, vesting_schedule));
+			assert_ok!(Airdrop::create_airdrop(
+				creator,
+				start,
+				vesting_schedule,
+				None,
+				None,
+				PayoutMode::Liquid
+			));
 			assert_eq!(1, Airdrop::airdrop_count());
 			assert_eq!(STAKE, Balances::balance(&Airdrop::get_airdrop_account_id(1)));
 		})
@@ -111,7 +145,14 @@ mod create_airdrop {
 		ExtBuilder::default().build().execute_with(|| {
 			Balances::make_free_balance_be(&CREATOR, STAKE);
 
-			assert_ok!(Airdrop::create_airdrop(creator, start, vesting_schedule));
+			assert_ok!(Airdrop::create_airdrop(
+				creator,
+				start,
+				vesting_schedule,
+				None,
+				None,
+				PayoutMode::Liquid
+			));
 			assert_eq!(1, Airdrop::airdrop_count());
 			assert_eq!(STAKE, Balances::balance(&Airdrop::get_airdrop_account_id(1)));
 			assert_eq!(start, Airdrop::airdrops(1).unwrap().start);
@@ -129,7 +170,14 @@ mod create_airdrop {
 			Timestamp::set_timestamp(DEFAULT_VESTING_PERIOD * 3);
 
 			assert_noop!(
-				Airdrop::create_airdrop(creator, start, vesting_schedule),
+				Airdrop::create_airdrop(
+					creator,
+					start,
+					vesting_schedule,
+					None,
+					None,
+					PayoutMode::Liquid
+				),
 				Error::<MockRuntime>::BackToTheFuture
 			);
 			assert_eq!(0, Airdrop::airdrop_count());
@@ -164,7 +212,14 @@ mod add_recipient {
 		ExtBuilder::default().build().execute_with(|| {
 			Balances::make_free_balance_be(&CREATOR, STAKE);
 
-			assert_ok!(Airdrop::create_airdrop(creator, start, vesting_schedule));
+			assert_ok!(Airdrop::create_airdrop(
+				creator,
+				start,
+				vesting_schedule,
+				None,
+				None,
+				PayoutMode::Liquid
+			));
 			assert_noop!(
 				Airdrop::add_recipient(other, 1, recipients),
 				Error::<MockRuntime>::NotAirdropCreator
@@ -193,7 +248,14 @@ mod add_recipient {
 		ExtBuilder::default().build().execute_with(|| {
 			Balances::make_free_balance_be(&CREATOR, STAKE);
 
-			assert_ok!(Airdrop::create_airdrop(creator.clone(), start, vesting_schedule));
+			assert_ok!(Airdrop::create_airdrop(
+				creator.clone(),
+				start,
+				vesting_schedule,
+				None,
+				None,
+				PayoutMode::Liquid
+			));
 			assert_noop!(
 				Airdrop::add_recipient(creator, 1, recipients),
 				pallet_balances::Error::<MockRuntime>::InsufficientBalance
@@ -272,7 +334,8 @@ mod remove_recipient {
 				RuntimeOrigin::none(),
 				1,
 				accounts[0].clone().0,
-				accounts[0].clone().1.proof(accounts[0].clone().0)
+				accounts[0].clone().1.proof(accounts[0].clone().0),
+				None
 			));
 			assert_noop!(
 				Airdrop::remove_recipient(creator, 1, accounts[0].1.as_remote_public()),
@@ -338,7 +401,14 @@ mod enable_airdrop {
 		ExtBuilder::default().build().execute_with(|| {
 			Balances::make_free_balance_be(&CREATOR, STAKE);
 
-			assert_ok!(Airdrop::create_airdrop(creator.clone(), start_at, DEFAULT_VESTING_PERIOD));
+			assert_ok!(Airdrop::create_airdrop(
+				creator.clone(),
+				start_at,
+				DEFAULT_VESTING_PERIOD,
+				None,
+				None,
+				PayoutMode::Liquid
+			));
 			assert_noop!(
 				Airdrop::enable_airdrop(creator, 1),
 				Error::<MockRuntime>::AirdropAlreadyStarted
@@ -451,6 +521,599 @@ mod claim {
 	}
 }
 
+#[cfg(test)]
+mod relay_claim {
+	use super::*;
+	use crate::mocks::{RELAYER, RELAYER_FEE};
+	use frame_support::error::BadOrigin;
+
+	/// Funds the Airdrop account with enough funds to pay one relayer on top of the recipient
+	/// funds and the creation stake.
+	fn fund_sponsorship() {
+		Balances::make_free_balance_be(
+			&Airdrop::get_airdrop_account_id(1),
+			STAKE + DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS + RELAYER_FEE,
+		);
+	}
+
+	#[test]
+	fn should_claim_and_pay_relayer_from_airdrop_account() {
+		with_default_recipients(|set_moment, accounts| {
+			fund_sponsorship();
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			let (local_account, remote_account) = accounts[0].clone();
+			assert_ok!(remote_account.relay_claim(1, local_account.clone()));
+			assert_eq!(Balances::balance(&local_account), DEFAULT_REWARD);
+			assert_eq!(Balances::balance(&RELAYER), RELAYER_FEE);
+			System::assert_has_event(
+				crate::Event::ClaimRelayed {
+					airdrop_id: 1,
+					relayer: RELAYER,
+					recipient_account: local_account,
+					fee: RELAYER_FEE,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn should_not_pay_relayer_from_recipient_funds_or_stake() {
+		with_default_recipients(|set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			let (local_account, remote_account) = accounts[0].clone();
+			assert_noop!(
+				remote_account.relay_claim(1, local_account),
+				Error::<MockRuntime>::SponsorshipNotFunded
+			);
+		})
+	}
+
+	#[test]
+	fn should_only_be_relayed_by_relayer_origin() {
+		with_default_recipients(|set_moment, accounts| {
+			fund_sponsorship();
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			let (local_account, remote_account) = accounts[0].clone();
+			assert_noop!(
+				Airdrop::relay_claim(
+					RuntimeOrigin::none(),
+					1,
+					local_account.clone(),
+					remote_account.proof(local_account),
+					None,
+				),
+				BadOrigin
+			);
+		})
+	}
+}
+
+#[cfg(test)]
+mod sweep {
+	use super::*;
+
+	const EXPIRE_AFTER: Moment = DEFAULT_VESTING_PERIOD * 2;
+
+	fn with_expiring_recipients<R>(
+		execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
+	) -> R {
+		let accounts = generate_accounts(DEFAULT_NB_OF_CONTRIBUTORS as _);
+		let recipients = accounts
+			.iter()
+			.map(|(_, account)| {
+				(
+					account.as_remote_public(),
+					DEFAULT_REWARD,
+					DEFAULT_VESTING_PERIOD,
+					DEFAULT_FUNDED_CLAIM,
+				)
+			})
+			.collect();
+
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(0xDEADC0DE);
+			let creator = RuntimeOrigin::signed(CREATOR);
+			let start_moment = 0xCAFEBABE;
+			let set_moment = |x: Moment| Timestamp::set_timestamp(start_moment + x);
+
+			Balances::make_free_balance_be(
+				&CREATOR,
+				STAKE + DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS,
+			);
+
+			assert_ok!(Airdrop::create_airdrop(
+				creator.clone(),
+				Some(start_moment),
+				DEFAULT_VESTING_SCHEDULE,
+				None,
+				Some(start_moment + EXPIRE_AFTER),
+				PayoutMode::Liquid,
+			));
+			assert_ok!(Airdrop::add_recipient(creator, 1, recipients));
+
+			execute(&set_moment, accounts)
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_disable_airdrop_at_expiry() {
+		with_expiring_recipients(|set_moment, accounts| {
+			set_moment(EXPIRE_AFTER - 1);
+			assert_eq!(AirdropState::Enabled, Airdrop::get_airdrop_state(1).unwrap());
+
+			set_moment(EXPIRE_AFTER);
+			assert_eq!(AirdropState::Disabled, Airdrop::get_airdrop_state(1).unwrap());
+			assert_noop!(
+				accounts[0].1.claim(1, accounts[0].0.clone()),
+				Error::<MockRuntime>::AirdropIsNotEnabled
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_sweep_before_expiry() {
+		with_expiring_recipients(|set_moment, _| {
+			set_moment(EXPIRE_AFTER - 1);
+
+			assert_noop!(
+				Airdrop::sweep(RuntimeOrigin::signed(OTHER), 1),
+				Error::<MockRuntime>::AirdropNotExpired
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_sweep_airdrop_without_deadline() {
+		with_default_recipients(|set_moment, _| {
+			set_moment(DEFAULT_VESTING_PERIOD * 100);
+
+			assert_noop!(
+				Airdrop::sweep(RuntimeOrigin::signed(OTHER), 1),
+				Error::<MockRuntime>::AirdropNotExpired
+			);
+		})
+	}
+
+	#[test]
+	fn should_return_unclaimed_funds_to_creator_on_sweep() {
+		with_expiring_recipients(|set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+			for (local_account, remote_account) in accounts.iter().take(10) {
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+			}
+
+			set_moment(EXPIRE_AFTER);
+			assert_ok!(Airdrop::sweep(RuntimeOrigin::signed(OTHER), 1));
+
+			assert!(Airdrop::airdrops(1).is_none());
+			assert_eq!(0, Balances::balance(&Airdrop::get_airdrop_account_id(1)));
+			assert_eq!(
+				STAKE + DEFAULT_REWARD * (DEFAULT_NB_OF_CONTRIBUTORS - 10),
+				Balances::balance(&CREATOR)
+			);
+			System::assert_has_event(
+				crate::Event::AirdropSwept {
+					airdrop_id: 1,
+					unclaimed_funds: DEFAULT_REWARD * (DEFAULT_NB_OF_CONTRIBUTORS - 10),
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_create_airdrop_expiring_before_start() {
+		ExtBuilder::default().build().execute_with(|| {
+			Balances::make_free_balance_be(&CREATOR, STAKE);
+			Timestamp::set_timestamp(0xCAFEBABE);
+
+			assert_noop!(
+				Airdrop::create_airdrop(
+					RuntimeOrigin::signed(CREATOR),
+					None,
+					DEFAULT_VESTING_SCHEDULE,
+					None,
+					Some(0xCAFEBABE),
+					PayoutMode::Liquid
+				),
+				Error::<MockRuntime>::BackToTheFuture
+			);
+			assert_noop!(
+				Airdrop::create_airdrop(
+					RuntimeOrigin::signed(CREATOR),
+					Some(0xCAFEBABE + 10),
+					DEFAULT_VESTING_SCHEDULE,
+					None,
+					Some(0xCAFEBABE + 10),
+					PayoutMode::Liquid
+				),
+				Error::<MockRuntime>::ExpiresBeforeStart
+			);
+		})
+	}
+}
+
+#[cfg(test)]
+mod claim_status {
+	use super::*;
+	use composable_traits::airdrop::{AirdropFunds, RecipientClaimStatus};
+
+	#[test]
+	fn should_report_claimed_and_unclaimed_funds() {
+		with_default_recipients(|set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			assert_ok!(accounts[0].1.claim(1, accounts[0].0.clone()));
+
+			let claimed =
+				DEFAULT_REWARD * DEFAULT_VESTING_SCHEDULE as u128 / DEFAULT_VESTING_PERIOD as u128;
+			let total = DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS;
+			assert_eq!(
+				Some(AirdropFunds { total, claimed, unclaimed: total - claimed }),
+				Airdrop::airdrop_funds(1)
+			);
+			assert_eq!(None, Airdrop::airdrop_funds(2));
+		})
+	}
+
+	#[test]
+	fn should_report_recipient_claim_status() {
+		with_default_recipients(|set_moment, accounts| {
+			let step =
+				DEFAULT_REWARD * DEFAULT_VESTING_SCHEDULE as u128 / DEFAULT_VESTING_PERIOD as u128;
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			assert_ok!(accounts[0].1.claim(1, accounts[0].0.clone()));
+			set_moment(DEFAULT_VESTING_SCHEDULE * 2);
+
+			assert_eq!(
+				Some(RecipientClaimStatus {
+					total: DEFAULT_REWARD,
+					claimed: step,
+					claimable: step
+				}),
+				Airdrop::recipient_claim_status(1, accounts[0].1.as_remote_public())
+			);
+			assert_eq!(
+				Some(RecipientClaimStatus {
+					total: DEFAULT_REWARD,
+					claimed: 0,
+					claimable: step * 2
+				}),
+				Airdrop::recipient_claim_status(1, accounts[1].1.as_remote_public())
+			);
+			assert_eq!(None, Airdrop::recipient_claim_status(2, accounts[0].1.as_remote_public()));
+		})
+	}
+}
+
+#[cfg(test)]
+mod claim_with_additional_identities {
+	use super::*;
+
+	fn with_identities<R>(
+		accounts: Vec<(AccountId, Identity)>,
+		execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
+	) -> R {
+		with_accounts(
+			accounts,
+			DEFAULT_REWARD,
+			DEFAULT_FUNDED_CLAIM,
+			DEFAULT_VESTING_SCHEDULE,
+			DEFAULT_VESTING_PERIOD,
+			execute,
+		)
+	}
+
+	#[test]
+	fn should_give_full_fund_to_ed25519_recipients() {
+		with_identities(ed25519_generate(10), |set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			for (local_account, remote_account) in accounts {
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+				assert_eq!(DEFAULT_REWARD, Balances::balance(&local_account));
+			}
+		})
+	}
+
+	#[test]
+	fn should_give_full_fund_to_ethereum_typed_data_recipients() {
+		with_identities(ethereum_typed_data_generate(10), |set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			for (local_account, remote_account) in accounts {
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+				assert_eq!(DEFAULT_REWARD, Balances::balance(&local_account));
+			}
+		})
+	}
+
+	#[test]
+	fn should_accept_both_ethereum_signature_schemes_for_the_same_identity() {
+		let accounts = ethereum_generate(1);
+		let (local_account, remote_account) = accounts[0].clone();
+		let Identity::Eth(ethereum_key) = remote_account.clone() else {
+			panic!("ethereum_generate only generates Ethereum accounts")
+		};
+
+		with_identities(accounts, |set_moment, _| {
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			assert_ok!(remote_account.claim(1, local_account.clone()));
+
+			set_moment(DEFAULT_VESTING_PERIOD);
+			assert_ok!(Identity::EthTypedData(ethereum_key).claim(1, local_account.clone()));
+			assert_eq!(DEFAULT_REWARD, Balances::balance(&local_account));
+		})
+	}
+
+	#[test]
+	fn should_fail_with_ed25519_proof_of_another_reward_account() {
+		with_identities(ed25519_generate(2), |set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			assert_noop!(
+				Airdrop::claim(
+					RuntimeOrigin::none(),
+					1,
+					accounts[0].0.clone(),
+					accounts[0].1.clone().proof(accounts[1].0.clone()),
+					None
+				),
+				Error::<MockRuntime>::InvalidProof
+			);
+		})
+	}
+}
+
+#[cfg(test)]
+mod merkle_claim {
+	use super::*;
+	use crate::{
+		merkle::{self, MerkleTree},
+		models::{MerkleDistribution, MerkleLeaf, MerkleProof},
+		MerkleProofOf,
+	};
+
+	fn with_merkle_recipients<R>(
+		execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>, MerkleTree) -> R,
+	) -> R {
+		let accounts = generate_accounts(DEFAULT_NB_OF_CONTRIBUTORS as _);
+		let leaves = accounts
+			.iter()
+			.enumerate()
+			.map(|(index, (_, account))| {
+				merkle::leaf_hash(&MerkleLeaf {
+					index: index as u32,
+					identity: account.as_remote_public(),
+					total: DEFAULT_REWARD,
+					vesting_period: DEFAULT_VESTING_PERIOD,
+					funded_claim: DEFAULT_FUNDED_CLAIM,
+				})
+			})
+			.collect();
+		let tree = MerkleTree::new(leaves);
+
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(0xDEADC0DE);
+			let start_moment = 0xCAFEBABE;
+			let set_moment = |x: Moment| Timestamp::set_timestamp(start_moment + x);
+			let total_funds = DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS;
+
+			Balances::make_free_balance_be(&CREATOR, STAKE + total_funds);
+
+			assert_ok!(Airdrop::create_airdrop(
+				RuntimeOrigin::signed(CREATOR),
+				Some(start_moment),
+				DEFAULT_VESTING_SCHEDULE,
+				Some(MerkleDistribution {
+					root: tree.root().unwrap(),
+					total_funds,
+					total_recipients: DEFAULT_NB_OF_CONTRIBUTORS as u32,
+				}),
+				None,
+				PayoutMode::Liquid,
+			));
+
+			execute(&set_moment, accounts, tree)
+		})
+	}
+
+	fn merkle_proof(tree: &MerkleTree, index: usize, total: Balance) -> MerkleProofOf<MockRuntime> {
+		MerkleProof {
+			index: index as u32,
+			total,
+			vesting_period: DEFAULT_VESTING_PERIOD,
+			funded_claim: DEFAULT_FUNDED_CLAIM,
+			path: tree.proof(index).unwrap().try_into().unwrap(),
+		}
+	}
+
+	fn claim(
+		airdrop_id: AirdropId,
+		(local_account, remote_account): &(AccountId, Identity),
+		merkle_proof: Option<MerkleProofOf<MockRuntime>>,
+	) -> DispatchResultWithPostInfo {
+		Airdrop::claim(
+			RuntimeOrigin::none(),
+			airdrop_id,
+			local_account.clone(),
+			remote_account.clone().proof(local_account.clone()),
+			merkle_proof,
+		)
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_commit_root_and_fund_airdrop() {
+		with_merkle_recipients(|_, _, tree| {
+			let airdrop = Airdrop::airdrops(1).unwrap();
+
+			assert_eq!(tree.root(), airdrop.merkle_root);
+			assert_eq!(DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS, airdrop.total_funds);
+			assert_eq!(DEFAULT_NB_OF_CONTRIBUTORS as u32, airdrop.total_recipients);
+			assert_eq!(
+				STAKE + DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS,
+				Balances::balance(&Airdrop::get_airdrop_account_id(1))
+			);
+			assert_eq!(0, Balances::balance(&CREATOR));
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_give_full_fund_to_recipients_with_merkle_proofs() {
+		with_merkle_recipients(|set_moment, accounts, tree| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			for (index, account) in accounts.iter().enumerate() {
+				assert_ok!(claim(1, account, Some(merkle_proof(&tree, index, DEFAULT_REWARD))));
+				assert_eq!(DEFAULT_REWARD, Balances::balance(&account.0));
+			}
+
+			assert!(Airdrop::airdrops(1).is_none());
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_claim_vested_funds_without_proof_once_registered() {
+		with_merkle_recipients(|set_moment, accounts, tree| {
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			assert_ok!(claim(1, &accounts[0], Some(merkle_proof(&tree, 0, DEFAULT_REWARD))));
+			assert!(Airdrop::is_merkle_leaf_claimed(1, 0));
+			assert!(!Airdrop::is_merkle_leaf_claimed(1, 1));
+			assert_eq!(
+				DEFAULT_REWARD * DEFAULT_VESTING_SCHEDULE as u128 / DEFAULT_VESTING_PERIOD as u128,
+				Balances::balance(&accounts[0].0)
+			);
+
+			set_moment(DEFAULT_VESTING_PERIOD);
+			assert_ok!(claim(1, &accounts[0], None));
+			assert_eq!(DEFAULT_REWARD, Balances::balance(&accounts[0].0));
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_fail_to_claim_with_invalid_merkle_proof() {
+		with_merkle_recipients(|set_moment, accounts, tree| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			assert_noop!(
+				claim(1, &accounts[0], Some(merkle_proof(&tree, 0, DEFAULT_REWARD * 2))),
+				Error::<MockRuntime>::InvalidMerkleProof
+			);
+			assert_noop!(
+				claim(1, &accounts[0], Some(merkle_proof(&tree, 1, DEFAULT_REWARD))),
+				Error::<MockRuntime>::InvalidMerkleProof
+			);
+			assert_noop!(claim(1, &accounts[0], None), Error::<MockRuntime>::RecipientNotFound);
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_fail_to_claim_merkle_leaf_twice() {
+		with_merkle_recipients(|set_moment, accounts, tree| {
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			assert_ok!(claim(1, &accounts[0], Some(merkle_proof(&tree, 0, DEFAULT_REWARD))));
+
+			set_moment(DEFAULT_VESTING_SCHEDULE * 2);
+			assert_noop!(
+				claim(1, &accounts[0], Some(merkle_proof(&tree, 0, DEFAULT_REWARD))),
+				Error::<MockRuntime>::MerkleLeafAlreadyClaimed
+			);
+		})
+	}
+
+	#[test]
+	fn should_fail_to_add_or_remove_recipients_of_merkle_airdrop() {
+		with_merkle_recipients(|_, accounts, _| {
+			let recipient = accounts[0].1.as_remote_public();
+
+			assert_noop!(
+				Airdrop::add_recipient(
+					RuntimeOrigin::signed(CREATOR),
+					1,
+					vec![(
+						recipient.clone(),
+						DEFAULT_REWARD,
+						DEFAULT_VESTING_PERIOD,
+						DEFAULT_FUNDED_CLAIM
+					)]
+				),
+				Error::<MockRuntime>::RecipientsCommittedByMerkleRoot
+			);
+			assert_noop!(
+				Airdrop::remove_recipient(RuntimeOrigin::signed(CREATOR), 1, recipient),
+				Error::<MockRuntime>::RecipientsCommittedByMerkleRoot
+			);
+		})
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_fail_to_claim_with_merkle_proof_from_airdrop_without_root() {
+		let tree = MerkleTree::new(vec![merkle::leaf_hash(&0_u32)]);
+
+		with_default_recipients(|set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_PERIOD);
+
+			assert_noop!(
+				claim(1, &accounts[0], Some(merkle_proof(&tree, 0, DEFAULT_REWARD))),
+				Error::<MockRuntime>::NotMerkleAirdrop
+			);
+		})
+	}
+}
+
+#[cfg(test)]
+mod merkle_tree {
+	use crate::merkle::{self, MerkleTree};
+	use sp_core::H256;
+
+	fn leaves(count: u32) -> Vec<H256> {
+		(0..count).map(|leaf| merkle::leaf_hash(&leaf)).collect()
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_prove_every_leaf() {
+		for count in 1..=17 {
+			let tree = MerkleTree::new(leaves(count));
+			let root = tree.root().unwrap();
+
+			for (index, leaf) in leaves(count).into_iter().enumerate() {
+				assert!(merkle::verify(&root, leaf, &tree.proof(index).unwrap()));
+			}
+			assert_eq!(None, tree.proof(count as usize));
+		}
+	}
+
+	#[test]
+	#[allow(clippy::disallowed_methods)] // Allow unwrap
+	fn should_not_prove_leaf_with_proof_of_another_leaf() {
+		let tree = MerkleTree::new(leaves(8));
+		let root = tree.root().unwrap();
+
+		assert!(!merkle::verify(&root, merkle::leaf_hash(&0_u32), &tree.proof(1).unwrap()));
+		assert!(!merkle::verify(&root, merkle::leaf_hash(&8_u32), &tree.proof(0).unwrap()));
+	}
+
+	#[test]
+	fn should_have_leaf_as_root_of_single_leaf_tree() {
+		let tree = MerkleTree::new(leaves(1));
+
+		assert_eq!(Some(merkle::leaf_hash(&0_u32)), tree.root());
+		assert_eq!(Some(vec![]), tree.proof(0));
+		assert_eq!(None, MerkleTree::new(vec![]).root());
+	}
+}
+
 #[cfg(test)]
 mod ethereum_recover {
 	use super::*;
@@ -507,3 +1170,220 @@ mod cosmos_recover {
 		assert_eq!(Ok(CosmosPublicKey::Secp256r1(pub_key)), verified);
 	}
 }
+
+#[cfg(test)]
+mod payout_mode {
+	use super::*;
+	use crate::mocks::{MockPosition, MIN_VESTED_TRANSFER, POSITIONS, SCHEDULES, STAKING_ACCOUNT};
+	use composable_traits::{
+		time::{ONE_HOUR, ONE_MONTH},
+		vesting::{VestingCurve, VestingWindow},
+	};
+	use frame_support::unsigned::ValidateUnsigned;
+	use sp_runtime::{
+		transaction_validity::{InvalidTransaction, TransactionSource},
+		DispatchError,
+	};
+
+	const POOL_ID: u128 = 1;
+	const VEST_PERIOD: Moment = 3600 * 24;
+	const VEST_PERIOD_COUNT: u32 = 3;
+
+	fn with_payout_mode<R>(
+		payout_mode: PayoutMode<u128, Moment>,
+		execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
+	) -> R {
+		with_payout_mode_and_reward(payout_mode, DEFAULT_REWARD, execute)
+	}
+
+	fn with_payout_mode_and_reward<R>(
+		payout_mode: PayoutMode<u128, Moment>,
+		reward: Balance,
+		execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, Identity)>) -> R,
+	) -> R {
+		let accounts = generate_accounts(2);
+		let recipients = accounts
+			.iter()
+			.map(|(_, account)| {
+				(account.as_remote_public(), reward, DEFAULT_VESTING_PERIOD, DEFAULT_FUNDED_CLAIM)
+			})
+			.collect();
+
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(0xDEADC0DE);
+			let creator = RuntimeOrigin::signed(CREATOR);
+			let start_moment = 0xCAFEBABE;
+			let set_moment = |x: Moment| Timestamp::set_timestamp(start_moment + x);
+
+			Balances::make_free_balance_be(&CREATOR, STAKE + reward * 2);
+
+			assert_ok!(Airdrop::create_airdrop(
+				creator.clone(),
+				Some(start_moment),
+				DEFAULT_VESTING_SCHEDULE,
+				None,
+				None,
+				payout_mode,
+			));
+			assert_ok!(Airdrop::add_recipient(creator, 1, recipients));
+
+			execute(&set_moment, accounts)
+		})
+	}
+
+	#[test]
+	fn should_stake_whole_fund_on_claim() {
+		with_payout_mode(
+			PayoutMode::Stake { pool_id: POOL_ID, duration_preset: ONE_MONTH },
+			|set_moment, accounts| {
+				set_moment(0);
+				let (local_account, remote_account) = &accounts[0];
+
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+				assert_eq!(Balances::balance(local_account), 0);
+				assert_eq!(Balances::balance(&STAKING_ACCOUNT), DEFAULT_REWARD);
+				assert_eq!(
+					POSITIONS.with(|positions| positions.borrow().clone()),
+					vec![MockPosition {
+						owner: local_account.clone(),
+						pool_id: POOL_ID,
+						amount: DEFAULT_REWARD,
+						duration_preset: ONE_MONTH,
+					}]
+				);
+
+				set_moment(DEFAULT_VESTING_PERIOD);
+				assert_noop!(
+					remote_account.claim(1, local_account.clone()),
+					Error::<MockRuntime>::NothingToClaim
+				);
+				// Free claims paying nothing out are not even accepted in the pool
+				assert_eq!(
+					Airdrop::validate_unsigned(
+						TransactionSource::External,
+						&crate::Call::claim {
+							airdrop_id: 1,
+							reward_account: local_account.clone(),
+							proof: remote_account.clone().proof(local_account.clone()),
+							merkle_proof: None,
+						},
+					),
+					InvalidTransaction::Custom(crate::ValidityError::NotClaimable as u8).into()
+				);
+			},
+		)
+	}
+
+	#[test]
+	fn should_fail_to_create_airdrop_staking_in_invalid_pool() {
+		ExtBuilder::default().build().execute_with(|| {
+			Balances::make_free_balance_be(&CREATOR, STAKE);
+
+			for (payout_mode, error) in [
+				(PayoutMode::Stake { pool_id: 3, duration_preset: ONE_MONTH }, "pool not found"),
+				(
+					PayoutMode::Stake { pool_id: 2, duration_preset: ONE_MONTH },
+					"staked asset mismatch",
+				),
+				(
+					PayoutMode::Stake { pool_id: POOL_ID, duration_preset: ONE_HOUR },
+					"duration preset not found",
+				),
+			] {
+				assert_noop!(
+					Airdrop::create_airdrop(
+						RuntimeOrigin::signed(CREATOR),
+						None,
+						DEFAULT_VESTING_SCHEDULE,
+						None,
+						None,
+						payout_mode,
+					),
+					DispatchError::Other(error)
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn should_vest_whole_fund_on_claim() {
+		with_payout_mode(
+			PayoutMode::Vest { period: VEST_PERIOD, period_count: VEST_PERIOD_COUNT },
+			|set_moment, accounts| {
+				set_moment(0);
+				let (local_account, remote_account) = &accounts[0];
+				let per_period = DEFAULT_REWARD / VEST_PERIOD_COUNT as Balance;
+
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+				// The remainder of the division in periods is paid out liquid
+				assert_eq!(Balances::balance(local_account), DEFAULT_REWARD);
+
+				let schedules = SCHEDULES.with(|schedules| schedules.borrow().clone());
+				assert_eq!(schedules.len(), 1);
+				let (to, schedule) = &schedules[0];
+				assert_eq!(to, local_account);
+				assert_eq!(
+					schedule.window,
+					VestingWindow::MomentBased { start: 0xCAFEBABE, period: VEST_PERIOD }
+				);
+				assert_eq!(schedule.period_count, VEST_PERIOD_COUNT);
+				assert_eq!(schedule.per_period, per_period);
+				assert_eq!(schedule.curve, VestingCurve::Linear);
+				assert_eq!(schedule.revoker, None);
+			},
+		)
+	}
+
+	#[test]
+	fn should_pay_out_fund_too_small_to_vest_liquid() {
+		with_payout_mode_and_reward(
+			PayoutMode::Vest { period: VEST_PERIOD, period_count: VEST_PERIOD_COUNT },
+			MIN_VESTED_TRANSFER - 1,
+			|set_moment, accounts| {
+				set_moment(0);
+				let (local_account, remote_account) = &accounts[0];
+
+				assert_ok!(remote_account.claim(1, local_account.clone()));
+				assert_eq!(Balances::balance(local_account), MIN_VESTED_TRANSFER - 1);
+				assert!(SCHEDULES.with(|schedules| schedules.borrow().is_empty()));
+			},
+		)
+	}
+
+	#[test]
+	fn should_pay_out_liquid_along_vesting_schedule() {
+		with_payout_mode(PayoutMode::Liquid, |set_moment, accounts| {
+			set_moment(DEFAULT_VESTING_SCHEDULE);
+			let (local_account, remote_account) = &accounts[0];
+
+			assert_ok!(remote_account.claim(1, local_account.clone()));
+			assert!(Balances::balance(local_account) < DEFAULT_REWARD);
+			assert!(POSITIONS.with(|positions| positions.borrow().is_empty()));
+			assert!(SCHEDULES.with(|schedules| schedules.borrow().is_empty()));
+		})
+	}
+
+	#[test]
+	fn should_fail_to_create_airdrop_vesting_over_no_time() {
+		ExtBuilder::default().build().execute_with(|| {
+			Balances::make_free_balance_be(&CREATOR, STAKE);
+
+			for payout_mode in [
+				PayoutMode::Vest { period: 0, period_count: VEST_PERIOD_COUNT },
+				PayoutMode::Vest { period: VEST_PERIOD, period_count: 0 },
+			] {
+				assert_noop!(
+					Airdrop::create_airdrop(
+						RuntimeOrigin::signed(CREATOR),
+						None,
+						DEFAULT_VESTING_SCHEDULE,
+						None,
+						None,
+						payout_mode,
+					),
+					Error::<MockRuntime>::InvalidPayoutMode
+				);
+			}
+		})
+	}
+}
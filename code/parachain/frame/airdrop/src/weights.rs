@@ -7,6 +7,12 @@ pub trait WeightInfo {
 	fn enable_airdrop() -> Weight;
 	fn disable_airdrop() -> Weight;
 	fn claim(x: u32) -> Weight;
+	fn claim_with_merkle_proof(x: u32) -> Weight;
+	fn claim_staked(x: u32) -> Weight;
+	fn claim_vested(x: u32) -> Weight;
+	fn relay_claim(x: u32) -> Weight;
+	fn relay_claim_with_merkle_proof(x: u32) -> Weight;
+	fn sweep(x: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -33,4 +39,28 @@ impl WeightInfo for () {
 	fn claim(_x: u32) -> Weight {
 		Weight::from_ref_time(10_000)
 	}
+
+	fn claim_with_merkle_proof(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn claim_staked(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn claim_vested(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn relay_claim(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn relay_claim_with_merkle_proof(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
+
+	fn sweep(_x: u32) -> Weight {
+		Weight::from_ref_time(10_000)
+	}
 }
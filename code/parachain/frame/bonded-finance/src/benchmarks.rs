@@ -2,10 +2,12 @@
 
 #[cfg(test)]
 use crate::Pallet as BondedFinance;
-use crate::{AssetIdOf, BalanceOf, BlockNumberOf, BondOfferOf, Call, Config, Pallet};
+use crate::{AssetIdOf, BalanceOf, BlockNumberOf, BondOfferOf, Bonds, Call, Config, Pallet};
 use codec::Decode;
 use composable_support::validation::Validated;
-use composable_traits::bonded_finance::{BondDuration, BondOffer, BondOfferReward};
+use composable_traits::bonded_finance::{
+	BondDuration, BondOffer, BondOfferReward, BondPayment, BondPricing,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
 	dispatch::UnfilteredDispatchable,
@@ -34,7 +36,9 @@ where
 	BondOffer {
 		beneficiary: whitelisted_caller(),
 		asset: bond_asset,
+		payment: BondPayment::Asset,
 		bond_price: BalanceOf::<T>::from(MIN_VESTED_TRANSFER),
+		pricing: BondPricing::Fixed,
 		maturity: BondDuration::Finite { return_in: BlockNumberOf::<T>::from(1u32) },
 		nb_of_bonds: BalanceOf::<T>::from(1u128),
 		reward: BondOfferReward {
@@ -53,7 +57,7 @@ where
 	let keep_alive = false;
 	T::NativeCurrency::mint_into(&offer_account_id, <_>::try_from(BALANCE).unwrap_or_default())
 		.unwrap();
-	Call::<T>::bond { nb_of_bonds, offer_id, keep_alive }
+	Call::<T>::bond { nb_of_bonds, offer_id, max_price: None, keep_alive }
 		.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into())
 		.unwrap();
 }
@@ -100,7 +104,7 @@ benchmarks! {
 		let nb_of_bonds = bond_offer.nb_of_bonds;
 		call_offer::<T>(bond_offer, &caller);
 		let offer_id = T::BondOfferId::one();
-	}: _(RawOrigin::Signed(caller), offer_id, nb_of_bonds, false)
+	}: _(RawOrigin::Signed(caller), offer_id, nb_of_bonds, None, false)
 
 	cancel {
 		let [bond_asset, reward_asset] = assets::<T>();
@@ -112,6 +116,20 @@ benchmarks! {
 		let offer_id = T::BondOfferId::one();
 		call_bond::<T>(&caller, nb_of_bonds, offer_id);
 	}: _(RawOrigin::Signed(caller), offer_id)
+
+	claim {
+		let [bond_asset, reward_asset] = assets::<T>();
+		let caller: T::AccountId = account("caller", 0, 0xCAFEBABE);
+		initial_mint::<T>(bond_asset, &caller, reward_asset);
+		let bond_offer = bond_offer::<T>(bond_asset, reward_asset);
+		let nb_of_bonds = bond_offer.nb_of_bonds;
+		call_offer::<T>(bond_offer, &caller);
+		let offer_id = T::BondOfferId::one();
+		call_bond::<T>(&caller, nb_of_bonds, offer_id);
+		let fnft_instance_id = Bonds::<T>::iter_keys().next().unwrap();
+		// claim both the returned amount and the reward
+		frame_system::Pallet::<T>::set_block_number(BlockNumberOf::<T>::from(97u32));
+	}: _(RawOrigin::Signed(caller), fnft_instance_id)
 }
 
 impl_benchmark_test_suite!(BondedFinance, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
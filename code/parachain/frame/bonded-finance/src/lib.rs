@@ -33,6 +33,7 @@
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+pub mod migrations;
 mod mock;
 mod tests;
 pub mod weights;
@@ -52,50 +53,86 @@ pub mod pallet {
 				start_at::ZeroInit,
 			},
 		},
-		math::safe::{safe_multiply_by_rational, SafeAdd},
+		math::safe::{safe_multiply_by_rational, SafeAdd, SafeMul},
 		validation::Validated,
 	};
 	use composable_traits::{
-		bonded_finance::{BondDuration, BondOffer, BondedFinance, ValidBondOffer},
-		vesting::{VestedTransfer, VestingScheduleInfo, VestingWindow::BlockNumberBased},
+		bonded_finance::{
+			BondDuration, BondFnftAttribute, BondOffer, BondPayment, BondPricing, BondedFinance,
+			ValidBondOffer,
+		},
+		dex::Amm,
+		fnft::{FinancialNft, FinancialNftProtocol},
+		oracle::Oracle,
+		vesting::{
+			VestedTransfer, VestingCurve, VestingScheduleInfo, VestingWindow::BlockNumberBased,
+		},
 	};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{
 			fungible::{self, Inspect as FungibleInspect, Transfer as FungibleTransfer},
 			fungibles::{self, Inspect as FungiblesInspect, Transfer as FungiblesTransfer},
+			tokens::{
+				nonfungibles::{
+					Create as NonFungiblesCreate, Inspect as NonFungiblesInspect,
+					Mutate as NonFungiblesMutate,
+				},
+				AssetId,
+			},
 		},
 		transactional, PalletId,
 	};
 	use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 	use scale_info::TypeInfo;
-	use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, Convert, One, Zero};
-	use sp_std::fmt::Debug;
+	use sp_core::U256;
+	use sp_runtime::{
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, BlockNumberProvider, Convert, One, Zero},
+		ArithmeticError, Permill, Rounding,
+	};
+	use sp_std::{fmt::Debug, vec, vec::Vec};
 
 	use crate::weights::WeightInfo;
 
 	pub(crate) type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-	pub(crate) type AssetIdOf<T> =
-		<<T as Config>::Currency as FungiblesInspect<AccountIdOf<T>>>::AssetId;
+	pub(crate) type AssetIdOf<T> = <T as Config>::AssetId;
 	pub(crate) type BalanceOf<T> =
 		<<T as Config>::Currency as FungiblesInspect<AccountIdOf<T>>>::Balance;
 	pub(crate) type NativeBalanceOf<T> =
 		<<T as Config>::NativeCurrency as FungibleInspect<AccountIdOf<T>>>::Balance;
-	pub(crate) type BondOfferOf<T> =
-		BondOffer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+	pub(crate) type BondOfferOf<T> = BondOffer<
+		AccountIdOf<T>,
+		AssetIdOf<T>,
+		BalanceOf<T>,
+		BlockNumberOf<T>,
+		<T as Config>::PoolId,
+	>;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new offer has been created.
 		NewOffer { offer_id: T::BondOfferId, beneficiary: AccountIdOf<T> },
-		/// A new bond has been registered.
-		NewBond { offer_id: T::BondOfferId, who: AccountIdOf<T>, nb_of_bonds: BalanceOf<T> },
+		/// A new bond has been registered, represented by the financial NFT `fnft_instance_id`.
+		NewBond {
+			offer_id: T::BondOfferId,
+			who: AccountIdOf<T>,
+			nb_of_bonds: BalanceOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		},
 		/// An offer has been cancelled by the `AdminOrigin`.
 		OfferCancelled { offer_id: T::BondOfferId },
 		/// An offer has been completed.
 		OfferCompleted { offer_id: T::BondOfferId },
+		/// The owner of the financial NFT of a bond claimed its vested reward and bonded amount.
+		BondClaimed {
+			fnft_instance_id: T::FinancialNftInstanceId,
+			owner: AccountIdOf<T>,
+			reward_amount: BalanceOf<T>,
+			returned_amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -108,6 +145,20 @@ pub mod pallet {
 		OfferCompleted,
 		/// Someone tried to bond with an invalid number of nb_of_bonds.
 		InvalidNumberOfBonds,
+		/// Someone tried to submit an offer paid with the LP token of a pool whose LP token is
+		/// not the asset of the offer, or which is not a 50/50 pool of two assets.
+		InvalidLiquidityPool,
+		/// The LP tokens of the pool of an offer have no value.
+		WorthlessLiquidityPool,
+		/// There is no bond represented by the financial NFT.
+		BondNotFound,
+		/// Only the owner of the financial NFT of a bond can claim it.
+		NotBondOwner,
+		/// The current bond price of the offer is above the maximum price of the bonder.
+		BondPriceAboveMaxPrice,
+		/// Someone tried to submit an offer paid with the LP token of a pool whose assets have no
+		/// oracle price.
+		UnpricedLiquidityPool,
 	}
 
 	#[pallet::config]
@@ -119,8 +170,31 @@ pub mod pallet {
 		type NativeCurrency: fungible::Mutate<AccountIdOf<Self>>
 			+ fungible::Transfer<AccountIdOf<Self>>;
 
+		/// The ID of the assets offers are based on.
+		type AssetId: AssetId + Ord;
+
 		/// The multi currency system offers are based on.
-		type Currency: fungibles::Mutate<AccountIdOf<Self>> + FungiblesTransfer<AccountIdOf<Self>>;
+		type Currency: fungibles::Mutate<AccountIdOf<Self>, AssetId = Self::AssetId>
+			+ FungiblesTransfer<AccountIdOf<Self>>;
+
+		/// The ID of a liquidity pool.
+		type PoolId: FullCodec + MaxEncodedLen + TypeInfo + Copy + Eq + Debug;
+
+		/// The AMM whose LP tokens can pay bonds.
+		type Pablo: Amm<
+			AssetId = AssetIdOf<Self>,
+			Balance = BalanceOf<Self>,
+			AccountId = AccountIdOf<Self>,
+			PoolId = Self::PoolId,
+		>;
+
+		/// The oracle valuing the assets of liquidity pools.
+		type Oracle: Oracle<AssetId = AssetIdOf<Self>, Balance = BalanceOf<Self>>;
+
+		/// The account receiving the LP tokens paying bonds of infinite maturity, the protocol
+		/// owning this liquidity.
+		#[pallet::constant]
+		type TreasuryAccount: Get<AccountIdOf<Self>>;
 
 		/// The dependency managing vesting transfer of rewards.
 		type Vesting: VestedTransfer<
@@ -130,6 +204,25 @@ pub mod pallet {
 			Balance = BalanceOf<Self>,
 		>;
 
+		/// Financial NFTs representing bonds.
+		type FinancialNft: NonFungiblesMutate<AccountIdOf<Self>>
+			+ NonFungiblesCreate<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			> + FinancialNft<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			>;
+
+		/// The ID of a financial NFT instance.
+		type FinancialNftInstanceId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// The financial NFT collection of bonds.
+		#[pallet::constant]
+		type FnftCollectionId: Get<AssetIdOf<Self>>;
+
 		/// The ID of a bond offer.
 		type BondOfferId: Copy
 			+ Clone
@@ -169,8 +262,11 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -192,10 +288,24 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// A mapping from the financial NFT of a bond to the triple: (offer ID, asset, reward asset)
+	#[pallet::storage]
+	#[pallet::getter(fn bonds)]
+	pub type Bonds<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::FinancialNftInstanceId,
+		(T::BondOfferId, AssetIdOf<T>, AssetIdOf<T>),
+		OptionQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new bond offer. To be `bond` to later.
 		///
+		/// Offers paid with LP tokens must have the LP token of their pool, a 50/50 pool of two
+		/// assets, as `asset`.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have the
 		/// appropriate funds to stake the offer.
 		///
@@ -219,7 +329,12 @@ pub mod pallet {
 		}
 		/// Bond to an offer.
 		///
-		/// The issuer should provide the number of contracts they are willing to buy.
+		/// The issuer should provide the number of contracts they are willing to buy, charged at
+		/// the current bond price of the offer. Offers paid with LP tokens charge the amount of
+		/// LP tokens worth that price. Bonding fails if the bond price is above `max_price`, if
+		/// any, as dynamically priced offers may change price before the bond is included.
+		/// The bond is represented by a financial NFT minted to the issuer, whose owner can
+		/// `claim` the reward and the bonded amount once vested.
 		/// Once there are no more contracts available on the offer, the `stake` put by the
 		/// offer creator is refunded.
 		///
@@ -236,10 +351,30 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			offer_id: T::BondOfferId,
 			nb_of_bonds: BalanceOf<T>,
+			max_price: Option<BalanceOf<T>>,
 			keep_alive: bool,
 		) -> DispatchResult {
 			let from = ensure_signed(origin)?;
-			Self::do_bond(offer_id, &from, nb_of_bonds, keep_alive)?;
+			Self::do_bond(offer_id, &from, nb_of_bonds, max_price, keep_alive)?;
+			Ok(())
+		}
+
+		/// Claim the vested reward and bonded amount of a bond.
+		///
+		/// Bonds are represented by financial NFTs holding their vesting schedules, and only the
+		/// owner of the NFT can claim. The NFT is burnt once everything has been claimed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must own the
+		/// financial NFT of the bond.
+		///
+		/// Emits a `BondClaimed`.
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(
+			origin: OriginFor<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_claim(&owner, fnft_instance_id)?;
 			Ok(())
 		}
 
@@ -282,6 +417,12 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The price of a bond of an offer at the current block.
+		pub fn current_bond_price(offer_id: T::BondOfferId) -> Result<BalanceOf<T>, DispatchError> {
+			let (_, offer) = Self::get_offer(offer_id)?;
+			Ok(offer.current_bond_price(frame_system::Pallet::<T>::current_block_number())?)
+		}
+
 		pub fn get_offer(
 			offer_id: T::BondOfferId,
 		) -> Result<(AccountIdOf<T>, BondOfferOf<T>), DispatchError> {
@@ -291,9 +432,16 @@ pub mod pallet {
 		#[transactional]
 		pub fn do_offer(
 			from: &AccountIdOf<T>,
-			offer: BondOfferOf<T>,
+			mut offer: BondOfferOf<T>,
 			keep_alive: bool,
 		) -> Result<T::BondOfferId, DispatchError> {
+			if let BondPricing::Dynamic(pricing) = &mut offer.pricing {
+				// The debt decays from the creation of the offer
+				pricing.last_decay = frame_system::Pallet::<T>::current_block_number();
+			}
+			if let BondPayment::LiquidityProvider { pool_id } = offer.payment {
+				Self::ensure_valid_liquidity_pool(pool_id, offer.asset)?;
+			}
 			let offer_id = BondOfferCount::<T>::increment()?;
 			let beneficiary = offer.beneficiary.clone();
 			let offer_account = Self::account_id(offer_id);
@@ -315,6 +463,7 @@ pub mod pallet {
 			offer_id: T::BondOfferId,
 			from: &AccountIdOf<T>,
 			nb_of_bonds: BalanceOf<T>,
+			max_price: Option<BalanceOf<T>>,
 			keep_alive: bool,
 		) -> Result<BalanceOf<T>, DispatchError> {
 			BondOffers::<T>::try_mutate(offer_id, |offer| {
@@ -330,28 +479,36 @@ pub mod pallet {
 								nb_of_bonds <= offer.nb_of_bonds,
 							Error::<T>::InvalidNumberOfBonds
 						);
-						// can't overflow, subsumed by `offer.valid()` in
-						// `do_offer`
-						let value = nb_of_bonds * offer.bond_price;
+						let current_block = frame_system::Pallet::<T>::current_block_number();
+						let bond_price = offer.current_bond_price(current_block)?;
+						ensure!(
+							max_price.map_or(true, |max_price| bond_price <= max_price),
+							Error::<T>::BondPriceAboveMaxPrice
+						);
+						let value = nb_of_bonds.safe_mul(&bond_price)?;
+						let (amount, recipient) = match (&offer.payment, &offer.maturity) {
+							(BondPayment::Asset, _) => (value, offer.beneficiary.clone()),
+							(BondPayment::LiquidityProvider { pool_id }, maturity) => (
+								Self::lp_amount_for_value(*pool_id, offer.asset, value)?,
+								match maturity {
+									BondDuration::Finite { .. } => offer.beneficiary.clone(),
+									BondDuration::Infinite => T::TreasuryAccount::get(),
+								},
+							),
+						};
 						let reward_share = T::Convert::convert(safe_multiply_by_rational(
 							T::Convert::convert(nb_of_bonds),
 							T::Convert::convert(offer.reward.amount),
 							T::Convert::convert(offer.nb_of_bonds),
 						)?);
 						let offer_account = Self::account_id(offer_id);
-						T::Currency::transfer(
-							offer.asset,
-							from,
-							&offer.beneficiary,
-							value,
-							keep_alive,
-						)?;
-						let current_block = frame_system::Pallet::<T>::current_block_number();
+						T::Currency::transfer(offer.asset, from, &recipient, amount, keep_alive)?;
+						let (fnft_instance_id, fnft_account) = Self::mint_bond_fnft(from)?;
 						// Schedule the vesting of the reward.
 						T::Vesting::vested_transfer(
 							offer.reward.asset,
 							&offer_account,
-							from,
+							&fnft_account,
 							VestingScheduleInfo {
 								window: BlockNumberBased {
 									start: current_block,
@@ -359,41 +516,64 @@ pub mod pallet {
 								},
 								period_count: 1,
 								per_period: reward_share,
+								cliff: 0,
+								curve: VestingCurve::Linear,
+								revoker: None,
 							},
 						)?;
-						match offer.maturity {
+						let maturity = match offer.maturity {
 							BondDuration::Finite { return_in } => {
 								// Schedule the return of the bonded amount
 								T::Vesting::vested_transfer(
 									offer.asset,
 									&offer.beneficiary,
-									from,
+									&fnft_account,
 									VestingScheduleInfo {
 										window: BlockNumberBased {
 											start: current_block,
 											period: return_in,
 										},
 										period_count: 1,
-										per_period: value,
+										per_period: amount,
+										cliff: 0,
+										curve: VestingCurve::Linear,
+										revoker: None,
 									},
 								)?;
+								offer.reward.maturity.max(return_in)
 							},
 							BondDuration::Infinite => {
 								// the offer, the liquidity is never returned to the bonder, meaning
 								// that the protocol is now owning the funds.
+								offer.reward.maturity
 							},
-						}
+						};
+						Self::set_bond_fnft_attributes(
+							&fnft_instance_id,
+							offer_id,
+							nb_of_bonds,
+							current_block.safe_add(&maturity)?,
+						)?;
+						Bonds::<T>::insert(
+							fnft_instance_id,
+							(offer_id, offer.asset, offer.reward.asset),
+						);
 						// NOTE(hussein-aitlahcen): can't overflow as checked to be <=
 						// offer.nb_of_bonds prior to this
 						// Same goes for reward_share as nb_of_bonds * bond_price <= total_price is
 						// checked by the `Validate` instance of `BondOffer`
 						offer.nb_of_bonds -= nb_of_bonds;
 						offer.reward.amount -= reward_share;
+						if let BondPricing::Dynamic(pricing) = &mut offer.pricing {
+							// Demand raises the price of the next bonds
+							pricing.add_debt(nb_of_bonds, current_block)?;
+						}
 						let new_bond_event = || {
 							Self::deposit_event(Event::<T>::NewBond {
 								offer_id,
 								who: from.clone(),
 								nb_of_bonds,
+								fnft_instance_id,
 							});
 						};
 						if offer.completed() {
@@ -415,17 +595,193 @@ pub mod pallet {
 			})
 		}
 
+		#[transactional]
+		pub fn do_claim(
+			owner: &AccountIdOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let fnft_collection_id = T::FnftCollectionId::get();
+			let (_, asset, reward_asset) =
+				Bonds::<T>::get(fnft_instance_id).ok_or(Error::<T>::BondNotFound)?;
+			ensure!(
+				T::FinancialNft::owner(&fnft_collection_id, &fnft_instance_id).as_ref() ==
+					Some(owner),
+				Error::<T>::NotBondOwner
+			);
+
+			let fnft_account =
+				T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			// Pays out everything unlocked in the financial NFT account, which includes amounts
+			// sent to the account besides the vesting bond
+			let claim = |asset| -> Result<BalanceOf<T>, DispatchError> {
+				T::Vesting::claim(asset, &fnft_account)?;
+				let claimed_amount = T::Currency::reducible_balance(asset, &fnft_account, false);
+				T::Currency::transfer(asset, &fnft_account, owner, claimed_amount, false)?;
+				Ok(claimed_amount)
+			};
+			// NOTE: both schedules are claimed at once if the reward asset is the bonded asset
+			let reward_amount = claim(reward_asset)?;
+			let returned_amount = claim(asset)?;
+
+			// The financial NFT account only holds the vesting bond, fully claimed once empty
+			if T::Currency::balance(reward_asset, &fnft_account).is_zero() &&
+				T::Currency::balance(asset, &fnft_account).is_zero()
+			{
+				T::FinancialNft::burn(&fnft_collection_id, &fnft_instance_id, Some(owner))?;
+				Bonds::<T>::remove(fnft_instance_id);
+			}
+
+			Self::deposit_event(Event::<T>::BondClaimed {
+				fnft_instance_id,
+				owner: owner.clone(),
+				reward_amount,
+				returned_amount,
+			});
+			Ok(())
+		}
+
+		/// Mint the financial NFT of a new bond to `owner`, returning its ID and asset account.
+		fn mint_bond_fnft(
+			owner: &AccountIdOf<T>,
+		) -> Result<(T::FinancialNftInstanceId, AccountIdOf<T>), DispatchError> {
+			let fnft_collection_id = T::FnftCollectionId::get();
+			T::FinancialNft::ensure_collection(
+				&fnft_collection_id,
+				&T::PalletId::get().into_account_truncating(),
+			)?;
+			let fnft_instance_id = T::FinancialNft::get_next_nft_id(&fnft_collection_id)?;
+			T::FinancialNft::mint_into(&fnft_collection_id, &fnft_instance_id, owner)?;
+			let fnft_account =
+				T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+			Ok((fnft_instance_id, fnft_account))
+		}
+
+		fn set_bond_fnft_attributes(
+			fnft_instance_id: &T::FinancialNftInstanceId,
+			offer_id: T::BondOfferId,
+			nb_of_bonds: BalanceOf<T>,
+			maturity: BlockNumberOf<T>,
+		) -> DispatchResult {
+			let fnft_collection_id = T::FnftCollectionId::get();
+			T::FinancialNft::set_typed_attribute(
+				&fnft_collection_id,
+				fnft_instance_id,
+				&BondFnftAttribute::OfferId,
+				&offer_id,
+			)?;
+			T::FinancialNft::set_typed_attribute(
+				&fnft_collection_id,
+				fnft_instance_id,
+				&BondFnftAttribute::Amount,
+				&nb_of_bonds,
+			)?;
+			T::FinancialNft::set_typed_attribute(
+				&fnft_collection_id,
+				fnft_instance_id,
+				&BondFnftAttribute::Maturity,
+				&maturity,
+			)
+		}
+
+		/// Whether bonds can be paid with the LP token of `pool_id`, which must be the offered
+		/// `asset`, and priced by [`Self::lp_amount_for_value`], which needs an oracle price for
+		/// both assets of the pool.
+		pub(crate) fn ensure_valid_liquidity_pool(
+			pool_id: T::PoolId,
+			asset: AssetIdOf<T>,
+		) -> DispatchResult {
+			ensure!(T::Pablo::lp_token(pool_id)? == asset, Error::<T>::InvalidLiquidityPool);
+			let weights = T::Pablo::assets(pool_id)?;
+			ensure!(
+				weights.len() == 2 &&
+					weights.values().all(|weight| *weight == Permill::from_percent(50)),
+				Error::<T>::InvalidLiquidityPool
+			);
+			ensure!(
+				weights.keys().all(|asset| T::Oracle::get_price(*asset, One::one()).is_ok()),
+				Error::<T>::UnpricedLiquidityPool
+			);
+			Ok(())
+		}
+
+		/// The amount of `lp_token`, the LP token of `pool_id`, worth `value` in the currency of
+		/// the oracle.
+		///
+		/// The pool is valued at its fair reserves, `2 * sqrt(k * p0 * p1)` with `k` the product
+		/// of its reserves and `p0`, `p1` the oracle prices of its assets. Swaps keep `k`, so
+		/// unlike the spot reserves, the valuation can't be lowered by unbalancing the pool before
+		/// bonding. The amount is rounded up in favor of the protocol.
+		pub(crate) fn lp_amount_for_value(
+			pool_id: T::PoolId,
+			lp_token: AssetIdOf<T>,
+			value: BalanceOf<T>,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let lp_issuance = T::Currency::total_issuance(lp_token);
+			ensure!(!lp_issuance.is_zero(), Error::<T>::WorthlessLiquidityPool);
+			// the oracle values of the reserves, `reserve * price`, multiply to `k * p0 * p1`
+			let reserve_values = T::Pablo::redeemable_assets_for_lp_tokens(pool_id, lp_issuance)?
+				.into_iter()
+				.map(|(asset, reserve)| {
+					Ok(T::Convert::convert(T::Oracle::get_price(asset, reserve)?.price))
+				})
+				.collect::<Result<Vec<u128>, DispatchError>>()?;
+			let pool_value = match reserve_values[..] {
+				[value_0, value_1] => (U256::from(value_0) * U256::from(value_1))
+					.integer_sqrt()
+					.checked_mul(U256::from(2_u8))
+					.and_then(|pool_value| u128::try_from(pool_value).ok())
+					.ok_or(ArithmeticError::Overflow)?,
+				_ => return Err(Error::<T>::InvalidLiquidityPool.into()),
+			};
+			ensure!(!pool_value.is_zero(), Error::<T>::WorthlessLiquidityPool);
+
+			let amount = multiply_by_rational_with_rounding(
+				T::Convert::convert(value),
+				T::Convert::convert(lp_issuance),
+				pool_value,
+				Rounding::Up,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			Ok(T::Convert::convert(amount))
+		}
+
 		pub(crate) fn account_id(offer_id: T::BondOfferId) -> AccountIdOf<T> {
 			T::PalletId::get().into_sub_account_truncating(offer_id)
 		}
 	}
 
+	impl<T: Config> FinancialNftProtocol for Pallet<T> {
+		type ItemId = T::FinancialNftInstanceId;
+		type AssetId = AssetIdOf<T>;
+		type Balance = BalanceOf<T>;
+
+		fn collection_asset_ids() -> Vec<Self::AssetId> {
+			vec![T::FnftCollectionId::get()]
+		}
+
+		fn value_of(
+			collection: &Self::AssetId,
+			instance: &Self::ItemId,
+		) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError> {
+			ensure!(*collection == T::FnftCollectionId::get(), Error::<T>::BondNotFound);
+			let (_, asset, reward_asset) =
+				Bonds::<T>::get(instance).ok_or(Error::<T>::BondNotFound)?;
+			let fnft_account = T::FinancialNft::asset_account(collection, instance);
+			let mut value = vec![(reward_asset, T::Currency::balance(reward_asset, &fnft_account))];
+			if asset != reward_asset {
+				value.push((asset, T::Currency::balance(asset, &fnft_account)));
+			}
+			Ok(value)
+		}
+	}
+
 	impl<T: Config> BondedFinance for Pallet<T> {
 		type AccountId = AccountIdOf<T>;
 		type AssetId = AssetIdOf<T>;
 		type Balance = BalanceOf<T>;
 		type BlockNumber = BlockNumberOf<T>;
 		type BondOfferId = T::BondOfferId;
+		type PoolId = T::PoolId;
 		type MinReward = T::MinReward;
 		type MinVestedTransfer = <T::Vesting as VestedTransfer>::MinVestedTransfer;
 
@@ -444,9 +800,10 @@ pub mod pallet {
 			offer: Self::BondOfferId,
 			from: &Self::AccountId,
 			nb_of_bonds: Self::Balance,
+			max_price: Option<Self::Balance>,
 			keep_alive: bool,
 		) -> Result<Self::Balance, DispatchError> {
-			Self::do_bond(offer, from, nb_of_bonds, keep_alive)
+			Self::do_bond(offer, from, nb_of_bonds, max_price, keep_alive)
 		}
 	}
 }
@@ -0,0 +1,66 @@
+//! Storage migrations of the bonded finance pallet.
+
+pub mod v1 {
+	use crate::{
+		pallet::{AccountIdOf, AssetIdOf, BalanceOf, BlockNumberOf, BondOfferOf},
+		BondOffers, Config, Pallet,
+	};
+	use codec::{Decode, Encode};
+	use composable_traits::bonded_finance::{
+		BondDuration, BondOffer, BondOfferReward, BondPayment, BondPricing,
+	};
+	use frame_support::{
+		log,
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// Bond offer before offers could be paid with LP tokens and priced dynamically.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldBondOffer<AccountId, AssetId, Balance, BlockNumber> {
+		pub beneficiary: AccountId,
+		pub asset: AssetId,
+		pub bond_price: Balance,
+		pub nb_of_bonds: Balance,
+		pub maturity: BondDuration<BlockNumber>,
+		pub reward: BondOfferReward<AssetId, Balance, BlockNumber>,
+	}
+
+	pub(crate) type OldBondOfferOf<T> =
+		OldBondOffer<AccountIdOf<T>, AssetIdOf<T>, BalanceOf<T>, BlockNumberOf<T>>;
+
+	/// Makes existing offers paid in their asset at a fixed price, which keeps them selling bonds
+	/// as before.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0_u64;
+			BondOffers::<T>::translate::<(AccountIdOf<T>, OldBondOfferOf<T>), _>(
+				|_, (issuer, offer)| {
+					translated += 1;
+					let offer: BondOfferOf<T> = BondOffer {
+						beneficiary: offer.beneficiary,
+						asset: offer.asset,
+						payment: BondPayment::Asset,
+						bond_price: offer.bond_price,
+						pricing: BondPricing::Fixed,
+						nb_of_bonds: offer.nb_of_bonds,
+						maturity: offer.maturity,
+						reward: offer.reward,
+					};
+					Some((issuer, offer))
+				},
+			);
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "bonded-finance", "migrated {} bond offers to v1", translated);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+	}
+}
@@ -3,11 +3,18 @@
 #![cfg(test)]
 
 use super::*;
+use composable_traits::{
+	account_proxy::{AccountProxyWrapper, ProxyType},
+	defi::{CurrencyPair, Ratio},
+	dex::{Amm, AssetAmount, SwapResult},
+	fnft::NoFnftAccountProxyType,
+	oracle::{Oracle, Price},
+};
 use frame_support::{
 	construct_runtime,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{EnsureOrigin, Everything},
+	traits::{fungibles::Inspect, ConstU16, ConstU32, EnsureOrigin, Everything, InstanceFilter},
 	PalletId,
 };
 use frame_system::{EnsureRoot, RawOrigin};
@@ -17,8 +24,10 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{ConvertInto, IdentityLookup, Zero},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup, Zero},
+	Permill,
 };
+use sp_std::{cell::RefCell, collections::btree_map::BTreeMap};
 
 pub type BlockNumber = u64;
 pub type Moment = u64;
@@ -34,6 +43,16 @@ pub const MILLISECS_PER_BLOCK: u64 = 6000;
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
 pub const CHARLIE: AccountId = 3;
+pub const TREASURY: AccountId = 4;
+
+pub type PoolId = u128;
+
+pub const LP_POOL_ID: PoolId = 1;
+pub const BTC_RESERVE: Balance = 1_000;
+pub const ETH_RESERVE: Balance = 10_000;
+/// Oracle prices of the smallest units of assets.
+pub const BTC_PRICE: Balance = 100;
+pub const ETH_PRICE: Balance = 10;
 
 #[derive(
 	PartialOrd,
@@ -56,6 +75,15 @@ pub enum MockCurrencyId {
 	PICA,
 	BTC,
 	ETH,
+	VESTING_FNFT,
+	BOND_FNFT,
+	BTC_ETH_LP,
+}
+
+impl From<MockCurrencyId> for u128 {
+	fn from(currency_id: MockCurrencyId) -> Self {
+		currency_id as u128
+	}
 }
 
 parameter_types! {
@@ -68,7 +96,7 @@ impl frame_system::Config for Runtime {
 	type Index = u64;
 	type BlockNumber = BlockNumber;
 	type Hash = H256;
-	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type Hashing = BlakeTwo256;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
@@ -155,9 +183,59 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+impl pallet_fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProperties = ConstU32<16>;
+	type FinancialNftCollectionId = MockCurrencyId;
+	type FinancialNftInstanceId = u64;
+	type ProxyType = ProxyType;
+	type AccountProxy = AccountProxyWrapper<Runtime>;
+	type ProxyTypeSelector = NoFnftAccountProxyType;
+	type TransferHook = ();
+	type PalletId = FnftPalletId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub MaxProxies: u32 = 4;
+	pub MaxPending: u32 = 32;
+	pub ProxyPrice: u32 = 0;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = ();
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyPrice;
+	type ProxyDepositFactor = ProxyPrice;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = ();
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ProxyPrice;
+	type AnnouncementDepositFactor = ProxyPrice;
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _c: &RuntimeCall) -> bool {
+		matches!(self, ProxyType::Any)
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		self == o || matches!(self, ProxyType::Any)
+	}
+}
+
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 2;
 	pub const MinVestedTransfer: u64 = MIN_VESTED_TRANSFER as _;
+	pub const VestingFnftCollectionId: MockCurrencyId = MockCurrencyId::VESTING_FNFT;
+	pub const VestingPalletId: PalletId = PalletId(*b"pal_vest");
 }
 
 impl pallet_vesting::Config for Runtime {
@@ -171,21 +249,188 @@ impl pallet_vesting::Config for Runtime {
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = u64;
+	type FnftCollectionId = VestingFnftCollectionId;
+	type PalletId = VestingPalletId;
 }
 
 parameter_types! {
 	// cspell:disable-next
 	pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
+	pub const BondFnftCollectionId: MockCurrencyId = MockCurrencyId::BOND_FNFT;
 	pub const Stake: Balance = 10_000;
 	pub const NativeCurrencyId: MockCurrencyId = NATIVE_CURRENCY_ID;
 	pub const MinReward: Balance = MIN_REWARD;
+	pub const TreasuryAccount: AccountId = TREASURY;
+}
+
+thread_local! {
+	/// The BTC and ETH reserves of the pool of [`MockPablo`].
+	pub static RESERVES: RefCell<(Balance, Balance)> = RefCell::new((BTC_RESERVE, ETH_RESERVE));
+	/// Whether [`MockOracle`] has a price for ETH.
+	pub static ETH_PRICED: RefCell<bool> = RefCell::new(true);
+}
+
+/// A single BTC/ETH pool holding [`RESERVES`], initially [`BTC_RESERVE`] and [`ETH_RESERVE`].
+pub struct MockPablo;
+
+impl Amm for MockPablo {
+	type AssetId = MockCurrencyId;
+	type Balance = Balance;
+	type AccountId = AccountId;
+	type PoolId = PoolId;
+
+	fn pool_exists(pool_id: Self::PoolId) -> bool {
+		pool_id == LP_POOL_ID
+	}
+
+	fn assets(pool_id: Self::PoolId) -> Result<BTreeMap<Self::AssetId, Permill>, DispatchError> {
+		ensure!(Self::pool_exists(pool_id), DispatchError::Other("pool not found"));
+		Ok(BTreeMap::from([
+			(MockCurrencyId::BTC, Permill::from_percent(50)),
+			(MockCurrencyId::ETH, Permill::from_percent(50)),
+		]))
+	}
+
+	fn lp_token(pool_id: Self::PoolId) -> Result<Self::AssetId, DispatchError> {
+		ensure!(Self::pool_exists(pool_id), DispatchError::Other("pool not found"));
+		Ok(MockCurrencyId::BTC_ETH_LP)
+	}
+
+	fn redeemable_assets_for_lp_tokens(
+		pool_id: Self::PoolId,
+		lp_amount: Self::Balance,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		let lp_issuance = Tokens::total_issuance(Self::lp_token(pool_id)?);
+		let (btc_reserve, eth_reserve) = RESERVES.with(|reserves| *reserves.borrow());
+		Ok(BTreeMap::from([
+			(MockCurrencyId::BTC, btc_reserve * lp_amount / lp_issuance),
+			(MockCurrencyId::ETH, eth_reserve * lp_amount / lp_issuance),
+		]))
+	}
+
+	fn simulate_add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn simulate_remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_amounts: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn spot_price(
+		_pool_id: Self::PoolId,
+		_base_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_quote_asset_id: Self::AssetId,
+		_calculate_with_fees: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn do_buy(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset_id: Self::AssetId,
+		_out_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn add_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_assets: BTreeMap<Self::AssetId, Self::Balance>,
+		_min_mint_amount: Self::Balance,
+		_keep_alive: bool,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn remove_liquidity(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_lp_amount: Self::Balance,
+		_min_receive: BTreeMap<Self::AssetId, Self::Balance>,
+	) -> Result<BTreeMap<Self::AssetId, Self::Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn do_swap(
+		_who: &Self::AccountId,
+		_pool_id: Self::PoolId,
+		_in_asset: AssetAmount<Self::AssetId, Self::Balance>,
+		_min_receive: AssetAmount<Self::AssetId, Self::Balance>,
+		_keep_alive: bool,
+	) -> Result<SwapResult<Self::AssetId, Self::Balance>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+}
+
+/// An oracle pricing the smallest units of BTC at [`BTC_PRICE`] and of ETH at [`ETH_PRICE`].
+pub struct MockOracle;
+
+impl Oracle for MockOracle {
+	type AssetId = MockCurrencyId;
+	type Balance = Balance;
+	type Timestamp = ();
+	type LocalAssets = ();
+	type MaxAnswerBound = ConstU32<0>;
+	type TwapWindow = ConstU16<0>;
+
+	fn get_price(
+		asset_id: Self::AssetId,
+		amount: Self::Balance,
+	) -> Result<Price<Self::Balance, Self::Timestamp>, DispatchError> {
+		let price = match asset_id {
+			MockCurrencyId::BTC => BTC_PRICE,
+			MockCurrencyId::ETH if ETH_PRICED.with(|priced| *priced.borrow()) => ETH_PRICE,
+			_ => return Err(DispatchError::Other("price not found")),
+		};
+		Ok(Price { price: price * amount, block: () })
+	}
+
+	fn get_twap_for_amount(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn get_ratio(_pair: CurrencyPair<Self::AssetId>) -> Result<Ratio, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn get_price_inverse(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
 }
 
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type NativeCurrency = CurrencyAdapter<Runtime, NativeCurrencyId>;
+	type AssetId = MockCurrencyId;
 	type Currency = Tokens;
+	type PoolId = PoolId;
+	type Pablo = MockPablo;
+	type Oracle = MockOracle;
+	type TreasuryAccount = TreasuryAccount;
 	type Vesting = Vesting;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = u64;
+	type FnftCollectionId = BondFnftCollectionId;
 	type BondOfferId = u64;
 	type Convert = ConvertInto;
 	type PalletId = BondedFinanceId;
@@ -209,6 +454,8 @@ construct_runtime!(
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
 		BondedFinance: pallet::{Pallet, Call, Storage, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		Fnft: pallet_fnft,
+		Proxy: pallet_proxy,
 	}
 );
 
@@ -5,8 +5,8 @@
 use super::*;
 use composable_tests_helpers::{prop_assert_acceptable_computation_error, prop_assert_ok};
 use composable_traits::{
-	bonded_finance::{BondDuration, BondOffer, BondOfferReward},
-	vesting::VestingScheduleIdSet,
+	bonded_finance::{BondDuration, BondOffer, BondOfferReward, BondPayment, BondPricing},
+	fnft::FinancialNft,
 };
 use frame_support::{
 	error::BadOrigin,
@@ -33,11 +33,13 @@ prop_compose! {
 					  reward_amount in MIN_REWARD..Balance::MAX / 2,
 					  reward_maturity in 1..BlockNumber::MAX / 2
 			  )
-			  -> BondOffer<AccountId, MockCurrencyId, Balance, BlockNumber> {
+			  -> BondOffer<AccountId, MockCurrencyId, Balance, BlockNumber, PoolId> {
 					  BondOffer {
 							  beneficiary: ALICE,
 							  asset: MockCurrencyId::BTC,
+								payment: BondPayment::Asset,
 								bond_price,
+								pricing: BondPricing::Fixed,
 								nb_of_bonds,
 								maturity,
 							  reward: BondOfferReward {
@@ -110,7 +112,7 @@ proptest! {
 					  let half_nb_of_bonds = offer.nb_of_bonds / 2;
 					  let half_reward = offer.reward.amount / 2;
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, half_nb_of_bonds * offer.bond_price));
-					  prop_assert_ok!(BondedFinance::do_bond(offer_id, &BOB, half_nb_of_bonds, false));
+					  prop_assert_ok!(BondedFinance::do_bond(offer_id, &BOB, half_nb_of_bonds, None, false));
 
 					  // Alice cancel the offer
 					  prop_assert_ok!(BondedFinance::cancel(RuntimeOrigin::signed(ALICE), offer_id));
@@ -155,9 +157,9 @@ proptest! {
 					  let offer_id = offer_id.expect("impossible; qed");
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, offer.total_price().expect("impossible; qed;")));
-					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, false));
+					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false));
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false),
 							  Err(Error::<Runtime>::OfferCompleted.into())
 					  );
 
@@ -175,7 +177,7 @@ proptest! {
 											  0
 									  );
 									  System::set_block_number(return_in);
-									  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), offer.asset, VestingScheduleIdSet::All));
+									  prop_assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(BOB), 0));
 									  prop_assert_eq!(
 											  Tokens::balance(offer.asset, &BOB),
 											  offer.total_price().expect("impossible; qed;")
@@ -230,20 +232,22 @@ proptest! {
 					  let offer_id = offer_id.expect("impossible; qed");
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, offer.total_price().expect("impossible; qed;")));
-					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds - 1, false));
+					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds - 1, None, false));
 
 					  System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::NewBond {
 							  offer_id,
 							  who: BOB,
-							  nb_of_bonds: offer.nb_of_bonds - 1
+							  nb_of_bonds: offer.nb_of_bonds - 1,
+							  fnft_instance_id: 0,
 					  }));
 
-					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 1, false));
+					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 1, None, false));
 
 					  System::assert_has_event(RuntimeEvent::BondedFinance(crate::Event::NewBond {
 							  offer_id,
 							  who: BOB,
-							  nb_of_bonds: 1
+							  nb_of_bonds: 1,
+							  fnft_instance_id: 1,
 					  }));
 
 					  System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::OfferCompleted { offer_id }));
@@ -266,12 +270,12 @@ proptest! {
 					  let half_reward = offer.reward.amount / 2;
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, half_nb_of_bonds * offer.bond_price));
-					  let bob_reward = BondedFinance::do_bond(offer_id, &BOB, half_nb_of_bonds,false);
+					  let bob_reward = BondedFinance::do_bond(offer_id, &BOB, half_nb_of_bonds, None, false);
 					  prop_assert_ok!(bob_reward);
 					  let bob_reward = bob_reward.expect("impossible; qed;");
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &CHARLIE, half_nb_of_bonds * offer.bond_price));
-					  let charlie_reward = BondedFinance::do_bond(offer_id, &CHARLIE, half_nb_of_bonds,false);
+					  let charlie_reward = BondedFinance::do_bond(offer_id, &CHARLIE, half_nb_of_bonds, None, false);
 					  prop_assert_ok!(charlie_reward);
 					  let charlie_reward = charlie_reward.expect("impossible; qed;");
 
@@ -280,13 +284,15 @@ proptest! {
 					  prop_assert_acceptable_computation_error!(bob_reward, half_reward, precision, epsilon);
 					  prop_assert_acceptable_computation_error!(charlie_reward, half_reward, precision, epsilon);
 
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &BOB, bob_reward) == WithdrawConsequence::Frozen);
-					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &CHARLIE, charlie_reward) == WithdrawConsequence::Frozen);
+					  let bob_fnft_account = Fnft::asset_account(&BondFnftCollectionId::get(), &0);
+					  let charlie_fnft_account = Fnft::asset_account(&BondFnftCollectionId::get(), &1);
+					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &bob_fnft_account, bob_reward) == WithdrawConsequence::Frozen);
+					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &charlie_fnft_account, charlie_reward) == WithdrawConsequence::Frozen);
 
 					  System::set_block_number(offer.reward.maturity);
 
-					  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(BOB), offer.reward.asset, VestingScheduleIdSet::All));
-					  prop_assert_ok!(Vesting::claim(RuntimeOrigin::signed(CHARLIE), offer.reward.asset, VestingScheduleIdSet::All));
+					  prop_assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(BOB), 0));
+					  prop_assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(CHARLIE), 1));
 
 					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &BOB, bob_reward) == WithdrawConsequence::Success);
 					  prop_assert!(Tokens::can_withdraw(offer.reward.asset, &CHARLIE, charlie_reward) == WithdrawConsequence::Success);
@@ -306,7 +312,7 @@ proptest! {
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, offer.total_price().expect("impossible; qed;")));
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id + 1, offer.nb_of_bonds,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id + 1, offer.nb_of_bonds, None, false),
 							  Err(Error::<Runtime>::BondOfferNotFound.into())
 					  );
 
@@ -325,11 +331,11 @@ proptest! {
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, offer.total_price().expect("impossible; qed;")));
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds + 1,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds + 1, None, false),
 							  Err(Error::<Runtime>::InvalidNumberOfBonds.into())
 					  );
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 0,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 0, None, false),
 							  Err(Error::<Runtime>::InvalidNumberOfBonds.into())
 					  );
 
@@ -347,9 +353,9 @@ proptest! {
 					  let offer_id = offer_id.expect("impossible; qed");
 
 					  prop_assert_ok!(Tokens::mint_into(offer.asset, &BOB, offer.total_price().expect("impossible; qed;")));
-					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds,false));
+					  prop_assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false));
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false),
 							  Err(Error::<Runtime>::OfferCompleted.into())
 					  );
 
@@ -380,7 +386,7 @@ proptest! {
 						prop_assert_eq!(Tokens::balance(offer.reward.asset, &ALICE), offer.reward.amount);
 
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false),
 							  Err(Error::<Runtime>::BondOfferNotFound.into())
 					  );
 
@@ -411,7 +417,7 @@ proptest! {
 						prop_assert_eq!(Tokens::balance(offer.reward.asset, &ALICE), offer.reward.amount);
 
 					  prop_assert_eq!(
-							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds,false),
+							  BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, offer.nb_of_bonds, None, false),
 							  Err(Error::<Runtime>::BondOfferNotFound.into())
 					  );
 
@@ -436,7 +442,9 @@ mod test_bond_offer {
 		let valid_bond_offer = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Infinite,
 			reward: BondOfferReward {
@@ -454,7 +462,9 @@ mod test_bond_offer {
 		let valid_bond_offer2 = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 1_u128,
 			maturity: BondDuration::Finite { return_in: 1 },
 			reward: BondOfferReward {
@@ -472,7 +482,9 @@ mod test_bond_offer {
 		let valid_bond_offer3 = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Finite { return_in: 1_000_000 },
 			reward: BondOfferReward {
@@ -493,7 +505,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::PICA,
+			payment: BondPayment::Asset,
 			bond_price: MIN_VESTED_TRANSFER as u128 - 1,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Infinite,
 			reward: BondOfferReward {
@@ -515,7 +529,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: MIN_VESTED_TRANSFER as _,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 0,
 			maturity: BondDuration::Finite { return_in: 1 },
 			reward: BondOfferReward {
@@ -537,7 +553,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Finite { return_in: 0 },
 			reward: BondOfferReward {
@@ -559,7 +577,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Finite { return_in: 1_000_000 },
 			reward: BondOfferReward {
@@ -581,7 +601,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Finite { return_in: 1_000_000 },
 			reward: BondOfferReward {
@@ -603,7 +625,9 @@ mod test_bond_offer {
 		let invalid = BondOfferOf::<Runtime> {
 			beneficiary: ALICE,
 			asset: mock::MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
 			bond_price: 1_000_000 + MIN_VESTED_TRANSFER as u128,
+			pricing: BondPricing::Fixed,
 			nb_of_bonds: 100_000_u128,
 			maturity: BondDuration::Finite { return_in: 1_000_000 },
 			reward: BondOfferReward {
@@ -620,3 +644,474 @@ mod test_bond_offer {
 		.is_err());
 	}
 }
+
+#[cfg(test)]
+mod dynamic_pricing {
+	use super::*;
+	use crate::BondOfferOf;
+	use composable_support::validation::Validate;
+	use composable_traits::bonded_finance::{DynamicBondPricing, ValidBondOffer};
+	use frame_support::{assert_noop, assert_ok};
+	use sp_runtime::{FixedPointNumber, FixedU128};
+
+	const FLOOR_PRICE: Balance = 1_000;
+	const DECAY_PERIOD: BlockNumber = 100;
+
+	fn dynamic_offer(decay_period: BlockNumber) -> BondOfferOf<Runtime> {
+		BondOfferOf::<Runtime> {
+			beneficiary: CHARLIE,
+			asset: MockCurrencyId::BTC,
+			payment: BondPayment::Asset,
+			bond_price: FLOOR_PRICE,
+			pricing: BondPricing::Dynamic(DynamicBondPricing {
+				control_variable: FixedU128::one(),
+				debt: 0,
+				decay_period,
+				last_decay: 0,
+			}),
+			nb_of_bonds: 10,
+			maturity: BondDuration::Infinite,
+			reward: BondOfferReward {
+				asset: MockCurrencyId::ETH,
+				amount: MIN_REWARD,
+				maturity: 96,
+			},
+		}
+	}
+
+	#[test]
+	fn bond_charges_current_price() {
+		ExtBuilder::build().execute_with(|| {
+			System::set_block_number(1);
+			let offer = dynamic_offer(DECAY_PERIOD);
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+			assert_ok!(Tokens::mint_into(offer.asset, &BOB, FLOOR_PRICE * 100));
+			let offer_id =
+				BondedFinance::do_offer(&ALICE, offer.clone(), false).expect("valid offer; qed");
+
+			// No demand yet, bonds cost the floor price
+			assert_eq!(BondedFinance::current_bond_price(offer_id), Ok(FLOOR_PRICE));
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 5, None, false));
+			assert_eq!(Tokens::balance(offer.asset, &CHARLIE), 5 * FLOOR_PRICE);
+
+			// Half of the bonds sold, the debt ratio is 1/2
+			assert_eq!(BondedFinance::current_bond_price(offer_id), Ok(FLOOR_PRICE * 3 / 2));
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+			assert_eq!(Tokens::balance(offer.asset, &CHARLIE), 5 * FLOOR_PRICE + 3 * FLOOR_PRICE);
+
+			// The debt decays back to zero over the decay period
+			System::set_block_number(1 + DECAY_PERIOD / 2);
+			assert!(
+				BondedFinance::current_bond_price(offer_id).expect("offer exists; qed") >
+					FLOOR_PRICE
+			);
+			System::set_block_number(1 + DECAY_PERIOD);
+			assert_eq!(BondedFinance::current_bond_price(offer_id), Ok(FLOOR_PRICE));
+		});
+	}
+
+	#[test]
+	fn fixed_price_ignores_demand() {
+		ExtBuilder::build().execute_with(|| {
+			System::set_block_number(1);
+			let offer = BondOfferOf::<Runtime> { pricing: BondPricing::Fixed, ..dynamic_offer(0) };
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+			assert_ok!(Tokens::mint_into(offer.asset, &BOB, FLOOR_PRICE * 100));
+			let offer_id = BondedFinance::do_offer(&ALICE, offer, false).expect("valid offer; qed");
+
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 5, None, false));
+			assert_eq!(BondedFinance::current_bond_price(offer_id), Ok(FLOOR_PRICE));
+		});
+	}
+
+	#[test]
+	fn bond_above_max_price() {
+		ExtBuilder::build().execute_with(|| {
+			System::set_block_number(1);
+			let offer = dynamic_offer(DECAY_PERIOD);
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+			assert_ok!(Tokens::mint_into(offer.asset, &BOB, FLOOR_PRICE * 100));
+			let offer_id = BondedFinance::do_offer(&ALICE, offer, false).expect("valid offer; qed");
+
+			assert_ok!(BondedFinance::bond(
+				RuntimeOrigin::signed(BOB),
+				offer_id,
+				5,
+				Some(FLOOR_PRICE),
+				false
+			));
+
+			// The price went up with the debt before the next bond is included
+			assert_noop!(
+				BondedFinance::bond(
+					RuntimeOrigin::signed(BOB),
+					offer_id,
+					1,
+					Some(FLOOR_PRICE),
+					false
+				),
+				Error::<Runtime>::BondPriceAboveMaxPrice
+			);
+			assert_ok!(BondedFinance::bond(
+				RuntimeOrigin::signed(BOB),
+				offer_id,
+				1,
+				Some(FLOOR_PRICE * 3 / 2),
+				false
+			));
+		});
+	}
+
+	#[test]
+	fn invalid_decay_period() {
+		let offer = |decay_period| BondOfferOf::<Runtime> {
+			bond_price: 1_000_000 + MIN_VESTED_TRANSFER,
+			nb_of_bonds: 1,
+			..dynamic_offer(decay_period)
+		};
+
+		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
+			BondOfferOf<Runtime>,
+			ValidBondOffer<MinReward, MinVestedTransfer>,
+		>>::validate(offer(0))
+		.is_err());
+
+		assert_ok!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
+			BondOfferOf<Runtime>,
+			ValidBondOffer<MinReward, MinVestedTransfer>,
+		>>::validate(offer(DECAY_PERIOD)));
+	}
+}
+
+#[cfg(test)]
+mod lp_bonds {
+	use super::*;
+	use crate::BondOfferOf;
+	use frame_support::{assert_noop, assert_ok};
+
+	const LP_ISSUANCE: Balance = 1_000;
+	// The pool is worth 2 * sqrt(BTC_RESERVE * BTC_PRICE * ETH_RESERVE * ETH_PRICE) = 200_000
+	const LP_TOKEN_VALUE: Balance = 200;
+	const BOND_PRICE: Balance = 10_000;
+
+	fn lp_offer(bond_price: Balance, maturity: BondDuration<BlockNumber>) -> BondOfferOf<Runtime> {
+		BondOfferOf::<Runtime> {
+			beneficiary: CHARLIE,
+			asset: MockCurrencyId::BTC_ETH_LP,
+			payment: BondPayment::LiquidityProvider { pool_id: LP_POOL_ID },
+			bond_price,
+			pricing: BondPricing::Fixed,
+			nb_of_bonds: 10,
+			maturity,
+			reward: BondOfferReward {
+				asset: MockCurrencyId::ETH,
+				amount: MIN_REWARD,
+				maturity: 96,
+			},
+		}
+	}
+
+	fn with_lp_offer(offer: BondOfferOf<Runtime>, execute: impl FnOnce(u64)) {
+		ExtBuilder::build().execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+			assert_ok!(Tokens::mint_into(MockCurrencyId::BTC_ETH_LP, &BOB, LP_ISSUANCE));
+			let offer_id = BondedFinance::do_offer(&ALICE, offer, false).expect("valid offer; qed");
+
+			execute(offer_id)
+		});
+	}
+
+	#[test]
+	fn infinite_bond_pays_lp_to_treasury() {
+		with_lp_offer(lp_offer(BOND_PRICE, BondDuration::Infinite), |offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+
+			let lp_amount = 2 * BOND_PRICE / LP_TOKEN_VALUE;
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &TREASURY), lp_amount);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &CHARLIE), 0);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &BOB), LP_ISSUANCE - lp_amount);
+		});
+	}
+
+	#[test]
+	fn finite_bond_returns_lp_to_bonder() {
+		with_lp_offer(lp_offer(BOND_PRICE, BondDuration::Finite { return_in: 10 }), |offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+
+			let lp_amount = 2 * BOND_PRICE / LP_TOKEN_VALUE;
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &TREASURY), 0);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &CHARLIE), lp_amount);
+
+			System::set_block_number(11);
+			assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(BOB), 0));
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC_ETH_LP, &BOB), LP_ISSUANCE);
+		});
+	}
+
+	#[test]
+	fn lp_amount_is_rounded_up() {
+		with_lp_offer(lp_offer(BOND_PRICE + 1, BondDuration::Infinite), |offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+
+			assert_eq!(
+				Tokens::balance(MockCurrencyId::BTC_ETH_LP, &TREASURY),
+				2 * BOND_PRICE / LP_TOKEN_VALUE + 1
+			);
+		});
+	}
+
+	#[test]
+	fn unbalanced_pool_does_not_lower_lp_amount() {
+		with_lp_offer(lp_offer(BOND_PRICE, BondDuration::Infinite), |offer_id| {
+			// Swapping ETH for BTC keeps the product of the reserves, but the spot value of the
+			// reserves doubles
+			RESERVES.with(|reserves| *reserves.borrow_mut() = (BTC_RESERVE * 4, ETH_RESERVE / 4));
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+
+			assert_eq!(
+				Tokens::balance(MockCurrencyId::BTC_ETH_LP, &TREASURY),
+				2 * BOND_PRICE / LP_TOKEN_VALUE
+			);
+		});
+	}
+
+	#[test]
+	fn lp_bond_price_is_not_an_asset_amount() {
+		use composable_support::validation::Validate;
+		use composable_traits::bonded_finance::ValidBondOffer;
+
+		let offer = |bond_price| BondOfferOf::<Runtime> {
+			nb_of_bonds: 1,
+			..lp_offer(bond_price, BondDuration::Infinite)
+		};
+
+		// A value in the oracle currency, which may be below the minimum transfer of `asset`
+		assert_ok!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
+			BondOfferOf<Runtime>,
+			ValidBondOffer<MinReward, MinVestedTransfer>,
+		>>::validate(offer(1)));
+
+		assert!(<ValidBondOffer<MinReward, MinVestedTransfer> as Validate<
+			BondOfferOf<Runtime>,
+			ValidBondOffer<MinReward, MinVestedTransfer>,
+		>>::validate(offer(0))
+		.is_err());
+	}
+
+	#[test]
+	fn lp_offer_must_be_in_pool_lp_token() {
+		ExtBuilder::build().execute_with(|| {
+			let offer = BondOfferOf::<Runtime> {
+				asset: MockCurrencyId::BTC,
+				..lp_offer(BOND_PRICE, BondDuration::Infinite)
+			};
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+
+			assert_noop!(
+				BondedFinance::do_offer(&ALICE, offer, false),
+				Error::<Runtime>::InvalidLiquidityPool
+			);
+		});
+	}
+
+	#[test]
+	fn lp_offer_needs_oracle_prices_of_pool_assets() {
+		ExtBuilder::build().execute_with(|| {
+			let offer = lp_offer(BOND_PRICE, BondDuration::Infinite);
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+
+			ETH_PRICED.with(|priced| *priced.borrow_mut() = false);
+			assert_noop!(
+				BondedFinance::do_offer(&ALICE, offer, false),
+				Error::<Runtime>::UnpricedLiquidityPool
+			);
+		});
+	}
+}
+
+mod bond_fnft {
+	use super::*;
+	use crate::BondOfferOf;
+	use composable_traits::{bonded_finance::BondFnftAttribute, fnft::FinancialNftProtocol};
+	use frame_support::{assert_noop, assert_ok, traits::tokens::nonfungibles::Inspect as _};
+
+	const BOND_PRICE: Balance = MIN_VESTED_TRANSFER;
+	const NB_OF_BONDS: Balance = 10;
+	const REWARD_MATURITY: BlockNumber = 96;
+	const RETURN_IN: BlockNumber = 48;
+
+	fn with_offer(execute: impl FnOnce(u64)) {
+		ExtBuilder::build().execute_with(|| {
+			System::set_block_number(1);
+			let offer = BondOfferOf::<Runtime> {
+				beneficiary: ALICE,
+				asset: MockCurrencyId::BTC,
+				payment: BondPayment::Asset,
+				bond_price: BOND_PRICE,
+				pricing: BondPricing::Fixed,
+				nb_of_bonds: NB_OF_BONDS,
+				maturity: BondDuration::Finite { return_in: RETURN_IN },
+				reward: BondOfferReward {
+					asset: MockCurrencyId::ETH,
+					amount: MIN_REWARD * NB_OF_BONDS,
+					maturity: REWARD_MATURITY,
+				},
+			};
+			assert_ok!(Tokens::mint_into(NATIVE_CURRENCY_ID, &ALICE, Stake::get()));
+			assert_ok!(Tokens::mint_into(offer.reward.asset, &ALICE, offer.reward.amount));
+			assert_ok!(Tokens::mint_into(offer.asset, &BOB, BOND_PRICE * NB_OF_BONDS));
+			let offer_id = BondedFinance::do_offer(&ALICE, offer, false).expect("valid offer; qed");
+
+			execute(offer_id)
+		});
+	}
+
+	#[test]
+	fn bond_mints_fnft_with_attributes() {
+		with_offer(|offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+
+			let collection = BondFnftCollectionId::get();
+			assert_eq!(Fnft::owner(&collection, &0), Some(BOB));
+			assert_eq!(
+				Fnft::typed_attribute::<_, u64>(&collection, &0, &BondFnftAttribute::OfferId),
+				Some(offer_id)
+			);
+			assert_eq!(
+				Fnft::typed_attribute::<_, Balance>(&collection, &0, &BondFnftAttribute::Amount),
+				Some(2)
+			);
+			assert_eq!(
+				Fnft::typed_attribute::<_, BlockNumber>(
+					&collection,
+					&0,
+					&BondFnftAttribute::Maturity
+				),
+				Some(1 + REWARD_MATURITY)
+			);
+			assert_eq!(
+				BondedFinance::value_of(&collection, &0),
+				Ok(vec![
+					(MockCurrencyId::ETH, 2 * MIN_REWARD),
+					(MockCurrencyId::BTC, 2 * BOND_PRICE)
+				])
+			);
+		});
+	}
+
+	#[test]
+	fn claims_follow_fnft_owner() {
+		with_offer(|offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+			assert_ok!(Fnft::transfer(
+				RuntimeOrigin::signed(BOB),
+				BondFnftCollectionId::get(),
+				0,
+				CHARLIE
+			));
+
+			System::set_block_number(1 + RETURN_IN);
+			assert_noop!(
+				BondedFinance::claim(RuntimeOrigin::signed(BOB), 0),
+				Error::<Runtime>::NotBondOwner
+			);
+			assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(CHARLIE), 0));
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &CHARLIE), 2 * BOND_PRICE);
+			// the reward vests in a single period
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &CHARLIE), 0);
+			assert!(BondedFinance::bonds(0).is_some());
+
+			System::set_block_number(1 + REWARD_MATURITY);
+			assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(CHARLIE), 0));
+			System::assert_last_event(RuntimeEvent::BondedFinance(crate::Event::BondClaimed {
+				fnft_instance_id: 0,
+				owner: CHARLIE,
+				reward_amount: 2 * MIN_REWARD,
+				returned_amount: 0,
+			}));
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &CHARLIE), 2 * MIN_REWARD);
+			assert_eq!(Tokens::balance(MockCurrencyId::BTC, &BOB), (NB_OF_BONDS - 2) * BOND_PRICE);
+			// fully claimed bonds are burnt
+			assert_eq!(Fnft::owner(&BondFnftCollectionId::get(), &0), None);
+			assert_eq!(BondedFinance::bonds(0), None);
+		});
+	}
+
+	#[test]
+	fn claim_pays_out_the_full_fnft_account_balance() {
+		with_offer(|offer_id| {
+			assert_ok!(BondedFinance::bond(RuntimeOrigin::signed(BOB), offer_id, 2, None, false));
+			let fnft_account = Fnft::asset_account(&BondFnftCollectionId::get(), &0);
+			assert_ok!(Tokens::mint_into(MockCurrencyId::ETH, &fnft_account, MIN_REWARD));
+
+			System::set_block_number(1 + REWARD_MATURITY);
+			assert_ok!(BondedFinance::claim(RuntimeOrigin::signed(BOB), 0));
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &BOB), 3 * MIN_REWARD);
+			assert_eq!(Tokens::balance(MockCurrencyId::ETH, &fnft_account), 0);
+			assert_eq!(BondedFinance::bonds(0), None);
+		});
+	}
+
+	#[test]
+	fn claim_unknown_bond() {
+		with_offer(|_| {
+			assert_noop!(
+				BondedFinance::claim(RuntimeOrigin::signed(BOB), 0),
+				Error::<Runtime>::BondNotFound
+			);
+		});
+	}
+}
+
+#[test]
+fn migration_to_v1_keeps_offers_selling_as_before() {
+	use crate::{migrations, BondOfferOf};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	ExtBuilder::build().execute_with(|| {
+		let reward =
+			BondOfferReward { asset: MockCurrencyId::ETH, amount: MIN_REWARD, maturity: 96 };
+		frame_support::storage::unhashed::put(
+			&BondOffers::<Runtime>::hashed_key_for(0_u64),
+			&(
+				ALICE,
+				migrations::v1::OldBondOffer {
+					beneficiary: BOB,
+					asset: MockCurrencyId::BTC,
+					bond_price: MIN_VESTED_TRANSFER,
+					nb_of_bonds: 10_u128,
+					maturity: BondDuration::Finite { return_in: 10_u64 },
+					reward: reward.clone(),
+				},
+			),
+		);
+		StorageVersion::new(0).put::<BondedFinance>();
+
+		migrations::v1::MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(BondedFinance::on_chain_storage_version(), 1);
+		assert_eq!(
+			BondedFinance::offers(0),
+			Some((
+				ALICE,
+				BondOfferOf::<Runtime> {
+					beneficiary: BOB,
+					asset: MockCurrencyId::BTC,
+					payment: BondPayment::Asset,
+					bond_price: MIN_VESTED_TRANSFER,
+					pricing: BondPricing::Fixed,
+					nb_of_bonds: 10,
+					maturity: BondDuration::Finite { return_in: 10 },
+					reward,
+				}
+			))
+		);
+	});
+}
@@ -3,22 +3,34 @@
 #![allow(unused_imports)]
 #![allow(clippy::unnecessary_cast)]
 
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::{constants::RocksDbWeight, Weight}};
 
 pub trait WeightInfo {
 	fn offer() -> Weight;
 	fn bond() -> Weight;
 	fn cancel() -> Weight;
+	fn claim() -> Weight;
 }
 
 impl WeightInfo for () {
 	fn offer() -> Weight {
     Weight::from_ref_time(10_000)
 	}
+	// not benchmarked: pays the offer, mints the bond fNFT and vests its reward to the asset
+	// account
 	fn bond() -> Weight {
-    Weight::from_ref_time(10_000)
+		Weight::from_ref_time(275_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(19_u64))
+			.saturating_add(RocksDbWeight::get().writes(20_u64))
 	}
 	fn cancel() -> Weight {
     Weight::from_ref_time(10_000)
 	}
+	// not benchmarked: claims the vested reward of the bond fNFT, returning the bonded LP tokens
+	// at maturity
+	fn claim() -> Weight {
+		Weight::from_ref_time(180_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
 }
@@ -0,0 +1,111 @@
+//! Proofs of remote account ownership and their verification.
+//!
+//! A proof is a signature by a remote account of `{prefix}{hex(local_account)}`, as specified
+//! by the functions of [`signature_verification`].
+use crate::{
+	signature_verification::{self, SignatureVerificationError},
+	types::{
+		CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, Ed25519PublicKey, Ed25519Signature,
+		EthereumAddress,
+	},
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{AccountId32, MultiSignature, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+/// Proof that a remote account owns a local account.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum Proof<RelayChainAccountId> {
+	RelayChain(RelayChainAccountId, MultiSignature),
+	Ethereum(EcdsaSignature),
+	Cosmos(CosmosPublicKey, CosmosEcdsaSignature),
+	/// ed25519 signature of a Solana account or Cosmos ed25519 key.
+	Ed25519(Ed25519PublicKey, Ed25519Signature),
+	/// EIP-712 typed data signature of an Ethereum account.
+	EthereumTypedData(EcdsaSignature),
+}
+
+/// Remote account that is associated with a local account.
+#[derive(Hash, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum RemoteAccount<RelayChainAccountId> {
+	RelayChain(RelayChainAccountId),
+	Ethereum(EthereumAddress),
+	Cosmos(CosmosPublicKey),
+	Ed25519(Ed25519PublicKey),
+}
+
+/// Verifies proofs of remote account ownership of local accounts.
+pub trait IdentityVerifier<AccountId> {
+	/// Proof of ownership of a local account.
+	type Proof;
+	/// Remote identity proven by a [`Self::Proof`].
+	type Identity;
+
+	/// Returns the remote identity owning `account`, as proven by `proof` of `prefix`.
+	fn verify(
+		prefix: &[u8],
+		account: &AccountId,
+		proof: Self::Proof,
+	) -> signature_verification::Result<Self::Identity>;
+}
+
+/// Verifies relay chain, Ethereum (`eth_sign` or EIP-712 typed data), Cosmos (secp256k1 or
+/// secp256r1) and ed25519 (Solana or Cosmos) proofs.
+pub struct MultiChainVerifier<RelayChainAccountId>(PhantomData<RelayChainAccountId>);
+
+impl<AccountId, RelayChainAccountId> IdentityVerifier<AccountId>
+	for MultiChainVerifier<RelayChainAccountId>
+where
+	AccountId: Encode + Clone,
+	RelayChainAccountId: Into<AccountId32> + Clone,
+{
+	type Proof = Proof<RelayChainAccountId>;
+	type Identity = RemoteAccount<RelayChainAccountId>;
+
+	fn verify(
+		prefix: &[u8],
+		account: &AccountId,
+		proof: Self::Proof,
+	) -> signature_verification::Result<Self::Identity> {
+		let account_encoded = account.using_encoded(signature_verification::get_encoded_vec);
+		match proof {
+			Proof::RelayChain(relay_account, relay_proof) => {
+				if signature_verification::verify_relay(
+					prefix,
+					account.clone(),
+					relay_account.clone().into(),
+					&relay_proof,
+				) {
+					Ok(RemoteAccount::RelayChain(relay_account))
+				} else {
+					Err(SignatureVerificationError::FailedVerification)
+				}
+			},
+			Proof::Ethereum(eth_proof) =>
+				signature_verification::ethereum_recover(prefix, &account_encoded, &eth_proof)
+					.map(RemoteAccount::Ethereum),
+			Proof::Cosmos(cosmos_address, cosmos_proof) => signature_verification::cosmos_recover(
+				prefix,
+				&account_encoded,
+				cosmos_address,
+				&cosmos_proof,
+			)
+			.map(RemoteAccount::Cosmos),
+			Proof::Ed25519(public_key, ed25519_proof) => signature_verification::ed25519_verify(
+				prefix,
+				&account_encoded,
+				public_key,
+				&ed25519_proof,
+			)
+			.map(RemoteAccount::Ed25519),
+			Proof::EthereumTypedData(eth_proof) =>
+				signature_verification::ethereum_typed_data_recover(
+					prefix,
+					&account_encoded,
+					&eth_proof,
+				)
+				.map(RemoteAccount::Ethereum),
+		}
+	}
+}
@@ -0,0 +1,15 @@
+//! Building blocks of distribution programs letting remote accounts claim funds on a local
+//! account, such as crowdloan rewards and airdrops.
+//!
+//! * [`identity`] - Proofs of remote account ownership and their pluggable verification.
+//! * [`vesting`] - Vesting schedules and the accounting of claimed funds.
+//! * [`relay`] - Limits on the claims relayed on behalf of remote accounts.
+//!
+//! A distribution program associates local accounts with the remote accounts proven by an
+//! [`IdentityVerifier`](identity::IdentityVerifier), keeps a
+//! [`ClaimAccount`](vesting::ClaimAccount) per remote account, and pays out what vested along its
+//! [`VestingSchedule`](vesting::VestingSchedule).
+
+pub mod identity;
+pub mod relay;
+pub mod vesting;
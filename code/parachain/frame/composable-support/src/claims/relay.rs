@@ -0,0 +1,58 @@
+//! Limits on the claims relayed on behalf of remote accounts.
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The number of claims relayed in the block of the last relayed claim.
+///
+/// Encoded as a `(block, count)` tuple.
+#[derive(
+	Clone, Copy, Default, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub struct RelayedClaimQuota<BlockNumber> {
+	/// Block of the last relayed claim.
+	pub block: BlockNumber,
+	/// Number of claims relayed in `block`.
+	pub count: u32,
+}
+
+impl<BlockNumber: PartialEq + Copy> RelayedClaimQuota<BlockNumber> {
+	/// Counts a claim relayed at block `now`, unless `max_per_block` claims have already been
+	/// relayed in it.
+	///
+	/// Returns whether the claim was counted.
+	pub fn try_use(&mut self, now: BlockNumber, max_per_block: u32) -> bool {
+		if self.block != now {
+			self.block = now;
+			self.count = 0;
+		}
+		if self.count >= max_per_block {
+			return false
+		}
+		self.count += 1;
+		true
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn try_use_is_limited_per_block() {
+		let mut quota = RelayedClaimQuota::<u32>::default();
+		assert!(quota.try_use(1, 2));
+		assert!(quota.try_use(1, 2));
+		assert!(!quota.try_use(1, 2));
+		assert_eq!(quota, RelayedClaimQuota { block: 1, count: 2 });
+
+		assert!(quota.try_use(2, 2));
+		assert_eq!(quota, RelayedClaimQuota { block: 2, count: 1 });
+	}
+
+	#[test]
+	fn quota_is_encoded_as_a_tuple() {
+		let quota = RelayedClaimQuota { block: 7_u32, count: 3 };
+		assert_eq!(quota.encode(), (7_u32, 3_u32).encode());
+	}
+}
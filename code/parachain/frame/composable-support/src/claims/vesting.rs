@@ -0,0 +1,158 @@
+//! Vesting schedules and the accounting of claimed funds.
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32Bit, AtLeast32BitUnsigned, Convert, Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+
+/// Schedule along which claimable funds vest.
+///
+/// Nothing is released before `start`. The `initial_payment` share of the funds is released at
+/// `start`, the rest vests linearly over the vesting period of the funds, released by windows of
+/// `step`.
+#[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct VestingSchedule<Moment> {
+	/// Moment at which the funds start vesting.
+	pub start: Moment,
+	/// Time between two releases of vested funds.
+	pub step: Moment,
+	/// Share of the funds released at `start`.
+	pub initial_payment: Perbill,
+}
+
+impl<Moment: AtLeast32Bit + Copy> VestingSchedule<Moment> {
+	/// The amount of `total` released at `now`, `total` vesting over `vesting_period`.
+	pub fn vested<Balance, MomentToBalance>(
+		&self,
+		total: Balance,
+		vesting_period: Moment,
+		now: Moment,
+	) -> Balance
+	where
+		Balance: AtLeast32BitUnsigned + Copy,
+		MomentToBalance: Convert<Moment, Balance>,
+	{
+		if now < self.start {
+			// Nothing vests before start, not even the initial payment.
+			return Balance::zero()
+		}
+		let upfront_payment = self.initial_payment.mul_floor(total);
+
+		// Current point in time
+		let vesting_point = now.saturating_sub(self.start);
+		if vesting_point >= vesting_period {
+			// The vesting period is over, everything vested.
+			return total
+		}
+
+		// Current window, rounded to previous window.
+		let vesting_window = if self.step.is_zero() {
+			vesting_point
+		} else {
+			vesting_point.saturating_sub(vesting_point % self.step)
+		};
+		let vested = total.saturating_sub(upfront_payment);
+		upfront_payment.saturating_add(
+			vested.saturating_mul(MomentToBalance::convert(vesting_window)) /
+				MomentToBalance::convert(vesting_period),
+		)
+	}
+}
+
+/// Accounting of the funds a remote account claims.
+pub trait ClaimAccount {
+	type Balance: AtLeast32BitUnsigned + Copy;
+	type Moment: AtLeast32Bit + Copy;
+
+	/// Total funds to be claimed.
+	fn total(&self) -> Self::Balance;
+
+	/// Funds claimed so far.
+	fn claimed(&self) -> Self::Balance;
+
+	/// Period over which the `total` vests.
+	fn vesting_period(&self) -> Self::Moment;
+
+	/// Records a claim of `amount`.
+	fn record_claim(&mut self, amount: Self::Balance);
+
+	/// The funds vested at `now` along `schedule` that have not been claimed yet.
+	fn available_to_claim<MomentToBalance: Convert<Self::Moment, Self::Balance>>(
+		&self,
+		schedule: &VestingSchedule<Self::Moment>,
+		now: Self::Moment,
+	) -> Self::Balance {
+		schedule
+			.vested::<_, MomentToBalance>(self.total(), self.vesting_period(), now)
+			.saturating_sub(self.claimed())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::traits::ConvertInto;
+
+	fn schedule() -> VestingSchedule<u64> {
+		VestingSchedule { start: 100, step: 10, initial_payment: Perbill::from_percent(50) }
+	}
+
+	struct Fund {
+		total: u128,
+		claimed: u128,
+	}
+
+	impl ClaimAccount for Fund {
+		type Balance = u128;
+		type Moment = u64;
+
+		fn total(&self) -> u128 {
+			self.total
+		}
+
+		fn claimed(&self) -> u128 {
+			self.claimed
+		}
+
+		fn vesting_period(&self) -> u64 {
+			100
+		}
+
+		fn record_claim(&mut self, amount: u128) {
+			self.claimed += amount;
+		}
+	}
+
+	#[test]
+	fn vested_releases_initial_payment_at_start() {
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 100), 500);
+		// Nothing vested before start, not even the initial payment.
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 99), 0);
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 0, 0), 0);
+	}
+
+	#[test]
+	fn vested_releases_by_steps() {
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 119), 550);
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 120), 600);
+	}
+
+	#[test]
+	fn vested_releases_everything_at_end_of_period() {
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 199), 950);
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 100, 200), 1_000);
+		assert_eq!(schedule().vested::<u128, ConvertInto>(1_000, 0, 100), 1_000);
+	}
+
+	#[test]
+	fn available_to_claim_excludes_claimed_funds() {
+		let mut fund = Fund { total: 1_000, claimed: 0 };
+		let available = fund.available_to_claim::<ConvertInto>(&schedule(), 150);
+		assert_eq!(available, 750);
+
+		fund.record_claim(available);
+		assert_eq!(fund.available_to_claim::<ConvertInto>(&schedule(), 150), 0);
+		assert_eq!(fund.available_to_claim::<ConvertInto>(&schedule(), 200), 250);
+	}
+}
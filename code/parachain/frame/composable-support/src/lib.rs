@@ -13,6 +13,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod abstractions;
+pub mod claims;
 pub mod collections;
 pub mod math;
 pub mod rpc_helpers;
@@ -2,10 +2,14 @@
 //!
 //! Signed messages/proofs are expected to be in the format of `{prefix}-{msg}` before they
 //! are modified to fit their chains signature specifications.
-use crate::types::{CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, EthereumAddress};
+use crate::types::{
+	CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, Ed25519PublicKey, Ed25519Signature,
+	EthereumAddress,
+};
 use codec::{Decode, Encode};
 use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
 use scale_info::TypeInfo;
+use sp_core::ed25519;
 use sp_io::hashing::{keccak_256, sha2_256};
 use sp_runtime::{traits::Verify, AccountId32, MultiSignature};
 use sp_std::vec::Vec;
@@ -105,6 +109,72 @@ pub fn ethereum_signable_message(prefix: &[u8], msg: &[u8]) -> Vec<u8> {
 	signed_message
 }
 
+/// Name of the EIP-712 domain of typed data signatures.
+pub const EIP712_DOMAIN_NAME: &[u8] = b"Composable Finance";
+
+/// Version of the EIP-712 domain of typed data signatures.
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
+
+/// Recover the public key of an EIP-712 (`eth_signTypedData_v4`) signature.
+///
+/// Requires the original message. See [`ethereum_typed_data_hash`] for the signed structure.
+pub fn ethereum_typed_data_recover(
+	prefix: &[u8],
+	msg: &[u8],
+	EcdsaSignature(sig): &EcdsaSignature,
+) -> Result<EthereumAddress> {
+	let msg = ethereum_typed_data_hash(prefix, msg);
+	let mut address = EthereumAddress::default();
+
+	address.0.copy_from_slice(
+		&keccak_256(&sp_io::crypto::secp256k1_ecdsa_recover(sig, &msg)?[..])[12..],
+	);
+
+	Ok(address)
+}
+
+/// Generates the EIP-712 hash of a claim, so that wallets display the `prefix` and `msg` instead
+/// of an opaque message.
+///
+/// The typed data is a `Claim(string prefix,string account)` in the
+/// `EIP712Domain(string name,string version)` domain named [`EIP712_DOMAIN_NAME`].
+pub fn ethereum_typed_data_hash(prefix: &[u8], msg: &[u8]) -> [u8; 32] {
+	let domain_separator = keccak_256(
+		&[
+			keccak_256(b"EIP712Domain(string name,string version)"),
+			keccak_256(EIP712_DOMAIN_NAME),
+			keccak_256(EIP712_DOMAIN_VERSION),
+		]
+		.concat(),
+	);
+	let claim = keccak_256(
+		&[keccak_256(b"Claim(string prefix,string account)"), keccak_256(prefix), keccak_256(msg)]
+			.concat(),
+	);
+
+	keccak_256(&[&b"\x19\x01"[..], &domain_separator[..], &claim[..]].concat())
+}
+
+/// Verify an ed25519 signature, as produced by Solana wallets or Cosmos ed25519 keys.
+///
+/// The signed message is `{prefix}{msg}`, without any pre-hashing.
+pub fn ed25519_verify(
+	prefix: &[u8],
+	msg: &[u8],
+	Ed25519PublicKey(pub_key): Ed25519PublicKey,
+	Ed25519Signature(sig): &Ed25519Signature,
+) -> Result<Ed25519PublicKey> {
+	if sp_io::crypto::ed25519_verify(
+		&ed25519::Signature::from_raw(*sig),
+		&[prefix, msg].concat(),
+		&ed25519::Public::from_raw(pub_key),
+	) {
+		Ok(Ed25519PublicKey(pub_key))
+	} else {
+		Err(SignatureVerificationError::FailedVerification)
+	}
+}
+
 /// From a signature and message, will attempt to recover and validate a Cosmos public key.
 ///
 /// Supports both secp256k1 and secp256r1 signatures.
@@ -59,6 +59,12 @@ impl<'de> frame_support::Deserialize<'de> for EthereumAddress {
 	}
 }
 
+/// Raw ed25519 public key, as used by Solana accounts and Cosmos ed25519 keys.
+#[derive(
+	Hash, Clone, Copy, PartialEq, Eq, Encode, Decode, Default, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct Ed25519PublicKey(pub [u8; 32]);
+
 #[derive(PartialEq, Eq, Encode, Decode, Clone, MaxEncodedLen, TypeInfo)]
 pub struct CosmosEcdsaSignature(pub [u8; 64]);
 
@@ -68,6 +74,15 @@ impl sp_std::fmt::Debug for CosmosEcdsaSignature {
 	}
 }
 
+#[derive(PartialEq, Eq, Encode, Decode, Clone, MaxEncodedLen, TypeInfo)]
+pub struct Ed25519Signature(pub [u8; 64]);
+
+impl sp_std::fmt::Debug for Ed25519Signature {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		write!(f, "Ed25519Signature({:?})", &self.0[..])
+	}
+}
+
 /// Struct representing an Elliptic Curve Signature
 #[derive(PartialEq, Eq, Encode, Decode, Clone, MaxEncodedLen, TypeInfo)]
 pub struct EcdsaSignature(pub [u8; 65]);
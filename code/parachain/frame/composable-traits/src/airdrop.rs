@@ -1,7 +1,35 @@
 //! Traits used in the implementation of the Airdrop pallet.
 
+use codec::{Decode, Encode};
 use frame_support::dispatch::{DispatchResult, DispatchResultWithPostInfo};
-use sp_runtime::DispatchError;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{DispatchError, RuntimeDebug};
+
+/// Claimed and unclaimed funds of an Airdrop.
+#[derive(RuntimeDebug, Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AirdropFunds<Balance> {
+	/// Total funds committed to the Airdrop.
+	pub total: Balance,
+	/// Amount of the `total` already claimed by recipients.
+	pub claimed: Balance,
+	/// Amount of the `total` not claimed yet.
+	pub unclaimed: Balance,
+}
+
+/// Claim status of a recipient of an Airdrop.
+#[derive(RuntimeDebug, Encode, Decode, Default, Clone, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct RecipientClaimStatus<Balance> {
+	/// Total funds committed for the recipient.
+	pub total: Balance,
+	/// Amount of the `total` already claimed by the recipient.
+	pub claimed: Balance,
+	/// Amount the recipient can claim right now.
+	pub claimable: Balance,
+}
 
 /// Contains functions necessary functions for the business logic for managing Airdrops
 pub trait Airdropper {
@@ -14,12 +42,18 @@ pub trait Airdropper {
 	type RecipientCollection;
 	type Identity;
 	type VestingSchedule;
+	type MerkleDistribution;
+	type PayoutMode;
 
-	/// Create a new Airdrop.
+	/// Create a new Airdrop, optionally committing to its recipients with a Merkle root and
+	/// ending at a claim deadline, paying claims out as set by `payout_mode`.
 	fn create_airdrop(
 		creator_id: Self::AccountId,
 		start: Option<Self::AirdropStart>,
 		schedule: Self::VestingSchedule,
+		merkle_distribution: Option<Self::MerkleDistribution>,
+		expire_at: Option<Self::AirdropStart>,
+		payout_mode: Self::PayoutMode,
 	) -> DispatchResult;
 
 	/// Add one or more recipients to an Airdrop.
@@ -45,6 +79,9 @@ pub trait Airdropper {
 		airdrop_id: Self::AirdropId,
 	) -> Result<Self::Balance, DispatchError>;
 
+	/// Return the unclaimed funds of an Airdrop that reached its claim deadline to its creator.
+	fn sweep(airdrop_id: Self::AirdropId) -> Result<Self::Balance, DispatchError>;
+
 	/// Claim a recipient reward from an Airdrop.
 	fn claim(
 		airdrop_id: Self::AirdropId,
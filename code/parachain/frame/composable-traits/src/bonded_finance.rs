@@ -1,10 +1,13 @@
 use composable_support::{
-	math::safe::{SafeDiv, SafeMul},
+	math::safe::{safe_multiply_by_rational, SafeDiv, SafeMul},
 	validation::{Validate, Validated},
 };
 use frame_support::{pallet_prelude::*, traits::Get};
 use scale_info::TypeInfo;
-use sp_runtime::{traits::Zero, ArithmeticError};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, CheckedAdd, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	ArithmeticError, FixedPointNumber, FixedU128,
+};
 
 pub trait BondedFinance {
 	type AccountId;
@@ -12,6 +15,7 @@ pub trait BondedFinance {
 	type Balance;
 	type BlockNumber;
 	type BondOfferId;
+	type PoolId;
 	type MinReward;
 	type MinVestedTransfer;
 
@@ -19,17 +23,24 @@ pub trait BondedFinance {
 	fn offer(
 		from: &Self::AccountId,
 		offer: Validated<
-			BondOffer<Self::AccountId, Self::AssetId, Self::Balance, Self::BlockNumber>,
+			BondOffer<
+				Self::AccountId,
+				Self::AssetId,
+				Self::Balance,
+				Self::BlockNumber,
+				Self::PoolId,
+			>,
 			ValidBondOffer<Self::MinReward, Self::MinVestedTransfer>,
 		>,
 		keep_alive: bool,
 	) -> Result<Self::BondOfferId, DispatchError>;
 
-	/// Bond for an offer.
+	/// Bond for an offer, unless its bond price is above `max_price`.
 	fn bond(
 		offer: Self::BondOfferId,
 		from: &Self::AccountId,
 		nb_of_bonds: Self::Balance,
+		max_price: Option<Self::Balance>,
 		keep_alive: bool,
 	) -> Result<Self::Balance, DispatchError>;
 }
@@ -43,16 +54,31 @@ pub enum BondDuration<BlockNumber> {
 	Infinite,
 }
 
+/// How the bonds of an offer are paid.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum BondPayment<PoolId> {
+	/// Bonds are paid in `asset`, `bond_price` being an amount of `asset`.
+	Asset,
+	/// Bonds are paid in `asset`, the LP token of the liquidity pool `pool_id`, `bond_price`
+	/// being a value in the currency of the oracle. LP tokens are valued with the fair reserves
+	/// of the pool, a 50/50 pool of two assets, and the oracle prices of its assets.
+	LiquidityProvider { pool_id: PoolId },
+}
+
 /// The Bond offer.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
-pub struct BondOffer<AccountId, AssetId, Balance, BlockNumber> {
+pub struct BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId> {
 	/// The account that will receive the locked assets.
 	pub beneficiary: AccountId,
 	/// Asset to be locked. Unlockable after `maturity`.
 	/// Asset which `beneficiary` wants to get for their offer.
 	pub asset: AssetId,
-	/// Price of a bond unit in `asset`.
+	/// How the bonds are paid in `asset`.
+	pub payment: BondPayment<PoolId>,
+	/// Price of a bond unit in `asset`. The floor price of offers with dynamic pricing.
 	pub bond_price: Balance,
+	/// How the price of a bond unit evolves as bonds are sold.
+	pub pricing: BondPricing<Balance, BlockNumber>,
 	/// Number of bonds. We use the Balance type for the sake of simplicity.
 	pub nb_of_bonds: Balance,
 	/// Duration for which the asset has to be locked.
@@ -61,6 +87,98 @@ pub struct BondOffer<AccountId, AssetId, Balance, BlockNumber> {
 	pub reward: BondOfferReward<AssetId, Balance, BlockNumber>,
 }
 
+/// The pricing of the bonds of an offer.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum BondPricing<Balance, BlockNumber> {
+	/// Every bond costs `bond_price`.
+	Fixed,
+	/// The price of a bond rises with demand and decays back toward `bond_price` over time.
+	Dynamic(DynamicBondPricing<Balance, BlockNumber>),
+}
+
+/// Control variables of an Olympus-style bond pricing.
+///
+/// Bonds sold add to the `debt` of the offer, which decays linearly over `decay_period`. The debt
+/// ratio is the share of the decayed debt in the debt and the bonds left to sell. The price of a
+/// bond is `bond_price * (1 + control_variable * debt_ratio)`, so it never falls below
+/// `bond_price`, and never exceeds `bond_price * (1 + control_variable)`.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct DynamicBondPricing<Balance, BlockNumber> {
+	/// Scales the premium over `bond_price` with the debt ratio.
+	pub control_variable: FixedU128,
+	/// Number of bonds recently sold, as of `last_decay`.
+	pub debt: Balance,
+	/// Number of blocks for the debt to fully decay.
+	pub decay_period: BlockNumber,
+	/// Block at which `debt` was last updated.
+	pub last_decay: BlockNumber,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned + Copy>
+	DynamicBondPricing<Balance, BlockNumber>
+{
+	/// The debt once decayed up to block `now`.
+	pub fn decayed_debt(&self, now: BlockNumber) -> Balance {
+		let elapsed = now.saturating_sub(self.last_decay);
+		if elapsed >= self.decay_period {
+			return Balance::zero()
+		}
+
+		// can't fail, the remaining time is lower than the nonzero `decay_period`
+		safe_multiply_by_rational(
+			self.debt.unique_saturated_into(),
+			(self.decay_period - elapsed).unique_saturated_into(),
+			self.decay_period.unique_saturated_into(),
+		)
+		.map_or_else(|_| Balance::zero(), Balance::unique_saturated_from)
+	}
+
+	/// The price of a bond at block `now`, with `nb_of_bonds` bonds left to sell.
+	pub fn bond_price(
+		&self,
+		bond_price: Balance,
+		nb_of_bonds: Balance,
+		now: BlockNumber,
+	) -> Result<Balance, ArithmeticError> {
+		let debt = self.decayed_debt(now);
+		let supply = debt.checked_add(&nb_of_bonds).ok_or(ArithmeticError::Overflow)?;
+		if supply.is_zero() {
+			return Ok(bond_price)
+		}
+
+		let debt_ratio = FixedU128::checked_from_rational::<u128, u128>(
+			debt.unique_saturated_into(),
+			supply.unique_saturated_into(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let premium = self
+			.control_variable
+			.checked_mul(&debt_ratio)
+			.ok_or(ArithmeticError::Overflow)?
+			.checked_mul_int::<u128>(bond_price.unique_saturated_into())
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Balance::try_from(premium)
+			.ok()
+			.and_then(|premium| bond_price.checked_add(&premium))
+			.ok_or(ArithmeticError::Overflow)
+	}
+
+	/// Decays the debt up to block `now` and adds `nb_of_bonds` bonds sold to it.
+	pub fn add_debt(
+		&mut self,
+		nb_of_bonds: Balance,
+		now: BlockNumber,
+	) -> Result<(), ArithmeticError> {
+		self.debt = self
+			.decayed_debt(now)
+			.checked_add(&nb_of_bonds)
+			.ok_or(ArithmeticError::Overflow)?;
+		self.last_decay = now;
+		Ok(())
+	}
+}
+
 /// The Bond reward. Asset and rules reward will be given.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
 pub struct BondOfferReward<AssetId, Balance, BlockNumber> {
@@ -72,6 +190,18 @@ pub struct BondOfferReward<AssetId, Balance, BlockNumber> {
 	pub maturity: BlockNumber,
 }
 
+/// The keys of the typed attributes of the financial NFT of a bond position.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum BondFnftAttribute {
+	/// The offer bonded to, a `BondOfferId`.
+	OfferId,
+	/// The number of bonds bought, a `Balance`.
+	Amount,
+	/// The block at which the reward and the bonded amount, if returned, are fully vested, a
+	/// `BlockNumber`.
+	Maturity,
+}
+
 #[derive(Debug, Decode)]
 pub struct ValidBondOffer<U, V> {
 	_marker: PhantomData<(U, V)>,
@@ -92,9 +222,10 @@ impl<
 		AssetId,
 		Balance: Zero + PartialOrd + SafeDiv + SafeMul,
 		BlockNumber: Zero,
+		PoolId,
 	>
 	Validate<
-		BondOffer<AccountId, AssetId, Balance, BlockNumber>,
+		BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId>,
 		ValidBondOffer<MinTransfer, MinReward>,
 	> for ValidBondOffer<MinTransfer, MinReward>
 where
@@ -103,8 +234,8 @@ where
 	MinReward: Get<Balance>,
 {
 	fn validate(
-		input: BondOffer<AccountId, AssetId, Balance, BlockNumber>,
-	) -> Result<BondOffer<AccountId, AssetId, Balance, BlockNumber>, &'static str> {
+		input: BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId>,
+	) -> Result<BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId>, &'static str> {
 		let nonzero_maturity = match &input.maturity {
 			BondDuration::Finite { return_in } => !return_in.is_zero(),
 			BondDuration::Infinite => true,
@@ -114,14 +245,29 @@ where
 			return Err("MATURITY_CANNOT_BE_ZERO")
 		}
 
-		if input.bond_price < MinTransfer::get() {
-			return Err("BOND_PRICE_BELOW_MIN_TRANSFER")
+		match input.payment {
+			BondPayment::Asset =>
+				if input.bond_price < MinTransfer::get() {
+					return Err("BOND_PRICE_BELOW_MIN_TRANSFER")
+				},
+			// The price is a value in the oracle currency rather than an amount of `asset`. The LP
+			// amount is only known at bond, where vesting its return checks the minimum transfer.
+			BondPayment::LiquidityProvider { .. } =>
+				if input.bond_price.is_zero() {
+					return Err("BOND_PRICE_CANNOT_BE_ZERO")
+				},
 		}
 
 		if input.nb_of_bonds.is_zero() {
 			return Err("NUMBER_OF_BOND_CANNOT_BE_ZERO")
 		}
 
+		if let BondPricing::Dynamic(pricing) = &input.pricing {
+			if pricing.decay_period.is_zero() {
+				return Err("DECAY_PERIOD_CANNOT_BE_ZERO")
+			}
+		}
+
 		let valid_reward = input.reward.amount >= MinReward::get() &&
 			input
 				.reward
@@ -146,8 +292,8 @@ where
 	}
 }
 
-impl<AccountId, AssetId, Balance: Zero + PartialOrd + SafeMul, BlockNumber: Zero>
-	BondOffer<AccountId, AssetId, Balance, BlockNumber>
+impl<AccountId, AssetId, Balance: Zero + PartialOrd + SafeMul, BlockNumber: Zero, PoolId>
+	BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId>
 {
 	/// An offer is completed once all it's nb_of_bonds has been sold.
 	pub fn completed(&self) -> bool {
@@ -158,3 +304,92 @@ impl<AccountId, AssetId, Balance: Zero + PartialOrd + SafeMul, BlockNumber: Zero
 		self.nb_of_bonds.safe_mul(&self.bond_price)
 	}
 }
+
+impl<
+		AccountId,
+		AssetId,
+		Balance: AtLeast32BitUnsigned + Copy,
+		BlockNumber: AtLeast32BitUnsigned + Copy,
+		PoolId,
+	> BondOffer<AccountId, AssetId, Balance, BlockNumber, PoolId>
+{
+	/// The price of a bond of the offer at block `now`.
+	pub fn current_bond_price(&self, now: BlockNumber) -> Result<Balance, ArithmeticError> {
+		match &self.pricing {
+			BondPricing::Fixed => Ok(self.bond_price),
+			BondPricing::Dynamic(pricing) =>
+				pricing.bond_price(self.bond_price, self.nb_of_bonds, now),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const FLOOR_PRICE: u128 = 1_000;
+	const DECAY_PERIOD: u64 = 100;
+
+	fn pricing(control_variable: FixedU128, debt: u128) -> DynamicBondPricing<u128, u64> {
+		DynamicBondPricing { control_variable, debt, decay_period: DECAY_PERIOD, last_decay: 0 }
+	}
+
+	#[test]
+	fn debt_decays_linearly() {
+		let pricing = pricing(FixedU128::one(), 1_000);
+
+		assert_eq!(pricing.decayed_debt(0), 1_000);
+		assert_eq!(pricing.decayed_debt(25), 750);
+		assert_eq!(pricing.decayed_debt(50), 500);
+		assert_eq!(pricing.decayed_debt(DECAY_PERIOD - 1), 10);
+		assert_eq!(pricing.decayed_debt(DECAY_PERIOD), 0);
+		assert_eq!(pricing.decayed_debt(DECAY_PERIOD * 2), 0);
+	}
+
+	#[test]
+	fn price_is_floor_without_debt() {
+		let pricing = pricing(FixedU128::saturating_from_integer(2), 0);
+
+		assert_eq!(pricing.bond_price(FLOOR_PRICE, 1_000, 0), Ok(FLOOR_PRICE));
+		assert_eq!(pricing.bond_price(FLOOR_PRICE, 0, 0), Ok(FLOOR_PRICE));
+	}
+
+	#[test]
+	fn price_rises_with_debt_ratio() {
+		let control_variable = FixedU128::saturating_from_integer(2);
+
+		// debt ratio of 1/4
+		assert_eq!(pricing(control_variable, 250).bond_price(FLOOR_PRICE, 750, 0), Ok(1_500));
+		// debt ratio of 1/2
+		assert_eq!(pricing(control_variable, 500).bond_price(FLOOR_PRICE, 500, 0), Ok(2_000));
+		// all the bonds sold, the price is capped at `bond_price * (1 + control_variable)`
+		assert_eq!(pricing(control_variable, 1_000).bond_price(FLOOR_PRICE, 0, 0), Ok(3_000));
+	}
+
+	#[test]
+	fn price_decays_toward_floor() {
+		let pricing = pricing(FixedU128::saturating_from_integer(2), 500);
+
+		let prices = [0, 50, DECAY_PERIOD]
+			.map(|now| pricing.bond_price(FLOOR_PRICE, 500, now).expect("no overflow; qed"));
+		// debt ratios of 1/2, 1/3 and 0
+		assert_eq!(prices, [2_000, 1_666, FLOOR_PRICE]);
+	}
+
+	#[test]
+	fn add_debt_decays_previous_debt() {
+		let mut pricing = pricing(FixedU128::one(), 1_000);
+
+		assert_eq!(pricing.add_debt(100, 50), Ok(()));
+		assert_eq!(pricing.debt, 600);
+		assert_eq!(pricing.last_decay, 50);
+		assert_eq!(pricing.decayed_debt(100), 300);
+	}
+
+	#[test]
+	fn price_overflow_is_an_error() {
+		let pricing = pricing(FixedU128::saturating_from_integer(2), 1);
+
+		assert_eq!(pricing.bond_price(u128::MAX, 0, 0), Err(ArithmeticError::Overflow));
+	}
+}
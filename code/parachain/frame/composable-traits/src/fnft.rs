@@ -6,9 +6,9 @@ use crate::account_proxy::ProxyType;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::collections::vec::bounded::BiBoundedVec;
 use core::fmt::Debug;
-use frame_support::traits::tokens::nonfungibles::Inspect;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect};
 use scale_info::TypeInfo;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, DispatchResult};
 use sp_std::vec::Vec;
 
 pub type Key = BiBoundedVec<u8, 1, 64>;
@@ -25,6 +25,18 @@ pub trait FinancialNft<AccountId>: Inspect<AccountId> {
 	/// Retrieve the next valid financial NFT ID for the given collection in order to
 	/// mint a new NFT.
 	fn get_next_nft_id(collection: &Self::CollectionId) -> Result<Self::ItemId, DispatchError>;
+
+	/// Create `collection`, owned and administered by `owner`, unless it already exists. Lets
+	/// protocols create their collection with their first financial NFT.
+	fn ensure_collection(collection: &Self::CollectionId, owner: &AccountId) -> DispatchResult
+	where
+		Self: Create<AccountId>,
+	{
+		if Self::collection_owner(collection).is_none() {
+			Self::create_collection(collection, owner, owner)?;
+		}
+		Ok(())
+	}
 }
 
 /// Trait to be implemented by protocol supporting financial NFTs.
@@ -61,6 +73,30 @@ pub trait FinancialNftProtocol {
 	) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError>;
 }
 
+/// Called by the financial NFT implementation whenever an item changes owner, allowing the
+/// originating protocol to keep owner-indexed state in sync.
+pub trait FinancialNftTransferHook<AccountId, CollectionId, ItemId> {
+	fn on_transfer(
+		collection: &CollectionId,
+		instance: &ItemId,
+		from: &AccountId,
+		to: &AccountId,
+	) -> DispatchResult;
+}
+
+impl<AccountId, CollectionId, ItemId> FinancialNftTransferHook<AccountId, CollectionId, ItemId>
+	for ()
+{
+	fn on_transfer(
+		_collection: &CollectionId,
+		_instance: &ItemId,
+		_from: &AccountId,
+		_to: &AccountId,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
 /// Default Version type used for NFTs.
 #[derive(
 	Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Decode, MaxEncodedLen, TypeInfo,
@@ -83,3 +119,11 @@ impl FnftAccountProxyTypeSelector<ProxyType> for FnftAccountProxyType {
 		[ProxyType::Governance, ProxyType::CancelProxy].into()
 	}
 }
+
+/// Selects no account proxy types, leaving the asset accounts of financial NFTs without proxies.
+pub struct NoFnftAccountProxyType;
+impl<T> FnftAccountProxyTypeSelector<T> for NoFnftAccountProxyType {
+	fn get_proxy_types() -> Vec<T> {
+		Vec::new()
+	}
+}
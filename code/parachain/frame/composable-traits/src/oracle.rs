@@ -2,7 +2,12 @@ use crate::{
 	currency::LocalAssets,
 	defi::{CurrencyPair, Ratio},
 };
-use frame_support::{dispatch::DispatchError, pallet_prelude::*};
+use frame_support::{
+	dispatch::DispatchError,
+	pallet_prelude::*,
+	traits::{ConstU16, ConstU32},
+};
+use sp_std::marker::PhantomData;
 
 // block timestamped value
 #[derive(Encode, Decode, MaxEncodedLen, Default, Debug, PartialEq, Eq, TypeInfo, Clone)]
@@ -117,3 +122,45 @@ pub trait Oracle {
 		amount: Self::Balance,
 	) -> Result<Self::Balance, DispatchError>;
 }
+
+/// An oracle without any price, for runtimes that do not run one yet.
+///
+/// Every query fails, so nothing depending on prices, like bonds paid with LP tokens, can go
+/// through.
+pub struct NoPriceOracle<AssetId, Balance, Timestamp>(PhantomData<(AssetId, Balance, Timestamp)>);
+
+impl<AssetId: Copy, Balance: From<u64>, Timestamp> Oracle
+	for NoPriceOracle<AssetId, Balance, Timestamp>
+{
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type Timestamp = Timestamp;
+	type LocalAssets = ();
+	type MaxAnswerBound = ConstU32<0>;
+	type TwapWindow = ConstU16<0>;
+
+	fn get_price(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Price<Self::Balance, Self::Timestamp>, DispatchError> {
+		Err(DispatchError::Other("no price oracle"))
+	}
+
+	fn get_twap_for_amount(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("no price oracle"))
+	}
+
+	fn get_ratio(_pair: CurrencyPair<Self::AssetId>) -> Result<Ratio, DispatchError> {
+		Err(DispatchError::Other("no price oracle"))
+	}
+
+	fn get_price_inverse(
+		_asset_id: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::Other("no price oracle"))
+	}
+}
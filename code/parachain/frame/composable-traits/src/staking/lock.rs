@@ -1,8 +1,8 @@
 use codec::{Decode, Encode};
-use composable_support::validation::{validators::GeOne, Validated};
+use composable_support::validation::{validators::GeOne, TryIntoValidated, Validated};
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*, BoundedBTreeMap};
 use scale_info::TypeInfo;
-use sp_arithmetic::fixed_point::FixedU64;
+use sp_arithmetic::{fixed_point::FixedU64, FixedPointNumber};
 use sp_runtime::Perbill;
 
 use core::fmt::Debug;
@@ -31,7 +31,17 @@ pub enum DurationMultipliers<MaxDurationPresets: Get<u32>> {
 	/// Fixed duration multipliers mapped to their respective multipliers.
 	// TODO(benluelo): Wrap this in `Validated` to ensure that at least one preset is provided?
 	Presets(BoundedBTreeMap<DurationSeconds, Validated<FixedU64, GeOne>, MaxDurationPresets>),
-	// eventually add linear, curve, etc
+	/// Any duration within `min..=max` is valid. The multiplier grows linearly from
+	/// `min_multiplier` at `min` to `max_multiplier` at `max`.
+	Linear {
+		min: DurationSeconds,
+		max: DurationSeconds,
+		min_multiplier: Validated<FixedU64, GeOne>,
+		max_multiplier: Validated<FixedU64, GeOne>,
+	},
+	/// Any duration between the shortest and the longest point of the curve is valid. The
+	/// multiplier is interpolated linearly between the two points surrounding the duration.
+	Curve(BoundedBTreeMap<DurationSeconds, Validated<FixedU64, GeOne>, MaxDurationPresets>),
 }
 
 impl<MaxDurationPresets: Get<u32>>
@@ -48,20 +58,66 @@ impl<MaxDurationPresets: Get<u32>>
 impl<MaxDurationPresets: Get<u32>> DurationMultipliers<MaxDurationPresets> {
 	/// Get the multiplier for the given duration, if it's valid for this type of
 	/// [`DurationMultiplier`].
-	pub fn multiplier(&self, duration: DurationSeconds) -> Option<&Validated<FixedU64, GeOne>> {
+	pub fn multiplier(&self, duration: DurationSeconds) -> Option<Validated<FixedU64, GeOne>> {
 		match self {
-			DurationMultipliers::Presets(presets) => presets.get(&duration),
+			DurationMultipliers::Presets(presets) => presets.get(&duration).copied(),
+			DurationMultipliers::Linear { min, max, min_multiplier, max_multiplier } => {
+				if duration < *min || duration > *max {
+					return None
+				}
+				interpolate((*min, **min_multiplier), (*max, **max_multiplier), duration)
+			},
+			DurationMultipliers::Curve(points) => {
+				let (from, from_multiplier) = points.range(..=duration).next_back()?;
+				let (to, to_multiplier) = points.range(duration..).next()?;
+				interpolate((*from, **from_multiplier), (*to, **to_multiplier), duration)
+			},
 		}
 	}
 
 	/// Checks that there is at least one valid lock duration for this [`DurationMultiplier`].
 	pub fn has_at_least_one_valid_duration(&self) -> bool {
 		match self {
-			DurationMultipliers::Presets(presets) => presets.len() > 0,
+			DurationMultipliers::Presets(presets) | DurationMultipliers::Curve(presets) =>
+				presets.len() > 0,
+			DurationMultipliers::Linear { min, max, .. } => min <= max,
+		}
+	}
+
+	/// The longest valid lock duration for this [`DurationMultiplier`], if any.
+	pub fn max_duration(&self) -> Option<DurationSeconds> {
+		match self {
+			DurationMultipliers::Presets(presets) | DurationMultipliers::Curve(presets) =>
+				presets.keys().next_back().copied(),
+			DurationMultipliers::Linear { min, max, .. } => (min <= max).then_some(*max),
 		}
 	}
 }
 
+/// Linearly interpolates the multiplier at `duration` between the points `from` and `to`, where
+/// `from.0 <= duration <= to.0`.
+fn interpolate(
+	(from, from_multiplier): (DurationSeconds, FixedU64),
+	(to, to_multiplier): (DurationSeconds, FixedU64),
+	duration: DurationSeconds,
+) -> Option<Validated<FixedU64, GeOne>> {
+	let span = u128::from(to.checked_sub(from)?);
+	let elapsed = u128::from(duration.checked_sub(from)?);
+	let multiplier = if span == 0 {
+		from_multiplier
+	} else {
+		let start = u128::from(from_multiplier.into_inner());
+		let end = u128::from(to_multiplier.into_inner());
+		let inner = if end >= start {
+			start.checked_add(end.checked_sub(start)?.checked_mul(elapsed)?.checked_div(span)?)?
+		} else {
+			start.checked_sub(start.checked_sub(end)?.checked_mul(elapsed)?.checked_div(span)?)?
+		};
+		FixedU64::from_inner(u64::try_from(inner).ok()?)
+	};
+	multiplier.try_into_validated().ok()
+}
+
 /// staking typed fNFT, usually can be mapped to raw fNFT storage type
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Encode, Decode, TypeInfo)]
 pub struct Lock {
@@ -82,3 +138,67 @@ pub trait Locking {
 		duration: Option<DurationSeconds>,
 	) -> DispatchResult;
 }
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::ConstU32;
+
+	use super::*;
+
+	fn multiplier(value: u64) -> Validated<FixedU64, GeOne> {
+		FixedU64::saturating_from_rational(value, 10)
+			.try_into_validated()
+			.expect("value >= 10")
+	}
+
+	#[test]
+	fn linear_multiplier_is_interpolated_within_range() {
+		let multipliers = DurationMultipliers::<ConstU32<0>>::Linear {
+			min: 100,
+			max: 300,
+			min_multiplier: multiplier(10),
+			max_multiplier: multiplier(30),
+		};
+		assert_eq!(multipliers.multiplier(99), None);
+		assert_eq!(multipliers.multiplier(100), Some(multiplier(10)));
+		assert_eq!(multipliers.multiplier(200), Some(multiplier(20)));
+		assert_eq!(multipliers.multiplier(250), Some(multiplier(25)));
+		assert_eq!(multipliers.multiplier(300), Some(multiplier(30)));
+		assert_eq!(multipliers.multiplier(301), None);
+		assert!(multipliers.has_at_least_one_valid_duration());
+		assert_eq!(multipliers.max_duration(), Some(300));
+	}
+
+	#[test]
+	fn linear_multiplier_with_empty_range_has_no_valid_duration() {
+		let multipliers = DurationMultipliers::<ConstU32<0>>::Linear {
+			min: 300,
+			max: 100,
+			min_multiplier: multiplier(10),
+			max_multiplier: multiplier(30),
+		};
+		assert!(!multipliers.has_at_least_one_valid_duration());
+		assert_eq!(multipliers.multiplier(200), None);
+		assert_eq!(multipliers.max_duration(), None);
+	}
+
+	#[test]
+	fn curve_multiplier_is_interpolated_between_points() {
+		let points: BoundedBTreeMap<_, _, ConstU32<3>> =
+			[(100, multiplier(10)), (200, multiplier(30)), (400, multiplier(20))]
+				.into_iter()
+				.collect::<sp_std::collections::btree_map::BTreeMap<_, _>>()
+				.try_into()
+				.expect("within bounds");
+		let multipliers = DurationMultipliers::Curve(points);
+		assert_eq!(multipliers.multiplier(50), None);
+		assert_eq!(multipliers.multiplier(100), Some(multiplier(10)));
+		assert_eq!(multipliers.multiplier(150), Some(multiplier(20)));
+		assert_eq!(multipliers.multiplier(200), Some(multiplier(30)));
+		// decreasing segments are supported as well
+		assert_eq!(multipliers.multiplier(300), Some(multiplier(25)));
+		assert_eq!(multipliers.multiplier(400), Some(multiplier(20)));
+		assert_eq!(multipliers.multiplier(401), None);
+		assert_eq!(multipliers.max_duration(), Some(400));
+	}
+}
@@ -9,10 +9,11 @@ use codec::{Decode, Encode};
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*, BoundedBTreeMap};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::Zero;
-use sp_runtime::{DispatchError, Permill};
+use sp_runtime::{DispatchError, Perbill, Permill};
 
 pub mod lock;
 pub mod math;
+pub mod vote_escrow;
 
 /// Defines staking duration, rewards and early unstake penalty for a given asset type.
 /// TODO refer to the relevant section in the design doc.
@@ -147,6 +148,25 @@ pub struct RewardConfig<Balance> {
 	pub reward_rate: RewardRate<Balance>,
 }
 
+/// Bounds the emission of a reward beyond the funds available in its pot.
+#[derive(RuntimeDebug, PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
+pub struct EmissionSchedule<BlockNumber> {
+	/// The reward is no longer emitted from this block on.
+	pub end_block: BlockNumber,
+	/// Periodic reduction of the reward rate, if any.
+	pub step_down: Option<RewardRateStepDown>,
+}
+
+/// Reduction of a reward rate applied every `periods` reward rate periods, counted from when the
+/// emission schedule is set.
+#[derive(RuntimeDebug, PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, TypeInfo)]
+pub struct RewardRateStepDown {
+	/// Number of reward rate periods between two reductions.
+	pub periods: u64,
+	/// Part of the reward rate kept at each reduction, `50%` halves it.
+	pub ratio: Perbill,
+}
+
 /// Categorize the reward pool by it's incentive characteristics and expose
 /// initial configuration parameters.
 /// TODO refer to the relevant section in the design doc.
@@ -262,6 +282,22 @@ pub trait ProtocolStaking {
 		amount: Self::Balance,
 		keep_alive: bool,
 	) -> DispatchResult;
+
+	/// Slashes `ratio` of the stake and share of every position in the pool, owing the slashed
+	/// stake to `beneficiary`. Positions are not iterated, each one pays its part of the slash
+	/// the next time it is settled.
+	fn slash(
+		pool_id: &Self::RewardPoolId,
+		ratio: Perbill,
+		beneficiary: &Self::AccountId,
+	) -> DispatchResult;
+
+	/// Ensures `asset` can be staked in `pool_id` with a lock of `duration_preset`.
+	fn ensure_stakeable(
+		pool_id: &Self::RewardPoolId,
+		asset: Self::AssetId,
+		duration_preset: DurationSeconds,
+	) -> DispatchResult;
 }
 
 /// Interface for protocol staking.
@@ -310,6 +346,14 @@ pub trait Staking {
 		ratio: Permill,
 	) -> Result<Self::PositionId, DispatchError>;
 
+	/// Merge `other` into `position`, the inverse of [`Staking::split`]. Both positions must be
+	/// in the same pool. The merged position is locked until the later of the two lock ends.
+	fn merge(
+		who: &Self::AccountId,
+		position: &Self::PositionId,
+		other: &Self::PositionId,
+	) -> DispatchResult;
+
 	/// Claim remaining reward earned up to this point in time.
 	///
 	/// Arguments
@@ -0,0 +1,72 @@
+//! Vote-escrow view over locked staking positions.
+//!
+//! Every position in a vote-escrowed pool grants voting power proportional to its shares,
+//! decaying linearly to zero at the end of its lock. Other pallets (governance, gauges) read the
+//! aggregate power of an account through [`VotingPower`].
+//!
+//! Voting power can be used to vote on gauges, splitting a configured [`GaugeEmission`] across
+//! reward pools at the start of every [`GaugeEpoch`].
+
+use codec::{Decode, Encode};
+use frame_support::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::DispatchError;
+
+use crate::{staking::RewardRate, time::Timestamp};
+
+/// Voting power recorded at a block. Between checkpoints the power decays by `slope` per second,
+/// with the slope itself decreasing as the underlying locks expire.
+#[derive(
+	RuntimeDebug, Default, PartialEq, Eq, Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub struct VotingPowerCheckpoint<Balance, BlockNumber> {
+	/// Voting power at `timestamp`.
+	pub bias: Balance,
+	/// Voting power lost per second.
+	pub slope: Balance,
+	/// Unix time (seconds) of the checkpoint.
+	pub timestamp: Timestamp,
+	/// Block at which the checkpoint was written.
+	pub block: BlockNumber,
+}
+
+/// Query interface for vote-escrowed voting power.
+pub trait VotingPower {
+	type AccountId;
+	type Balance;
+	type BlockNumber;
+
+	/// Voting power of `who` at the current block.
+	fn voting_power(who: &Self::AccountId) -> Self::Balance;
+
+	/// Voting power of `who` at `block`. `block` must not be in the future.
+	fn voting_power_at(
+		who: &Self::AccountId,
+		block: Self::BlockNumber,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Sum of the voting power of all accounts at the current block.
+	fn total_voting_power() -> Self::Balance;
+
+	/// Sum of the voting power of all accounts at `block`. `block` must not be in the future.
+	fn total_voting_power_at(block: Self::BlockNumber) -> Result<Self::Balance, DispatchError>;
+}
+
+/// Reward rate split across gauges in proportion to the votes they received during the previous
+/// epoch.
+#[derive(RuntimeDebug, PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct GaugeEmission<AssetId, Balance> {
+	/// The reward asset of the gauge pools whose reward rate is set.
+	pub reward_asset_id: AssetId,
+	/// The total reward rate shared by all gauges.
+	pub reward_rate: RewardRate<Balance>,
+}
+
+/// A gauge voting epoch. Votes are weighted by the voting power at `start_block`.
+#[derive(RuntimeDebug, PartialEq, Eq, Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct GaugeEpoch<BlockNumber> {
+	/// Number of epochs since the unix epoch.
+	pub index: u64,
+	/// Block at which the epoch started.
+	pub start_block: BlockNumber,
+}
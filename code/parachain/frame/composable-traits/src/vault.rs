@@ -2,7 +2,7 @@ use codec::Codec;
 use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::Perquintill,
-	sp_std::{collections::btree_map::BTreeMap, fmt::Debug},
+	sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData},
 };
 use scale_info::TypeInfo;
 
@@ -119,6 +119,51 @@ pub trait Vault {
 		vault_id: &Self::VaultId,
 		asset_amount: Self::Balance,
 	) -> Result<Self::Balance, DispatchError>;
+
+	/// Amount of LP tokens that [`deposit`](Vault::deposit)ing `asset_amount` would mint in the
+	/// current block. Fails with the same errors as the deposit itself, except for the depositor's
+	/// balance not being checked.
+	fn preview_deposit(
+		vault_id: &Self::VaultId,
+		asset_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Amount of underlying assets that [`withdraw`](Vault::withdraw)ing `lp_amount` would return
+	/// in the current block. Fails with the same errors as the withdrawal itself, except for the
+	/// withdrawer's LP balance not being checked.
+	fn preview_withdraw(
+		vault_id: &Self::VaultId,
+		lp_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Amount of LP tokens the vault exchanges for `asset_amount`, ignoring deposit limits and
+	/// the vault's capabilities. Rounds down.
+	fn convert_to_shares(
+		vault_id: &Self::VaultId,
+		asset_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Amount of underlying assets the vault exchanges for `lp_amount`, ignoring withdrawal limits
+	/// and the vault's capabilities. Rounds down.
+	fn convert_to_assets(
+		vault_id: &Self::VaultId,
+		lp_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Maximum amount of underlying assets `account` is able to deposit in the current block, zero
+	/// if deposits are not allowed.
+	fn max_deposit(
+		vault_id: &Self::VaultId,
+		account: &Self::AccountId,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Maximum amount of LP tokens `account` is able to withdraw in the current block, bounded by
+	/// its LP balance and by the liquidity held by the vault itself. Zero if withdrawals are not
+	/// allowed or if the amount does not exceed the minimum withdrawal of the vault.
+	fn max_withdraw(
+		vault_id: &Self::VaultId,
+		account: &Self::AccountId,
+	) -> Result<Self::Balance, DispatchError>;
 }
 
 /// CapabilityVault exposes functionalities for stopping and limiting vault functionality.
@@ -200,3 +245,191 @@ pub trait ReportableStrategicVault: StrategicVault {
 		report: &Self::Report,
 	) -> Result<(), DispatchError>;
 }
+
+/// Strategy which is driven by the vault itself, instead of by external calls into
+/// [`StrategicVault`]. Strategies are identified by the account they hold funds in, which is used
+/// as key of [`VaultConfig::strategies`].
+///
+/// Implemented for tuples, so that a runtime can register several strategy pallets at once. Calls
+/// are routed to the first strategy which [`handles`](VaultStrategy::handles) the account.
+pub trait VaultStrategy {
+	type AccountId;
+	type AssetId;
+	type Balance;
+	type VaultId;
+
+	/// Indicates if `strategy` account is managed by this implementation.
+	fn handles(strategy: &Self::AccountId) -> bool;
+
+	/// Called after the vault transferred `amount` to the `strategy` account, so that the funds
+	/// can be put to work.
+	fn deposit(
+		strategy: &Self::AccountId,
+		vault: &Self::VaultId,
+		asset: Self::AssetId,
+		amount: Self::Balance,
+	) -> DispatchResult;
+
+	/// Unwinds up to `amount` of positions into free balance of the `strategy` account, so that
+	/// the vault can take it back. Returns the amount which was actually freed.
+	fn withdraw(
+		strategy: &Self::AccountId,
+		vault: &Self::VaultId,
+		asset: Self::AssetId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Collects earnings of the strategy, such as rewards or interest. Returns the harvested
+	/// amount, which is included in the next [`report`](VaultStrategy::report).
+	fn harvest(
+		strategy: &Self::AccountId,
+		vault: &Self::VaultId,
+		asset: Self::AssetId,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// The total amount of `asset` the strategy holds on behalf of the vault.
+	fn report(
+		strategy: &Self::AccountId,
+		vault: &Self::VaultId,
+		asset: Self::AssetId,
+	) -> Result<Self::Balance, DispatchError>;
+}
+
+/// No strategies are driven by the vault.
+pub struct NoStrategies<AccountId, AssetId, Balance, VaultId>(
+	PhantomData<(AccountId, AssetId, Balance, VaultId)>,
+);
+
+impl<AccountId, AssetId, Balance, VaultId> VaultStrategy
+	for NoStrategies<AccountId, AssetId, Balance, VaultId>
+{
+	type AccountId = AccountId;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type VaultId = VaultId;
+
+	fn handles(_strategy: &Self::AccountId) -> bool {
+		false
+	}
+
+	fn deposit(
+		_strategy: &Self::AccountId,
+		_vault: &Self::VaultId,
+		_asset: Self::AssetId,
+		_amount: Self::Balance,
+	) -> DispatchResult {
+		Err(DispatchError::CannotLookup)
+	}
+
+	fn withdraw(
+		_strategy: &Self::AccountId,
+		_vault: &Self::VaultId,
+		_asset: Self::AssetId,
+		_amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::CannotLookup)
+	}
+
+	fn harvest(
+		_strategy: &Self::AccountId,
+		_vault: &Self::VaultId,
+		_asset: Self::AssetId,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::CannotLookup)
+	}
+
+	fn report(
+		_strategy: &Self::AccountId,
+		_vault: &Self::VaultId,
+		_asset: Self::AssetId,
+	) -> Result<Self::Balance, DispatchError> {
+		Err(DispatchError::CannotLookup)
+	}
+}
+
+macro_rules! impl_vault_strategy_for_tuple {
+	($first:ident, $($rest:ident),+) => {
+		impl<$first, $($rest),+> VaultStrategy for ($first, $($rest),+)
+		where
+			$first: VaultStrategy,
+			$($rest: VaultStrategy<
+				AccountId = $first::AccountId,
+				AssetId = $first::AssetId,
+				Balance = $first::Balance,
+				VaultId = $first::VaultId,
+			>),+
+		{
+			type AccountId = $first::AccountId;
+			type AssetId = $first::AssetId;
+			type Balance = $first::Balance;
+			type VaultId = $first::VaultId;
+
+			fn handles(strategy: &Self::AccountId) -> bool {
+				$first::handles(strategy) $(|| $rest::handles(strategy))+
+			}
+
+			fn deposit(
+				strategy: &Self::AccountId,
+				vault: &Self::VaultId,
+				asset: Self::AssetId,
+				amount: Self::Balance,
+			) -> DispatchResult {
+				if $first::handles(strategy) {
+					return $first::deposit(strategy, vault, asset, amount)
+				}
+				$(if $rest::handles(strategy) {
+					return $rest::deposit(strategy, vault, asset, amount)
+				})+
+				Err(DispatchError::CannotLookup)
+			}
+
+			fn withdraw(
+				strategy: &Self::AccountId,
+				vault: &Self::VaultId,
+				asset: Self::AssetId,
+				amount: Self::Balance,
+			) -> Result<Self::Balance, DispatchError> {
+				if $first::handles(strategy) {
+					return $first::withdraw(strategy, vault, asset, amount)
+				}
+				$(if $rest::handles(strategy) {
+					return $rest::withdraw(strategy, vault, asset, amount)
+				})+
+				Err(DispatchError::CannotLookup)
+			}
+
+			fn harvest(
+				strategy: &Self::AccountId,
+				vault: &Self::VaultId,
+				asset: Self::AssetId,
+			) -> Result<Self::Balance, DispatchError> {
+				if $first::handles(strategy) {
+					return $first::harvest(strategy, vault, asset)
+				}
+				$(if $rest::handles(strategy) {
+					return $rest::harvest(strategy, vault, asset)
+				})+
+				Err(DispatchError::CannotLookup)
+			}
+
+			fn report(
+				strategy: &Self::AccountId,
+				vault: &Self::VaultId,
+				asset: Self::AssetId,
+			) -> Result<Self::Balance, DispatchError> {
+				if $first::handles(strategy) {
+					return $first::report(strategy, vault, asset)
+				}
+				$(if $rest::handles(strategy) {
+					return $rest::report(strategy, vault, asset)
+				})+
+				Err(DispatchError::CannotLookup)
+			}
+		}
+	};
+}
+
+// as with `Validate`, flat impls are enough for now, extend if more strategies are needed
+impl_vault_strategy_for_tuple!(A, B);
+impl_vault_strategy_for_tuple!(A, B, C);
+impl_vault_strategy_for_tuple!(A, B, C, D);
@@ -5,8 +5,8 @@ use composable_support::math::safe::SafeMul;
 use frame_support::pallet_prelude::*;
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AtLeast32Bit, Zero},
-	ArithmeticError,
+	traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedInto, Zero},
+	ArithmeticError, PerThing, Perbill,
 };
 use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 
@@ -29,8 +29,17 @@ pub trait VestedTransfer {
 		asset: Self::AssetId,
 		from: &Self::AccountId,
 		to: &Self::AccountId,
-		schedule: VestingScheduleInfo<Self::BlockNumber, Self::Moment, Self::Balance>,
+		schedule: VestingScheduleInfo<
+			Self::BlockNumber,
+			Self::Moment,
+			Self::Balance,
+			Self::AccountId,
+		>,
 	) -> DispatchResult;
+
+	/// Claim the unlocked balance of all the vesting schedules of `who` for `asset`, returning
+	/// the claimed amount. Nothing is claimed if `who` has no vesting schedule for `asset`.
+	fn claim(asset: Self::AssetId, who: &Self::AccountId) -> Result<Self::Balance, DispatchError>;
 }
 
 /// Vesting window type for the vesting schedules.
@@ -51,6 +60,44 @@ pub enum VestingWindow<BlockNumber, Moment> {
 	},
 }
 
+/// Maximum number of points of a [`VestingCurve::Custom`] curve.
+pub type MaxVestingCurvePoints = ConstU32<32>;
+
+/// How the amount of a vesting schedule is released over its periods.
+///
+/// Whatever the curve, the whole amount is released at the end of the last period.
+#[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum VestingCurve {
+	/// `per_period` is released at the end of every period.
+	Linear,
+	/// The amount vested over `periods` periods is released at once at the end of them, monthly
+	/// for example with daily periods.
+	Step { periods: u32 },
+	/// Cumulative share of the total amount released once a number of periods have elapsed.
+	///
+	/// Points are ordered by strictly increasing number of periods, all lower than the number of
+	/// periods of the schedule, and their shares never decrease.
+	Custom(BoundedVec<(u32, Perbill), MaxVestingCurvePoints>),
+}
+
+impl VestingCurve {
+	/// Checks that the curve can be used by a schedule of `period_count` periods.
+	pub fn is_valid(&self, period_count: u32) -> bool {
+		match self {
+			VestingCurve::Linear => true,
+			VestingCurve::Step { periods } => !periods.is_zero(),
+			VestingCurve::Custom(points) =>
+				points.iter().all(|(periods, _)| *periods < period_count) &&
+					points.windows(2).all(|pair| match pair {
+						[(from_periods, from_share), (to_periods, to_share)] =>
+							from_periods < to_periods && from_share <= to_share,
+						_ => true,
+					}),
+		}
+	}
+}
+
 /// VestingScheduleId type for claiming.
 #[cfg_attr(feature = "std", derive(serde::Deserialize, serde::Serialize))]
 #[derive(
@@ -78,9 +125,9 @@ impl<Id: Clone + Copy + Eq + PartialEq + Debug, MaxVestingSchedules: Get<u32>>
 {
 	/// Returns a `Vec` containing all the ids of the schedules to be claimed. A reference to all
 	/// claimable schedules is passed in case `Self` is `All`.
-	pub fn into_all_ids<BlockNumber, Moment, Balance: HasCompact>(
+	pub fn into_all_ids<BlockNumber, Moment, Balance: HasCompact, AccountId>(
 		self,
-		all_schedules: &BTreeMap<Id, VestingSchedule<Id, BlockNumber, Moment, Balance>>,
+		all_schedules: &BTreeMap<Id, VestingSchedule<Id, BlockNumber, Moment, Balance, AccountId>>,
 	) -> Vec<Id> {
 		match self {
 			VestingScheduleIdSet::All => all_schedules.keys().copied().collect(),
@@ -93,10 +140,10 @@ impl<Id: Clone + Copy + Eq + PartialEq + Debug, MaxVestingSchedules: Get<u32>>
 /// The vesting schedule.
 ///
 /// Benefits would be granted gradually, `per_period` amount every `window.period`
-/// of blocks after `window.start`.
+/// of blocks after `window.start`, released according to the `curve` once the `cliff` is over.
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasCompact> {
+pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasCompact, AccountId> {
 	/// Vesting schedule id
 	pub vesting_schedule_id: VestingScheduleId,
 	pub window: VestingWindow<BlockNumber, Moment>,
@@ -107,6 +154,13 @@ pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasC
 	pub per_period: Balance,
 	/// Amount already claimed
 	pub already_claimed: Balance,
+	/// Number of periods during which nothing is released, the amount vested by then is released
+	/// at once at the end of the cliff
+	pub cliff: u32,
+	/// Release curve of the vested amount
+	pub curve: VestingCurve,
+	/// Account allowed to revoke the unvested part of the schedule, if any
+	pub revoker: Option<AccountId>,
 }
 
 /// Vesting schedule input, which is used to create a VestingSchedule.
@@ -114,13 +168,20 @@ pub struct VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance: HasC
 /// This is used for creating a VestingSchedule
 #[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
-pub struct VestingScheduleInfo<BlockNumber, Moment, Balance: HasCompact> {
+pub struct VestingScheduleInfo<BlockNumber, Moment, Balance: HasCompact, AccountId> {
 	pub window: VestingWindow<BlockNumber, Moment>,
 	/// Number of vest
 	pub period_count: u32,
 	/// Amount of tokens to release per vest
 	#[codec(compact)]
 	pub per_period: Balance,
+	/// Number of periods during which nothing is released, the amount vested by then is released
+	/// at once at the end of the cliff
+	pub cliff: u32,
+	/// Release curve of the vested amount
+	pub curve: VestingCurve,
+	/// Account allowed to revoke the unvested part of the schedule, if any
+	pub revoker: Option<AccountId>,
 }
 
 pub enum VestingWindowResult<BlockNumber, Moment> {
@@ -130,10 +191,11 @@ pub enum VestingWindowResult<BlockNumber, Moment> {
 
 impl<
 		VestingScheduleId,
-		BlockNumber: AtLeast32Bit + Copy,
-		Moment: AtLeast32Bit + Copy,
-		Balance: AtLeast32Bit + Copy,
-	> VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance>
+		BlockNumber: AtLeast32BitUnsigned + Copy,
+		Moment: AtLeast32BitUnsigned + Copy,
+		Balance: AtLeast32BitUnsigned + Copy,
+		AccountId,
+	> VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance, AccountId>
 {
 	/// Check if the period is zero
 	pub fn is_zero_period(&self) -> bool {
@@ -168,40 +230,106 @@ impl<
 	/// Note this func assumes schedule is a valid one(non-zero period and
 	/// non-overflow total amount), and it should be guaranteed by callers.
 	pub fn locked_amount(&self, block_number: BlockNumber, moment: Moment) -> Balance {
+		// total - vested
+		self.per_period
+			.checked_mul(&self.period_count.into())
+			.expect("ensured non-overflow total amount; qed")
+			.saturating_sub(self.vested_amount(block_number, moment))
+	}
+
+	/// Returns the amount released at a given time, following the cliff and curve of the
+	/// schedule. Nothing is released before the start of the schedule.
+	///
+	/// Same assumptions as [`Self::locked_amount`].
+	pub fn vested_amount(&self, block_number: BlockNumber, moment: Moment) -> Balance {
+		let started = match self.window {
+			VestingWindow::BlockNumberBased { start, period: _ } => block_number >= start,
+			VestingWindow::MomentBased { start, period: _ } => moment >= start,
+		};
+		if !started {
+			return Zero::zero()
+		}
+
+		let total_amount = self
+			.per_period
+			.checked_mul(&self.period_count.into())
+			.expect("ensured non-overflow total amount; qed");
+		let elapsed = self.elapsed_periods(block_number, moment);
+		if elapsed >= self.period_count {
+			return total_amount
+		}
+		if elapsed < self.cliff {
+			return Zero::zero()
+		}
+
+		match &self.curve {
+			VestingCurve::Linear => self.per_period.saturating_mul(elapsed.into()),
+			VestingCurve::Step { periods } => self
+				.per_period
+				.saturating_mul(elapsed.saturating_sub(elapsed % (*periods).max(1)).into()),
+			VestingCurve::Custom(points) => points
+				.iter()
+				.rev()
+				.find(|(periods, _)| *periods <= elapsed)
+				.map(|(_, share)| share.mul_floor(total_amount))
+				.unwrap_or_else(Zero::zero),
+		}
+	}
+
+	/// Returns the number of full periods elapsed at a given time.
+	fn elapsed_periods(&self, block_number: BlockNumber, moment: Moment) -> u32 {
 		// full = (time - start) / period
-		// unrealized = period_count - full
-		// per_period * unrealized
-		let unrealized = match self.window {
-			VestingWindow::BlockNumberBased { start, period } => {
-				let full = block_number
-					.saturating_sub(start)
-					.checked_div(&period)
-					.expect("ensured non-zero period; qed");
-				self.period_count.saturating_sub(full.unique_saturated_into())
+		match self.window {
+			VestingWindow::BlockNumberBased { start, period } => block_number
+				.saturating_sub(start)
+				.checked_div(&period)
+				.expect("ensured non-zero period; qed")
+				.unique_saturated_into(),
+			VestingWindow::MomentBased { start, period } => moment
+				.saturating_sub(start)
+				.checked_div(&period)
+				.expect("ensured non-zero period; qed")
+				.unique_saturated_into(),
+		}
+	}
+
+	/// Revokes the amount not vested yet at a given time, leaving the vested amount in the
+	/// schedule. Returns the revoked amount.
+	///
+	/// The schedule can no longer be revoked afterwards.
+	pub fn revoke(&mut self, block_number: BlockNumber, moment: Moment) -> Balance {
+		let vested_amount = self.vested_amount(block_number, moment);
+		let revoked_amount = self.locked_amount(block_number, moment);
+		match self.curve {
+			// the vested amount is a whole number of periods, which have all elapsed
+			VestingCurve::Linear | VestingCurve::Step { .. } if !self.per_period.is_zero() => {
+				self.period_count = (vested_amount / self.per_period).unique_saturated_into();
 			},
-			VestingWindow::MomentBased { start, period } => {
-				let full = moment
-					.saturating_sub(start)
-					.checked_div(&period)
-					.expect("ensured non-zero period; qed");
-				self.period_count.saturating_sub(full.unique_saturated_into())
+			_ => {
+				self.per_period = vested_amount;
+				self.period_count = 1;
+				self.curve =
+					VestingCurve::Custom(BoundedVec::truncate_from(vec![(0, Perbill::one())]));
 			},
-		};
-		self.per_period
-			.checked_mul(&unrealized.into())
-			.expect("ensured non-overflow total amount; qed")
+		}
+		self.cliff = 0;
+		self.revoker = None;
+		revoked_amount
 	}
 
 	pub fn from_input(
 		vesting_schedule_id: VestingScheduleId,
-		vesting_schedule_input: VestingScheduleInfo<BlockNumber, Moment, Balance>,
-	) -> VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance> {
+		vesting_schedule_input: VestingScheduleInfo<BlockNumber, Moment, Balance, AccountId>,
+	) -> VestingSchedule<VestingScheduleId, BlockNumber, Moment, Balance, AccountId> {
 		VestingSchedule {
 			vesting_schedule_id,
 			window: vesting_schedule_input.window,
 			per_period: vesting_schedule_input.per_period,
 			period_count: vesting_schedule_input.period_count,
 			already_claimed: Zero::zero(),
+			cliff: vesting_schedule_input.cliff,
+			curve: vesting_schedule_input.curve,
+			revoker: vesting_schedule_input.revoker,
 		}
 	}
 }
@@ -213,23 +341,29 @@ mod tests {
 
 	#[test]
 	fn test_is_zero_period() {
-		let mut vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64> {
+		let mut vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64, u64> {
 			vesting_schedule_id: 1_u128,
 			window: MomentBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert!(!vesting_schedule_time_based.is_zero_period());
 		vesting_schedule_time_based.window = MomentBased { start: 1, period: 0 };
 		assert!(vesting_schedule_time_based.is_zero_period());
 
-		let mut vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64> {
+		let mut vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64, u64> {
 			vesting_schedule_id: 2_u128,
 			window: BlockNumberBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert!(!vesting_schedule_block_number_based.is_zero_period());
 		vesting_schedule_block_number_based.window = BlockNumberBased { start: 1, period: 0 };
@@ -238,12 +372,15 @@ mod tests {
 
 	#[test]
 	fn test_end() {
-		let vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64> {
+		let vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64, u64> {
 			vesting_schedule_id: 3_u128,
 			window: MomentBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		match vesting_schedule_time_based.end() {
 			None => {},
@@ -252,12 +389,15 @@ mod tests {
 				VestingWindowResult::BlockNumberResult(_) => panic!("Unexpected BlockNumberResult"),
 			},
 		}
-		let vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64> {
+		let vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64, u64> {
 			vesting_schedule_id: 4_u128,
 			window: BlockNumberBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		match vesting_schedule_block_number_based.end() {
 			None => {},
@@ -270,12 +410,15 @@ mod tests {
 
 	#[test]
 	fn test_total_amount() {
-		let vesting_schedule = VestingSchedule::<u128, u64, u64, u64> {
+		let vesting_schedule = VestingSchedule::<u128, u64, u64, u64, u64> {
 			vesting_schedule_id: 5_u128,
 			window: BlockNumberBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_eq!(vesting_schedule.total_amount().unwrap(), 100)
 	}
@@ -283,26 +426,107 @@ mod tests {
 	/// TODO proptest for exhaustive tests
 	#[test]
 	fn test_locked_amount() {
-		let vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64> {
+		let vesting_schedule_time_based = VestingSchedule::<u128, u32, u64, u64, u64> {
 			vesting_schedule_id: 6_u128,
 			window: MomentBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_eq!(vesting_schedule_time_based.locked_amount(1, 1), 100);
 		assert_eq!(vesting_schedule_time_based.locked_amount(1, 11), 99);
 		assert_eq!(vesting_schedule_time_based.locked_amount(1, 1001), 0);
 
-		let vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64> {
+		let vesting_schedule_block_number_based = VestingSchedule::<u128, u64, u32, u64, u64> {
 			vesting_schedule_id: 7_u128,
 			window: BlockNumberBased { start: 1_u64, period: 10_u64 },
 			period_count: 100,
 			per_period: 1_u64,
 			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(1, 1), 100);
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(11, 1), 99);
 		assert_eq!(vesting_schedule_block_number_based.locked_amount(1001, 1), 0);
 	}
+
+	#[test]
+	fn test_vested_amount_follows_cliff_and_curve() {
+		let mut vesting_schedule = VestingSchedule::<u128, u64, u64, u64, u64> {
+			vesting_schedule_id: 8_u128,
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 12,
+			per_period: 10_u64,
+			already_claimed: 0_u64,
+			cliff: 3,
+			curve: VestingCurve::Linear,
+			revoker: None,
+		};
+		assert_eq!(vesting_schedule.vested_amount(29, 0), 0);
+		assert_eq!(vesting_schedule.vested_amount(30, 0), 30);
+		assert_eq!(vesting_schedule.vested_amount(45, 0), 40);
+
+		vesting_schedule.curve = VestingCurve::Step { periods: 5 };
+		assert_eq!(vesting_schedule.vested_amount(45, 0), 0);
+		assert_eq!(vesting_schedule.vested_amount(50, 0), 50);
+		assert_eq!(vesting_schedule.vested_amount(119, 0), 100);
+		assert_eq!(vesting_schedule.vested_amount(120, 0), 120);
+
+		vesting_schedule.cliff = 0;
+		vesting_schedule.curve = VestingCurve::Custom(BoundedVec::truncate_from(vec![
+			(0, Perbill::from_percent(10)),
+			(6, Perbill::from_percent(75)),
+		]));
+		assert_eq!(vesting_schedule.vested_amount(0, 0), 12);
+		assert_eq!(vesting_schedule.vested_amount(60, 0), 90);
+		assert_eq!(vesting_schedule.locked_amount(60, 0), 30);
+		assert_eq!(vesting_schedule.vested_amount(120, 0), 120);
+	}
+
+	#[test]
+	fn test_vested_amount_is_zero_before_start() {
+		let mut vesting_schedule = VestingSchedule::<u128, u64, u64, u64, u64> {
+			vesting_schedule_id: 9_u128,
+			window: BlockNumberBased { start: 100_u64, period: 10_u64 },
+			period_count: 12,
+			per_period: 10_u64,
+			already_claimed: 0_u64,
+			cliff: 0,
+			curve: VestingCurve::Custom(BoundedVec::truncate_from(vec![(
+				0,
+				Perbill::from_percent(10),
+			)])),
+			revoker: None,
+		};
+		assert_eq!(vesting_schedule.vested_amount(99, 0), 0);
+		assert_eq!(vesting_schedule.locked_amount(99, 0), 120);
+		assert_eq!(vesting_schedule.vested_amount(100, 0), 12);
+
+		vesting_schedule.window = MomentBased { start: 100_u64, period: 10_u64 };
+		assert_eq!(vesting_schedule.vested_amount(1_000, 99), 0);
+		assert_eq!(vesting_schedule.vested_amount(0, 100), 12);
+	}
+
+	#[test]
+	fn test_curve_is_valid() {
+		assert!(VestingCurve::Linear.is_valid(1));
+		assert!(!VestingCurve::Step { periods: 0 }.is_valid(1));
+		let custom = |points: Vec<(u32, u32)>| {
+			VestingCurve::Custom(BoundedVec::truncate_from(
+				points
+					.into_iter()
+					.map(|(periods, percent)| (periods, Perbill::from_percent(percent)))
+					.collect(),
+			))
+		};
+		assert!(custom(vec![(1, 10), (2, 10), (3, 50)]).is_valid(4));
+		assert!(!custom(vec![(1, 10), (4, 50)]).is_valid(4));
+		assert!(!custom(vec![(2, 10), (1, 50)]).is_valid(4));
+		assert!(!custom(vec![(1, 50), (2, 10)]).is_valid(4));
+	}
 }
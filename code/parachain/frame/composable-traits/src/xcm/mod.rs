@@ -129,10 +129,72 @@ pub struct SellOrderResponse {
 	pub body: SellResponse,
 }
 
+/// Lifecycle of sell order requested via XCM as seen by engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub enum XcmSellOrderState {
+	/// Nothing was taken yet.
+	Open,
+	/// Some `base` was sold, but order is still on engine.
+	PartiallyFilled,
+	/// All `base` was sold.
+	Filled,
+	/// Order was removed from engine before all `base` was sold.
+	/// Not sold `base` is released to `XcmSellRequest::from_to` on engine side.
+	Expired,
+}
+
+impl XcmSellOrderState {
+	/// No more responses follow final state.
+	pub fn is_final(&self) -> bool {
+		matches!(self, Self::Filled | Self::Expired)
+	}
+}
+
+/// Status of sell order requested via XCM.
+/// Sent in callbacks on each fill and on expiry, or as answer to status query.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct XcmSellOrderStatus {
+	/// sender `order_id` from `XcmSellRequest`
+	pub order_id: OrderId,
+	pub state: XcmSellOrderState,
+	/// Amount of `base` sold up to now.
+	pub total_amount_taken: Balance,
+	/// Amount of `quote` received for `total_amount_taken`.
+	pub total_amount_received: Balance,
+	/// Amount of `base` not sold yet.
+	pub remaining_amount: Balance,
+}
+
+impl XcmSellOrderStatus {
+	pub fn new(order_id: OrderId, amount: Balance) -> Self {
+		Self {
+			order_id,
+			state: XcmSellOrderState::Open,
+			total_amount_taken: 0,
+			total_amount_received: 0,
+			remaining_amount: amount,
+		}
+	}
+}
+
+/// Asks engine to send `SellResponse::Status` of order with `order_id` back to callback location
+/// of sender.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct XcmSellStatusQuery {
+	/// sender `order_id` from `XcmSellRequest`
+	pub order_id: OrderId,
+}
+
 // Next relation must hold:
 // Sell minimal price  <= initial price <= final price
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub enum SellResponse {
 	Initial(XcmSellInitialResponseTransact),
 	Final(XcmSellInitialResponseTransact),
+	/// Part of order was taken, `quote` received by this fill is transferred in same XCM message.
+	PartiallyFilled(XcmSellOrderStatus),
+	/// Order was removed before being filled.
+	Expired(XcmSellOrderStatus),
+	/// Answer to `XcmSellStatusQuery`, no assets are transferred.
+	Status(XcmSellOrderStatus),
 }
@@ -1,13 +1,16 @@
 use crate::*;
 
 use crate::models::{Proof, RemoteAccount};
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::{
+	signature_verification,
+	types::{EcdsaSignature, EthereumAddress},
+};
 use ed25519_dalek::{Keypair, Signer};
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite};
 use frame_support::{pallet_prelude::*, traits::fungible::Mutate};
 use frame_system::RawOrigin;
 use sp_io::hashing::keccak_256;
-use sp_runtime::AccountId32;
+use sp_runtime::{AccountId32, Perbill};
 use sp_std::prelude::*;
 
 type RelayKey = [u8; 32];
@@ -69,7 +72,7 @@ fn ethereum_proof(
 	reward_account: AccountId,
 ) -> Proof<RelayChainAccountId> {
 	let msg = keccak_256(
-		&crate::ethereum_signable_message(
+		&signature_verification::ethereum_signable_message(
 			PROOF_PREFIX,
 			&reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()),
 		)[..],
@@ -189,6 +192,23 @@ benchmarks! {
 		Pallet::<T>::initialize(RawOrigin::Root.into())?;
 	}: _(RawOrigin::None, accounts[0].0.clone(), accounts[0].1.clone().proof(accounts[0].0.clone()))
 
+	relay_associate {
+		let x in 100..1000;
+		let accounts =
+			generate_accounts(x as _);
+		let accounts_reward = accounts.clone()
+			.into_iter()
+			.map(|(_, a)| (a.as_remote_public(), ACCOUNT_REWARD, VESTING_PERIOD)).collect();
+
+		<T::RewardAsset as Mutate<AccountId>>::mint_into(
+			&Pallet::<T>::account_id(),
+			ACCOUNT_REWARD * x as Balance + T::RelayerFee::get()
+		)?;
+		Pallet::<T>::populate(RawOrigin::Root.into(), accounts_reward)?;
+		Pallet::<T>::initialize(RawOrigin::Root.into())?;
+		let relayer = T::RelayerOrigin::successful_origin();
+	}: _(relayer, accounts[0].0.clone(), accounts[0].1.clone().proof(accounts[0].0.clone()))
+
 	claim {
 		let x in 100..1000;
 		let accounts =
@@ -245,6 +265,12 @@ benchmarks! {
 
 		let reward_accounts = accounts.into_iter().map(|(account_id, _)| account_id).collect();
 	}: _(RawOrigin::Root, reward_accounts)
+
+	create_round {
+	}: _(RawOrigin::Root, WEEKS, Perbill::from_percent(25))
+	verify {
+		assert!(Rounds::<T>::contains_key(1));
+	}
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mocks::ExtBuilder::default().build(), crate::mocks::Test,);
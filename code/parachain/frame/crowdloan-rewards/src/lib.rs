@@ -7,11 +7,22 @@ liquidity (% of the vested reward). The rest of the reward can be claimed every
 `VestingStep` starting at the timestamp when the pallet was initialized
 using the `initialize` extrinsic.
 
+Additional crowdloans are handled as rounds created with `create_round`, each with its own
+reward dataset, vesting parameters, start time and pot account. Claims aggregate the rewards
+of a remote account across all the rounds.
+
 Proof to provide when associating a reward account:
 ```haskell
 proof = sign (concat prefix (hex reward_account))
 ```
 
+Relay chain, Ethereum (`eth_sign` or EIP-712 typed data), Cosmos (secp256k1 or secp256r1) and
+ed25519 (Solana or Cosmos) signatures are accepted.
+
+Associations can also be relayed by a `RelayerOrigin` with `relay_associate`, in which case
+the relayer pays the transaction fees and is reimbursed the `RelayerFee` from a round pot.
+Relayed associations are limited to `MaxRelayedClaimsPerBlock` per block.
+
 Reference for proof mechanism: https://github.com/paritytech/polkadot/blob/master/runtime/common/src/claims.rs
 */
 
@@ -67,12 +78,16 @@ pub mod weights;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use super::models::{Proof, RemoteAccount, Reward};
+	use super::models::{Proof, RemoteAccount, Reward, Round};
 	use crate::weights::WeightInfo;
 	use codec::{Codec, FullCodec};
 	use composable_support::{
+		claims::{
+			identity::IdentityVerifier,
+			relay::RelayedClaimQuota,
+			vesting::{ClaimAccount, VestingSchedule},
+		},
 		math::safe::{SafeAdd, SafeSub},
-		types::{EcdsaSignature, EthereumAddress},
 	};
 	use frame_support::{
 		dispatch::PostDispatchInfo,
@@ -85,13 +100,12 @@ pub mod pallet {
 		PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_io::hashing::keccak_256;
 	use sp_runtime::{
 		traits::{
 			AccountIdConversion, AtLeast32Bit, AtLeast32BitUnsigned, CheckedAdd, CheckedMul,
-			CheckedSub, Convert, Saturating, Verify, Zero,
+			CheckedSub, Convert, Saturating, Zero,
 		},
-		AccountId32, DispatchErrorWithPostInfo, MultiSignature, Perbill,
+		AccountId32, DispatchErrorWithPostInfo, Perbill,
 	};
 	use sp_std::vec::Vec;
 
@@ -102,6 +116,11 @@ pub mod pallet {
 	pub type RewardAmountOf<T> = <T as Config>::Balance;
 	pub type ProofOf<T> = Proof<<T as Config>::RelayChainAccountId>;
 	pub type BalanceOf<T> = <T as Config>::Balance;
+	pub type RoundOf<T> = Round<<T as Config>::Balance, MomentOf<T>>;
+	pub type RoundId = u32;
+
+	/// The round configured through the pallet `Config` and populated with `populate`.
+	pub const INITIAL_ROUND: RoundId = 0;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -125,6 +144,14 @@ pub mod pallet {
 		RewardsAdded { additions: Vec<(RemoteAccountOf<T>, RewardAmountOf<T>, VestingPeriodOf<T>)> },
 		/// Called after rewards have been deleted through the `delete` extrinsic.
 		RewardsDeleted { deletions: Vec<RemoteAccountOf<T>> },
+		/// A new round has been created, its rewards are paid from `pot_account`.
+		RoundCreated { round_id: RoundId, pot_account: T::AccountId },
+		/// A round has been initialized or set to initialize at some time.
+		RoundInitialized { round_id: RoundId, at: MomentOf<T> },
+		/// A round was successfully initialized, but with excess funds that won't be claimed.
+		RoundOverFunded { round_id: RoundId, excess_funds: T::Balance },
+		/// An association has been relayed, the relayer was paid `fee` from the pallet account.
+		AssociationRelayed { relayer: T::AccountId, reward_account: T::AccountId, fee: T::Balance },
 	}
 
 	#[pallet::error]
@@ -141,6 +168,16 @@ pub mod pallet {
 		NotClaimableYet,
 		/// Returned by `delete` if the provided expected reward mismatches the actual reward.
 		UnexpectedRewardAmount,
+		/// The round does not exist.
+		RoundNotFound,
+		/// The vesting step of a round must be greater than zero.
+		InvalidVestingStep,
+		/// `MaxRounds` rounds have already been created.
+		TooManyRounds,
+		/// The maximum number of relayed claims for this block has been reached.
+		RelayedClaimQuotaExceeded,
+		/// No round pot holds enough funds, besides the rewards of its round, to pay relayers.
+		SponsorshipNotFunded,
 	}
 
 	#[pallet::config]
@@ -187,6 +224,13 @@ pub mod pallet {
 			+ Into<AccountId32>
 			+ Ord;
 
+		/// The verifier of the proofs associating reward accounts with remote accounts.
+		type IdentityVerifier: IdentityVerifier<
+			Self::AccountId,
+			Proof = ProofOf<Self>,
+			Identity = RemoteAccountOf<Self>,
+		>;
+
 		/// The upfront liquidity unlocked at first claim.
 		#[pallet::constant]
 		type InitialPayment: Get<Perbill>;
@@ -217,6 +261,22 @@ pub mod pallet {
 		/// If claimed amounts should be locked by the pallet
 		#[pallet::constant]
 		type LockByDefault: Get<bool>;
+
+		/// The origin that is allowed to relay associations on behalf of contributors.
+		type RelayerOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The fee paid from the pallet account to the relayer of an association.
+		#[pallet::constant]
+		type RelayerFee: Get<Self::Balance>;
+
+		/// The maximum number of associations that can be relayed in a block.
+		#[pallet::constant]
+		type MaxRelayedClaimsPerBlock: Get<u32>;
+
+		/// The maximum number of rounds that can be created after the initial one, bounding the
+		/// rounds `associate` and `claim` go through.
+		#[pallet::constant]
+		type MaxRounds: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -261,6 +321,37 @@ pub mod pallet {
 	#[pallet::getter(fn remove_reward_locks)]
 	pub type RemoveRewardLocks<T: Config> = StorageValue<_, (), OptionQuery>;
 
+	/// The block of the last relayed association and the number of associations relayed in it.
+	#[pallet::storage]
+	// Absence of relayed claims is equivalent to none in block 0, so ValueQuery is allowed.
+	#[allow(clippy::disallowed_types)]
+	pub type RelayedClaims<T: Config> =
+		StorageValue<_, RelayedClaimQuota<T::BlockNumber>, ValueQuery>;
+
+	/// The id of the last round created with `create_round`.
+	#[pallet::storage]
+	#[pallet::getter(fn round_count)]
+	// Absence of rounds is equivalent to 0, the id of the initial round, so ValueQuery is allowed.
+	#[allow(clippy::disallowed_types)]
+	pub type RoundCount<T: Config> = StorageValue<_, RoundId, ValueQuery>;
+
+	/// The rounds created after the initial one, identified from 1 to `RoundCount`.
+	#[pallet::storage]
+	#[pallet::getter(fn rounds)]
+	pub type Rounds<T: Config> = StorageMap<_, Twox64Concat, RoundId, RoundOf<T>, OptionQuery>;
+
+	/// The rewards of the rounds created after the initial one.
+	#[pallet::storage]
+	pub type RoundRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundId,
+		Blake2_128Concat,
+		RemoteAccountOf<T>,
+		RewardOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
 	pub struct Pallet<T>(_);
@@ -309,7 +400,8 @@ pub mod pallet {
 		/// ```haskell
 		/// proof = sign (concat prefix (hex reward_account))
 		/// ```
-		#[pallet::weight(<T as Config>::WeightInfo::associate(TotalContributors::<T>::get()))]
+		#[pallet::weight(<T as Config>::WeightInfo::associate(TotalContributors::<T>::get())
+			.saturating_add(Pallet::<T>::rounds_weight()))]
 		pub fn associate(
 			origin: OriginFor<T>,
 			reward_account: T::AccountId,
@@ -319,10 +411,27 @@ pub mod pallet {
 			Self::do_associate(reward_account, proof)
 		}
 
+		/// Associate a reward account on behalf of a contributor, see `associate`.
+		///
+		/// The relayer pays the transaction fees, whether the association succeeds or not, and
+		/// is paid the `RelayerFee` from a round pot when it does. At most
+		/// `MaxRelayedClaimsPerBlock` associations can be relayed per block.
+		#[pallet::weight(<T as Config>::WeightInfo::relay_associate(TotalContributors::<T>::get())
+			.saturating_add(Pallet::<T>::rounds_weight()))]
+		pub fn relay_associate(
+			origin: OriginFor<T>,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+		) -> DispatchResult {
+			let relayer = T::RelayerOrigin::ensure_origin(origin)?;
+			Self::do_relay_associate(relayer, reward_account, proof)
+		}
+
 		/// Claim a reward from the associated reward account.
 		/// A previous call to `associate` should have been made.
 		/// If logic gate pass, no fees are applied.
-		#[pallet::weight(<T as Config>::WeightInfo::claim(TotalContributors::<T>::get()))]
+		#[pallet::weight(<T as Config>::WeightInfo::claim(TotalContributors::<T>::get())
+			.saturating_add(Pallet::<T>::rounds_weight()))]
 		pub fn claim(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let reward_account = ensure_signed(origin)?;
 			let remote_account = Associations::<T>::try_get(&reward_account)
@@ -354,6 +463,48 @@ pub mod pallet {
 			Self::deposit_event(Event::RewardsAdded { additions });
 			Ok(())
 		}
+
+		/// Create a new round, with its own reward dataset, vesting parameters and pot account.
+		///
+		/// The round must then be populated with `populate_round`, its pot account funded and
+		/// finally initialized with `initialize_round`.
+		#[pallet::weight(<T as Config>::WeightInfo::create_round())]
+		pub fn create_round(
+			origin: OriginFor<T>,
+			vesting_step: MomentOf<T>,
+			initial_payment: Perbill,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_create_round(vesting_step, initial_payment)
+		}
+
+		/// Populate a round by adding more rewards.
+		///
+		/// Same as `populate`, for a round created with `create_round`.
+		///
+		/// Can only be called before `initialize_round`.
+		#[pallet::weight(<T as Config>::WeightInfo::populate(rewards.len() as _))]
+		pub fn populate_round(
+			origin: OriginFor<T>,
+			round_id: RoundId,
+			rewards: Vec<(RemoteAccountOf<T>, RewardAmountOf<T>, VestingPeriodOf<T>)>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_populate_round(round_id, rewards)
+		}
+
+		/// Initialize a round created with `create_round` at the given timestamp.
+		#[pallet::weight(<T as Config>::WeightInfo::initialize(
+			Rounds::<T>::get(round_id).map_or(0, |round| round.total_contributors)
+		))]
+		pub fn initialize_round(
+			origin: OriginFor<T>,
+			round_id: RoundId,
+			at: MomentOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::do_initialize_round(round_id, at)
+		}
 	}
 
 	#[pallet::extra_constants]
@@ -365,6 +516,71 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The account the rewards of a round are paid from.
+		pub fn round_account_id(round_id: RoundId) -> T::AccountId {
+			if round_id == INITIAL_ROUND {
+				Self::account_id()
+			} else {
+				T::PalletId::get().into_sub_account_truncating(round_id)
+			}
+		}
+
+		/// All the rounds, starting with the initial one which is described by the pallet
+		/// `Config` and the storage items predating rounds. Rounds are looked up by id, at most
+		/// `MaxRounds` of them besides the initial one.
+		pub(crate) fn all_rounds() -> Vec<(RoundId, RoundOf<T>)> {
+			let initial_round = Round {
+				vesting_start: VestingTimeStart::<T>::get(),
+				vesting_step: T::VestingStep::get(),
+				initial_payment: T::InitialPayment::get(),
+				total_rewards: TotalRewards::<T>::get(),
+				claimed_rewards: ClaimedRewards::<T>::get(),
+				total_contributors: TotalContributors::<T>::get(),
+			};
+			sp_std::iter::once((INITIAL_ROUND, initial_round))
+				.chain((1..=RoundCount::<T>::get()).filter_map(|round_id| {
+					Rounds::<T>::get(round_id).map(|round| (round_id, round))
+				}))
+				.collect()
+		}
+
+		/// The reward of a remote account in the given round.
+		pub(crate) fn reward_of(
+			round_id: RoundId,
+			remote_account: &RemoteAccountOf<T>,
+		) -> Option<RewardOf<T>> {
+			if round_id == INITIAL_ROUND {
+				Rewards::<T>::get(remote_account)
+			} else {
+				RoundRewards::<T>::get(round_id, remote_account)
+			}
+		}
+
+		/// The additional weight of `associate` and `claim` for the rounds created after the
+		/// initial one.
+		pub(crate) fn rounds_weight() -> Weight {
+			T::DbWeight::get()
+				.reads_writes(3, 3)
+				.saturating_mul(RoundCount::<T>::get().into())
+		}
+
+		/// Ensures that at least one round has started.
+		///
+		/// # Errors
+		/// * `NotInitialized` - No round has been initialized yet
+		/// * `NotClaimableYet` - Rounds have been initialized, but none of their redemption periods
+		///   has begun
+		pub(crate) fn ensure_any_round_started() -> DispatchResult {
+			let now = T::Time::now();
+			let starts: Vec<_> = Self::all_rounds()
+				.into_iter()
+				.filter_map(|(_, round)| round.vesting_start)
+				.collect();
+			ensure!(!starts.is_empty(), Error::<T>::NotInitialized);
+			ensure!(starts.into_iter().any(|start| start <= now), Error::<T>::NotClaimableYet);
+			Ok(())
+		}
+
 		/// Patch existing rewards by deleting them.
 		/// This will cause inconsistencies if a claim has already been made. Errors can be avoided
 		/// by passing the expected amount of rewards claimable.
@@ -438,11 +654,8 @@ pub mod pallet {
 			let now = T::Time::now();
 			ensure!(at >= now, Error::<T>::BackToTheFuture);
 
-			let available_funds = T::RewardAsset::balance(&Self::account_id());
 			let total_rewards = TotalRewards::<T>::get();
-			let excess_funds = available_funds
-				.checked_sub(&total_rewards)
-				.ok_or(Error::<T>::RewardsNotFunded)?;
+			let excess_funds = Self::excess_funds(INITIAL_ROUND, total_rewards)?;
 
 			if excess_funds > T::OverFundedThreshold::get().mul_floor(total_rewards) {
 				Self::deposit_event(Event::OverFunded { excess_funds })
@@ -454,21 +667,129 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// The funds of the pot account of a round exceeding its total rewards.
+		///
+		/// # Errors
+		/// * `RewardsNotFunded` - The round pot does not hold enough funds to provide the total
+		///   rewards
+		fn excess_funds(
+			round_id: RoundId,
+			total_rewards: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let available_funds = T::RewardAsset::balance(&Self::round_account_id(round_id));
+			available_funds
+				.checked_sub(&total_rewards)
+				.ok_or_else(|| Error::<T>::RewardsNotFunded.into())
+		}
+
+		/// Creates a new round with the given vesting parameters and emits `RoundCreated`.
+		///
+		/// # Errors
+		/// * `InvalidVestingStep` - The vesting step is zero
+		/// * `TooManyRounds` - `MaxRounds` rounds have already been created
+		/// * `ArithmeticError` - Overflow detected while computing the round id
+		pub(crate) fn do_create_round(
+			vesting_step: MomentOf<T>,
+			initial_payment: Perbill,
+		) -> DispatchResult {
+			ensure!(!vesting_step.is_zero(), Error::<T>::InvalidVestingStep);
+
+			let round_id = RoundCount::<T>::try_mutate(|count| -> Result<_, DispatchError> {
+				ensure!(*count < T::MaxRounds::get(), Error::<T>::TooManyRounds);
+				*count = count.safe_add(&1)?;
+				Ok(*count)
+			})?;
+			Rounds::<T>::insert(
+				round_id,
+				Round {
+					vesting_start: None,
+					vesting_step,
+					initial_payment,
+					total_rewards: T::Balance::zero(),
+					claimed_rewards: T::Balance::zero(),
+					total_contributors: 0,
+				},
+			);
+			Self::deposit_event(Event::RoundCreated {
+				round_id,
+				pot_account: Self::round_account_id(round_id),
+			});
+
+			Ok(())
+		}
+
+		/// Populates the rewards of a round created with `create_round`, see `do_populate`.
+		///
+		/// # Errors
+		/// * `RoundNotFound` - The round does not exist
+		/// * `AlreadyInitialized` - The round has been set to initialize, population may no longer
+		///   commence
+		/// * `ArithmeticError` - Overflow/Underflow detected while calculating totals
+		pub(crate) fn do_populate_round(
+			round_id: RoundId,
+			rewards: Vec<(RemoteAccountOf<T>, RewardAmountOf<T>, VestingPeriodOf<T>)>,
+		) -> DispatchResult {
+			Rounds::<T>::try_mutate(round_id, |round| {
+				let round = round.as_mut().ok_or(Error::<T>::RoundNotFound)?;
+				ensure!(round.vesting_start.is_none(), Error::<T>::AlreadyInitialized);
+
+				let (total_rewards, total_contributors) = rewards.into_iter().try_fold(
+					(round.total_rewards, round.total_contributors),
+					|totals, (remote_account, account_total, vesting_period)| {
+						RoundRewards::<T>::try_mutate_exists(round_id, remote_account, |reward| {
+							upsert_reward::<T>(reward, totals, account_total, vesting_period)
+						})
+					},
+				)?;
+
+				round.total_rewards = total_rewards;
+				round.total_contributors = total_contributors;
+				Ok(())
+			})
+		}
+
+		/// Initializes a round created with `create_round` at a given timestamp.
+		///
+		/// If the round is over funded by more than the `OverFundedThreshold`, the
+		/// `RoundOverFunded` event will be emitted with the excess amount.
+		///
+		/// # Errors
+		/// * `RoundNotFound` - The round does not exist
+		/// * `AlreadyInitialized` - The round has already been scheduled to start at some time
+		/// * `BackToTheFuture` - The given timestamp, `at`, is before the current time
+		/// * `RewardsNotFunded` - The round pot has not been funded with the minimum amount of
+		///   funds to provide the total rewards
+		pub(crate) fn do_initialize_round(round_id: RoundId, at: MomentOf<T>) -> DispatchResult {
+			Rounds::<T>::try_mutate(round_id, |round| {
+				let round = round.as_mut().ok_or(Error::<T>::RoundNotFound)?;
+				ensure!(round.vesting_start.is_none(), Error::<T>::AlreadyInitialized);
+				ensure!(at >= T::Time::now(), Error::<T>::BackToTheFuture);
+
+				let excess_funds = Self::excess_funds(round_id, round.total_rewards)?;
+				if excess_funds > T::OverFundedThreshold::get().mul_floor(round.total_rewards) {
+					Self::deposit_event(Event::RoundOverFunded { round_id, excess_funds })
+				}
+
+				round.vesting_start = Some(at);
+				Self::deposit_event(Event::RoundInitialized { round_id, at });
+
+				Ok(())
+			})
+		}
+
 		/// Associates a reward account with some remote account provided by a proof. Calls
 		/// `do_claim` to perform the first claim.
 		///
 		/// # Errors
-		/// * `NotInitialized` - The Crowdloan has not been initialized yet
-		/// * `NotClaimableYet` - The Crowdloan has been initialized, but the redemption period has
-		///   not begun
+		/// * `NotInitialized` - No round has been initialized yet
+		/// * `NotClaimableYet` - Rounds have been initialized, but none of their redemption periods
+		///   has begun
 		/// * `AlreadyAssociated` - The reward account has already been associated
 		pub(crate) fn do_associate(
 			reward_account: T::AccountId,
 			proof: ProofOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let now = T::Time::now();
-			let enabled = VestingTimeStart::<T>::get().ok_or(Error::<T>::NotInitialized)? <= now;
-			ensure!(enabled, Error::<T>::NotClaimableYet);
+			Self::ensure_any_round_started()?;
 			let remote_account = get_remote_account::<T>(proof, &reward_account, T::Prefix::get())?;
 			// NOTE(hussein-aitlahcen): this is also checked by the ValidateUnsigned implementation
 			// of the pallet. theoretically useless, but 1:1 to make it clear
@@ -489,6 +810,46 @@ pub mod pallet {
 			Ok(Pays::No.into())
 		}
 
+		/// Relays the association of a reward account and pays the relayer from the first round
+		/// pot holding enough funds beyond the rewards left to claim in the round.
+		///
+		/// # Errors
+		/// * `RelayedClaimQuotaExceeded` - `MaxRelayedClaimsPerBlock` associations have already
+		///   been relayed in this block
+		/// * `SponsorshipNotFunded` - No round pot can pay the `RelayerFee` without dipping into
+		///   the rewards of its round
+		/// * Any error of `do_associate`
+		pub(crate) fn do_relay_associate(
+			relayer: T::AccountId,
+			reward_account: T::AccountId,
+			proof: ProofOf<T>,
+		) -> DispatchResult {
+			let current_block = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				RelayedClaims::<T>::mutate(|quota| {
+					quota.try_use(current_block, T::MaxRelayedClaimsPerBlock::get())
+				}),
+				Error::<T>::RelayedClaimQuotaExceeded
+			);
+
+			Self::do_associate(reward_account.clone(), proof)?;
+
+			let fee = T::RelayerFee::get();
+			let sponsor = Self::all_rounds()
+				.into_iter()
+				.map(|(round_id, round)| (Self::round_account_id(round_id), round))
+				.find(|(pot_account, round)| {
+					let remaining = round.total_rewards.saturating_sub(round.claimed_rewards);
+					T::RewardAsset::balance(pot_account).saturating_sub(remaining) >= fee
+				})
+				.map(|(pot_account, _)| pot_account)
+				.ok_or(Error::<T>::SponsorshipNotFunded)?;
+			T::RewardAsset::transfer(&sponsor, &relayer, fee, false)?;
+			Self::deposit_event(Event::AssociationRelayed { relayer, reward_account, fee });
+
+			Ok(())
+		}
+
 		/// Populates the `Rewards` while updating `TotalRewards` and `TotalContributors`
 		///
 		/// If a reward already exits, the reward and respective totals will be updated to account
@@ -508,35 +869,10 @@ pub mod pallet {
 
 			let (total_rewards, total_contributors) = rewards.into_iter().try_fold(
 				(total_rewards, total_contributors),
-				|(total_rewards, total_contributors),
-				 (remote_account, account_total, vesting_period)| {
-					Rewards::<T>::try_mutate_exists::<_, _, DispatchError, _>(
-						remote_account,
-						|reward| match reward {
-							Some(reward) => {
-								let total_rewards = total_rewards
-									.safe_sub(&reward.total)?
-									.safe_add(&account_total)?;
-
-								reward.total = account_total;
-								reward.vesting_period = vesting_period;
-
-								Ok((total_rewards, total_contributors))
-							},
-							None => {
-								let total_rewards = total_rewards.safe_add(&account_total)?;
-								let total_contributors = total_contributors.safe_add(&1)?;
-
-								reward.replace(Reward {
-									total: account_total,
-									claimed: T::Balance::zero(),
-									vesting_period,
-								});
-
-								Ok((total_rewards, total_contributors))
-							},
-						},
-					)
+				|totals, (remote_account, account_total, vesting_period)| {
+					Rewards::<T>::try_mutate_exists(remote_account, |reward| {
+						upsert_reward::<T>(reward, totals, account_total, vesting_period)
+					})
 				},
 			)?;
 
@@ -545,7 +881,8 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Do claim the reward for a given remote account, rewarding the `reward_account`.
+		/// Do claim the rewards of all the started rounds for a given remote account, rewarding
+		/// the `reward_account`. Each round pays its share from its own pot account.
 		///
 		/// # Errors
 		/// * `NothingToClaim` - No rewards are available to claim at this time
@@ -554,41 +891,63 @@ pub mod pallet {
 			remote_account: RemoteAccountOf<T>,
 			reward_account: &T::AccountId,
 		) -> Result<T::Balance, DispatchError> {
-			Rewards::<T>::try_mutate(remote_account, |reward| {
-				if let Some(reward) = reward {
-					let should_have_claimed = should_have_claimed::<T>(reward)?;
-					let available_to_claim = should_have_claimed.saturating_sub(reward.claimed);
-					ensure!(available_to_claim > T::Balance::zero(), Error::<T>::NothingToClaim);
-
-					reward.claimed = available_to_claim.saturating_add(reward.claimed);
-
-					let funds_account = Self::account_id();
-					// No need to keep the pallet account alive.
-					T::RewardAsset::transfer(
-						&funds_account,
-						reward_account,
-						available_to_claim,
-						false,
-					)?;
-
-					// IMPORTANT: Order of execution of this lock matters for proper locking of
-					// funds. Refer https://app.clickup.com/t/33e4tdu
-					if T::LockByDefault::get() && !RemoveRewardLocks::<T>::exists() {
-						T::RewardAsset::set_lock(
-							T::LockId::get(),
+			let now = T::Time::now();
+			let mut is_contributor = false;
+			let mut available_to_claim = T::Balance::zero();
+			let mut claimed = T::Balance::zero();
+
+			for (round_id, round) in Self::all_rounds() {
+				let Some(mut reward) = Self::reward_of(round_id, &remote_account) else { continue };
+				is_contributor = true;
+
+				if round.vesting_start.map_or(false, |start| start <= now) {
+					let schedule = vesting_schedule::<T>(&round)?;
+					let round_available = reward.available_to_claim::<T::Convert>(&schedule, now);
+					if !round_available.is_zero() {
+						reward.record_claim(round_available);
+						available_to_claim = available_to_claim.saturating_add(round_available);
+
+						// No need to keep the pot account alive.
+						T::RewardAsset::transfer(
+							&Self::round_account_id(round_id),
 							reward_account,
-							reward.claimed,
-							WithdrawReasons::TRANSFER,
-						);
+							round_available,
+							false,
+						)?;
+
+						if round_id == INITIAL_ROUND {
+							Rewards::<T>::insert(&remote_account, reward);
+							ClaimedRewards::<T>::mutate(|x| *x = x.saturating_add(round_available));
+						} else {
+							RoundRewards::<T>::insert(round_id, &remote_account, reward);
+							Rounds::<T>::mutate(round_id, |round| {
+								if let Some(round) = round {
+									round.claimed_rewards =
+										round.claimed_rewards.saturating_add(round_available);
+								}
+							});
+						}
 					}
+				}
 
-					ClaimedRewards::<T>::mutate(|x| *x = x.saturating_add(available_to_claim));
+				claimed = claimed.saturating_add(reward.claimed);
+			}
 
-					Ok(available_to_claim)
-				} else {
-					Err(Error::<T>::InvalidProof.into())
-				}
-			})
+			ensure!(is_contributor, Error::<T>::InvalidProof);
+			ensure!(available_to_claim > T::Balance::zero(), Error::<T>::NothingToClaim);
+
+			// IMPORTANT: Order of execution of this lock matters for proper locking of
+			// funds. Refer https://app.clickup.com/t/33e4tdu
+			if T::LockByDefault::get() && !RemoveRewardLocks::<T>::exists() {
+				T::RewardAsset::set_lock(
+					T::LockId::get(),
+					reward_account,
+					claimed,
+					WithdrawReasons::TRANSFER,
+				);
+			}
+
+			Ok(available_to_claim)
 		}
 
 		/// Sets `RemoveRewardLocks`, removes `RewardAsset` locks on provided accounts, emits
@@ -604,49 +963,92 @@ pub mod pallet {
 		}
 	}
 
-	/// The reward amount a user should have claimed until now.
+	/// Inserts or replaces a reward while updating the `(total_rewards, total_contributors)`
+	/// totals of its round accordingly.
+	fn upsert_reward<T: Config>(
+		reward: &mut Option<RewardOf<T>>,
+		(total_rewards, total_contributors): (T::Balance, u32),
+		account_total: RewardAmountOf<T>,
+		vesting_period: VestingPeriodOf<T>,
+	) -> Result<(T::Balance, u32), DispatchError> {
+		match reward {
+			Some(reward) => {
+				let total_rewards =
+					total_rewards.safe_sub(&reward.total)?.safe_add(&account_total)?;
+
+				reward.total = account_total;
+				reward.vesting_period = vesting_period;
+
+				Ok((total_rewards, total_contributors))
+			},
+			None => {
+				let total_rewards = total_rewards.safe_add(&account_total)?;
+				let total_contributors = total_contributors.safe_add(&1)?;
+
+				reward.replace(Reward {
+					total: account_total,
+					claimed: T::Balance::zero(),
+					vesting_period,
+				});
+
+				Ok((total_rewards, total_contributors))
+			},
+		}
+	}
+
+	/// The vesting schedule of the rewards of the given round.
+	///
+	/// # Errors
+	/// * `NotInitialized` - The round has not been initialized
+	pub fn vesting_schedule<T: Config>(
+		round: &RoundOf<T>,
+	) -> Result<VestingSchedule<MomentOf<T>>, DispatchError> {
+		let start = round.vesting_start.ok_or(Error::<T>::NotInitialized)?;
+		Ok(VestingSchedule {
+			start,
+			step: round.vesting_step,
+			initial_payment: round.initial_payment,
+		})
+	}
+
+	/// The reward amount a user should have claimed until now in the given round.
 	///
 	/// # Errors
-	/// * `NotInitialized` - The Crowdloan has not been initialized
+	/// * `NotInitialized` - The round has not been initialized
 	pub fn should_have_claimed<T: Config>(
 		reward: &RewardOf<T>,
+		round: &RoundOf<T>,
 	) -> Result<T::Balance, DispatchError> {
-		let start = VestingTimeStart::<T>::get().ok_or(Error::<T>::NotInitialized)?;
-		let upfront_payment = T::InitialPayment::get().mul_floor(reward.total);
-
-		let now = T::Time::now();
-		// Current point in time
-		let vesting_point = now.saturating_sub(start);
-		if vesting_point >= reward.vesting_period {
-			// If the user is claiming when the period is over, they should
-			// probably have already claimed everything.
-			Ok(reward.total)
-		} else {
-			let vesting_step = T::VestingStep::get();
-			// Current window, rounded to previous window.
-			let vesting_window = vesting_point.saturating_sub(vesting_point % vesting_step);
-			// The user should have claimed the upfront payment + the vested
-			// amount until this window point.
-			let vested_reward = reward.total.saturating_sub(upfront_payment);
-			Ok(upfront_payment.saturating_add(
-				vested_reward.saturating_mul(T::Convert::convert(vesting_window)) /
-					T::Convert::convert(reward.vesting_period),
-			))
-		}
+		Ok(vesting_schedule::<T>(round)?.vested::<_, T::Convert>(
+			reward.total,
+			reward.vesting_period,
+			T::Time::now(),
+		))
 	}
 
-	/// Returns the amount available to claim for the specified account.
+	/// Returns the amount available to claim for the specified account, across all the started
+	/// rounds.
 	pub fn amount_available_to_claim_for<T: Config>(
 		account_id: <T as frame_system::Config>::AccountId,
 	) -> Result<T::Balance, DispatchError> {
 		let association = Associations::<T>::get(account_id).ok_or(Error::<T>::NotAssociated)?;
-		let reward = Rewards::<T>::get(association).ok_or(Error::<T>::InvalidProof)?;
-		let should_have_claimed = should_have_claimed::<T>(&reward)?;
-		let available_to_claim = should_have_claimed.saturating_sub(reward.claimed);
+		let now = T::Time::now();
+		let mut is_contributor = false;
+		let mut available_to_claim = T::Balance::zero();
+		for (round_id, round) in Pallet::<T>::all_rounds() {
+			let Some(reward) = Pallet::<T>::reward_of(round_id, &association) else { continue };
+			is_contributor = true;
+			if round.vesting_start.map_or(false, |start| start <= now) {
+				let schedule = vesting_schedule::<T>(&round)?;
+				available_to_claim = available_to_claim
+					.saturating_add(reward.available_to_claim::<T::Convert>(&schedule, now));
+			}
+		}
+		ensure!(is_contributor, Error::<T>::InvalidProof);
 		Ok(available_to_claim)
 	}
 
-	/// Retrieves the remote account from a proof
+	/// Retrieves the remote account from a proof, as verified by the `IdentityVerifier`.
 	///
 	/// # Errors
 	/// * `InvalidProof` - The proof was invalid for the reward account
@@ -655,75 +1057,8 @@ pub mod pallet {
 		reward_account: &<T as frame_system::Config>::AccountId,
 		prefix: &[u8],
 	) -> Result<RemoteAccountOf<T>, DispatchErrorWithPostInfo<PostDispatchInfo>> {
-		match proof {
-			Proof::Ethereum(eth_proof) => {
-				let reward_account_encoded =
-					reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec());
-				let ethereum_address =
-					ethereum_recover(prefix, &reward_account_encoded, &eth_proof)
-						.ok_or(Error::<T>::InvalidProof)?;
-				Ok(RemoteAccount::Ethereum(ethereum_address))
-			},
-			Proof::RelayChain(relay_account, relay_proof) => {
-				ensure!(
-					verify_relay(
-						prefix,
-						reward_account.clone(),
-						relay_account.clone(),
-						&relay_proof
-					),
-					Error::<T>::InvalidProof
-				);
-				Ok(RemoteAccount::RelayChain(relay_account))
-			},
-		}
-	}
-
-	/// Verify that the proof is valid for the given account.
-	pub fn verify_relay<AccountId: Encode, RelayChainAccountId: Into<AccountId32>>(
-		prefix: &[u8],
-		reward_account: AccountId,
-		relay_account: RelayChainAccountId,
-		proof: &MultiSignature,
-	) -> bool {
-		/// Polkadotjs wraps the message in this tag before signing.
-		const WRAPPED_PREFIX: &[u8] = b"<Bytes>";
-		const WRAPPED_POSTFIX: &[u8] = b"</Bytes>";
-		let mut msg = WRAPPED_PREFIX.to_vec();
-		msg.append(&mut prefix.to_vec());
-		msg.append(&mut reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()));
-		msg.append(&mut WRAPPED_POSTFIX.to_vec());
-		proof.verify(&msg[..], &relay_account.into())
-	}
-
-	/// Sign a message and produce an `eth_sign` compatible signature.
-	pub fn ethereum_signable_message(prefix: &[u8], msg: &[u8]) -> Vec<u8> {
-		let mut l = prefix.len() + msg.len();
-		let mut msg_len = Vec::new();
-		while l > 0 {
-			msg_len.push(b'0' + (l % 10) as u8);
-			l /= 10;
-		}
-		let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
-		v.extend(msg_len.into_iter().rev());
-		v.extend_from_slice(prefix);
-		v.extend_from_slice(msg);
-		v
-	}
-
-	/// Recover the public key of an `eth_sign` signature.
-	/// The original message is required for this extraction to be possible.
-	pub fn ethereum_recover(
-		prefix: &[u8],
-		msg: &[u8],
-		EcdsaSignature(sig): &EcdsaSignature,
-	) -> Option<EthereumAddress> {
-		let msg = keccak_256(&ethereum_signable_message(prefix, msg));
-		let mut addr = EthereumAddress::default();
-		let _ = &addr.0.copy_from_slice(
-			&keccak_256(&sp_io::crypto::secp256k1_ecdsa_recover(sig, &msg).ok()?[..])[12..],
-		);
-		Some(addr)
+		T::IdentityVerifier::verify(prefix, reward_account, proof)
+			.map_err(|_| Error::<T>::InvalidProof.into())
 	}
 
 	#[pallet::validate_unsigned]
@@ -732,11 +1067,7 @@ pub mod pallet {
 
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			if let Call::associate { reward_account, proof } = call {
-				let now = T::Time::now();
-				let enabled = VestingTimeStart::<T>::get()
-					.ok_or(InvalidTransaction::Custom(ValidityError::NotClaimableYet as u8))? <=
-					now;
-				if !enabled {
+				if Pallet::<T>::ensure_any_round_started().is_err() {
 					return InvalidTransaction::Custom(ValidityError::NotClaimableYet as u8).into()
 				}
 
@@ -750,14 +1081,16 @@ pub mod pallet {
 								ValidityError::InvalidProof as u8,
 							))
 						})?;
-				match Rewards::<T>::get(remote_account.clone()) {
-					None => InvalidTransaction::Custom(ValidityError::NoReward as u8).into(),
-					Some(reward) if reward.total.is_zero() =>
-						InvalidTransaction::Custom(ValidityError::NoReward as u8).into(),
-					Some(_) =>
-						ValidTransaction::with_tag_prefix("CrowdloanRewardsAssociationCheck")
-							.and_provides(remote_account)
-							.build(),
+				let has_reward = Pallet::<T>::all_rounds().into_iter().any(|(round_id, _)| {
+					Pallet::<T>::reward_of(round_id, &remote_account)
+						.map_or(false, |reward| !reward.total.is_zero())
+				});
+				if has_reward {
+					ValidTransaction::with_tag_prefix("CrowdloanRewardsAssociationCheck")
+						.and_provides(remote_account)
+						.build()
+				} else {
+					InvalidTransaction::Custom(ValidityError::NoReward as u8).into()
 				}
 			} else {
 				Err(InvalidTransaction::Call.into())
@@ -3,25 +3,35 @@ use crate::{
 	models::{Proof, RemoteAccount},
 };
 use codec::Encode;
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::{
+	claims::identity::MultiChainVerifier,
+	signature_verification,
+	types::{
+		CosmosEcdsaSignature, CosmosPublicKey, EcdsaSignature, Ed25519PublicKey, Ed25519Signature,
+		EthereumAddress,
+	},
+};
 use frame_support::{
 	construct_runtime,
-	dispatch::DispatchResultWithPostInfo,
-	parameter_types,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	ord_parameter_types, parameter_types,
 	traits::{Everything, LockIdentifier},
 	PalletId,
 };
 use frame_system as system;
-use sp_core::{ed25519, keccak_256, Pair, H256};
+use p256::ecdsa::{signature::Signer, SigningKey, VerifyingKey};
+use sp_core::{ed25519, keccak_256, sha2_256, Pair, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
 	AccountId32, Perbill,
 };
 use sp_std::vec::Vec;
-use system::EnsureRoot;
+use system::{EnsureRoot, EnsureSignedBy};
 
 pub type RelayKey = ed25519::Pair;
 pub type EthKey = libsecp256k1::SecretKey;
+pub type CosmosKey = SigningKey;
+pub type Ed25519Key = ed25519::Pair;
 
 pub type Moment = u64;
 pub type BlockNumber = u32;
@@ -33,7 +43,12 @@ pub const VESTING_STEP: Moment = 3600 * 24 * 7;
 pub const INITIAL_PAYMENT: Perbill = Perbill::from_percent(50);
 pub const OVER_FUNDED_THRESHOLD: Perbill = Perbill::from_percent(1);
 
+pub const RELAYER_FEE: Balance = 10;
+pub const MAX_RELAYED_CLAIMS_PER_BLOCK: u32 = 10;
+pub const MAX_ROUNDS: u32 = 4;
+
 pub const ALICE: AccountId = AccountId32::new([0_u8; 32]);
+pub const RELAYER: AccountId = AccountId32::new([0xfe_u8; 32]);
 
 // picasso-{account_id}
 pub const PROOF_PREFIX: &[u8] = b"picasso-";
@@ -91,6 +106,13 @@ parameter_types! {
 	pub const VestingStep: Moment = VESTING_STEP;
 	pub const Prefix: &'static [u8] = PROOF_PREFIX;
 	pub const LockCrowdloanRewards: bool = true;
+	pub const RelayerFee: Balance = RELAYER_FEE;
+	pub const MaxRelayedClaimsPerBlock: u32 = MAX_RELAYED_CLAIMS_PER_BLOCK;
+	pub const MaxRounds: u32 = MAX_ROUNDS;
+}
+
+ord_parameter_types! {
+	pub const Relayer: AccountId = RELAYER;
 }
 
 impl pallet_crowdloan_rewards::Config for Test {
@@ -99,6 +121,7 @@ impl pallet_crowdloan_rewards::Config for Test {
 	type Balance = Balance;
 	type Convert = ConvertInto;
 	type RelayChainAccountId = RelayChainAccountId;
+	type IdentityVerifier = MultiChainVerifier<RelayChainAccountId>;
 	type InitialPayment = InitialPayment;
 	type OverFundedThreshold = OverFundedThreshold;
 	type VestingStep = VestingStep;
@@ -110,6 +133,10 @@ impl pallet_crowdloan_rewards::Config for Test {
 	type Time = Timestamp;
 	type LockId = CrowdloanRewardsLockId;
 	type LockByDefault = LockCrowdloanRewards;
+	type RelayerOrigin = EnsureSignedBy<Relayer, AccountId>;
+	type RelayerFee = RelayerFee;
+	type MaxRelayedClaimsPerBlock = MaxRelayedClaimsPerBlock;
+	type MaxRounds = MaxRounds;
 }
 
 parameter_types! {
@@ -160,6 +187,9 @@ impl ExtBuilder {
 pub enum ClaimKey {
 	Relay(RelayKey),
 	Eth(EthKey),
+	Cosmos(CosmosKey),
+	Ed25519(Ed25519Key),
+	EthTypedData(EthKey),
 }
 
 impl ClaimKey {
@@ -167,27 +197,34 @@ impl ClaimKey {
 		match self {
 			ClaimKey::Relay(relay_account) =>
 				RemoteAccount::RelayChain(relay_account.public().into()),
-			ClaimKey::Eth(ethereum_account) =>
+			ClaimKey::Eth(ethereum_account) | ClaimKey::EthTypedData(ethereum_account) =>
 				RemoteAccount::Ethereum(ethereum_address(ethereum_account)),
+			ClaimKey::Cosmos(cosmos_account) =>
+				RemoteAccount::Cosmos(cosmos_address(cosmos_account)),
+			ClaimKey::Ed25519(ed25519_account) =>
+				RemoteAccount::Ed25519(Ed25519PublicKey(ed25519_account.public().0)),
 		}
 	}
 	pub fn proof(self, reward_account: AccountId32) -> Proof<[u8; 32]> {
 		match self {
 			ClaimKey::Relay(relay) => relay_proof(&relay, reward_account),
 			ClaimKey::Eth(eth) => ethereum_proof(&eth, reward_account),
+			ClaimKey::Cosmos(cosmos) => cosmos_proof(&cosmos, reward_account),
+			ClaimKey::Ed25519(ed25519) => ed25519_proof(&ed25519, reward_account),
+			ClaimKey::EthTypedData(eth) => ethereum_typed_data_proof(&eth, reward_account),
 		}
 	}
 	pub fn claim(&self, reward_account: AccountId) -> DispatchResultWithPostInfo {
 		CrowdloanRewards::claim(RuntimeOrigin::signed(reward_account))
 	}
 	pub fn associate(&self, reward_account: AccountId) -> DispatchResultWithPostInfo {
-		let proof = match self {
-			ClaimKey::Relay(relay_account) => relay_proof(relay_account, reward_account.clone()),
-			ClaimKey::Eth(ethereum_account) =>
-				ethereum_proof(ethereum_account, reward_account.clone()),
-		};
+		let proof = self.clone().proof(reward_account.clone());
 		CrowdloanRewards::associate(RuntimeOrigin::none(), reward_account, proof)
 	}
+	pub fn relay_associate(&self, reward_account: AccountId) -> DispatchResult {
+		let proof = self.clone().proof(reward_account.clone());
+		CrowdloanRewards::relay_associate(RuntimeOrigin::signed(RELAYER), reward_account, proof)
+	}
 }
 
 fn relay_proof(relay_account: &RelayKey, reward_account: AccountId) -> Proof<RelayChainAccountId> {
@@ -203,7 +240,7 @@ pub fn ethereum_proof(
 	reward_account: AccountId,
 ) -> Proof<RelayChainAccountId> {
 	let msg = keccak_256(
-		&pallet_crowdloan_rewards::ethereum_signable_message(
+		&signature_verification::ethereum_signable_message(
 			PROOF_PREFIX,
 			&reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()),
 		)[..],
@@ -216,6 +253,51 @@ pub fn ethereum_proof(
 	Proof::Ethereum(EcdsaSignature(r))
 }
 
+fn cosmos_proof(
+	cosmos_account: &CosmosKey,
+	reward_account: AccountId,
+) -> Proof<RelayChainAccountId> {
+	let mut msg = PROOF_PREFIX.to_vec();
+	msg.append(&mut reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()));
+	let mut sig = [0_u8; 64];
+	sig.copy_from_slice(cosmos_account.sign(&sha2_256(&msg)).to_vec().as_slice());
+	Proof::Cosmos(cosmos_address(cosmos_account), CosmosEcdsaSignature(sig))
+}
+
+fn ed25519_proof(
+	ed25519_account: &Ed25519Key,
+	reward_account: AccountId,
+) -> Proof<RelayChainAccountId> {
+	let mut msg = PROOF_PREFIX.to_vec();
+	msg.append(&mut reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()));
+	Proof::Ed25519(
+		Ed25519PublicKey(ed25519_account.public().0),
+		Ed25519Signature(ed25519_account.sign(&msg).0),
+	)
+}
+
+pub fn ethereum_typed_data_proof(
+	ethereum_account: &EthKey,
+	reward_account: AccountId,
+) -> Proof<RelayChainAccountId> {
+	let msg = signature_verification::ethereum_typed_data_hash(
+		PROOF_PREFIX,
+		&reward_account.using_encoded(|x| hex::encode(x).as_bytes().to_vec()),
+	);
+	let (sig, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), ethereum_account);
+	let mut r = [0_u8; 65];
+	r[0..64].copy_from_slice(&sig.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	Proof::EthereumTypedData(EcdsaSignature(r))
+}
+
+pub fn cosmos_address(secret: &CosmosKey) -> CosmosPublicKey {
+	let mut pub_key = [0_u8; 33];
+	pub_key.copy_from_slice(VerifyingKey::from(secret).to_encoded_point(true).as_bytes());
+	CosmosPublicKey::Secp256r1(pub_key)
+}
+
 pub fn ethereum_public(secret: &EthKey) -> libsecp256k1::PublicKey {
 	libsecp256k1::PublicKey::from_secret_key(secret)
 }
@@ -262,6 +344,61 @@ pub fn ethereum_generate(count: u64) -> Vec<(AccountId, ClaimKey)> {
 		.collect()
 }
 
+pub fn cosmos_generate(count: u64) -> Vec<(AccountId, ClaimKey)> {
+	(0..count)
+		.map(|i| {
+			let account_id = [[1_u8; 16], (i as u128 + 1).to_le_bytes()]
+				.concat()
+				.try_into()
+				.expect("Account ID is valid; QED");
+			(
+				AccountId::new(account_id),
+				ClaimKey::Cosmos(
+					CosmosKey::from_bytes(&keccak_256(
+						&[&b"cosmos"[..], &i.to_le_bytes()[..]].concat(),
+					))
+					.expect("Key is valid; QED"),
+				),
+			)
+		})
+		.collect()
+}
+
+pub fn ed25519_generate(count: u64) -> Vec<(AccountId, ClaimKey)> {
+	(0..count)
+		.map(|i| {
+			let account_id = [[2_u8; 16], (i as u128 + 1).to_le_bytes()]
+				.concat()
+				.try_into()
+				.expect("Account ID is valid; QED");
+			(
+				AccountId::new(account_id),
+				ClaimKey::Ed25519(ed25519::Pair::from_seed(&keccak_256(
+					&[&b"ed25519"[..], &i.to_le_bytes()[..]].concat(),
+				))),
+			)
+		})
+		.collect()
+}
+
+pub fn ethereum_typed_data_generate(count: u64) -> Vec<(AccountId, ClaimKey)> {
+	(0..count)
+		.map(|i| {
+			let account_id = [(i as u128 + 1).to_le_bytes(), [3_u8; 16]]
+				.concat()
+				.try_into()
+				.expect("Account ID is valid; QED");
+			(
+				AccountId::new(account_id),
+				ClaimKey::EthTypedData(
+					EthKey::parse(&keccak_256(&[&b"eip712"[..], &i.to_le_bytes()[..]].concat()))
+						.expect("Key is valid; QED"),
+				),
+			)
+		})
+		.collect()
+}
+
 pub fn generate_accounts(count: u64) -> Vec<(AccountId, ClaimKey)> {
 	let mut x = relay_generate(count / 2);
 	let mut y = ethereum_generate(count / 2);
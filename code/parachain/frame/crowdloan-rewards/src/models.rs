@@ -1,7 +1,12 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::claims::vesting::ClaimAccount;
 use scale_info::TypeInfo;
-use sp_runtime::{MultiSignature, RuntimeDebug};
+use sp_runtime::{
+	traits::{AtLeast32Bit, AtLeast32BitUnsigned, Saturating},
+	Perbill, RuntimeDebug,
+};
+
+pub use composable_support::claims::identity::{Proof, RemoteAccount};
 
 #[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Reward<Balance, Period> {
@@ -10,14 +15,45 @@ pub struct Reward<Balance, Period> {
 	pub(crate) vesting_period: Period,
 }
 
-#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum Proof<AccountId> {
-	RelayChain(AccountId, MultiSignature),
-	Ethereum(EcdsaSignature),
+impl<Balance, Period> ClaimAccount for Reward<Balance, Period>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	Period: AtLeast32Bit + Copy,
+{
+	type Balance = Balance;
+	type Moment = Period;
+
+	fn total(&self) -> Balance {
+		self.total
+	}
+
+	fn claimed(&self) -> Balance {
+		self.claimed
+	}
+
+	fn vesting_period(&self) -> Period {
+		self.vesting_period
+	}
+
+	fn record_claim(&mut self, amount: Balance) {
+		self.claimed = self.claimed.saturating_add(amount);
+	}
 }
 
-#[derive(Hash, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
-pub enum RemoteAccount<AccountId> {
-	RelayChain(AccountId),
-	Ethereum(EthereumAddress),
+/// A crowdloan round created after the initial one, with its own reward dataset, vesting
+/// parameters and pot account.
+#[derive(Encode, Decode, PartialEq, Eq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Round<Balance, Moment> {
+	/// The timestamp at which the contributors of the round are able to claim their rewards.
+	pub(crate) vesting_start: Option<Moment>,
+	/// The time to wait to unlock another part of a reward.
+	pub(crate) vesting_step: Moment,
+	/// The upfront liquidity unlocked at first claim.
+	pub(crate) initial_payment: Perbill,
+	/// The total amount of rewards to be claimed.
+	pub(crate) total_rewards: Balance,
+	/// The rewards claimed so far.
+	pub(crate) claimed_rewards: Balance,
+	/// The total number of contributors.
+	pub(crate) total_contributors: u32,
 }
@@ -1,7 +1,7 @@
 use crate::{
-	ethereum_recover,
 	mocks::{
-		ethereum_address, generate_accounts, AccountId, Balance, Balances, ClaimKey,
+		cosmos_generate, ed25519_generate, ethereum_address, ethereum_generate,
+		ethereum_typed_data_generate, generate_accounts, AccountId, Balance, Balances, ClaimKey,
 		CrowdloanRewards, EthKey, ExtBuilder, Moment, RuntimeOrigin, System, Test, Timestamp,
 		ALICE, INITIAL_PAYMENT, PROOF_PREFIX, VESTING_STEP,
 	},
@@ -9,7 +9,10 @@ use crate::{
 	Error, Event, RemoteAccountOf, RewardAmountOf, VestingPeriodOf,
 };
 use codec::Encode;
-use composable_support::types::{EcdsaSignature, EthereumAddress};
+use composable_support::{
+	signature_verification,
+	types::{EcdsaSignature, EthereumAddress},
+};
 use composable_tests_helpers::test::helper::RuntimeTrait;
 use frame_support::{
 	assert_noop, assert_ok,
@@ -24,7 +27,16 @@ fn with_rewards<R>(
 	vesting_period: Moment,
 	execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, ClaimKey)>) -> R,
 ) -> R {
-	let accounts = generate_accounts(count as _);
+	with_accounts(generate_accounts(count as _), reward, vesting_period, execute)
+}
+
+fn with_accounts<R>(
+	accounts: Vec<(AccountId, ClaimKey)>,
+	reward: Balance,
+	vesting_period: Moment,
+	execute: impl FnOnce(&dyn Fn(Moment), Vec<(AccountId, ClaimKey)>) -> R,
+) -> R {
+	let count = accounts.len() as u128;
 	let rewards = accounts
 		.iter()
 		.map(|(_, account)| (account.as_remote_public(), reward, vesting_period))
@@ -408,6 +420,59 @@ fn test_association_ok() {
 	});
 }
 
+#[test]
+fn test_association_with_additional_identities_ok() {
+	let accounts =
+		[cosmos_generate(10), ed25519_generate(10), ethereum_typed_data_generate(10)].concat();
+	with_accounts(accounts, DEFAULT_REWARD, DEFAULT_VESTING_PERIOD, |set_moment, accounts| {
+		assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+		for (picasso_account, remote_account) in accounts.clone().into_iter() {
+			assert_ok!(remote_account.associate(picasso_account.clone()));
+			assert_eq!(
+				CrowdloanRewards::associations(picasso_account),
+				Some(remote_account.as_remote_public())
+			);
+		}
+		set_moment(DEFAULT_VESTING_PERIOD);
+		for (picasso_account, remote_account) in accounts.into_iter() {
+			assert_ok!(remote_account.claim(picasso_account.clone()));
+			assert_eq!(Balances::total_balance(&picasso_account), DEFAULT_REWARD);
+		}
+	});
+}
+
+#[test]
+fn test_association_with_typed_data_of_ethereum_contributor_ok() {
+	let accounts = ethereum_generate(1);
+	let (picasso_account, remote_account) = accounts[0].clone();
+	let ClaimKey::Eth(ethereum_key) = remote_account.clone() else {
+		panic!("ethereum_generate only generates Ethereum accounts")
+	};
+	with_accounts(accounts, DEFAULT_REWARD, DEFAULT_VESTING_PERIOD, |_, _| {
+		assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+		assert_ok!(ClaimKey::EthTypedData(ethereum_key).associate(picasso_account.clone()));
+		assert_eq!(
+			CrowdloanRewards::associations(picasso_account),
+			Some(remote_account.as_remote_public())
+		);
+	});
+}
+
+#[test]
+fn test_association_with_cosmos_proof_of_another_account_ko() {
+	with_accounts(cosmos_generate(2), DEFAULT_REWARD, DEFAULT_VESTING_PERIOD, |_, accounts| {
+		assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+		assert_noop!(
+			CrowdloanRewards::associate(
+				RuntimeOrigin::none(),
+				accounts[0].0.clone(),
+				accounts[0].1.clone().proof(accounts[1].0.clone())
+			),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
 #[test]
 fn test_association_ko() {
 	with_rewards_default(|_, accounts| {
@@ -491,13 +556,13 @@ fn test_valid_eth_hardcoded() {
 	let eth_proof = EcdsaSignature(hex!("42f2fa6a3db41e6654891e4408ce56ba31fc2b4dea18e82db1c78e33a3f65a55119a23fa7b3fe7a5088197a74a0102266836bb721461b9eaef128bec120db0401c"));
 
 	// Make sure we are able to recover the address
-	let recovered_address = ethereum_recover(
+	let recovered_address = signature_verification::ethereum_recover(
 		PROOF_PREFIX,
 		&ALICE.using_encoded(|x| hex::encode(x).as_bytes().to_vec()),
 		&eth_proof,
 	);
 
-	assert_eq!(Some(eth_address), recovered_address);
+	assert_eq!(Ok(eth_address), recovered_address);
 
 	let reward_amount = DEFAULT_REWARD;
 	let rewards =
@@ -517,6 +582,84 @@ fn test_valid_eth_hardcoded() {
 	});
 }
 
+mod relay_associate {
+	use super::*;
+	use crate::mocks::{RELAYER, RELAYER_FEE};
+	use frame_support::error::BadOrigin;
+
+	/// Funds the pallet account with enough funds to pay one relayer on top of the rewards.
+	fn fund_sponsorship() {
+		Balances::make_free_balance_be(
+			&CrowdloanRewards::account_id(),
+			DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS + RELAYER_FEE,
+		);
+	}
+
+	#[test]
+	fn should_associate_and_pay_relayer_from_pallet_account() {
+		with_rewards_default(|_, accounts| {
+			fund_sponsorship();
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+
+			let (picasso_account, remote_account) = accounts[0].clone();
+			assert_ok!(remote_account.relay_associate(picasso_account.clone()));
+			assert_eq!(
+				CrowdloanRewards::associations(&picasso_account),
+				Some(remote_account.as_remote_public())
+			);
+			assert_eq!(Balances::total_balance(&picasso_account), INITIAL_PAYMENT * DEFAULT_REWARD);
+			assert_eq!(Balances::total_balance(&RELAYER), RELAYER_FEE);
+			System::assert_has_event(
+				Event::AssociationRelayed {
+					relayer: RELAYER,
+					reward_account: picasso_account,
+					fee: RELAYER_FEE,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn should_not_pay_relayer_from_rewards() {
+		with_rewards_default(|_, accounts| {
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+
+			let (picasso_account, remote_account) = accounts[0].clone();
+			assert_noop!(
+				remote_account.relay_associate(picasso_account),
+				Error::<Test>::SponsorshipNotFunded
+			);
+		});
+	}
+
+	#[test]
+	fn should_only_be_relayed_by_relayer_origin() {
+		with_rewards_default(|_, accounts| {
+			fund_sponsorship();
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+
+			let (picasso_account, remote_account) = accounts[0].clone();
+			assert_noop!(
+				CrowdloanRewards::relay_associate(
+					RuntimeOrigin::none(),
+					picasso_account.clone(),
+					remote_account.proof(picasso_account),
+				),
+				BadOrigin
+			);
+			assert_noop!(
+				CrowdloanRewards::relay_associate(
+					RuntimeOrigin::signed(picasso_account.clone()),
+					picasso_account.clone(),
+					remote_account.proof(picasso_account),
+				),
+				BadOrigin
+			);
+		});
+	}
+}
+
 mod test_prevalidate_association {
 	use super::{
 		with_rewards, with_rewards_default, ClaimKey, DEFAULT_NB_OF_CONTRIBUTORS,
@@ -577,3 +720,261 @@ mod test_prevalidate_association {
 		});
 	}
 }
+
+mod rounds {
+	use super::*;
+	use crate::{
+		mocks::{MAX_ROUNDS, RELAYER, RELAYER_FEE},
+		RoundId, INITIAL_ROUND,
+	};
+	use sp_runtime::Perbill;
+
+	const ROUND_REWARD: Balance = 4_000;
+	const ROUND_INITIAL_PAYMENT: Perbill = Perbill::from_percent(25);
+
+	/// Creates, populates, funds and initializes a new round rewarding `accounts`.
+	fn new_round(accounts: &[(AccountId, ClaimKey)]) -> RoundId {
+		assert_ok!(CrowdloanRewards::create_round(
+			RuntimeOrigin::root(),
+			VESTING_STEP,
+			ROUND_INITIAL_PAYMENT
+		));
+		let round_id = CrowdloanRewards::round_count();
+		let rewards = accounts
+			.iter()
+			.map(|(_, account)| (account.as_remote_public(), ROUND_REWARD, DEFAULT_VESTING_PERIOD))
+			.collect();
+		assert_ok!(CrowdloanRewards::populate_round(RuntimeOrigin::root(), round_id, rewards));
+		Balances::make_free_balance_be(
+			&CrowdloanRewards::round_account_id(round_id),
+			ROUND_REWARD * accounts.len() as Balance,
+		);
+		assert_ok!(CrowdloanRewards::initialize_round(
+			RuntimeOrigin::root(),
+			round_id,
+			Timestamp::now()
+		));
+		round_id
+	}
+
+	#[test]
+	fn create_round_should_use_a_dedicated_pot_account() {
+		ExtBuilder::default().build().execute_with(|| {
+			System::set_block_number(1);
+			assert_noop!(
+				CrowdloanRewards::create_round(RuntimeOrigin::root(), 0, ROUND_INITIAL_PAYMENT),
+				Error::<Test>::InvalidVestingStep
+			);
+
+			assert_ok!(CrowdloanRewards::create_round(
+				RuntimeOrigin::root(),
+				VESTING_STEP,
+				ROUND_INITIAL_PAYMENT
+			));
+			assert_ok!(CrowdloanRewards::create_round(
+				RuntimeOrigin::root(),
+				VESTING_STEP,
+				ROUND_INITIAL_PAYMENT
+			));
+
+			assert_eq!(CrowdloanRewards::round_count(), 2);
+			assert_eq!(
+				CrowdloanRewards::round_account_id(INITIAL_ROUND),
+				CrowdloanRewards::account_id()
+			);
+			assert_ne!(CrowdloanRewards::round_account_id(1), CrowdloanRewards::account_id());
+			assert_ne!(
+				CrowdloanRewards::round_account_id(1),
+				CrowdloanRewards::round_account_id(2)
+			);
+			System::assert_has_event(
+				Event::RoundCreated {
+					round_id: 2,
+					pot_account: CrowdloanRewards::round_account_id(2),
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn create_round_should_be_bounded_by_max_rounds() {
+		ExtBuilder::default().build().execute_with(|| {
+			for _ in 0..MAX_ROUNDS {
+				assert_ok!(CrowdloanRewards::create_round(
+					RuntimeOrigin::root(),
+					VESTING_STEP,
+					ROUND_INITIAL_PAYMENT
+				));
+			}
+
+			assert_noop!(
+				CrowdloanRewards::create_round(
+					RuntimeOrigin::root(),
+					VESTING_STEP,
+					ROUND_INITIAL_PAYMENT
+				),
+				Error::<Test>::TooManyRounds
+			);
+			assert_eq!(CrowdloanRewards::all_rounds().len(), MAX_ROUNDS as usize + 1);
+		});
+	}
+
+	#[test]
+	fn populate_and_initialize_round_should_be_independent_of_initial_round() {
+		with_rewards_default(|_, accounts| {
+			let rewards: Vec<_> = accounts
+				.iter()
+				.map(|(_, account)| {
+					(account.as_remote_public(), ROUND_REWARD, DEFAULT_VESTING_PERIOD)
+				})
+				.collect();
+			assert_noop!(
+				CrowdloanRewards::populate_round(RuntimeOrigin::root(), 1, rewards.clone()),
+				Error::<Test>::RoundNotFound
+			);
+			assert_noop!(
+				CrowdloanRewards::initialize_round(RuntimeOrigin::root(), 1, Timestamp::now()),
+				Error::<Test>::RoundNotFound
+			);
+
+			assert_ok!(CrowdloanRewards::create_round(
+				RuntimeOrigin::root(),
+				VESTING_STEP,
+				ROUND_INITIAL_PAYMENT
+			));
+			assert_ok!(CrowdloanRewards::populate_round(RuntimeOrigin::root(), 1, rewards.clone()));
+			assert_noop!(
+				CrowdloanRewards::initialize_round(RuntimeOrigin::root(), 1, Timestamp::now()),
+				Error::<Test>::RewardsNotFunded
+			);
+
+			Balances::make_free_balance_be(
+				&CrowdloanRewards::round_account_id(1),
+				ROUND_REWARD * DEFAULT_NB_OF_CONTRIBUTORS,
+			);
+			assert_ok!(CrowdloanRewards::initialize_round(
+				RuntimeOrigin::root(),
+				1,
+				Timestamp::now()
+			));
+			assert_noop!(
+				CrowdloanRewards::initialize_round(RuntimeOrigin::root(), 1, Timestamp::now()),
+				Error::<Test>::AlreadyInitialized
+			);
+			assert_noop!(
+				CrowdloanRewards::populate_round(RuntimeOrigin::root(), 1, rewards),
+				Error::<Test>::AlreadyInitialized
+			);
+
+			let round = CrowdloanRewards::rounds(1).expect("round was created; QED");
+			assert_eq!(round.total_rewards, ROUND_REWARD * DEFAULT_NB_OF_CONTRIBUTORS);
+			assert_eq!(round.total_contributors as u128, DEFAULT_NB_OF_CONTRIBUTORS);
+			assert_eq!(
+				CrowdloanRewards::total_rewards(),
+				DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS
+			);
+			// The initial round can still be populated and initialized on its own.
+			assert_eq!(CrowdloanRewards::vesting_block_start(), None);
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+		});
+	}
+
+	#[test]
+	fn claims_should_aggregate_across_rounds() {
+		with_rewards_default(|set_moment, accounts| {
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+			let round_id = new_round(&accounts);
+
+			for (picasso_account, remote_account) in accounts.iter().cloned() {
+				assert_ok!(remote_account.associate(picasso_account.clone()));
+				assert_eq!(
+					Balances::total_balance(&picasso_account),
+					INITIAL_PAYMENT * DEFAULT_REWARD + ROUND_INITIAL_PAYMENT * ROUND_REWARD
+				);
+			}
+			assert_eq!(
+				CrowdloanRewards::claimed_rewards(),
+				INITIAL_PAYMENT * DEFAULT_REWARD * DEFAULT_NB_OF_CONTRIBUTORS
+			);
+			assert_eq!(
+				CrowdloanRewards::rounds(round_id).map(|round| round.claimed_rewards),
+				Some(ROUND_INITIAL_PAYMENT * ROUND_REWARD * DEFAULT_NB_OF_CONTRIBUTORS)
+			);
+
+			set_moment(DEFAULT_VESTING_PERIOD);
+			for (picasso_account, remote_account) in accounts {
+				assert_eq!(
+					crate::amount_available_to_claim_for::<Test>(picasso_account.clone()),
+					Ok(DEFAULT_REWARD - INITIAL_PAYMENT * DEFAULT_REWARD + ROUND_REWARD -
+						ROUND_INITIAL_PAYMENT * ROUND_REWARD)
+				);
+				assert_ok!(remote_account.claim(picasso_account.clone()));
+				assert_eq!(
+					Balances::total_balance(&picasso_account),
+					DEFAULT_REWARD + ROUND_REWARD
+				);
+			}
+			assert_eq!(Balances::total_balance(&CrowdloanRewards::account_id()), 0);
+			assert_eq!(Balances::total_balance(&CrowdloanRewards::round_account_id(round_id)), 0);
+		});
+	}
+
+	#[test]
+	fn relayer_should_be_paid_from_a_funded_round_pot() {
+		with_rewards_default(|_, accounts| {
+			assert_ok!(CrowdloanRewards::initialize(RuntimeOrigin::root()));
+			let round_id = new_round(&accounts);
+			let pot_account = CrowdloanRewards::round_account_id(round_id);
+			Balances::make_free_balance_be(
+				&pot_account,
+				ROUND_REWARD * DEFAULT_NB_OF_CONTRIBUTORS + RELAYER_FEE,
+			);
+
+			let (picasso_account, remote_account) = accounts[0].clone();
+			assert_ok!(remote_account.relay_associate(picasso_account));
+			assert_eq!(Balances::total_balance(&RELAYER), RELAYER_FEE);
+			let round = CrowdloanRewards::rounds(round_id).expect("round was created; QED");
+			assert_eq!(
+				Balances::total_balance(&pot_account),
+				round.total_rewards - round.claimed_rewards
+			);
+
+			// Both pots are now down to the rewards left to claim in their round.
+			let (picasso_account, remote_account) = accounts[1].clone();
+			assert_noop!(
+				remote_account.relay_associate(picasso_account),
+				Error::<Test>::SponsorshipNotFunded
+			);
+		});
+	}
+
+	#[test]
+	fn later_round_contributors_should_associate_without_initial_round() {
+		with_rewards(0, DEFAULT_REWARD, DEFAULT_VESTING_PERIOD, |_, _| {
+			let accounts = generate_accounts(10);
+			let (contributors, others) = accounts.split_at(5);
+			for (picasso_account, remote_account) in contributors.iter().cloned() {
+				assert_noop!(
+					remote_account.associate(picasso_account),
+					Error::<Test>::NotInitialized
+				);
+			}
+
+			new_round(contributors);
+			for (picasso_account, remote_account) in contributors.iter().cloned() {
+				assert_ok!(remote_account.associate(picasso_account.clone()));
+				assert_eq!(
+					Balances::total_balance(&picasso_account),
+					ROUND_INITIAL_PAYMENT * ROUND_REWARD
+				);
+			}
+			for (picasso_account, remote_account) in others.iter().cloned() {
+				assert_noop!(
+					remote_account.associate(picasso_account),
+					Error::<Test>::InvalidProof
+				);
+			}
+		});
+	}
+}
@@ -13,8 +13,10 @@ pub trait WeightInfo {
 	fn populate(x: u32) -> Weight;
 	fn initialize(x: u32) -> Weight;
 	fn associate(x: u32) -> Weight;
+	fn relay_associate(x: u32) -> Weight;
 	fn claim(x: u32) -> Weight;
 	fn unlock_rewards_for(x: u32) -> Weight;
+	fn create_round() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -51,6 +53,20 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	// Storage: CrowdloanRewards RelayedClaims (r:1 w:1)
+	// Storage: CrowdloanRewards VestingBlockStart (r:1 w:0)
+	// Storage: CrowdloanRewards Rewards (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: CrowdloanRewards ClaimedRewards (r:1 w:1)
+	// Storage: CrowdloanRewards TotalRewards (r:1 w:0)
+	// Storage: CrowdloanRewards Associations (r:0 w:1)
+	// not benchmarked: `associate` with the relayed claim quota and the payment of the relayer
+	fn relay_associate(x: u32) -> Weight {
+		Weight::from_ref_time(200_000_000_u64)
+			.saturating_add(Weight::from_ref_time(8_000_u64).saturating_mul(x as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 	// Storage: CrowdloanRewards Associations (r:1 w:0)
 	// Storage: CrowdloanRewards VestingBlockStart (r:1 w:0)
 	// Storage: CrowdloanRewards Rewards (r:1 w:1)
@@ -68,4 +84,12 @@ impl WeightInfo for () {
 		// TODO(hussein-aitlahcen): extrinsic added without benchmark
 		Weight::from_ref_time(10_000_u64)
 	}
+	// Storage: CrowdloanRewards RoundCount (r:1 w:1)
+	// Storage: CrowdloanRewards Rounds (r:0 w:1)
+	// not benchmarked: reads and writes the round count, writes the round
+	fn create_round() -> Weight {
+		Weight::from_ref_time(25_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -9,7 +9,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	Permill,
+	Perbill, Permill,
 };
 use system::{EnsureRoot, EnsureSigned};
 
@@ -182,6 +182,15 @@ parameter_types! {
 	pub const MaxRewardConfigsPerPool: u32 = 10;
 	// TODO(benluelo): Use a better value here?
 	pub const TreasuryAccountId: AccountId = 123_456_789_u128;
+	pub const VotingPowerPoolId: CurrencyId = ETH;
+	pub const VoteEscrowEpoch: u64 = 24 * 60 * 60;
+	pub const MaxVoteEscrowEpochs: u32 = 4 * 365;
+	pub const MaxGauges: u32 = 10;
+	pub const GaugeEpoch: u64 = 7 * 24 * 60 * 60;
+	pub const AutoCompoundBatchSize: u32 = 10;
+	pub const AutoCompoundPeriod: u64 = 10;
+	pub const CompoundBounty: Perbill = Perbill::from_percent(1);
+	pub const ExpectedBlockTime: u64 = 12;
 }
 
 impl pallet_staking_rewards::Config for Test {
@@ -203,6 +212,15 @@ impl pallet_staking_rewards::Config for Test {
 	type LockId = StakingRewardsLockId;
 	type TreasuryAccount = TreasuryAccountId;
 	type ExistentialDeposits = ExistentialDeposits;
+	type VotingPowerPoolId = VotingPowerPoolId;
+	type VoteEscrowEpoch = VoteEscrowEpoch;
+	type MaxVoteEscrowEpochs = MaxVoteEscrowEpochs;
+	type MaxGauges = MaxGauges;
+	type GaugeEpoch = GaugeEpoch;
+	type AutoCompoundBatchSize = AutoCompoundBatchSize;
+	type AutoCompoundPeriod = AutoCompoundPeriod;
+	type CompoundBounty = CompoundBounty;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 impl pallet_pablo::Config for Test {
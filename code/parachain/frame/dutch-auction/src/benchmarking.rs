@@ -4,7 +4,9 @@ use codec::Decode;
 use composable_traits::{
 	defi::{CurrencyPair, DeFiComposableConfig, Ratio, Sell, Take},
 	time::{LinearDecrease, TimeReleaseFunction},
-	xcm::XcmSellRequest,
+	xcm::{
+		CumulusMethodId, XcmSellOrderState, XcmSellOrderStatus, XcmSellRequest, XcmSellStatusQuery,
+	},
 };
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::traits::{fungibles::Mutate, Currency, Get, Hooks};
@@ -137,6 +139,26 @@ benchmarks! {
 		};
 		let origin = cumulus_pallet_xcm::Origin::SiblingParachain(42_u32.into());
 	}: _(origin, request)
+	add_xcm_callback_location {
+		let parachain_id: polkadot_parachain::primitives::Id = 42_u32.into();
+		let method = CumulusMethodId { pallet_instance: 42, method_id: 42 };
+	}: _(RawOrigin::Root, parachain_id, method)
+	xcm_sell_status {
+		let parachain_id: polkadot_parachain::primitives::Id = 42_u32.into();
+		let method = CumulusMethodId { pallet_instance: 42, method_id: 42 };
+		ParachainXcmCallbackLocation::<T>::insert(parachain_id, method);
+		// final status is removed once sent, so is worst case
+		let status = XcmSellOrderStatus {
+			state: XcmSellOrderState::Filled,
+			..XcmSellOrderStatus::new(1, 1)
+		};
+		XcmSellOrderStatuses::<T>::insert(parachain_id, 1, status);
+		XcmSellOrders::<T>::insert(parachain_id, 1, OrdersIndex::<T>::get());
+		let origin = cumulus_pallet_xcm::Origin::SiblingParachain(parachain_id);
+	}: _(origin, XcmSellStatusQuery { order_id: 1 })
+	verify {
+		assert!(XcmSellOrderStatuses::<T>::get(parachain_id, 1).is_none());
+	}
 	known_overhead_for_on_finalize {
 		let sell = sell_identity::<T>();
 		let account_id: T::AccountId = whitelisted_caller();
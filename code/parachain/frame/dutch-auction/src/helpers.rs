@@ -2,9 +2,9 @@ use crate::{math::*, prelude::*, support::DefiMultiReservableCurrency, types::*}
 pub use crate::{pallet::*, weights::WeightInfo};
 use composable_support::abstractions::utils::increment::Increment;
 use composable_traits::{
-	defi::{DeFiComposableConfig, Sell, SellEngine, Take},
+	defi::{CurrencyPair, DeFiComposableConfig, Sell, SellEngine, Take},
 	time::TimeReleaseFunction,
-	xcm::XcmSellInitialResponseTransact,
+	xcm::{SellResponse, XcmSellInitialResponseTransact, XcmSellOrderState, XcmSellOrderStatus},
 };
 use frame_support::{
 	traits::{tokens::fungible::Transfer as NativeTransfer, UnixTime},
@@ -12,14 +12,26 @@ use frame_support::{
 };
 use orml_traits::MultiReservableCurrency;
 use sp_runtime::{traits::AccountIdConversion, DispatchError};
-use sp_std::convert::TryInto;
 use xcm::latest::{prelude::*, MultiAsset, WeightLimit::Unlimited};
 
 impl<T: Config> Pallet<T> {
+	/// Orders takes by priority: highest limit first, ties are resolved by submission order.
+	/// Takes are appended into storage, so position in `takes` is submission order.
+	pub fn prioritize_takes(takes: Vec<TakeOf<T>>) -> Vec<TakeOf<T>> {
+		let mut takes: Vec<_> = takes.into_iter().enumerate().collect();
+		takes.sort_by(|(a_index, a), (b_index, b)| {
+			b.take.limit.cmp(&a.take.limit).then_with(|| a_index.cmp(b_index))
+		});
+		takes.into_iter().map(|(_, take)| take).collect()
+	}
+
+	/// Executes all `takes` of block against order.
+	/// Each take is filled as much as order has left, unfilled quote reservation is returned to
+	/// taker. Raises event per take.
 	#[transactional]
 	pub fn take_order(
 		order_id: <T as Config>::OrderId,
-		mut takes: Vec<TakeOf<T>>,
+		takes: Vec<TakeOf<T>>,
 	) -> Result<(), DispatchError> {
 		<SellOrders<T>>::try_mutate_exists(order_id, |order_item| {
 			if let Some(crate::types::SellOrder {
@@ -31,17 +43,19 @@ impl<T: Config> Pallet<T> {
 			}) = order_item
 			{
 				let mut amount_received = T::Balance::zero();
-				// users payed N * WEIGHT before, we here pay N * (log N - 1) * Weight. We can
-				// retain pure N by first served principle so, not highest price.
-				takes.sort_by(|a, b| b.take.limit.cmp(&a.take.limit));
-				// calculate real price
-				for take in takes {
+				let mut amount_taken = T::Balance::zero();
+				for take in Self::prioritize_takes(takes) {
 					let quote_amount = take.take.quote_limit_amount()?;
 					// TODO: what to do with orders which nobody ever takes? some kind of dust
 					// orders
 					if order.take.amount == T::Balance::zero() {
 						// bidder was unlucky because order was sol out
-						T::MultiCurrency::unreserve(order.pair.quote, &take.from_to, quote_amount);
+						let refunded = Self::refund_take(&take, quote_amount);
+						Self::deposit_event(Event::TakeNotFilled {
+							order_id,
+							taker: take.from_to,
+							refunded,
+						});
 					} else {
 						let take_amount = take.take.amount.min(order.take.amount);
 						order.take.amount -= take_amount;
@@ -55,21 +69,42 @@ impl<T: Config> Pallet<T> {
 							&take.from_to,
 							real_quote_amount,
 						)?;
-						if real_quote_amount < quote_amount {
-							T::MultiCurrency::unreserve(
-								order.pair.quote,
-								&take.from_to,
-								quote_amount - real_quote_amount,
-							);
-						}
+						let refunded = Self::refund_take(
+							&take,
+							quote_amount.saturating_sub(real_quote_amount),
+						);
 						amount_received += real_quote_amount;
+						amount_taken += take_amount;
+						let event = if take_amount < take.take.amount {
+							Event::TakePartiallyFilled {
+								order_id,
+								taker: take.from_to,
+								requested: take.take.amount,
+								taken: take_amount,
+								paid: real_quote_amount,
+								refunded,
+							}
+						} else {
+							Event::TakeFilled {
+								order_id,
+								taker: take.from_to,
+								taken: take_amount,
+								paid: real_quote_amount,
+								refunded,
+							}
+						};
+						Self::deposit_event(event);
 					}
 				}
 
 				*total_amount_received += amount_received;
 
+				if amount_taken > T::Balance::zero() {
+					Self::deposit_event(Event::OrderTaken { order_id, taken: amount_taken });
+					Self::callback_xcm(order, seller, order_id, amount_taken, amount_received);
+				}
+
 				if order.take.amount == T::Balance::zero() {
-					Self::callback_xcm(order, seller, order_id, *total_amount_received)?;
 					*order_item = None;
 					Self::deposit_event(Event::OrderRemoved { order_id });
 				}
@@ -82,6 +117,40 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Unreserves up to `amount` of `quote` reserved by `take`, returns amount actually refunded.
+	fn refund_take(take: &TakeOf<T>, amount: T::Balance) -> T::Balance {
+		if amount.is_zero() {
+			return amount
+		}
+		let not_released = T::MultiCurrency::unreserve(take.quote, &take.from_to, amount);
+		amount.saturating_sub(not_released)
+	}
+
+	/// Releases `quote` reserved by `takes` which were not executed, so takers get funds back.
+	/// Works regardless of the order still existing.
+	pub fn release_takes(
+		order_id: <T as Config>::OrderId,
+		takes: Vec<TakeOf<T>>,
+		error: DispatchError,
+	) {
+		for take in takes {
+			let refunded = take
+				.take
+				.quote_limit_amount()
+				.map(|quote_amount| Self::refund_take(&take, quote_amount))
+				.unwrap_or_else(|_| T::Balance::zero());
+			Self::deposit_event(Event::TakeFailed {
+				order_id,
+				taker: take.from_to,
+				refunded,
+				error,
+			});
+		}
+	}
+
+	/// Updates status of order requested via XCM after it was taken and notifies sender chain,
+	/// transferring `received` proceeds of this take.
+	/// Failure to notify does not fail take, sender can query status later.
 	pub fn callback_xcm(
 		order: &Sell<
 			<T as DeFiComposableConfig>::MayBeAssetId,
@@ -89,92 +158,183 @@ impl<T: Config> Pallet<T> {
 		>,
 		seller: &<T as frame_system::Config>::AccountId,
 		order_id: <T as Config>::OrderId,
-		received_amount: <T as DeFiComposableConfig>::Balance,
+		taken: <T as DeFiComposableConfig>::Balance,
+		received: <T as DeFiComposableConfig>::Balance,
+	) {
+		if let Some((parachain_id, remote_order_id)) = LocalOrderIdToRemote::<T>::get(order_id) {
+			let status =
+				XcmSellOrderStatuses::<T>::mutate(parachain_id, remote_order_id, |status| {
+					let status =
+						status.get_or_insert_with(|| XcmSellOrderStatus::new(remote_order_id, 0));
+					status.total_amount_taken =
+						status.total_amount_taken.saturating_add(taken.into());
+					status.total_amount_received =
+						status.total_amount_received.saturating_add(received.into());
+					status.remaining_amount = order.take.amount.into();
+					status.state = if order.take.amount.is_zero() {
+						XcmSellOrderState::Filled
+					} else {
+						XcmSellOrderState::PartiallyFilled
+					};
+					status.clone()
+				});
+			let is_final = status.state.is_final();
+			let response = if is_final {
+				LocalOrderIdToRemote::<T>::remove(order_id);
+				SellResponse::Final(XcmSellInitialResponseTransact {
+					// `Final` always reported amount of `quote` received for the whole order
+					total_amount_taken: status.total_amount_received,
+					minimal_price: composable_traits::xcm::Balance::one(), /* auction goes to
+					                                                        * minimal price,
+					                                                        * can thin about
+					                                                        * better later */
+					order_id: remote_order_id,
+				})
+			} else {
+				SellResponse::PartiallyFilled(status)
+			};
+			let sent = Self::notify_xcm_sell(
+				parachain_id,
+				remote_order_id,
+				response,
+				Some((seller, &order.pair, received)),
+			);
+			if sent && is_final {
+				Self::forget_xcm_sell(parachain_id, remote_order_id);
+			}
+		}
+	}
+
+	/// Marks order requested via XCM as expired and notifies sender chain.
+	pub fn expire_xcm(order_id: <T as Config>::OrderId) {
+		if let Some((parachain_id, remote_order_id)) = LocalOrderIdToRemote::<T>::take(order_id) {
+			let status =
+				XcmSellOrderStatuses::<T>::mutate(parachain_id, remote_order_id, |status| {
+					let status =
+						status.get_or_insert_with(|| XcmSellOrderStatus::new(remote_order_id, 0));
+					status.state = XcmSellOrderState::Expired;
+					status.clone()
+				});
+			if Self::notify_xcm_sell(
+				parachain_id,
+				remote_order_id,
+				SellResponse::Expired(status),
+				None,
+			) {
+				Self::forget_xcm_sell(parachain_id, remote_order_id);
+			}
+		}
+	}
+
+	/// Removes status of order requested via XCM once sender was notified about its final state.
+	pub fn forget_xcm_sell(
+		parachain_id: polkadot_parachain::primitives::Id,
+		remote_order_id: composable_traits::xcm::OrderId,
+	) {
+		XcmSellOrderStatuses::<T>::remove(parachain_id, remote_order_id);
+		XcmSellOrders::<T>::remove(parachain_id, remote_order_id);
+	}
+
+	/// Sends `response`, returns if it was sent. Failure is reported by event only.
+	fn notify_xcm_sell(
+		parachain_id: polkadot_parachain::primitives::Id,
+		remote_order_id: composable_traits::xcm::OrderId,
+		response: SellResponse,
+		proceeds: Option<(
+			&<T as frame_system::Config>::AccountId,
+			&CurrencyPair<<T as DeFiComposableConfig>::MayBeAssetId>,
+			<T as DeFiComposableConfig>::Balance,
+		)>,
+	) -> bool {
+		match Self::send_xcm_sell_response(parachain_id, response, proceeds) {
+			Ok(()) => true,
+			Err(error) => {
+				Self::deposit_event(Event::XcmSellResponseFailed {
+					parachain_id,
+					remote_order_id,
+					error,
+				});
+				false
+			},
+		}
+	}
+
+	/// Sends `response` into callback location registered for `parachain_id`.
+	/// If `proceeds` are not zero, these are transferred to sender in same message.
+	pub fn send_xcm_sell_response(
+		parachain_id: polkadot_parachain::primitives::Id,
+		response: SellResponse,
+		proceeds: Option<(
+			&<T as frame_system::Config>::AccountId,
+			&CurrencyPair<<T as DeFiComposableConfig>::MayBeAssetId>,
+			<T as DeFiComposableConfig>::Balance,
+		)>,
 	) -> Result<(), DispatchError> {
-		LocalOrderIdToRemote::<T>::try_mutate_exists(order_id, |xcm_order_item| {
-			if let Some((parachain_id, xcm_order_id)) = xcm_order_item {
-				let parachain_id = *parachain_id;
-				let xcm_order_id = *xcm_order_id;
-				let mut account = vec![0_u8; 32];
-				seller.encode_to(&mut account);
-				let account: [u8; 32] =
-					account.try_into().expect("cumulus runtime has no account with 33 bytes");
-				// as of now we do only final sell
-				// setting up XCM message
+		let method = ParachainXcmCallbackLocation::<T>::get(parachain_id)
+			.ok_or(Error::<T>::XcmCallbackLocationNotFound)?;
+		let (remote_order_id, state) = match &response {
+			SellResponse::Initial(x) | SellResponse::Final(x) =>
+				(x.order_id, XcmSellOrderState::Filled),
+			SellResponse::PartiallyFilled(x) |
+			SellResponse::Expired(x) |
+			SellResponse::Status(x) => (x.order_id, x.state),
+		};
+		let callback = composable_traits::xcm::XcmCumulusDispatch::new(
+			method.pallet_instance,
+			method.method_id,
+			response,
+		);
+		let transact = Transact {
+			origin_type: OriginKind::Native,
+			require_weight_at_most: 0, /* TODO: make sure that
+			                            * callbacks are free (if
+			                            * correct) or specify
+			                            * price */
+			call: callback.encode().into(),
+		};
+		let msg = match proceeds {
+			Some((seller, pair, amount)) if amount > T::Balance::zero() => {
+				let account = <[u8; 32]>::decode(&mut &seller.encode()[..]).map_err(|_| {
+					Error::<T>::XcmCannotDecodeRemoteParametersToLocalRepresentations
+				})?;
+				let key = || {
+					GeneralKey(
+						frame_support::storage::weak_bounded_vec::WeakBoundedVec::force_from(
+							pair.encode(),
+							None,
+						),
+					)
+				};
 				let asset_id = MultiLocation {
 					parents: 1,
-					interior: X2(
-						AccountId32 { network: Any, id: account },
-						GeneralKey(
-							frame_support::storage::weak_bounded_vec::WeakBoundedVec::force_from(
-								order.pair.encode(),
-								None,
-							),
-						),
-					),
+					interior: X2(AccountId32 { network: Any, id: account }, key()),
 				};
-				let asset_id = AssetId::Concrete(asset_id);
-				let assets = MultiAsset { fun: Fungible(received_amount.into()), id: asset_id };
-				let callback =
-					composable_traits::xcm::SellResponse::Final(XcmSellInitialResponseTransact {
-						total_amount_taken: received_amount.into(),
-						minimal_price: composable_traits::xcm::Balance::one(), /* auction goes to
-						                                                        * minimal price,
-						                                                        * can
-						                                                        * thin about
-						                                                        * better
-						                                                        * later */
-						order_id: xcm_order_id,
-					});
-				if let Some(method) = ParachainXcmCallbackLocation::<T>::get(parachain_id) {
-					let callback = composable_traits::xcm::XcmCumulusDispatch::new(
-						method.pallet_instance,
-						method.method_id,
-						callback,
-					);
-					let callback = vec![
-						WithdrawAsset(assets.clone().into()),
-						BuyExecution { fees: assets.clone(), weight_limit: Unlimited },
-						TransferReserveAsset {
-							assets: assets.into(),
-							dest: (
-								Parent,
-								X3(
-									Parachain(parachain_id.into()),
-									AccountId32 { network: Any, id: account },
-						      GeneralKey(frame_support::storage::weak_bounded_vec::WeakBoundedVec::force_from(order.pair.encode(), None)),
-								),
-							)
-								.into(),
-							xcm: Xcm(vec![Transact {
-								origin_type: OriginKind::Native,
-								require_weight_at_most: 0, /* TODO: make sure that
-								                            * callbacks are free (if
-								                            * correct) or specify
-								                            * price */
-								call: callback.encode().into(),
-							}]),
-						},
-					];
-					let msg = Xcm(callback);
-					let result = T::XcmSender::send_xcm(
-						(Parent, Junction::Parachain(parachain_id.into())),
-						msg,
-					);
-					match result {
-						Ok(_) => {
-							// TODO: decide if need to send event about sent XCM
-							*xcm_order_item = None;
-						},
-						Err(_) => {
-							// TODO: insert here event to allow to act on failure
-							return Err(Error::<T>::TakeOrderDidNotHappen.into())
-						},
-					}
-				}
-			}
-			Ok(())
-		})
+				let assets =
+					MultiAsset { fun: Fungible(amount.into()), id: AssetId::Concrete(asset_id) };
+				Xcm(vec![
+					WithdrawAsset(assets.clone().into()),
+					BuyExecution { fees: assets.clone(), weight_limit: Unlimited },
+					TransferReserveAsset {
+						assets: assets.into(),
+						dest: (
+							Parent,
+							X3(
+								Parachain(parachain_id.into()),
+								AccountId32 { network: Any, id: account },
+								key(),
+							),
+						)
+							.into(),
+						xcm: Xcm(vec![transact]),
+					},
+				])
+			},
+			_ => Xcm(vec![transact]),
+		};
+		T::XcmSender::send_xcm((Parent, Junction::Parachain(parachain_id.into())), msg)
+			.map_err(|_| Error::<T>::XcmCallbackSendFailed)?;
+		Self::deposit_event(Event::XcmSellResponseSent { parachain_id, remote_order_id, state });
+		Ok(())
 	}
 }
 
@@ -225,7 +385,10 @@ impl<T: Config + DeFiComposableConfig> SellEngine<TimeReleaseFunction> for Palle
 		let quote_amount = take.quote_limit_amount()?;
 
 		T::MultiCurrency::reserve(order.order.pair.quote, from_to, quote_amount)?;
-		<Takes<T>>::append(order_id, TakeOf::<T> { from_to: from_to.clone(), take });
+		<Takes<T>>::append(
+			order_id,
+			TakeOf::<T> { from_to: from_to.clone(), quote: order.order.pair.quote, take },
+		);
 
 		Ok(())
 	}
@@ -4,7 +4,10 @@
 //! Initial price can start from price above market.
 //! Diminishes with time.
 //! Takers can take for price same or higher.
-//! Higher takers take first.
+//! Higher takers take first, takers with same price are served in order of submission.
+//! Take which cannot be fully served is partially filled, unfilled part of reserved `quote` is
+//! returned to taker. If order cannot be taken at all, all reservations of takes are released.
+//! Each take processed raises own event.
 //! Sell(ask) orders stored on chain. Sell takes deposit from seller, returned during take or
 //! liquidation. Takes live only one block.
 //!
@@ -30,6 +33,11 @@
 //! # XCMP
 //!
 //! Auction provides cross chain API. Alternative
+//!
+//! Sibling parachain asks to sell via `xcm_sell`. Each take of such order is reported back into
+//! callback location registered for sibling with proceeds of take, so is expiry of order.
+//! Status of order is kept on chain until sibling is notified of its final state, and can be
+//! asked by sibling via `xcm_sell_status`.
 
 #![cfg_attr(
 	not(test),
@@ -98,7 +106,10 @@ pub mod pallet {
 	use composable_traits::{
 		defi::{DeFiComposableConfig, DeFiEngine, OrderIdLike, Sell, SellEngine, Take},
 		time::TimeReleaseFunction,
-		xcm::{ConfigurationId, CumulusMethodId, XcmSellRequest},
+		xcm::{
+			ConfigurationId, CumulusMethodId, SellResponse, XcmSellOrderState, XcmSellOrderStatus,
+			XcmSellRequest, XcmSellStatusQuery,
+		},
 	};
 	use cumulus_pallet_xcm::{ensure_sibling_para, Origin as CumulusOrigin};
 	use frame_support::{
@@ -124,8 +135,11 @@ pub mod pallet {
 		TimeReleaseFunction,
 	>;
 
-	pub type TakeOf<T> =
-		TakeOrder<<T as DeFiComposableConfig>::Balance, <T as frame_system::Config>::AccountId>;
+	pub type TakeOf<T> = TakeOrder<
+		<T as DeFiComposableConfig>::MayBeAssetId,
+		<T as DeFiComposableConfig>::Balance,
+		<T as frame_system::Config>::AccountId,
+	>;
 
 	#[pallet::config]
 	#[pallet::disable_frame_system_supertrait_check]
@@ -174,10 +188,56 @@ pub mod pallet {
 		OrderRemoved {
 			order_id: OrderIdOf<T>,
 		},
+		/// take was fully filled, `refunded` is reserved `quote` above real price
+		TakeFilled {
+			order_id: OrderIdOf<T>,
+			taker: T::AccountId,
+			taken: T::Balance,
+			paid: T::Balance,
+			refunded: T::Balance,
+		},
+		/// only `taken` out of `requested` was filled because order had not enough left
+		TakePartiallyFilled {
+			order_id: OrderIdOf<T>,
+			taker: T::AccountId,
+			requested: T::Balance,
+			taken: T::Balance,
+			paid: T::Balance,
+			refunded: T::Balance,
+		},
+		/// order was sold out by takes with higher priority, all reserved `quote` is refunded
+		TakeNotFilled {
+			order_id: OrderIdOf<T>,
+			taker: T::AccountId,
+			refunded: T::Balance,
+		},
+		/// take failed to execute, reserved `quote` is released
+		TakeFailed {
+			order_id: OrderIdOf<T>,
+			taker: T::AccountId,
+			refunded: T::Balance,
+			error: DispatchError,
+		},
 		ConfigurationAdded {
 			configuration_id: ConfigurationId,
 			configuration: TimeReleaseFunction,
 		},
+		XcmCallbackLocationAdded {
+			parachain_id: polkadot_parachain::primitives::Id,
+			method: CumulusMethodId,
+		},
+		/// response about order requested via XCM was sent to sender chain
+		XcmSellResponseSent {
+			parachain_id: polkadot_parachain::primitives::Id,
+			remote_order_id: composable_traits::xcm::OrderId,
+			state: XcmSellOrderState,
+		},
+		/// response about order requested via XCM failed to be sent, sender can query status
+		XcmSellResponseFailed {
+			parachain_id: polkadot_parachain::primitives::Id,
+			remote_order_id: composable_traits::xcm::OrderId,
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::error]
@@ -193,6 +253,9 @@ pub mod pallet {
 		XcmCannotDecodeRemoteParametersToLocalRepresentations,
 		XcmCannotFindLocalIdentifiersAsDecodedFromRemote,
 		XcmNotFoundConfigurationById,
+		XcmSellOrderNotFound,
+		XcmCallbackLocationNotFound,
+		XcmCallbackSendFailed,
 	}
 
 	#[pallet::pallet]
@@ -235,6 +298,20 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// status of orders requested via XCM, removed once sender is notified of final state (fill or
+	/// expiry), if notification fails it is kept until sender queries it
+	#[pallet::storage]
+	#[pallet::getter(fn xcm_sell_order_statuses)]
+	pub type XcmSellOrderStatuses<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		polkadot_parachain::primitives::Id,
+		Twox64Concat,
+		composable_traits::xcm::OrderId,
+		XcmSellOrderStatus,
+		OptionQuery,
+	>;
+
 	/// registered callback location for specific parachain
 	#[pallet::storage]
 	#[pallet::getter(fn get_callback_locations)]
@@ -328,6 +405,9 @@ pub mod pallet {
 			// timeout set) using kind of account per order is possible, but may risk to
 			// pollute account system
 			let treasury = &T::PalletId::get().into_account_truncating();
+			if let Some(takes) = <Takes<T>>::take(order_id) {
+				Self::release_takes(order_id, takes, Error::<T>::OrderNotFound.into());
+			}
 			T::MultiCurrency::unreserve(order.order.pair.base, &who, order.order.take.amount);
 			<T::NativeCurrency as NativeTransfer<T::AccountId>>::transfer(
 				treasury,
@@ -337,6 +417,7 @@ pub mod pallet {
 			)?;
 
 			<SellOrders<T>>::remove(order_id);
+			Self::expire_xcm(order_id);
 			Self::deposit_event(Event::OrderRemoved { order_id });
 
 			Ok(Pays::No.into())
@@ -374,6 +455,12 @@ pub mod pallet {
 			let order_id =
 				<Self as SellEngine<TimeReleaseFunction>>::ask(&who, order, configuration)?;
 			LocalOrderIdToRemote::<T>::insert(order_id, (parachain_id, request.order_id));
+			XcmSellOrders::<T>::insert(parachain_id, request.order_id, order_id);
+			XcmSellOrderStatuses::<T>::insert(
+				parachain_id,
+				request.order_id,
+				XcmSellOrderStatus::new(request.order_id, request.order.take.amount),
+			);
 
 			Self::deposit_event(Event::OrderAdded {
 				order_id,
@@ -382,6 +469,38 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Registers method on `parachain_id` which receives `SellResponse` about orders
+		/// requested by that parachain.
+		#[pallet::weight(T::WeightInfo::add_xcm_callback_location())]
+		pub fn add_xcm_callback_location(
+			origin: OriginFor<T>,
+			parachain_id: polkadot_parachain::primitives::Id,
+			method: CumulusMethodId,
+		) -> DispatchResultWithPostInfo {
+			let _ = T::AdminOrigin::ensure_origin(origin)?;
+			ParachainXcmCallbackLocation::<T>::insert(parachain_id, method.clone());
+			Self::deposit_event(Event::XcmCallbackLocationAdded { parachain_id, method });
+			Ok(().into())
+		}
+
+		/// Sends `SellResponse::Status` of order requested by sibling back into its callback
+		/// location. Status of filled or expired order is removed once sent.
+		#[pallet::weight(T::WeightInfo::xcm_sell_status())]
+		pub fn xcm_sell_status(
+			origin: OriginFor<T>,
+			query: XcmSellStatusQuery,
+		) -> DispatchResultWithPostInfo {
+			let parachain_id = ensure_sibling_para(<T as Config>::XcmOrigin::from(origin))?;
+			let status = XcmSellOrderStatuses::<T>::get(parachain_id, query.order_id)
+				.ok_or(Error::<T>::XcmSellOrderNotFound)?;
+			let is_final = status.state.is_final();
+			Self::send_xcm_sell_response(parachain_id, SellResponse::Status(status), None)?;
+			if is_final {
+				Self::forget_xcm_sell(parachain_id, query.order_id);
+			}
+			Ok(().into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -390,8 +509,8 @@ pub mod pallet {
 		// so we stay fast and prevent attack
 		fn on_finalize(_n: T::BlockNumber) {
 			for (order_id, takes) in <Takes<T>>::drain() {
-				if let Err(err) = Self::take_order(order_id, takes) {
-					log::error!("failed to take order {:?} with {:?}", order_id, err);
+				if let Err(error) = Self::take_order(order_id, takes.clone()) {
+					Self::release_takes(order_id, takes, error);
 				}
 			}
 		}
@@ -21,6 +21,7 @@ use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
 };
+use sp_std::cell::RefCell;
 use xcm::latest::SendXcm;
 
 use super::governance_registry::GovernanceRegistry;
@@ -150,6 +151,8 @@ pub static ALICE: Public =
 	Public(hex!("0000000000000000000000000000000000000000000000000000000000000000"));
 pub static BOB: Public =
 	Public(hex!("0000000000000000000000000000000000000000000000000000000000000001"));
+pub static CHARLIE: Public =
+	Public(hex!("0000000000000000000000000000000000000000000000000000000000000002"));
 
 ord_parameter_types! {
 	pub const RootAccount: AccountId = ALICE;
@@ -217,15 +220,37 @@ impl From<RuntimeOrigin> for XcmFake {
 		todo!("please test via local-integration-tests")
 	}
 }
+thread_local! {
+	/// messages sent by `XcmFake`, sending fails if `None`
+	pub static SENT_XCM: RefCell<Option<Vec<(xcm::latest::MultiLocation, xcm::latest::Xcm<()>)>>> =
+		RefCell::new(Some(Vec::new()));
+}
+
 impl SendXcm for XcmFake {
 	fn send_xcm(
-		_destination: impl Into<xcm::latest::MultiLocation>,
-		_message: xcm::latest::Xcm<()>,
+		destination: impl Into<xcm::latest::MultiLocation>,
+		message: xcm::latest::Xcm<()>,
 	) -> xcm::latest::SendResult {
-		todo!("please test via local-integration-tests")
+		SENT_XCM.with(|sent| match sent.borrow_mut().as_mut() {
+			Some(sent) => {
+				sent.push((destination.into(), message));
+				Ok(())
+			},
+			None => Err(xcm::latest::SendError::Transport("XcmFake is disconnected")),
+		})
 	}
 }
 
+/// takes messages sent by `XcmFake`
+pub fn sent_xcm() -> Vec<(xcm::latest::MultiLocation, xcm::latest::Xcm<()>)> {
+	SENT_XCM.with(|sent| sent.borrow_mut().as_mut().map(core::mem::take).unwrap_or_default())
+}
+
+/// makes `XcmFake` fail (`false`) or succeed (`true`) to send messages
+pub fn connect_xcm(connected: bool) {
+	SENT_XCM.with(|sent| *sent.borrow_mut() = connected.then(Vec::new));
+}
+
 #[allow(dead_code)] // not really dead
 pub fn new_test_externalities() -> sp_io::TestExternalities {
 	let mut storage = frame_system::GenesisConfig::default().build_storage::<Runtime>().unwrap();
@@ -195,3 +195,349 @@ fn liquidation() {
 		})
 		.unwrap();
 }
+
+#[test]
+fn takes_with_same_price_are_served_in_submission_order() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(USDT, &CHARLIE, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let sell = Sell::new(BTC, USDT, 2, fixed(1000));
+		DutchAuction::ask(RuntimeOrigin::signed(ALICE), sell, configuration).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(CHARLIE),
+			order_id,
+			Take::new(2, fixed(1000))
+		));
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			order_id,
+			Take::new(2, fixed(1000))
+		));
+
+		DutchAuction::on_finalize(42);
+
+		assert_eq!(Tokens::balance(BTC, &CHARLIE), 2);
+		assert_eq!(Tokens::balance(BTC, &BOB), 0);
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 0);
+		assert_eq!(Tokens::balance(USDT, &BOB), 1_000_000);
+		System::assert_has_event(
+			pallet_dutch_auction::Event::TakeNotFilled { order_id, taker: BOB, refunded: 2000 }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn higher_price_take_is_served_first_and_rest_is_partially_filled() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(USDT, &CHARLIE, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let sell = Sell::new(BTC, USDT, 3, fixed(1000));
+		DutchAuction::ask(RuntimeOrigin::signed(ALICE), sell, configuration).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			order_id,
+			Take::new(2, fixed(1000))
+		));
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(CHARLIE),
+			order_id,
+			Take::new(2, fixed(1100))
+		));
+
+		DutchAuction::on_finalize(42);
+
+		assert_eq!(Tokens::balance(BTC, &CHARLIE), 2);
+		assert_eq!(Tokens::balance(BTC, &BOB), 1);
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 0);
+		assert_eq!(Tokens::balance(USDT, &BOB), 1_000_000 - 1000);
+		assert_eq!(Tokens::balance(USDT, &ALICE), 2200 + 1000);
+		assert!(crate::SellOrders::<Runtime>::get(order_id).is_none());
+		System::assert_has_event(
+			pallet_dutch_auction::Event::TakeFilled {
+				order_id,
+				taker: CHARLIE,
+				taken: 2,
+				paid: 2200,
+				refunded: 0,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			pallet_dutch_auction::Event::TakePartiallyFilled {
+				order_id,
+				taker: BOB,
+				requested: 2,
+				taken: 1,
+				paid: 1000,
+				refunded: 1000,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn liquidation_releases_pending_takes() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let sell = Sell::new(BTC, USDT, 1, fixed(1000));
+		DutchAuction::ask(RuntimeOrigin::signed(ALICE), sell, configuration).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			order_id,
+			Take::new(1, fixed(1000))
+		));
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 1000);
+
+		DutchAuction::liquidate(RuntimeOrigin::signed(ALICE), order_id).unwrap();
+		DutchAuction::on_finalize(42);
+
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 0);
+		assert_eq!(Tokens::balance(USDT, &BOB), 1_000_000);
+		assert!(crate::Takes::<Runtime>::get(order_id).is_none());
+	});
+}
+
+#[test]
+fn takes_of_removed_order_are_released() {
+	new_test_externalities().execute_with(|| {
+		Tokens::mint_into(USDT, &BOB, 1_000_000).unwrap();
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let configuration = TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 });
+		let sell = Sell::new(BTC, USDT, 1, fixed(1000));
+		DutchAuction::ask(RuntimeOrigin::signed(ALICE), sell, configuration).unwrap();
+		let order_id = crate::OrdersIndex::<Runtime>::get();
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(BOB),
+			order_id,
+			Take::new(1, fixed(1000))
+		));
+
+		crate::SellOrders::<Runtime>::remove(order_id);
+		DutchAuction::on_finalize(42);
+
+		assert_eq!(Assets::reserved_balance(USDT, &BOB), 0);
+		assert_eq!(Tokens::balance(USDT, &BOB), 1_000_000);
+		System::assert_has_event(
+			pallet_dutch_auction::Event::TakeFailed {
+				order_id,
+				taker: BOB,
+				refunded: 1000,
+				error: crate::Error::<Runtime>::TakeOrderDidNotHappen.into(),
+			}
+			.into(),
+		);
+	});
+}
+
+mod xcm_sell {
+	use super::*;
+	use codec::Encode;
+	use composable_traits::xcm::{
+		CumulusMethodId, SellResponse, XcmCumulusDispatch, XcmSellInitialResponseTransact,
+		XcmSellOrderState, XcmSellStatusQuery,
+	};
+	use polkadot_parachain::primitives::Id as ParaId;
+	use xcm::latest::prelude::*;
+
+	const SIBLING: u32 = 2000;
+	const REMOTE_ORDER_ID: u64 = 13;
+	const CALLBACK: CumulusMethodId = CumulusMethodId { pallet_instance: 42, method_id: 1 };
+
+	fn sibling() -> RuntimeOrigin {
+		cumulus_pallet_xcm::Origin::SiblingParachain(SIBLING.into()).into()
+	}
+
+	/// sibling asks to sell `amount` of BTC owned by ALICE for USDT at 1000
+	fn xcm_sell(amount: Balance) -> OrderId {
+		let configuration_id = 1;
+		assert_ok!(DutchAuction::add_configuration(
+			RuntimeOrigin::root(),
+			configuration_id,
+			TimeReleaseFunction::LinearDecrease(LinearDecrease { total: 42 }),
+		));
+		assert_ok!(DutchAuction::add_xcm_callback_location(
+			RuntimeOrigin::root(),
+			SIBLING.into(),
+			CALLBACK,
+		));
+		Tokens::mint_into(BTC, &ALICE, 10).unwrap();
+		let request = XcmSellRequest {
+			order_id: REMOTE_ORDER_ID,
+			from_to: ALICE.0,
+			order: Sell::new(BTC, USDT, amount, fixed(1000)),
+			configuration: configuration_id,
+		};
+		assert_ok!(DutchAuction::xcm_sell(sibling(), request));
+		crate::XcmSellOrders::<Runtime>::get(ParaId::from(SIBLING), REMOTE_ORDER_ID)
+			.expect("remote order is mapped to local")
+	}
+
+	fn take(taker: AccountId, order_id: OrderId, amount: Balance) {
+		Tokens::mint_into(USDT, &taker, amount * 1000).unwrap();
+		assert_ok!(DutchAuction::take(
+			RuntimeOrigin::signed(taker),
+			order_id,
+			Take::new(amount, fixed(1000))
+		));
+		DutchAuction::on_finalize(42);
+	}
+
+	fn status() -> Option<composable_traits::xcm::XcmSellOrderStatus> {
+		crate::XcmSellOrderStatuses::<Runtime>::get(ParaId::from(SIBLING), REMOTE_ORDER_ID)
+	}
+
+	fn response_sent(state: XcmSellOrderState) {
+		System::assert_has_event(
+			pallet_dutch_auction::Event::XcmSellResponseSent {
+				parachain_id: SIBLING.into(),
+				remote_order_id: REMOTE_ORDER_ID,
+				state,
+			}
+			.into(),
+		);
+	}
+
+	fn callback(response: SellResponse) -> Instruction<()> {
+		Transact {
+			origin_type: OriginKind::Native,
+			require_weight_at_most: 0,
+			call: XcmCumulusDispatch::new(CALLBACK.pallet_instance, CALLBACK.method_id, response)
+				.encode()
+				.into(),
+		}
+	}
+
+	/// checks that single message was sent to sibling, transferring `proceeds` with `response`
+	fn assert_sent_with_proceeds(proceeds: Balance, response: SellResponse) {
+		let sent = sent_xcm();
+		assert_eq!(sent.len(), 1);
+		let (destination, message) = &sent[0];
+		assert_eq!(destination, &MultiLocation::new(1, X1(Parachain(SIBLING))));
+		match &message.0[..] {
+			[WithdrawAsset(assets), BuyExecution { .. }, TransferReserveAsset { xcm, .. }] => {
+				assert_eq!(
+					assets.inner().iter().map(|asset| asset.fun.clone()).collect::<Vec<_>>(),
+					vec![Fungible(proceeds)]
+				);
+				assert_eq!(xcm.0, vec![callback(response)]);
+			},
+			_ => panic!("unexpected message {:?}", message),
+		}
+	}
+
+	#[test]
+	fn fills_are_reported_with_proceeds_and_final_status_is_removed() {
+		new_test_externalities().execute_with(|| {
+			let order_id = xcm_sell(3);
+
+			take(BOB, order_id, 2);
+
+			response_sent(XcmSellOrderState::PartiallyFilled);
+			let partially_filled = status().expect("order is not filled yet");
+			assert_eq!(partially_filled.state, XcmSellOrderState::PartiallyFilled);
+			assert_eq!(partially_filled.total_amount_taken, 2);
+			assert_eq!(partially_filled.total_amount_received, 2000);
+			assert_eq!(partially_filled.remaining_amount, 1);
+			assert_sent_with_proceeds(2000, SellResponse::PartiallyFilled(partially_filled));
+
+			take(CHARLIE, order_id, 1);
+
+			response_sent(XcmSellOrderState::Filled);
+			assert_sent_with_proceeds(
+				1000,
+				SellResponse::Final(XcmSellInitialResponseTransact {
+					total_amount_taken: 3000,
+					minimal_price: 1,
+					order_id: REMOTE_ORDER_ID,
+				}),
+			);
+			assert_eq!(status(), None);
+			assert_eq!(
+				crate::XcmSellOrders::<Runtime>::get(ParaId::from(SIBLING), REMOTE_ORDER_ID),
+				None
+			);
+			assert_eq!(crate::LocalOrderIdToRemote::<Runtime>::get(order_id), None);
+		});
+	}
+
+	#[test]
+	fn expiry_is_reported_and_status_is_removed() {
+		new_test_externalities().execute_with(|| {
+			let order_id = xcm_sell(3);
+
+			assert_ok!(DutchAuction::liquidate(RuntimeOrigin::signed(ALICE), order_id));
+
+			response_sent(XcmSellOrderState::Expired);
+			assert_eq!(sent_xcm().len(), 1);
+			assert_eq!(status(), None);
+		});
+	}
+
+	#[test]
+	fn final_status_is_kept_until_queried_if_notification_fails() {
+		new_test_externalities().execute_with(|| {
+			let order_id = xcm_sell(3);
+			connect_xcm(false);
+
+			assert_ok!(DutchAuction::liquidate(RuntimeOrigin::signed(ALICE), order_id));
+
+			System::assert_has_event(
+				pallet_dutch_auction::Event::XcmSellResponseFailed {
+					parachain_id: SIBLING.into(),
+					remote_order_id: REMOTE_ORDER_ID,
+					error: crate::Error::<Runtime>::XcmCallbackSendFailed.into(),
+				}
+				.into(),
+			);
+			let expired = status().expect("status is kept for query");
+			assert_eq!(expired.state, XcmSellOrderState::Expired);
+
+			connect_xcm(true);
+			assert_ok!(DutchAuction::xcm_sell_status(
+				sibling(),
+				XcmSellStatusQuery { order_id: REMOTE_ORDER_ID }
+			));
+
+			response_sent(XcmSellOrderState::Expired);
+			assert_eq!(status(), None);
+			assert_noop!(
+				DutchAuction::xcm_sell_status(
+					sibling(),
+					XcmSellStatusQuery { order_id: REMOTE_ORDER_ID }
+				),
+				crate::Error::<Runtime>::XcmSellOrderNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn status_query_of_open_order_keeps_status() {
+		new_test_externalities().execute_with(|| {
+			xcm_sell(3);
+
+			assert_ok!(DutchAuction::xcm_sell_status(
+				sibling(),
+				XcmSellStatusQuery { order_id: REMOTE_ORDER_ID }
+			));
+
+			let open = status().expect("status of open order is kept");
+			assert_eq!(open.state, XcmSellOrderState::Open);
+			let sent = sent_xcm();
+			assert_eq!(sent.len(), 1);
+			assert_eq!(sent[0].1 .0, vec![callback(SellResponse::Status(open))]);
+		});
+	}
+}
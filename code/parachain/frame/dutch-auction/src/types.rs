@@ -24,8 +24,11 @@ pub struct EDContext<Balance> {
 	pub deposit: Balance,
 }
 
-#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo, PartialEq, Eq)]
-pub struct TakeOrder<Balance, AccountId> {
+#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo, Clone, Debug, PartialEq, Eq)]
+pub struct TakeOrder<AssetId, Balance, AccountId> {
 	pub from_to: AccountId,
+	/// asset reserved from taker to pay for the take, kept so it can be released even if the
+	/// order is gone
+	pub quote: AssetId,
 	pub take: Take<Balance>,
 }
@@ -10,6 +10,8 @@ pub trait WeightInfo {
 	fn liquidate() -> Weight;
 	fn xcm_sell() -> Weight;
 	fn known_overhead_for_on_finalize() -> Weight;
+	fn add_xcm_callback_location() -> Weight;
+	fn xcm_sell_status() -> Weight;
 }
 
 /// Weight functions for `dutch_auction`.
@@ -69,4 +71,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	// Storage: DutchAuction ParachainXcmCallbackLocation (r:0 w:1)
+	fn add_xcm_callback_location() -> Weight {
+		Weight::from_ref_time(8_434_000_u64).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: DutchAuction XcmSellOrderStatuses (r:1 w:1)
+	// Storage: DutchAuction ParachainXcmCallbackLocation (r:1 w:0)
+	// Storage: DutchAuction XcmSellOrders (r:0 w:1)
+	fn xcm_sell_status() -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
@@ -51,7 +51,7 @@ pub mod pallet {
 	use composable_traits::{
 		account_proxy::AccountProxy,
 		currency::AssetIdLike,
-		fnft::{FinancialNft, FnftAccountProxyTypeSelector},
+		fnft::{FinancialNft, FinancialNftTransferHook, FnftAccountProxyTypeSelector},
 	};
 	use core::fmt::Debug;
 	use frame_support::{
@@ -145,6 +145,13 @@ pub mod pallet {
 
 		type ProxyTypeSelector: FnftAccountProxyTypeSelector<Self::ProxyType>;
 
+		/// Notified whenever a fNFT changes owner through a transfer.
+		type TransferHook: FinancialNftTransferHook<
+			Self::AccountId,
+			Self::FinancialNftCollectionId,
+			Self::FinancialNftInstanceId,
+		>;
+
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
@@ -251,6 +258,10 @@ pub mod pallet {
 			Collection::<T>::get(collection)
 				.and_then(|(_, _, attributes)| attributes.get(key).cloned())
 		}
+
+		fn collection_owner(collection: &Self::CollectionId) -> Option<AccountIdOf<T>> {
+			Collection::<T>::get(collection).map(|(owner, _, _)| owner)
+		}
 	}
 
 	impl<T: Config> Create<AccountIdOf<T>> for Pallet<T> {
@@ -350,6 +361,7 @@ pub mod pallet {
 						destination,
 						Some(owner),
 					)?;
+					T::TransferHook::on_transfer(collection, instance, owner, destination)?;
 					*owner = destination.clone();
 
 					Self::deposit_event(Event::FinancialNftTransferred {
@@ -396,25 +408,44 @@ pub mod pallet {
 			instance: &Self::ItemId,
 			_maybe_check_owner: Option<&AccountIdOf<T>>,
 		) -> DispatchResult {
-			Instance::<T>::try_mutate_exists(collection, instance, |entry| -> DispatchResult {
-				match entry {
-					Some((owner, _)) => {
-						OwnerInstances::<T>::mutate(owner, |x| match x {
-							Some(instances) => {
-								instances.remove(&(*collection, *instance));
-							},
-							None => {
-								debug_assert!(false, "unreachable")
-							},
-						});
-						*entry = None;
-						Ok(())
-					},
-					None => Err(Error::<T>::InstanceNotFound.into()),
+			let owner = Instance::<T>::try_mutate_exists(
+				collection,
+				instance,
+				|entry| -> Result<AccountIdOf<T>, DispatchError> {
+					match entry.take() {
+						Some((owner, _)) => {
+							OwnerInstances::<T>::mutate(&owner, |x| match x {
+								Some(instances) => {
+									instances.remove(&(*collection, *instance));
+								},
+								None => {
+									debug_assert!(false, "unreachable")
+								},
+							});
+							Ok(owner)
+						},
+						None => Err(Error::<T>::InstanceNotFound.into()),
+					}
+				},
+			)?;
+
+			// The owner no longer controls the asset account, unless the proxy was removed already
+			let asset_account =
+				<Self as FinancialNft<AccountIdOf<T>>>::asset_account(collection, instance);
+			for proxy_type in T::ProxyTypeSelector::get_proxy_types() {
+				if T::AccountProxy::find_proxy(&asset_account, &owner, Some(proxy_type.clone()))
+					.is_err()
+				{
+					continue
 				}
-			})?;
+				T::AccountProxy::remove_proxy_delegate(
+					&asset_account,
+					owner.clone(),
+					proxy_type,
+					T::BlockNumber::zero(),
+				)?;
+			}
 
-			// TODO (vim): Remove account proxy ??
 			Self::deposit_event(Event::FinancialNftBurned {
 				collection_id: *collection,
 				instance_id: *instance,
@@ -58,6 +58,7 @@ impl crate::Config for MockRuntime {
 	type ProxyType = ProxyType;
 	type AccountProxy = AccountProxyWrapperInstance;
 	type ProxyTypeSelector = MockFnftAccountProxyType;
+	type TransferHook = ();
 	type PalletId = FnftPalletId;
 	type WeightInfo = ();
 }
@@ -29,6 +29,9 @@ pub(crate) fn success() {
 			None,
 			"class should have no attributes"
 		);
+
+		// class owner check
+		assert_eq!(Nft::collection_owner(&TEST_COLLECTION_ID), Some(ALICE));
 	})
 }
 
@@ -58,5 +61,12 @@ pub(crate) fn failure() {
 			None,
 			"class does not exist, there should be no attributes"
 		);
+
+		// class owner check
+		assert_eq!(
+			Nft::collection_owner(&255),
+			None,
+			"class does not exist, there should be no owner"
+		);
 	})
 }
@@ -183,6 +183,7 @@ mod burn_from {
 	use composable_tests_helpers::test::{
 		block::process_and_progress_blocks, helper::RuntimeTrait,
 	};
+	use composable_traits::fnft::FinancialNft;
 	use frame_support::{
 		assert_ok,
 		traits::tokens::nonfungibles::{Create, Mutate},
@@ -224,6 +225,25 @@ mod burn_from {
 				None,
 				"instance should not exist"
 			);
+
+			let asset_account = Nft::asset_account(&TEST_COLLECTION_ID, &nft_to_burn);
+			assert!(
+				Proxy::find_proxy(&asset_account, &ALICE, None).is_err(),
+				"ALICE should no longer proxy the asset account"
+			);
+		})
+	}
+
+	/// Tests burning an NFT whose asset account proxies were already removed.
+	#[test]
+	fn without_proxies() {
+		new_test_ext().execute_with(|| {
+			let nft_to_burn = mint_into_and_assert();
+			let asset_account = Nft::asset_account(&TEST_COLLECTION_ID, &nft_to_burn);
+			Proxy::remove_all_proxy_delegates(&asset_account);
+
+			assert_ok!(Nft::burn(&TEST_COLLECTION_ID, &nft_to_burn, Some(&ALICE)));
+			assert_eq!(Instance::<MockRuntime>::get(TEST_COLLECTION_ID, nft_to_burn), None);
 		})
 	}
 
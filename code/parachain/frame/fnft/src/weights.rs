@@ -12,5 +12,11 @@ pub trait WeightInfo {
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	fn transfer() -> Weight { Weight::from_ref_time(10_000) }
+	// not benchmarked: moves the instance between both owners, replaces the proxies of the asset
+	// account and their deposits, without the transfer hook of the runtime
+	fn transfer() -> Weight {
+		Weight::from_ref_time(60_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -27,7 +27,7 @@ use sp_runtime::{
 	traits::{
 		BlakeTwo256, ConvertInto, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup, Verify,
 	},
-	DispatchError, Perbill,
+	DispatchError, Perbill, Perquintill,
 };
 use xcm::latest::SendXcm;
 
@@ -162,6 +162,10 @@ parameter_types! {
 	pub const MinimumWithdrawal: Balance = 0;
 	pub const VaultPalletId: PalletId = PalletId(*b"cubic___");
 	pub const TombstoneDuration: u64 = 42;
+	pub const RebalanceInterval: u64 = 10;
+	pub const MaxWithdrawalSettlements: u32 = 10;
+	pub const WithdrawalDelay: BlockNumber = 0;
+	pub const InstantExitFee: Perquintill = Perquintill::from_percent(0);
 }
 
 impl pallet_vault::Config for Runtime {
@@ -182,6 +186,12 @@ impl pallet_vault::Config for Runtime {
 	type VaultId = VaultId;
 	type TombstoneDuration = TombstoneDuration;
 	type WeightInfo = ();
+	type Strategies =
+		composable_traits::vault::NoStrategies<AccountId, CurrencyId, Balance, VaultId>;
+	type RebalanceInterval = RebalanceInterval;
+	type MaxWithdrawalSettlements = MaxWithdrawalSettlements;
+	type WithdrawalDelay = WithdrawalDelay;
+	type InstantExitFee = InstantExitFee;
 }
 
 parameter_type_with_key! {
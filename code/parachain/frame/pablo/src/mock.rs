@@ -16,7 +16,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
-	Permill,
+	Perbill, Permill,
 };
 
 pub type CurrencyId = u128;
@@ -169,6 +169,15 @@ parameter_types! {
 	pub const MaxRewardConfigsPerPool: u32 = 10;
 	// REVIEW(benluelo): Use a better value for this?
 	pub const TreasuryAccountId: AccountId = 123_456_789_u128;
+	pub const VotingPowerPoolId: CurrencyId = LP_TOKEN_ID;
+	pub const VoteEscrowEpoch: u64 = 24 * 60 * 60;
+	pub const MaxVoteEscrowEpochs: u32 = 4 * 365;
+	pub const MaxGauges: u32 = 10;
+	pub const GaugeEpoch: u64 = 7 * 24 * 60 * 60;
+	pub const AutoCompoundBatchSize: u32 = 10;
+	pub const AutoCompoundPeriod: u64 = 10;
+	pub const CompoundBounty: Perbill = Perbill::from_percent(1);
+	pub const ExpectedBlockTime: u64 = 12;
 }
 
 impl pallet_staking_rewards::Config for Test {
@@ -190,6 +199,15 @@ impl pallet_staking_rewards::Config for Test {
 	type LockId = StakingRewardsLockId;
 	type TreasuryAccount = TreasuryAccountId;
 	type ExistentialDeposits = ExistentialDeposits;
+	type VotingPowerPoolId = VotingPowerPoolId;
+	type VoteEscrowEpoch = VoteEscrowEpoch;
+	type MaxVoteEscrowEpochs = MaxVoteEscrowEpochs;
+	type MaxGauges = MaxGauges;
+	type GaugeEpoch = GaugeEpoch;
+	type AutoCompoundBatchSize = AutoCompoundBatchSize;
+	type AutoCompoundPeriod = AutoCompoundPeriod;
+	type CompoundBounty = CompoundBounty;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 ord_parameter_types! {
@@ -10,7 +10,7 @@ use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{generic::BlockId, traits::Block as BlockT};
 use sp_std::{cmp::Ord, collections::btree_map::BTreeMap, sync::Arc};
-use staking_rewards_runtime_api::{ClaimableAmountError, StakingRewardsRuntimeApi};
+use staking_rewards_runtime_api::{ClaimableAmountError, RewardEmission, StakingRewardsRuntimeApi};
 
 #[rpc(client, server)]
 pub trait StakingRewardsApi<BlockHash, AssetId, FinancialNftInstanceId, Balance>
@@ -26,6 +26,14 @@ where
 		fnft_instance_id: SafeRpcWrapper<FinancialNftInstanceId>,
 		at: Option<BlockHash>,
 	) -> RpcResult<Result<BTreeMap<AssetId, Balance>, ClaimableAmountError>>;
+
+	#[method(name = "stakingRewards_rewardEmission")]
+	fn reward_emission(
+		&self,
+		pool_id: SafeRpcWrapper<AssetId>,
+		reward_asset_id: SafeRpcWrapper<AssetId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RewardEmission<Balance>>>;
 }
 
 pub struct StakingRewards<C, Block> {
@@ -72,4 +80,24 @@ where
 			)))
 		})
 	}
+
+	fn reward_emission(
+		&self,
+		pool_id: SafeRpcWrapper<AssetId>,
+		reward_asset_id: SafeRpcWrapper<AssetId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RewardEmission<Balance>>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		let runtime_api_result = api.reward_emission(&at, pool_id, reward_asset_id);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
 }
@@ -4,6 +4,7 @@
 
 use codec::{Codec, Decode, Encode};
 use composable_support::rpc_helpers::SafeRpcWrapper;
+use sp_runtime::RuntimeDebug;
 use sp_std::collections::btree_map::BTreeMap;
 
 // Staking Rewards Runtime API declaration. Implemented for each runtime at
@@ -19,6 +20,12 @@ sp_api::decl_runtime_apis! {
 			fnft_collection_id: SafeRpcWrapper<AssetId>,
 			fnft_instance_id: SafeRpcWrapper<FinancialNftInstanceId>,
 		) -> Result<BTreeMap<AssetId, Balance>, ClaimableAmountError>;
+
+		/// Current emission of a reward of a pool, `None` if either could not be found.
+		fn reward_emission(
+			pool_id: SafeRpcWrapper<AssetId>,
+			reward_asset_id: SafeRpcWrapper<AssetId>,
+		) -> Option<RewardEmission<Balance>>;
 	}
 }
 
@@ -29,3 +36,20 @@ pub enum ClaimableAmountError {
 	StakeNotFound,
 	RewardsPoolNotFound,
 }
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardEmission<Balance> {
+	/// Amount rewarded each period.
+	pub amount: Balance,
+	/// Length of a reward rate period, in seconds.
+	pub period: u64,
+	/// Rewards held in the pot of the pool, not emitted yet.
+	pub rewards_pot: Balance,
+	/// Rewards the emission schedule still has to emit, if any.
+	pub remaining_emission: Option<Balance>,
+	/// Block at which the emission schedule ends, if any.
+	pub end_block: Option<u64>,
+	/// Time of the next reward rate step-down of the emission schedule, if any.
+	pub next_step_down: Option<u64>,
+}
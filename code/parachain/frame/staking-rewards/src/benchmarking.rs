@@ -8,9 +8,10 @@ use composable_tests_helpers::test::helper::RuntimeTrait;
 use composable_traits::{
 	staking::{
 		lock::{DurationMultipliers, LockConfig},
-		RewardConfig,
+		vote_escrow::{GaugeEmission, GaugeEpoch, VotingPowerCheckpoint},
+		EmissionSchedule, ProtocolStaking, RewardConfig,
 		RewardPoolConfiguration::RewardRateBasedIncentive,
-		RewardRate, RewardUpdate,
+		RewardRate, RewardRateStepDown, RewardUpdate,
 	},
 	time::{ONE_HOUR, ONE_MINUTE},
 };
@@ -22,7 +23,7 @@ use frame_support::{
 use frame_system::{pallet_prelude::OriginFor, EventRecord};
 use sp_arithmetic::{fixed_point::FixedU64, traits::SaturatedConversion, Perbill, Permill};
 use sp_runtime::traits::{BlockNumberProvider, One};
-use sp_std::collections::btree_map::BTreeMap;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 use crate::test_helpers::stake_and_assert;
 
@@ -80,6 +81,83 @@ fn reward_config<T: Config>(
 		.unwrap()
 }
 
+/// Creates `count` reward pools rewarding `BASE_ASSET_ID` and registers them as gauges.
+fn create_gauges<T: Config>(count: u32) -> Vec<T::AssetId>
+where
+	T::AssetId: From<u128>,
+	T::BlockNumber: From<u32>,
+{
+	frame_system::Pallet::<T>::set_block_number(1.into());
+	let owner: T::AccountId = account("owner", 0, 0);
+
+	(0..count as u128)
+		.map(|index| {
+			let pool_id =
+				<Pallet<T> as ManageStaking>::create_staking_pool(RewardRateBasedIncentive {
+					owner: owner.clone(),
+					asset_id: (1_000 + index).into(),
+					start_block: 2_u128.saturated_into(),
+					reward_configs: reward_config::<T>(1),
+					lock: lock_config::<T>(),
+					share_asset_id: (2_000 + index).into(),
+					financial_nft_asset_id: (3_000 + index).into(),
+					minimum_staking_amount: 10_000_u128.into(),
+				})
+				.unwrap();
+			Gauges::<T>::mutate(|gauges| gauges.try_insert(pool_id)).unwrap();
+			pool_id
+		})
+		.collect()
+}
+
+fn gauge_emission<T: Config>() -> GaugeEmissionOf<T>
+where
+	T::AssetId: From<u128>,
+{
+	GaugeEmission {
+		reward_asset_id: BASE_ASSET_ID.into(),
+		reward_rate: RewardRate::per_second(10_000_u128),
+	}
+}
+
+/// Gives `who` voting power at the current block and starts a gauge epoch at it.
+fn set_voting_power<T: Config>(who: &T::AccountId) {
+	let checkpoint = VotingPowerCheckpoint {
+		bias: 1_000_000_u128.into(),
+		slope: 0_u128.into(),
+		timestamp: T::UnixTime::now().as_secs(),
+		block: frame_system::Pallet::<T>::block_number(),
+	};
+	VotingPowerCheckpoints::<T>::insert(who, 0, checkpoint);
+	VotingPowerCheckpointCount::<T>::insert(who, 1);
+	TotalVotingPowerCheckpoints::<T>::insert(0, checkpoint);
+	TotalVotingPowerCheckpointCount::<T>::put(1);
+	CurrentGaugeEpoch::<T>::put(GaugeEpoch { index: 0, start_block: checkpoint.block });
+}
+
+/// Stakes for `staker` in a pool with `reward_count` rewards, the first one in the staked asset.
+fn create_pool_and_stake<T: Config>(staker: &T::AccountId, reward_count: u32)
+where
+	T::AssetId: From<u128>,
+	T::Balance: From<u128>,
+	T::BlockNumber: From<u32>,
+{
+	let asset_id = BASE_ASSET_ID.into();
+	let amount: T::Balance = 100_500_u128.into();
+	let pool_owner: T::AccountId = account("owner", 0, 0);
+
+	frame_system::Pallet::<T>::set_block_number(1.into());
+	<Pallet<T>>::create_reward_pool(
+		OriginFor::<T>::root(),
+		get_reward_pool::<T>(pool_owner, reward_count),
+	)
+	.unwrap();
+	<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, staker, amount * 2.into()).unwrap();
+
+	frame_system::Pallet::<T>::set_block_number(2.into());
+	<Pallet<T>>::stake(OriginFor::<T>::signed(staker.clone()), asset_id, amount, ONE_HOUR).unwrap();
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -321,5 +399,177 @@ benchmarks! {
 
 	}: _(OriginFor::<T>::signed(user), pool_id,  asset_id, amount, true)
 
+	add_gauge {
+		frame_system::Pallet::<T>::set_block_number(1.into());
+		let user: T::AccountId = account("user", 0, 0);
+		let pool_id = <Pallet<T> as ManageStaking>::create_staking_pool(get_reward_pool::<T>(user, 1)).unwrap();
+	}: _(OriginFor::<T>::root(), pool_id)
+	verify {
+		assert_last_event::<T>(Event::GaugeAdded { pool_id }.into());
+	}
+
+	remove_gauge {
+		let pool_id = create_gauges::<T>(1)[0];
+	}: _(OriginFor::<T>::root(), pool_id)
+	verify {
+		assert_last_event::<T>(Event::GaugeRemoved { pool_id }.into());
+	}
+
+	set_gauge_emission {
+		let emission = gauge_emission::<T>();
+	}: _(OriginFor::<T>::root(), Some(emission.clone()))
+	verify {
+		assert_last_event::<T>(Event::GaugeEmissionSet { emission: Some(emission) }.into());
+	}
+
+	vote_for_gauges {
+		let g in 1 .. T::MaxGauges::get();
+		let pool_ids = create_gauges::<T>(g);
+		let voter: T::AccountId = whitelisted_caller();
+		set_voting_power::<T>(&voter);
+
+		// worst case: the votes already cast in this epoch are replaced
+		let previous_votes = pool_ids
+			.iter()
+			.map(|pool_id| (*pool_id, 1_u128.into()))
+			.try_collect()
+			.unwrap();
+		GaugeVotes::<T>::insert(&voter, (0, previous_votes));
+
+		let votes = pool_ids
+			.iter()
+			.map(|pool_id| (*pool_id, Permill::from_rational(1, g)))
+			.try_collect()
+			.unwrap();
+	}: _(OriginFor::<T>::signed(voter), votes)
+
+	apply_gauge_votes {
+		let g in 1 .. T::MaxGauges::get();
+		for pool_id in create_gauges::<T>(g) {
+			GaugeWeights::<T>::insert(0, pool_id, T::Balance::from(1_000_u128));
+		}
+		GaugeEmissions::<T>::put(gauge_emission::<T>());
+	}: {
+		crate::gauge::apply_gauge_votes::<T>(0)
+	}
+
+	set_auto_compound {
+		let staker: T::AccountId = whitelisted_caller();
+		create_pool_and_stake::<T>(&staker, 1);
+	}: _(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into(), true)
+	verify {
+		assert_last_event::<T>(Event::AutoCompoundSet { fnft_collection_id: STAKING_FNFT_COLLECTION_ID.into(), fnft_instance_id: FNFT_INSTANCE_ID_BASE.into(), enabled: true }.into());
+	}
+
+	compound {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let staker: T::AccountId = whitelisted_caller();
+		let keeper: T::AccountId = account("keeper", 0, 0);
+		let pool_id = BASE_ASSET_ID.into();
+		create_pool_and_stake::<T>(&staker, r);
+		<Pallet<T>>::set_auto_compound(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into(), true)?;
+
+		// accrue rewards in every reward asset of the pool
+		let rewards: T::Balance = 1_000_u128.into();
+		let pool = RewardPools::<T>::get(pool_id).unwrap();
+		for reward_asset_id in pool.rewards.keys() {
+			<T::Assets as Mutate<T::AccountId>>::mint_into(*reward_asset_id, &Pallet::<T>::pool_account_id(&pool_id), rewards)?;
+		}
+		RewardPools::<T>::mutate(pool_id, |pool| {
+			if let Some(pool) = pool {
+				for (_, reward) in pool.rewards.iter_mut() {
+					reward.total_rewards = reward.total_rewards + rewards;
+				}
+			}
+		});
+	}: _(OriginFor::<T>::signed(keeper), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into())
+
+	merge {
+		let r in 1 .. T::MaxRewardConfigsPerPool::get();
+		let asset_id = BASE_ASSET_ID.into();
+		let amount = 100_500_u128.into();
+		let staker: T::AccountId = whitelisted_caller();
+		create_pool_and_stake::<T>(&staker, r);
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &staker, amount)?;
+		<Pallet<T>>::stake(OriginFor::<T>::signed(staker.clone()), asset_id, amount, ONE_HOUR)?;
+	}: _(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into(), (FNFT_INSTANCE_ID_BASE + 1).into())
+	verify {
+		assert_last_event::<T>(Event::MergedPositions {
+			fnft_collection_id: STAKING_FNFT_COLLECTION_ID.into(),
+			fnft_instance_id: FNFT_INSTANCE_ID_BASE.into(),
+			merged_fnft_instance_id: (FNFT_INSTANCE_ID_BASE + 1).into(),
+			stake: amount * 2.into(),
+		}.into());
+	}
+
+	settle_slashes {
+		let staker: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		create_pool_and_stake::<T>(&staker, 1);
+		<Pallet<T> as ProtocolStaking>::slash(&BASE_ASSET_ID.into(), Perbill::from_percent(1), &beneficiary)?;
+	}: _(OriginFor::<T>::signed(beneficiary), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into())
+	verify {
+		assert_eq!(
+			SlashCheckpoints::<T>::get(T::AssetId::from(STAKING_FNFT_COLLECTION_ID), T::FinancialNftInstanceId::from(FNFT_INSTANCE_ID_BASE)),
+			PoolSlashFactors::<T>::get(T::AssetId::from(BASE_ASSET_ID)),
+		);
+	}
+
+	withdraw_slashed {
+		let pool_id = BASE_ASSET_ID.into();
+		let staker: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+		create_pool_and_stake::<T>(&staker, 1);
+		<Pallet<T> as ProtocolStaking>::slash(&pool_id, Perbill::from_percent(1), &beneficiary)?;
+		<Pallet<T>>::settle_slashes(OriginFor::<T>::signed(staker), STAKING_FNFT_COLLECTION_ID.into(), FNFT_INSTANCE_ID_BASE.into())?;
+		let owed = SlashPayouts::<T>::get(pool_id, &beneficiary);
+	}: _(OriginFor::<T>::signed(beneficiary.clone()), pool_id)
+	verify {
+		assert!(SlashPayouts::<T>::get(pool_id, &beneficiary) < owed);
+	}
+
+	set_emission_schedule {
+		frame_system::Pallet::<T>::set_block_number(1.into());
+
+		let asset_id = BASE_ASSET_ID.into();
+		let amount = 1_000_000_u128.into();
+
+		let user: T::AccountId = account("user", 0, 0);
+		let pool_id = <Pallet<T> as ManageStaking>::create_staking_pool(get_reward_pool::<T>(user.clone(), 1)).unwrap();
+		<T::Assets as Mutate<T::AccountId>>::mint_into(asset_id, &user, amount * 2.into())?;
+		<Pallet<T>>::add_to_rewards_pot(OriginFor::<T>::signed(user), pool_id, asset_id, amount, true)?;
+
+		let schedule = EmissionSchedule {
+			end_block: 100_u32.into(),
+			step_down: Some(RewardRateStepDown { periods: ONE_MINUTE, ratio: Perbill::from_percent(50) }),
+		};
+	}: _(OriginFor::<T>::root(), pool_id, asset_id, Some(schedule.clone()))
+	verify {
+		assert_eq!(EmissionSchedules::<T>::get(pool_id, asset_id), Some(schedule));
+	}
+
+	vote_escrow_advance {
+		let e in 1 .. T::MaxVoteEscrowEpochs::get();
+		let who: T::AccountId = whitelisted_caller();
+		let epoch = T::VoteEscrowEpoch::get();
+		// worst case: a lock expires at every boundary, the last one at the end of the walk
+		let checkpoint = VotingPowerCheckpoint {
+			bias: (u128::from(e) * u128::from(e + 1) * u128::from(epoch)).into(),
+			slope: (u128::from(e) * 2).into(),
+			timestamp: 0,
+			block: frame_system::Pallet::<T>::block_number(),
+		};
+		for boundary in 1..=e {
+			VotingPowerSlopeChanges::<T>::insert(&who, u64::from(boundary) * epoch, T::Balance::from(2_u128));
+		}
+		let timestamp = u64::from(e) * epoch;
+	}: {
+		let (bias, slope) = Pallet::<T>::advance(checkpoint, timestamp, |timestamp| {
+			VotingPowerSlopeChanges::<T>::get(&who, timestamp)
+		});
+		let zero: T::Balance = 0_u128.into();
+		assert_eq!((bias, slope), (zero, zero));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::test::new_test_ext(), crate::runtime::Test);
 }
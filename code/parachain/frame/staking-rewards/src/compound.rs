@@ -0,0 +1,192 @@
+//! Auto-compounding of staking positions.
+//!
+//! Positions whose pool rewards their staked asset can opt in to having those rewards added back
+//! to their stake. Opted-in positions are compounded by [`auto_compound_hook`] in the idle time of
+//! blocks, visiting at most [`Config::AutoCompoundBatchSize`] positions per block and compounding
+//! each at most once every [`Config::AutoCompoundPeriod`] blocks, or at any time by a keeper
+//! calling [`Pallet::compound`] in exchange for a [`Config::CompoundBounty`]. Compounding does
+//! not restart the lock of the position.
+
+use crate::{
+	prelude::*, AutoCompound, AutoCompoundCursor, Config, Error, Event, Pallet, RewardPools,
+	Stakes, WeightInfo,
+};
+use frame_support::{
+	traits::{fungibles::Transfer, tokens::nonfungibles::Inspect as NonFungiblesInspect},
+	transactional,
+};
+use sp_runtime::{traits::Saturating, PerThing};
+
+/// Compounds the next batch of auto-compounding positions that are due, within
+/// `remaining_weight`.
+pub(crate) fn auto_compound_hook<T: Config>(
+	now: T::BlockNumber,
+	remaining_weight: Weight,
+) -> Weight {
+	let batch_size = T::AutoCompoundBatchSize::get();
+	let compound_weight =
+		Pallet::<T>::compound_weight().saturating_add(T::DbWeight::get().writes(1));
+	let visit_weight = T::DbWeight::get().reads(1);
+
+	let mut weight = T::DbWeight::get().reads_writes(1, 1);
+	if !remaining_weight
+		.all_gte(weight.saturating_add(visit_weight).saturating_add(compound_weight))
+	{
+		return Weight::zero()
+	}
+
+	let mut positions = match AutoCompoundCursor::<T>::get() {
+		Some((fnft_collection_id, fnft_instance_id)) => AutoCompound::<T>::iter_from(
+			AutoCompound::<T>::hashed_key_for(fnft_collection_id, fnft_instance_id),
+		),
+		None => AutoCompound::<T>::iter(),
+	};
+	let mut last_visited = None;
+
+	for _ in 0..batch_size {
+		// every visited position is assumed to be compounded, the batch ends when that no longer
+		// fits in the remaining weight
+		if !remaining_weight
+			.all_gte(weight.saturating_add(visit_weight).saturating_add(compound_weight))
+		{
+			break
+		}
+
+		let Some((fnft_collection_id, fnft_instance_id, last_compounded)) = positions.next() else {
+			// start over from the first position once every position was visited
+			AutoCompoundCursor::<T>::kill();
+			return weight
+		};
+		weight = weight.saturating_add(visit_weight);
+		last_visited = Some((fnft_collection_id, fnft_instance_id));
+
+		if now < last_compounded.saturating_add(T::AutoCompoundPeriod::get()) {
+			continue
+		}
+
+		weight = weight.saturating_add(compound_weight);
+		if let Err(error) =
+			Pallet::<T>::compound_position(fnft_collection_id, fnft_instance_id, None)
+		{
+			Pallet::<T>::deposit_event(Event::<T>::AutoCompoundFailed {
+				fnft_collection_id,
+				fnft_instance_id,
+				error,
+			});
+			// wait for another period before retrying
+			AutoCompound::<T>::insert(fnft_collection_id, fnft_instance_id, now);
+		}
+	}
+
+	if let Some(cursor) = last_visited {
+		AutoCompoundCursor::<T>::put(cursor);
+	}
+
+	weight
+}
+
+impl<T: Config> Pallet<T> {
+	/// Worst case weight of compounding a position.
+	pub(crate) fn compound_weight() -> Weight {
+		T::WeightInfo::compound(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(4))
+	}
+
+	pub(crate) fn do_set_auto_compound(
+		fnft_collection_id: T::AssetId,
+		fnft_instance_id: T::FinancialNftInstanceId,
+		enabled: bool,
+	) -> DispatchResult {
+		if enabled {
+			let stake = Stakes::<T>::get(fnft_collection_id, fnft_instance_id)
+				.ok_or(Error::<T>::StakeNotFound)?;
+			let rewards_pool = RewardPools::<T>::get(stake.reward_pool_id)
+				.ok_or(Error::<T>::RewardsPoolNotFound)?;
+			ensure!(
+				rewards_pool.rewards.contains_key(&stake.reward_pool_id),
+				Error::<T>::AutoCompoundNotSupported
+			);
+
+			AutoCompound::<T>::insert(
+				fnft_collection_id,
+				fnft_instance_id,
+				frame_system::Pallet::<T>::block_number(),
+			);
+		} else {
+			AutoCompound::<T>::remove(fnft_collection_id, fnft_instance_id);
+		}
+
+		Self::deposit_event(Event::<T>::AutoCompoundSet {
+			fnft_collection_id,
+			fnft_instance_id,
+			enabled,
+		});
+
+		Ok(())
+	}
+
+	/// Claims the rewards of a position and adds those in its staked asset to the stake, minus
+	/// the bounty of `keeper`.
+	#[transactional]
+	pub(crate) fn compound_position(
+		fnft_collection_id: T::AssetId,
+		fnft_instance_id: T::FinancialNftInstanceId,
+		keeper: Option<T::AccountId>,
+	) -> DispatchResult {
+		let owner = T::FinancialNft::owner(&fnft_collection_id, &fnft_instance_id)
+			.ok_or(Error::<T>::FnftNotFound)?;
+		Self::settle_position(&fnft_collection_id, &fnft_instance_id)?;
+
+		let (staked_asset_id, claimed_amounts) =
+			Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |stake| {
+				let stake = stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+				RewardPools::<T>::try_mutate(stake.reward_pool_id, |rewards_pool| {
+					let rewards_pool =
+						rewards_pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
+					let claimed_amounts = Self::collect_rewards(rewards_pool, stake, &owner)?;
+
+					Ok::<_, DispatchError>((stake.reward_pool_id, claimed_amounts))
+				})
+			})?;
+
+		let rewards = claimed_amounts.get(&staked_asset_id).copied().unwrap_or_else(Zero::zero);
+		ensure!(!rewards.is_zero(), Error::<T>::NothingToCompound);
+
+		Self::deposit_event(Event::<T>::Claimed {
+			owner: owner.clone(),
+			fnft_collection_id,
+			fnft_instance_id,
+			claimed_amounts,
+		});
+
+		let bounty = match &keeper {
+			Some(keeper) => {
+				let bounty = T::CompoundBounty::get().mul_floor(rewards);
+				if !bounty.is_zero() {
+					T::Assets::transfer(staked_asset_id, &owner, keeper, bounty, true)?;
+				}
+				bounty
+			},
+			None => Zero::zero(),
+		};
+		let amount = rewards.saturating_sub(bounty);
+
+		Self::add_to_stake(&owner, (fnft_collection_id, fnft_instance_id), amount, true, false)?;
+		AutoCompound::<T>::mutate_exists(fnft_collection_id, fnft_instance_id, |last_compounded| {
+			if let Some(last_compounded) = last_compounded {
+				*last_compounded = frame_system::Pallet::<T>::block_number();
+			}
+		});
+
+		Self::deposit_event(Event::<T>::Compounded {
+			fnft_collection_id,
+			fnft_instance_id,
+			amount,
+			keeper,
+			bounty,
+		});
+
+		Ok(())
+	}
+}
@@ -0,0 +1,272 @@
+//! Emission schedules of rewards.
+//!
+//! Without a schedule, a reward is emitted at its reward rate for as long as its pot is funded.
+//! An [`EmissionSchedule`] stops the emission at its end block, and can step the reward rate down
+//! every few periods, halving it for example. The pot must cover the whole remaining emission when
+//! the schedule is set and when the reward rate is updated. Since the schedule ends at a block,
+//! the remaining emission is estimated assuming a block every [`Config::ExpectedBlockTime`].
+
+use crate::{
+	accumulate_reward_and_report, prelude::*, Config, EmissionScheduleOf, EmissionSchedules, Error,
+	Event, NextRateStepDown, Pallet, RewardPools,
+};
+use composable_traits::time::{DurationSeconds, Timestamp};
+use frame_support::traits::{fungibles::InspectHold, UnixTime};
+use runtime_api::RewardEmission;
+use sp_arithmetic::fixed_point::{FixedPointNumber, FixedU128};
+use sp_runtime::{
+	traits::{CheckedDiv, Saturating},
+	PerThing, SaturatedConversion,
+};
+use sp_std::cmp;
+
+/// Duration of a reward rate step-down, in seconds.
+fn step_down_duration<T: Config>(
+	step_down: &RewardRateStepDown,
+	reward_rate: &RewardRate<T::Balance>,
+) -> Result<DurationSeconds, Error<T>> {
+	let duration = step_down.periods.saturating_mul(reward_rate.period.as_secs().get());
+	ensure!(
+		duration >= T::ExpectedBlockTime::get().max(1) && !step_down.ratio.is_one(),
+		Error::<T>::InvalidRewardRateStepDown
+	);
+
+	Ok(duration)
+}
+
+/// Rewards emitted over `periods` reward rate periods at `amount` per period, stepped down after
+/// the given number of periods and then every `step_down.periods`.
+///
+/// Step-downs round the reward rate down, this is an upper bound of the actual emission.
+pub(crate) fn scheduled_emission(
+	amount: u128,
+	periods: u64,
+	step_down: Option<(u64, &RewardRateStepDown)>,
+) -> u128 {
+	let (first_step_down, step_down) = match step_down {
+		Some((first_step_down, step_down)) if first_step_down < periods =>
+			(first_step_down, step_down),
+		_ => return amount.saturating_mul(periods.into()),
+	};
+
+	let stepped_periods = periods.saturating_sub(first_step_down);
+	let steps = stepped_periods / step_down.periods.max(1);
+	let remaining_periods = stepped_periods % step_down.periods.max(1);
+
+	// every step-down is followed by `step_down.periods` periods at the reduced rate, except the
+	// last one which is followed by the remaining periods:
+	//
+	//   ratio + ... + ratio^steps = ratio * (1 - ratio^steps) / (1 - ratio)
+	let ratio = FixedU128::from(step_down.ratio);
+	let steps_exponent = steps.saturated_into::<usize>();
+	let stepped_rates = if ratio == FixedU128::one() {
+		FixedU128::saturating_from_integer(steps)
+	} else {
+		ratio
+			.saturating_mul(FixedU128::one().saturating_sub(ratio.saturating_pow(steps_exponent)))
+			.checked_div(&FixedU128::one().saturating_sub(ratio))
+			.unwrap_or(FixedU128::from_inner(u128::MAX))
+	};
+	let last_rate = ratio.saturating_pow(steps_exponent.saturating_add(1));
+
+	amount
+		.saturating_mul(first_step_down.into())
+		.saturating_add(
+			stepped_rates.saturating_mul_int(amount.saturating_mul(step_down.periods.into())),
+		)
+		.saturating_add(
+			last_rate.saturating_mul_int(amount.saturating_mul(remaining_periods.into())),
+		)
+}
+
+/// Rewards a scheduled emission still has to emit, including the ones accumulated since the
+/// last accumulation of the reward.
+pub(crate) fn remaining_emission<T: Config>(
+	start_block: T::BlockNumber,
+	reward: &Reward<T::Balance>,
+	schedule: &EmissionScheduleOf<T>,
+	next_step_down: Option<Timestamp>,
+) -> u128 {
+	let now_seconds = T::UnixTime::now().as_secs();
+	let current_block = frame_system::Pallet::<T>::block_number();
+	let expected_time_of = |block: T::BlockNumber| {
+		now_seconds.saturating_add(
+			block
+				.saturating_sub(current_block)
+				.saturated_into::<u64>()
+				.saturating_mul(T::ExpectedBlockTime::get()),
+		)
+	};
+
+	// rewards of pools that have not started are accumulated from their start
+	let from = if start_block > current_block {
+		expected_time_of(start_block)
+	} else {
+		reward.last_updated_timestamp
+	};
+	let period = reward.reward_rate.period.as_secs();
+	let periods = expected_time_of(schedule.end_block).saturating_sub(from) / period;
+	let step_down = schedule.step_down.as_ref().zip(next_step_down).map(|(step_down, at)| {
+		let first_step_down = at.saturating_sub(from).saturating_add(period.get() - 1) / period;
+		(first_step_down, step_down)
+	});
+
+	scheduled_emission(reward.reward_rate.amount.into(), periods, step_down)
+}
+
+/// Ensures that the rewards pot covers the remaining emission of a schedule.
+pub(crate) fn ensure_emission_funded<T: Config>(
+	pool_id: T::AssetId,
+	asset_id: T::AssetId,
+	start_block: T::BlockNumber,
+	reward: &Reward<T::Balance>,
+	schedule: &EmissionScheduleOf<T>,
+	next_step_down: Option<Timestamp>,
+) -> DispatchResult {
+	let rewards_pot: u128 =
+		T::Assets::balance_on_hold(asset_id, &Pallet::<T>::pool_account_id(&pool_id)).into();
+	ensure!(
+		remaining_emission::<T>(start_block, reward, schedule, next_step_down) <= rewards_pot,
+		Error::<T>::EmissionNotFunded
+	);
+
+	Ok(())
+}
+
+/// Applies the reward rate step-downs due since the last accumulation, accumulating the rewards
+/// up to each of them at the rate in effect before.
+pub(crate) fn step_down_reward_rate<T: Config>(
+	pool_id: T::AssetId,
+	asset_id: T::AssetId,
+	reward: &mut Reward<T::Balance>,
+	step_down: &RewardRateStepDown,
+	unstaked_shares: T::Balance,
+	total_shares: T::Balance,
+	now_seconds: u64,
+) {
+	let Some(mut next_step_down) = NextRateStepDown::<T>::get(pool_id, asset_id) else {
+		return
+	};
+	if next_step_down > now_seconds {
+		return
+	}
+
+	// step-downs are at least a block apart, this usually runs once
+	let duration = step_down
+		.periods
+		.saturating_mul(reward.reward_rate.period.as_secs().get())
+		.max(1);
+	while next_step_down <= now_seconds {
+		if next_step_down > reward.last_updated_timestamp {
+			accumulate_reward_and_report::<T>(
+				pool_id,
+				asset_id,
+				reward,
+				unstaked_shares,
+				total_shares,
+				next_step_down,
+			);
+		}
+		reward.reward_rate.amount = step_down.ratio.mul_floor(reward.reward_rate.amount);
+		next_step_down = next_step_down.saturating_add(duration);
+	}
+
+	NextRateStepDown::<T>::insert(pool_id, asset_id, next_step_down);
+
+	Pallet::<T>::deposit_event(Event::<T>::RewardRateSteppedDown {
+		pool_id,
+		asset_id,
+		amount: reward.reward_rate.amount,
+	});
+}
+
+/// Stops the emission of a reward once the end block of its schedule is reached.
+pub(crate) fn end_emission_if_due<T: Config>(
+	pool_id: T::AssetId,
+	asset_id: T::AssetId,
+	reward: &mut Reward<T::Balance>,
+	schedule: &EmissionScheduleOf<T>,
+) {
+	if frame_system::Pallet::<T>::block_number() < schedule.end_block {
+		return
+	}
+
+	reward.reward_rate.amount = Zero::zero();
+	EmissionSchedules::<T>::remove(pool_id, asset_id);
+	NextRateStepDown::<T>::remove(pool_id, asset_id);
+
+	Pallet::<T>::deposit_event(Event::<T>::RewardEmissionEnded { pool_id, asset_id });
+}
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_set_emission_schedule(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		schedule: Option<EmissionScheduleOf<T>>,
+	) -> DispatchResult {
+		let pool = RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardsPoolNotFound)?;
+		let reward = pool.rewards.get(&asset_id).ok_or(Error::<T>::RewardAssetNotFound)?;
+
+		match &schedule {
+			Some(schedule) => {
+				let current_block = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					schedule.end_block > cmp::max(current_block, pool.start_block),
+					Error::<T>::InvalidEmissionEndBlock
+				);
+
+				let next_step_down = schedule
+					.step_down
+					.as_ref()
+					.map(|step_down| {
+						step_down_duration::<T>(step_down, &reward.reward_rate)
+							.map(|duration| T::UnixTime::now().as_secs().saturating_add(duration))
+					})
+					.transpose()?;
+
+				ensure_emission_funded::<T>(
+					pool_id,
+					asset_id,
+					pool.start_block,
+					reward,
+					schedule,
+					next_step_down,
+				)?;
+
+				EmissionSchedules::<T>::insert(pool_id, asset_id, schedule);
+				NextRateStepDown::<T>::set(pool_id, asset_id, next_step_down);
+			},
+			None => {
+				EmissionSchedules::<T>::remove(pool_id, asset_id);
+				NextRateStepDown::<T>::remove(pool_id, asset_id);
+			},
+		}
+
+		Self::deposit_event(Event::<T>::EmissionScheduleSet { pool_id, asset_id, schedule });
+
+		Ok(())
+	}
+
+	/// The current emission of a reward, and the rewards its schedule still has to emit if any.
+	/// Returns `None` if the pool or reward could not be found.
+	pub fn reward_emission(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+	) -> Option<RewardEmission<T::Balance>> {
+		let pool = RewardPools::<T>::get(pool_id)?;
+		let reward = pool.rewards.get(&asset_id)?;
+		let schedule = EmissionSchedules::<T>::get(pool_id, asset_id);
+		let next_step_down = NextRateStepDown::<T>::get(pool_id, asset_id);
+
+		Some(RewardEmission {
+			amount: reward.reward_rate.amount,
+			period: reward.reward_rate.period.as_secs().get(),
+			rewards_pot: T::Assets::balance_on_hold(asset_id, &Self::pool_account_id(&pool_id)),
+			remaining_emission: schedule.as_ref().map(|schedule| {
+				remaining_emission::<T>(pool.start_block, reward, schedule, next_step_down).into()
+			}),
+			end_block: schedule.map(|schedule| schedule.end_block.saturated_into()),
+			next_step_down,
+		})
+	}
+}
@@ -0,0 +1,210 @@
+//! Gauge voting on the emissions of reward pools.
+//!
+//! During every [`Config::GaugeEpoch`], accounts split the voting power they held at the start of
+//! the epoch across the reward pools registered as [`Gauges`]. When the next epoch starts, the
+//! [`GaugeEmissions`] reward rate is divided between the gauges in proportion to the weight they
+//! received and set as their new reward rate. Reward rates that fail to apply are kept in
+//! [`PendingGaugeUpdates`] and retried every block.
+
+use crate::{
+	prelude::*, update_rewards_pool, AccountIdOf, AssetIdOf, BalanceOf, Config, CurrentGaugeEpoch,
+	Error, Event, GaugeEmissions, GaugeVotes, GaugeWeights, Gauges, Pallet, PendingGaugeUpdates,
+	WeightInfo,
+};
+use composable_traits::staking::vote_escrow::{GaugeEpoch, VotingPower};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{TryCollect, UnixTime},
+	BoundedBTreeMap,
+};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, traits::Saturating, PerThing, Permill,
+	Rounding,
+};
+use sp_std::vec::Vec;
+
+/// Starts a new gauge epoch once the current one is over, applying its votes to the gauges.
+pub(crate) fn gauge_epoch_hook<T: Config>() -> Weight {
+	let index = T::UnixTime::now().as_secs() / T::GaugeEpoch::get().max(1);
+	let mut weight = T::WeightInfo::unix_time_now()
+		.saturating_add(T::DbWeight::get().reads(1))
+		.saturating_add(retry_pending_gauge_updates::<T>());
+
+	let previous = CurrentGaugeEpoch::<T>::get();
+	if matches!(previous, Some(epoch) if epoch.index >= index) {
+		return weight
+	}
+
+	if let Some(previous) = previous {
+		weight = weight.saturating_add(apply_gauge_votes::<T>(previous.index));
+	}
+
+	CurrentGaugeEpoch::<T>::put(GaugeEpoch {
+		index,
+		start_block: frame_system::Pallet::<T>::block_number(),
+	});
+	Pallet::<T>::deposit_event(Event::<T>::GaugeEpochStarted { epoch: index });
+
+	weight.saturating_add(T::DbWeight::get().writes(1))
+}
+
+/// Splits the gauge emission across the gauges according to the votes of `epoch`. Reward rates
+/// are left untouched if no emission is configured or no votes were cast.
+pub(crate) fn apply_gauge_votes<T: Config>(epoch: u64) -> Weight {
+	let gauges = Gauges::<T>::get();
+	let weight = T::WeightInfo::apply_gauge_votes(gauges.len() as u32);
+
+	let emission = match GaugeEmissions::<T>::get() {
+		Some(emission) => emission,
+		None => return weight,
+	};
+
+	let gauge_weights = gauges
+		.into_iter()
+		.map(|pool_id| (pool_id, GaugeWeights::<T>::get(epoch, pool_id).into()))
+		.collect::<Vec<(T::AssetId, u128)>>();
+	let total_weight = gauge_weights
+		.iter()
+		.fold(0_u128, |total, (_, gauge_weight)| total.saturating_add(*gauge_weight));
+
+	if total_weight > 0 {
+		for (pool_id, gauge_weight) in gauge_weights {
+			let amount = multiply_by_rational_with_rounding(
+				emission.reward_rate.amount.into(),
+				gauge_weight,
+				total_weight,
+				Rounding::Down,
+			)
+			.unwrap_or_default();
+
+			let reward_updates = [(
+				emission.reward_asset_id,
+				RewardUpdate {
+					reward_rate: RewardRate {
+						period: emission.reward_rate.period.clone(),
+						amount: amount.into(),
+					},
+				},
+			)]
+			.into_iter()
+			.try_collect();
+
+			let reward_updates = match reward_updates {
+				Ok(reward_updates) => reward_updates,
+				Err(_) => {
+					Pallet::<T>::deposit_event(Event::<T>::GaugeUpdateFailed {
+						pool_id,
+						epoch,
+						error: Error::<T>::TooManyRewardAssetTypes.into(),
+					});
+					continue
+				},
+			};
+
+			match update_gauge::<T>(pool_id, reward_updates.clone()) {
+				Ok(()) => PendingGaugeUpdates::<T>::remove(pool_id),
+				Err(error) => {
+					// a newer epoch replaces the update of an older one
+					PendingGaugeUpdates::<T>::insert(pool_id, reward_updates);
+					Pallet::<T>::deposit_event(Event::<T>::GaugeUpdateFailed {
+						pool_id,
+						epoch,
+						error,
+					});
+				},
+			}
+		}
+	}
+
+	let _ = GaugeWeights::<T>::clear_prefix(epoch, T::MaxGauges::get(), None);
+
+	weight
+}
+
+/// Updates the reward rate of a gauge, rolling back any change if it fails.
+fn update_gauge<T: Config>(
+	pool_id: T::AssetId,
+	reward_updates: BoundedBTreeMap<
+		AssetIdOf<T>,
+		RewardUpdate<BalanceOf<T>>,
+		T::MaxRewardConfigsPerPool,
+	>,
+) -> DispatchResult {
+	with_transaction(|| match update_rewards_pool::<T>(pool_id, reward_updates) {
+		Ok(()) => TransactionOutcome::Commit(Ok(())),
+		Err(error) => TransactionOutcome::Rollback(Err(error)),
+	})
+}
+
+/// Retries the gauge updates that failed to apply, dropping the ones that succeed.
+///
+/// There is at most one pending update per gauge, bounding the retries by [`Config::MaxGauges`].
+fn retry_pending_gauge_updates<T: Config>() -> Weight {
+	let mut weight = T::DbWeight::get().reads(1);
+
+	for (pool_id, reward_updates) in PendingGaugeUpdates::<T>::iter() {
+		weight = weight
+			.saturating_add(T::WeightInfo::update_rewards_pool(reward_updates.len() as u32))
+			.saturating_add(T::DbWeight::get().reads(1));
+
+		if update_gauge::<T>(pool_id, reward_updates).is_ok() {
+			PendingGaugeUpdates::<T>::remove(pool_id);
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+		}
+	}
+
+	weight
+}
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_vote_for_gauges(
+		who: &AccountIdOf<T>,
+		votes: BoundedBTreeMap<T::AssetId, Permill, T::MaxGauges>,
+	) -> DispatchResult {
+		let epoch = CurrentGaugeEpoch::<T>::get().ok_or(Error::<T>::GaugeEpochNotStarted)?;
+
+		let gauges = Gauges::<T>::get();
+		ensure!(votes.keys().all(|pool_id| gauges.contains(pool_id)), Error::<T>::GaugeNotFound);
+		let total_parts = votes
+			.values()
+			.try_fold(0_u32, |total, vote| total.checked_add(vote.deconstruct()))
+			.filter(|total| *total <= Permill::ACCURACY);
+		ensure!(total_parts.is_some(), Error::<T>::GaugeVotesExceedVotingPower);
+
+		let voting_power: BalanceOf<T> =
+			<Self as VotingPower>::voting_power_at(who, epoch.start_block)?;
+		ensure!(!voting_power.is_zero(), Error::<T>::NoVotingPower);
+
+		// votes cast earlier in this epoch are replaced
+		if let Some((previous_epoch, previous_votes)) = GaugeVotes::<T>::take(who) {
+			if previous_epoch == epoch.index {
+				for (pool_id, gauge_weight) in previous_votes {
+					GaugeWeights::<T>::mutate(epoch.index, pool_id, |total| {
+						*total = total.saturating_sub(gauge_weight)
+					});
+				}
+			}
+		}
+
+		let gauge_votes = votes
+			.into_iter()
+			.map(|(pool_id, vote)| (pool_id, vote.mul_floor(voting_power)))
+			.try_collect::<BoundedBTreeMap<_, _, T::MaxGauges>>()
+			.map_err(|_| Error::<T>::TooManyGauges)?;
+
+		for (pool_id, gauge_weight) in &gauge_votes {
+			GaugeWeights::<T>::mutate(epoch.index, pool_id, |total| {
+				*total = total.saturating_add(*gauge_weight)
+			});
+		}
+		GaugeVotes::<T>::insert(who, (epoch.index, gauge_votes.clone()));
+
+		Self::deposit_event(Event::<T>::GaugeVoted {
+			who: who.clone(),
+			epoch: epoch.index,
+			votes: gauge_votes.into_inner(),
+		});
+
+		Ok(())
+	}
+}
@@ -51,8 +51,14 @@ mod test;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 pub(crate) mod test_helpers;
 
+mod compound;
+mod emission;
+mod gauge;
+mod slashing;
 mod validation;
+mod vote_escrow;
 
+pub mod migrations;
 pub mod prelude;
 pub mod weights;
 
@@ -93,8 +99,13 @@ pub mod pallet {
 	use composable_traits::{
 		currency::{BalanceLike, CurrencyFactory},
 		fnft::{FinancialNft, FinancialNftProtocol},
-		staking::{RewardPoolConfiguration::RewardRateBasedIncentive, RewardRatePeriod},
-		time::DurationSeconds,
+		staking::{
+			lock::DurationMultipliers,
+			vote_escrow::{GaugeEmission, GaugeEpoch, VotingPowerCheckpoint},
+			RewardPoolConfiguration::RewardRateBasedIncentive,
+			RewardRatePeriod,
+		},
+		time::{DurationSeconds, Timestamp},
 	};
 	use frame_support::{
 		defensive,
@@ -111,25 +122,25 @@ pub mod pallet {
 				},
 				WithdrawConsequence,
 			},
-			Defensive, DefensiveSaturating, TryCollect, UnixTime,
+			Defensive, DefensiveSaturating, StorageVersion, TryCollect, UnixTime,
 		},
-		transactional, BoundedBTreeMap, PalletId,
+		transactional, BoundedBTreeMap, BoundedBTreeSet, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use orml_traits::{GetByKey, LockIdentifier, MultiLockableCurrency};
 	use sp_arithmetic::{
-		fixed_point::{FixedPointNumber, FixedU64},
-		Permill,
+		fixed_point::{FixedPointNumber, FixedU128, FixedU64},
+		Perbill, Permill,
 	};
 	use sp_runtime::{
-		traits::{AccountIdConversion, BlockNumberProvider, One},
+		traits::{AccountIdConversion, BlockNumberProvider, One, Saturating},
 		ArithmeticError, PerThing,
 	};
 	use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, ops::Mul, vec, vec::Vec};
 
 	use crate::{
-		accumulate_rewards_hook, add_to_rewards_pot, claim_of_stake, prelude::*,
-		update_rewards_pool, validation::ValidSplitRatio,
+		accumulate_rewards_hook, add_to_rewards_pot, claim_of_stake, compound::auto_compound_hook,
+		gauge::gauge_epoch_hook, prelude::*, update_rewards_pool, validation::ValidSplitRatio,
 	};
 
 	#[pallet::event]
@@ -197,6 +208,14 @@ pub mod pallet {
 		SplitPosition {
 			positions: Vec<(T::AssetId, T::FinancialNftInstanceId, BalanceOf<T>)>,
 		},
+		/// A staking position was merged into another one, burning its fNFT.
+		MergedPositions {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			merged_fnft_instance_id: T::FinancialNftInstanceId,
+			/// Staked amount of the resulting position.
+			stake: BalanceOf<T>,
+		},
 		/// Reward transfer event.
 		RewardTransferred {
 			from: T::AccountId,
@@ -228,6 +247,100 @@ pub mod pallet {
 			pool_id: T::AssetId,
 			asset_id: T::AssetId,
 		},
+		/// A reward pool was added to the gauges.
+		GaugeAdded {
+			pool_id: T::AssetId,
+		},
+		/// A reward pool was removed from the gauges.
+		GaugeRemoved {
+			pool_id: T::AssetId,
+		},
+		/// The emission split across gauges was updated.
+		GaugeEmissionSet {
+			emission: Option<GaugeEmissionOf<T>>,
+		},
+		/// Votes were cast on gauges for the current epoch.
+		GaugeVoted {
+			who: T::AccountId,
+			epoch: u64,
+			/// Weight given to each gauge.
+			votes: BTreeMap<T::AssetId, T::Balance>,
+		},
+		/// A new gauge epoch started, after applying the votes of the previous one.
+		GaugeEpochStarted {
+			epoch: u64,
+		},
+		/// The votes of an epoch could not be applied to a gauge. The update is retried every
+		/// block until it succeeds.
+		GaugeUpdateFailed {
+			pool_id: T::AssetId,
+			epoch: u64,
+			error: DispatchError,
+		},
+		/// Auto-compounding was turned on or off for a position.
+		AutoCompoundSet {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			enabled: bool,
+		},
+		/// The rewards of a position in its staked asset were added to its stake.
+		Compounded {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			/// Amount added to the stake.
+			amount: T::Balance,
+			/// Keeper paid for compounding the position, if any.
+			keeper: Option<T::AccountId>,
+			/// Part of the rewards paid to the keeper.
+			bounty: T::Balance,
+		},
+		/// An auto-compounding position could not be compounded. It is retried after another
+		/// `AutoCompoundPeriod`.
+		AutoCompoundFailed {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			error: DispatchError,
+		},
+		/// A slash of every position in a pool was recorded. Positions pay their part when they
+		/// are settled.
+		PoolSlashed {
+			pool_id: T::AssetId,
+			ratio: Perbill,
+			beneficiary: T::AccountId,
+			/// Stake owed to the beneficiary.
+			stake: T::Balance,
+		},
+		/// The slashes of its pool recorded since a position was last settled were applied to it.
+		PositionSlashed {
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			/// Stake paid to the slash account of the pool.
+			stake: T::Balance,
+		},
+		/// A beneficiary withdrew slashed stake owed to it by a pool.
+		SlashWithdrawn {
+			pool_id: T::AssetId,
+			beneficiary: T::AccountId,
+			amount: T::Balance,
+		},
+		/// The emission schedule of a reward was set, or removed if `None`.
+		EmissionScheduleSet {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			schedule: Option<EmissionScheduleOf<T>>,
+		},
+		/// The reward rate of a scheduled emission was reduced.
+		RewardRateSteppedDown {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			/// Amount rewarded each period from now on.
+			amount: T::Balance,
+		},
+		/// The end block of a scheduled emission was reached, the reward is no longer emitted.
+		RewardEmissionEnded {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+		},
 	}
 
 	#[derive(Copy, Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
@@ -240,12 +353,18 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Error when creating reward configs.
 		RewardConfigProblem,
+		/// A lock duration of the voting power pool spans more than
+		/// [`MaxVoteEscrowEpochs`](Config::MaxVoteEscrowEpochs).
+		VoteEscrowLockTooLong,
 		/// AssetId is invalid, asset IDs must be greater than 0
 		InvalidAssetId,
 		/// Reward pool already exists
 		RewardsPoolAlreadyExists,
 		/// The duration provided was not valid for the pool.
 		DurationPresetNotFound,
+		/// The duration provided is outside of the range of durations of a pool with continuous
+		/// lock duration multipliers.
+		LockDurationOutOfRange,
 		/// Too many rewarded asset types per pool violating the storage allowed.
 		TooManyRewardAssetTypes,
 		/// Invalid start block number provided for creating a pool.
@@ -286,6 +405,48 @@ pub mod pallet {
 		StakedAmountTooLowAfterSplit,
 		/// Some operation resulted in an arithmetic overflow.
 		ArithmeticError,
+		/// Voting power was queried for a block that has not been produced yet.
+		BlockNumberInFuture,
+		/// The reward pool is already a gauge.
+		GaugeAlreadyExists,
+		/// The reward pool is not a gauge.
+		GaugeNotFound,
+		/// Too many gauges violating the storage allowed.
+		TooManyGauges,
+		/// Gauge votes add up to more than the whole voting power.
+		GaugeVotesExceedVotingPower,
+		/// No voting power at the start of the current gauge epoch.
+		NoVotingPower,
+		/// Gauge voting has not started yet.
+		GaugeEpochNotStarted,
+		/// The pool of the position does not reward its staked asset.
+		AutoCompoundNotSupported,
+		/// The position has not opted in to auto-compounding.
+		AutoCompoundNotEnabled,
+		/// The position has no rewards in its staked asset to compound.
+		NothingToCompound,
+		/// Only positions of the same pool can be merged.
+		PositionsNotInSamePool,
+		/// A position cannot be merged with itself.
+		CannotMergePositionWithItself,
+		/// Slash ratio must be greater than zero and less than one.
+		InvalidSlashRatio,
+		/// The stake left to the positions of the pool after its slashes is too small to track.
+		TooManySlashes,
+		/// No slash of the pool is pending for the position.
+		NoSlashesToSettle,
+		/// The pool owes no slashed stake to the caller.
+		NoSlashedStakeOwed,
+		/// None of the stake owed to the caller has been paid by settled positions yet.
+		SlashedStakeNotSettled,
+		/// Emission end block must be after the current block and the start block of the pool.
+		InvalidEmissionEndBlock,
+		/// Reward rate step-downs must be at least a block apart and reduce the reward rate.
+		InvalidRewardRateStepDown,
+		/// The rewards pot does not cover the remaining scheduled emission.
+		EmissionNotFunded,
+		/// The pool does not stake the asset.
+		StakedAssetMismatch,
 	}
 
 	pub(crate) type AssetIdOf<T> = <T as Config>::AssetId;
@@ -394,6 +555,47 @@ pub mod pallet {
 		type TreasuryAccount: Get<Self::AccountId>;
 
 		type ExistentialDeposits: GetByKey<Self::AssetId, Self::Balance>;
+
+		/// The pool whose positions grant vote-escrowed voting power to their owners.
+		#[pallet::constant]
+		type VotingPowerPoolId: Get<Self::AssetId>;
+
+		/// Granularity of vote-escrow lock expiries. The voting power of a position reaches zero
+		/// at the last multiple of this duration before the end of its lock.
+		#[pallet::constant]
+		type VoteEscrowEpoch: Get<DurationSeconds>;
+
+		/// Maximum number of vote-escrow epochs a lock in the
+		/// [`VotingPowerPoolId`](Config::VotingPowerPoolId) pool may span. Bounds the epochs
+		/// walked when voting power is checkpointed.
+		#[pallet::constant]
+		type MaxVoteEscrowEpochs: Get<u32>;
+
+		/// Maximum number of reward pools receiving emissions through gauge votes.
+		#[pallet::constant]
+		type MaxGauges: Get<u32>;
+
+		/// Length of a gauge voting epoch. Reward rates of gauges are updated at the start of
+		/// every epoch from the votes cast during the previous one.
+		#[pallet::constant]
+		type GaugeEpoch: Get<DurationSeconds>;
+
+		/// Maximum number of auto-compounding positions visited in the idle time of a block.
+		#[pallet::constant]
+		type AutoCompoundBatchSize: Get<u32>;
+
+		/// Minimum number of blocks between two automatic compounds of a position.
+		#[pallet::constant]
+		type AutoCompoundPeriod: Get<Self::BlockNumber>;
+
+		/// Part of the compounded rewards paid to the keeper calling `compound`.
+		#[pallet::constant]
+		type CompoundBounty: Get<Perbill>;
+
+		/// Expected time between two blocks, used to convert the end block of emission schedules
+		/// into the rewards they still have to emit.
+		#[pallet::constant]
+		type ExpectedBlockTime: Get<DurationSeconds>;
 	}
 
 	/// Abstraction over RewardPoolConfiguration type
@@ -424,8 +626,22 @@ pub mod pallet {
 		<T as Config>::MaxRewardConfigsPerPool,
 	>;
 
+	/// Abstraction over EmissionSchedule type
+	pub(crate) type EmissionScheduleOf<T> =
+		EmissionSchedule<<T as frame_system::Config>::BlockNumber>;
+
+	/// Abstraction over GaugeEmission type
+	pub(crate) type GaugeEmissionOf<T> = GaugeEmission<AssetIdOf<T>, BalanceOf<T>>;
+
+	/// Abstraction over VotingPowerCheckpoint type
+	pub(crate) type VotingPowerCheckpointOf<T> =
+		VotingPowerCheckpoint<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub (super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -450,11 +666,197 @@ pub mod pallet {
 	pub(super) type RewardsPotIsEmpty<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, ()>;
 
+	/// Number of voting power checkpoints written for an account.
+	#[pallet::storage]
+	pub type VotingPowerCheckpointCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Voting power checkpoints of an account, indexed in increasing block order.
+	#[pallet::storage]
+	pub type VotingPowerCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		VotingPowerCheckpointOf<T>,
+	>;
+
+	/// Slope removed from an account's voting power at an epoch boundary, as the locks ending
+	/// there expire.
+	#[pallet::storage]
+	pub type VotingPowerSlopeChanges<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		Timestamp,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Number of total voting power checkpoints.
+	#[pallet::storage]
+	pub type TotalVotingPowerCheckpointCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Checkpoints of the total voting power, indexed in increasing block order.
+	#[pallet::storage]
+	pub type TotalVotingPowerCheckpoints<T: Config> =
+		StorageMap<_, Twox64Concat, u32, VotingPowerCheckpointOf<T>>;
+
+	/// Slope removed from the total voting power at an epoch boundary.
+	#[pallet::storage]
+	pub type TotalVotingPowerSlopeChanges<T: Config> =
+		StorageMap<_, Twox64Concat, Timestamp, BalanceOf<T>, ValueQuery>;
+
+	/// Reward pools whose reward rate is set by gauge votes.
+	#[pallet::storage]
+	#[pallet::getter(fn gauges)]
+	pub type Gauges<T: Config> =
+		StorageValue<_, BoundedBTreeSet<T::AssetId, T::MaxGauges>, ValueQuery>;
+
+	/// Emission split across gauges at the start of every epoch.
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_emission)]
+	pub type GaugeEmissions<T: Config> = StorageValue<_, GaugeEmissionOf<T>>;
+
+	/// The gauge epoch currently accepting votes.
+	#[pallet::storage]
+	#[pallet::getter(fn current_gauge_epoch)]
+	pub type CurrentGaugeEpoch<T: Config> = StorageValue<_, GaugeEpoch<T::BlockNumber>>;
+
+	/// Weight given by an account to each gauge, and the epoch it was given in.
+	#[pallet::storage]
+	pub type GaugeVotes<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		(u64, BoundedBTreeMap<T::AssetId, T::Balance, T::MaxGauges>),
+	>;
+
+	/// Total weight received by a gauge during an epoch.
+	#[pallet::storage]
+	#[pallet::getter(fn gauge_weight)]
+	pub type GaugeWeights<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		T::AssetId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Gauge reward rates that could not be applied at the start of an epoch. They are retried
+	/// every block until they succeed, a newer epoch replaces them or the gauge is removed.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_gauge_update)]
+	pub type PendingGaugeUpdates<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		BoundedBTreeMap<AssetIdOf<T>, RewardUpdate<BalanceOf<T>>, T::MaxRewardConfigsPerPool>,
+	>;
+
+	/// Positions opted in to auto-compounding, with the block they were last compounded at.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub type AutoCompound<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::FinancialNftInstanceId,
+		T::BlockNumber,
+	>;
+
+	/// The last position visited by the auto-compound hook. The next batch starts after it.
+	#[pallet::storage]
+	pub type AutoCompoundCursor<T: Config> =
+		StorageValue<_, (T::AssetId, T::FinancialNftInstanceId)>;
+
+	/// Part of the stake and share of its positions left to a pool by all of its slashes. Pools
+	/// that were never slashed have none.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_slash_factor)]
+	pub type PoolSlashFactors<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, FixedU128>;
+
+	/// Slash factor of its pool when a position was last settled. Positions created before their
+	/// pool was ever slashed have none.
+	#[pallet::storage]
+	pub type SlashCheckpoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::FinancialNftInstanceId,
+		FixedU128,
+	>;
+
+	/// Stake of all the positions of a pool, with the slashes of the pool applied whether or not
+	/// the positions were settled.
+	#[pallet::storage]
+	#[pallet::getter(fn total_stake)]
+	pub type TotalStakes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance, ValueQuery>;
+
+	/// Shares slashed from positions that were not settled yet. They stop earning rewards as soon
+	/// as the pool is slashed, like the unstaked shares held by the pool account.
+	#[pallet::storage]
+	pub type PendingSlashedShares<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, T::Balance, ValueQuery>;
+
+	/// Slashed stake owed by a pool to each beneficiary, withdrawn from the slash account of the
+	/// pool as positions are settled.
+	#[pallet::storage]
+	#[pallet::getter(fn slash_payout)]
+	pub type SlashPayouts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Number of slashes of the voting power pool.
+	#[pallet::storage]
+	pub type VotingPowerSlashCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Slash factor of the voting power pool after each of its slashes, with the block of the
+	/// slash. Voting power is stored without the slashes applied and scaled by these on reads.
+	#[pallet::storage]
+	pub type VotingPowerSlashes<T: Config> =
+		StorageMap<_, Twox64Concat, u32, (T::BlockNumber, FixedU128)>;
+
+	/// Emission schedule of a reward, by pool and reward asset.
+	#[pallet::storage]
+	#[pallet::getter(fn emission_schedule)]
+	pub type EmissionSchedules<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AssetId,
+		EmissionScheduleOf<T>,
+	>;
+
+	/// Time of the next reduction of the reward rate of a scheduled emission.
+	#[pallet::storage]
+	pub type NextRateStepDown<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Timestamp>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// Weight: see `begin_block`
-		fn on_initialize(_: T::BlockNumber) -> Weight {
-			accumulate_rewards_hook::<T>()
+		fn on_initialize(_now: T::BlockNumber) -> Weight {
+			accumulate_rewards_hook::<T>().saturating_add(gauge_epoch_hook::<T>())
+		}
+
+		/// Compounds the auto-compounding positions that are due, see `auto_compound_hook`.
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			auto_compound_hook::<T>(now, remaining_weight)
 		}
 	}
 
@@ -478,7 +880,8 @@ pub mod pallet {
 		/// Create a new stake.
 		///
 		/// Emits `Staked` when successful.
-		#[pallet::weight(T::WeightInfo::stake(T::MaxRewardConfigsPerPool::get()))]
+		#[pallet::weight(T::WeightInfo::stake(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(2)))]
 		#[pallet::call_index(2)]
 		pub fn stake(
 			origin: OriginFor<T>,
@@ -497,7 +900,9 @@ pub mod pallet {
 		/// Extend an existing stake.
 		///
 		/// Emits `StakeExtended` when successful.
-		#[pallet::weight(T::WeightInfo::extend(T::MaxRewardConfigsPerPool::get()))]
+		#[pallet::weight(T::WeightInfo::extend(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(4)))]
 		#[pallet::call_index(3)]
 		pub fn extend(
 			origin: OriginFor<T>,
@@ -527,7 +932,9 @@ pub mod pallet {
 		/// Remove a stake.
 		///
 		/// Emits `Unstaked` when successful.
-		#[pallet::weight(T::WeightInfo::unstake(T::MaxRewardConfigsPerPool::get()))]
+		#[pallet::weight(T::WeightInfo::unstake(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(4)))]
 		#[pallet::call_index(4)]
 		pub fn unstake(
 			origin: OriginFor<T>,
@@ -548,7 +955,9 @@ pub mod pallet {
 		/// Split a stake into two parts, by a ratio.
 		///
 		/// Emits `SplitPosition` when successful.
-		#[pallet::weight(T::WeightInfo::split(T::MaxRewardConfigsPerPool::get()))]
+		#[pallet::weight(T::WeightInfo::split(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(6)))]
 		#[pallet::call_index(5)]
 		pub fn split(
 			origin: OriginFor<T>,
@@ -586,7 +995,9 @@ pub mod pallet {
 		/// Claim a current reward for some position.
 		///
 		/// Emits `Claimed` when successful.
-		#[pallet::weight(T::WeightInfo::claim(T::MaxRewardConfigsPerPool::get()))]
+		#[pallet::weight(T::WeightInfo::claim(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes())
+			.saturating_add(Pallet::<T>::vote_escrow_weight(2)))]
 		#[pallet::call_index(7)]
 		pub fn claim(
 			origin: OriginFor<T>,
@@ -618,6 +1029,184 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			add_to_rewards_pot::<T>(&who, pool_id, asset_id, amount, keep_alive)
 		}
+
+		/// Add a reward pool to the gauges, letting voters direct emissions to it.
+		///
+		/// Emits `GaugeAdded` when successful.
+		#[pallet::weight(T::WeightInfo::add_gauge())]
+		#[pallet::call_index(9)]
+		pub fn add_gauge(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			T::RewardPoolUpdateOrigin::ensure_origin(origin)?;
+			ensure!(RewardPools::<T>::contains_key(pool_id), Error::<T>::RewardsPoolNotFound);
+			Gauges::<T>::try_mutate(|gauges| {
+				ensure!(
+					gauges.try_insert(pool_id).map_err(|_| Error::<T>::TooManyGauges)?,
+					Error::<T>::GaugeAlreadyExists
+				);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::GaugeAdded { pool_id });
+			Ok(())
+		}
+
+		/// Remove a reward pool from the gauges. Its reward rate is left as is and any pending
+		/// update of it is dropped.
+		///
+		/// Emits `GaugeRemoved` when successful.
+		#[pallet::weight(T::WeightInfo::remove_gauge())]
+		#[pallet::call_index(10)]
+		pub fn remove_gauge(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			T::RewardPoolUpdateOrigin::ensure_origin(origin)?;
+			Gauges::<T>::try_mutate(|gauges| {
+				ensure!(gauges.remove(&pool_id), Error::<T>::GaugeNotFound);
+				Ok::<_, DispatchError>(())
+			})?;
+			PendingGaugeUpdates::<T>::remove(pool_id);
+
+			Self::deposit_event(Event::<T>::GaugeRemoved { pool_id });
+			Ok(())
+		}
+
+		/// Set the emission split across gauges at the start of every epoch. `None` stops
+		/// updating the reward rate of gauges.
+		///
+		/// Emits `GaugeEmissionSet` when successful.
+		#[pallet::weight(T::WeightInfo::set_gauge_emission())]
+		#[pallet::call_index(11)]
+		pub fn set_gauge_emission(
+			origin: OriginFor<T>,
+			emission: Option<GaugeEmissionOf<T>>,
+		) -> DispatchResult {
+			T::RewardPoolUpdateOrigin::ensure_origin(origin)?;
+			GaugeEmissions::<T>::set(emission.clone());
+
+			Self::deposit_event(Event::<T>::GaugeEmissionSet { emission });
+			Ok(())
+		}
+
+		/// Split the caller's voting power across gauges for the current epoch, replacing any
+		/// votes already cast in it. Voting power is taken at the start of the epoch.
+		///
+		/// Emits `GaugeVoted` when successful.
+		#[pallet::weight(T::WeightInfo::vote_for_gauges(votes.len() as u32))]
+		#[pallet::call_index(12)]
+		pub fn vote_for_gauges(
+			origin: OriginFor<T>,
+			votes: BoundedBTreeMap<T::AssetId, Permill, T::MaxGauges>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_vote_for_gauges(&who, votes)
+		}
+
+		/// Opt a position in or out of auto-compounding. Only positions whose pool rewards the
+		/// staked asset can opt in.
+		///
+		/// Emits `AutoCompoundSet` when successful.
+		#[pallet::weight(T::WeightInfo::set_auto_compound())]
+		#[pallet::call_index(13)]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			enabled: bool,
+		) -> DispatchResult {
+			Self::ensure_stake_owner(
+				ensure_signed(origin)?,
+				&fnft_collection_id,
+				&fnft_instance_id,
+			)?;
+			Self::do_set_auto_compound(fnft_collection_id, fnft_instance_id, enabled)
+		}
+
+		/// Compound the rewards of an auto-compounding position into its stake. Callable by
+		/// anyone, the caller receives `CompoundBounty` of the compounded rewards.
+		///
+		/// Emits `Compounded` when successful.
+		#[pallet::weight(Pallet::<T>::compound_weight())]
+		#[pallet::call_index(14)]
+		pub fn compound(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			ensure!(
+				AutoCompound::<T>::contains_key(fnft_collection_id, fnft_instance_id),
+				Error::<T>::AutoCompoundNotEnabled
+			);
+			Self::compound_position(fnft_collection_id, fnft_instance_id, Some(keeper))
+		}
+
+		/// Merge a position into another one of the same pool, burning its fNFT.
+		///
+		/// Emits `MergedPositions` when successful.
+		#[pallet::weight(T::WeightInfo::merge(T::MaxRewardConfigsPerPool::get())
+			.saturating_add(T::WeightInfo::settle_slashes().saturating_mul(2))
+			.saturating_add(Pallet::<T>::vote_escrow_weight(8)))]
+		#[pallet::call_index(15)]
+		pub fn merge(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			merged_fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_stake_owner(who.clone(), &fnft_collection_id, &fnft_instance_id)?;
+			Self::ensure_stake_owner(who.clone(), &fnft_collection_id, &merged_fnft_instance_id)?;
+			<Self as Staking>::merge(
+				&who,
+				&(fnft_collection_id, fnft_instance_id),
+				&(fnft_collection_id, merged_fnft_instance_id),
+			)
+		}
+
+		/// Apply the pending slashes of its pool to a position, paying the slashed stake to the
+		/// slash account of the pool. Callable by anyone.
+		///
+		/// Emits `PositionSlashed` when successful.
+		#[pallet::weight(T::WeightInfo::settle_slashes()
+			.saturating_add(Pallet::<T>::vote_escrow_weight(2)))]
+		#[pallet::call_index(16)]
+		pub fn settle_slashes(
+			origin: OriginFor<T>,
+			fnft_collection_id: T::AssetId,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				Self::settle_position(&fnft_collection_id, &fnft_instance_id)?,
+				Error::<T>::NoSlashesToSettle
+			);
+			Ok(())
+		}
+
+		/// Set the emission schedule of a reward, or remove it with `None`. The rewards pot must
+		/// cover the emission remaining until the end block of the schedule.
+		///
+		/// Emits `EmissionScheduleSet` when successful.
+		#[pallet::weight(T::WeightInfo::set_emission_schedule())]
+		#[pallet::call_index(17)]
+		pub fn set_emission_schedule(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			schedule: Option<EmissionScheduleOf<T>>,
+		) -> DispatchResult {
+			T::RewardPoolUpdateOrigin::ensure_origin(origin)?;
+			Self::do_set_emission_schedule(pool_id, asset_id, schedule)
+		}
+
+		/// Withdraw the slashed stake owed to the caller by a pool, as far as settled positions
+		/// have paid it to the slash account of the pool.
+		///
+		/// Emits `SlashWithdrawn` when successful.
+		#[pallet::weight(T::WeightInfo::withdraw_slashed())]
+		#[pallet::call_index(18)]
+		pub fn withdraw_slashed(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_withdraw_slashed(&who, &pool_id)
+		}
 	}
 
 	impl<T: Config> ManageStaking for Pallet<T> {
@@ -665,6 +1254,13 @@ pub mod pallet {
 						lock.duration_multipliers.has_at_least_one_valid_duration(),
 						Error::<T>::NoDurationPresetsProvided
 					);
+					if pool_asset == T::VotingPowerPoolId::get() {
+						ensure!(
+							lock.duration_multipliers.max_duration().unwrap_or_default() <=
+								Self::max_vote_escrow_duration(),
+							Error::<T>::VoteEscrowLockTooLong
+						);
+					}
 
 					let now_seconds = T::UnixTime::now().as_secs();
 
@@ -779,7 +1375,7 @@ pub mod pallet {
 			);
 
 			let reward_multiplier = Self::reward_multiplier(&rewards_pool, duration_preset)
-				.ok_or(Error::<T>::DurationPresetNotFound)?;
+				.ok_or_else(|| Self::lock_duration_error(&rewards_pool))?;
 
 			ensure!(
 				matches!(
@@ -827,8 +1423,21 @@ pub mod pallet {
 			// Mint the fNFT
 			T::FinancialNft::mint_into(&fnft_collection_id, &fnft_instance_id, who)?;
 
+			Self::update_voting_power(
+				&fnft_collection_id,
+				&fnft_instance_id,
+				None,
+				Some(&new_position),
+			);
+
 			RewardPools::<T>::insert(pool_id, rewards_pool);
 			Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, new_position);
+			if let Some(slash_factor) = PoolSlashFactors::<T>::get(pool_id) {
+				SlashCheckpoints::<T>::insert(fnft_collection_id, fnft_instance_id, slash_factor);
+			}
+			TotalStakes::<T>::mutate(pool_id, |total_stake| {
+				*total_stake = total_stake.saturating_add(amount)
+			});
 
 			Self::deposit_event(Event::<T>::Staked {
 				pool_id: *pool_id,
@@ -847,109 +1456,23 @@ pub mod pallet {
 		#[transactional]
 		fn extend(
 			who: &Self::AccountId,
-			(fnft_collection_id, fnft_instance_id): Self::PositionId,
+			position: Self::PositionId,
 			amount: Self::Balance,
 			keep_alive: bool,
 		) -> DispatchResult {
-			Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |maybe_stake| {
-				let stake = maybe_stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+			Self::add_to_stake(who, position, amount, keep_alive, true)
+		}
 
-				RewardPools::<T>::try_mutate(stake.reward_pool_id, |maybe_rewards_pool| {
-					let rewards_pool =
-						maybe_rewards_pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
+		#[transactional]
+		fn unstake(
+			who: &Self::AccountId,
+			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
+		) -> DispatchResult {
+			Self::settle_position(fnft_collection_id, fnft_instance_id)?;
 
-					ensure!(
-						matches!(
-							T::Assets::can_withdraw(stake.reward_pool_id, who, amount),
-							WithdrawConsequence::Success
-						),
-						Error::<T>::NotEnoughAssets
-					);
-
-					// SAFETY: The duration preset on an existing stake should be valid in the
-					// pool since it's currently not possible to modify the presets after pool
-					// creation.
-					let reward_multiplier = rewards_pool
-						.lock
-						.duration_multipliers
-						.multiplier(stake.lock.duration)
-						.copied()
-						.defensive_unwrap_or_else(|| {
-							FixedU64::one().try_into_validated().expect("1 is >= 1")
-						});
-
-					let new_shares = Self::boosted_amount(reward_multiplier, amount)?;
-
-					let total_shares = T::Assets::total_issuance(rewards_pool.share_asset_id);
-
-					for (reward_asset_id, reward) in &mut rewards_pool.rewards {
-						let new_inflation = if total_shares.is_zero() {
-							T::Balance::zero()
-						} else {
-							reward.total_rewards.safe_mul(&new_shares)?.safe_div(&total_shares)?
-						};
-
-						reward.total_rewards = reward.total_rewards.safe_add(&new_inflation)?;
-						reward.total_dilution_adjustment =
-							reward.total_dilution_adjustment.safe_add(&new_inflation)?;
-
-						match stake.reductions.get_mut(reward_asset_id) {
-							Some(previous_inflation_and_claims) => {
-								*previous_inflation_and_claims =
-									previous_inflation_and_claims.safe_add(&new_inflation)?;
-							},
-							None => {
-								// REVIEW(benluelo): Is this an invariant we expect? In
-								// ProtocolStaking::transfer_reward assets can be added (and is
-								// currently the only way to add a new reward asset to a pool),
-								// but they are not added to all existing stakes so this
-								// invariant is not upheld
-								defensive!("stake.reductions should contain the same assets as reward_pool.rewards");
-							},
-						}
-					}
-
-					let fnft_asset_account =
-						T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
-
-					Self::transfer_stake(
-						who,
-						amount,
-						stake.reward_pool_id,
-						&fnft_asset_account,
-						keep_alive,
-					)?;
-
-					Self::allocate_shares(
-						&stake.reward_pool_id,
-						&fnft_asset_account,
-						rewards_pool,
-						new_shares,
-					)?;
-
-					Self::deposit_event(Event::<T>::StakeAmountExtended {
-						amount,
-						fnft_collection_id,
-						fnft_instance_id,
-					});
-
-					stake.stake = stake.stake.safe_add(&amount)?;
-					stake.share = stake.share.safe_add(&new_shares)?;
-					stake.lock.started_at = T::UnixTime::now().as_secs();
-
-					Ok(())
-				})
-			})
-		}
-
-		#[transactional]
-		fn unstake(
-			who: &Self::AccountId,
-			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
-		) -> DispatchResult {
-			// TODO(benluelo): Use ::take here instead of try_get and then remove
-			let mut stake = Stakes::<T>::try_get(fnft_collection_id, fnft_instance_id)
-				.map_err(|_| Error::<T>::StakeNotFound)?;
+			// TODO(benluelo): Use ::take here instead of try_get and then remove
+			let mut stake = Stakes::<T>::try_get(fnft_collection_id, fnft_instance_id)
+				.map_err(|_| Error::<T>::StakeNotFound)?;
 
 			let is_early_unlock = stake.lock.started_at.safe_add(&stake.lock.duration)? >=
 				T::UnixTime::now().as_secs();
@@ -987,6 +1510,11 @@ pub mod pallet {
 			)?;
 
 			Stakes::<T>::remove(fnft_collection_id, fnft_instance_id);
+			SlashCheckpoints::<T>::remove(fnft_collection_id, fnft_instance_id);
+			TotalStakes::<T>::mutate(stake.reward_pool_id, |total_stake| {
+				*total_stake = total_stake.saturating_sub(stake.stake)
+			});
+			AutoCompound::<T>::remove(fnft_collection_id, fnft_instance_id);
 
 			// transfer slashed stake to the treasury
 			if is_early_unlock {
@@ -1015,6 +1543,8 @@ pub mod pallet {
 				stake.share,
 				false,
 			)?;
+			Self::update_voting_power(fnft_collection_id, fnft_instance_id, Some(&stake), None);
+
 			// burn NFT
 			T::FinancialNft::burn(fnft_collection_id, fnft_instance_id, Some(who))?;
 
@@ -1035,12 +1565,15 @@ pub mod pallet {
 			(fnft_collection_id, existing_fnft_instance_id): &Self::PositionId,
 			ratio: Permill,
 		) -> Result<Self::PositionId, DispatchError> {
+			Self::settle_position(fnft_collection_id, existing_fnft_instance_id)?;
+
 			let (new_fnft_instance_id, new_position) = Stakes::<T>::try_mutate(
 				fnft_collection_id,
 				existing_fnft_instance_id,
 				|maybe_existing_position| {
 					let existing_position =
 						maybe_existing_position.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+					let position_before_split = existing_position.clone();
 
 					let left_from_one_ratio = ratio.left_from_one();
 
@@ -1122,29 +1655,156 @@ pub mod pallet {
 						],
 					});
 
-					Ok::<_, DispatchError>((
-						new_fnft_instance_id,
-						Stake {
-							stake: new_stake,
-							share: new_share,
-							reductions: new_reductions,
-							reward_pool_id: existing_position.reward_pool_id,
-							lock: existing_position.lock,
-						},
-					))
+					let new_position = Stake {
+						stake: new_stake,
+						share: new_share,
+						reductions: new_reductions,
+						reward_pool_id: existing_position.reward_pool_id,
+						lock: existing_position.lock,
+					};
+
+					Self::update_voting_power(
+						fnft_collection_id,
+						existing_fnft_instance_id,
+						Some(&position_before_split),
+						Some(existing_position),
+					);
+					Self::update_voting_power(
+						fnft_collection_id,
+						&new_fnft_instance_id,
+						None,
+						Some(&new_position),
+					);
+
+					Ok::<_, DispatchError>((new_fnft_instance_id, new_position))
 				},
 			)?;
 
 			Stakes::<T>::insert(fnft_collection_id, new_fnft_instance_id, new_position);
+			if let Some(slash_checkpoint) =
+				SlashCheckpoints::<T>::get(fnft_collection_id, existing_fnft_instance_id)
+			{
+				SlashCheckpoints::<T>::insert(
+					fnft_collection_id,
+					new_fnft_instance_id,
+					slash_checkpoint,
+				);
+			}
 
 			Ok((*fnft_collection_id, new_fnft_instance_id))
 		}
 
+		#[transactional]
+		fn merge(
+			who: &Self::AccountId,
+			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
+			(other_fnft_collection_id, other_fnft_instance_id): &Self::PositionId,
+		) -> DispatchResult {
+			ensure!(
+				fnft_collection_id == other_fnft_collection_id,
+				Error::<T>::PositionsNotInSamePool
+			);
+			ensure!(
+				fnft_instance_id != other_fnft_instance_id,
+				Error::<T>::CannotMergePositionWithItself
+			);
+
+			Self::settle_position(fnft_collection_id, fnft_instance_id)?;
+			Self::settle_position(fnft_collection_id, other_fnft_instance_id)?;
+
+			let other_position = Stakes::<T>::take(fnft_collection_id, other_fnft_instance_id)
+				.ok_or(Error::<T>::StakeNotFound)?;
+
+			let position =
+				Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |maybe_position| {
+					let position = maybe_position.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+					let position_before_merge = position.clone();
+
+					let rewards_pool = RewardPools::<T>::get(position.reward_pool_id)
+						.ok_or(Error::<T>::RewardsPoolNotFound)?;
+
+					position.lock = Self::merged_lock(
+						&position.lock,
+						position.stake,
+						&other_position.lock,
+						other_position.stake,
+					);
+					position.stake = position.stake.safe_add(&other_position.stake)?;
+					position.share = position.share.safe_add(&other_position.share)?;
+					// claims are linear in shares and reductions, so summing both keeps the
+					// claimable amounts of the two positions
+					for (reward_asset_id, other_reduction) in &other_position.reductions {
+						match position.reductions.get_mut(reward_asset_id) {
+							Some(reduction) => *reduction = reduction.safe_add(other_reduction)?,
+							None => {
+								position
+									.reductions
+									.try_insert(*reward_asset_id, *other_reduction)
+									.map_err(|_| Error::<T>::TooManyRewardAssetTypes)?;
+							},
+						}
+					}
+
+					let fnft_asset_account =
+						T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+					let other_fnft_asset_account =
+						T::FinancialNft::asset_account(fnft_collection_id, other_fnft_instance_id);
+
+					// staked asset
+					Self::merge_lock(
+						position.reward_pool_id,
+						&other_fnft_asset_account,
+						&fnft_asset_account,
+						other_position.stake,
+						position.stake,
+					)?;
+
+					// share asset (x-token)
+					Self::merge_lock(
+						rewards_pool.share_asset_id,
+						&other_fnft_asset_account,
+						&fnft_asset_account,
+						other_position.share,
+						position.share,
+					)?;
+
+					Self::update_voting_power(
+						fnft_collection_id,
+						fnft_instance_id,
+						Some(&position_before_merge),
+						Some(position),
+					);
+
+					Ok::<_, DispatchError>(position.clone())
+				})?;
+
+			Self::update_voting_power(
+				fnft_collection_id,
+				other_fnft_instance_id,
+				Some(&other_position),
+				None,
+			);
+			SlashCheckpoints::<T>::remove(fnft_collection_id, other_fnft_instance_id);
+			AutoCompound::<T>::remove(fnft_collection_id, other_fnft_instance_id);
+			T::FinancialNft::burn(fnft_collection_id, other_fnft_instance_id, Some(who))?;
+
+			Self::deposit_event(Event::<T>::MergedPositions {
+				fnft_collection_id: *fnft_collection_id,
+				fnft_instance_id: *fnft_instance_id,
+				merged_fnft_instance_id: *other_fnft_instance_id,
+				stake: position.stake,
+			});
+
+			Ok(())
+		}
+
 		#[transactional]
 		fn claim(
 			who: &Self::AccountId,
 			(fnft_collection_id, fnft_instance_id): &Self::PositionId,
 		) -> DispatchResult {
+			Self::settle_position(fnft_collection_id, fnft_instance_id)?;
+
 			let claimed_amounts =
 				Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |stake| {
 					let stake = stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
@@ -1168,6 +1828,120 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Adds `amount` of the staked asset from `who` to a position, restarting its lock if
+		/// `restart_lock` is set.
+		pub(crate) fn add_to_stake(
+			who: &T::AccountId,
+			(fnft_collection_id, fnft_instance_id): (T::AssetId, T::FinancialNftInstanceId),
+			amount: T::Balance,
+			keep_alive: bool,
+			restart_lock: bool,
+		) -> DispatchResult {
+			Self::settle_position(&fnft_collection_id, &fnft_instance_id)?;
+
+			Stakes::<T>::try_mutate(fnft_collection_id, fnft_instance_id, |maybe_stake| {
+				let stake = maybe_stake.as_mut().ok_or(Error::<T>::StakeNotFound)?;
+				let previous_stake = stake.clone();
+
+				RewardPools::<T>::try_mutate(stake.reward_pool_id, |maybe_rewards_pool| {
+					let rewards_pool =
+						maybe_rewards_pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
+
+					ensure!(
+						matches!(
+							T::Assets::can_withdraw(stake.reward_pool_id, who, amount),
+							WithdrawConsequence::Success
+						),
+						Error::<T>::NotEnoughAssets
+					);
+
+					// SAFETY: The duration preset on an existing stake should be valid in the
+					// pool since it's currently not possible to modify the presets after pool
+					// creation.
+					let reward_multiplier = rewards_pool
+						.lock
+						.duration_multipliers
+						.multiplier(stake.lock.duration)
+						.defensive_unwrap_or_else(|| {
+							FixedU64::one().try_into_validated().expect("1 is >= 1")
+						});
+
+					let new_shares = Self::boosted_amount(reward_multiplier, amount)?;
+
+					let total_shares = T::Assets::total_issuance(rewards_pool.share_asset_id);
+
+					for (reward_asset_id, reward) in &mut rewards_pool.rewards {
+						let new_inflation = if total_shares.is_zero() {
+							T::Balance::zero()
+						} else {
+							reward.total_rewards.safe_mul(&new_shares)?.safe_div(&total_shares)?
+						};
+
+						reward.total_rewards = reward.total_rewards.safe_add(&new_inflation)?;
+						reward.total_dilution_adjustment =
+							reward.total_dilution_adjustment.safe_add(&new_inflation)?;
+
+						match stake.reductions.get_mut(reward_asset_id) {
+							Some(previous_inflation_and_claims) => {
+								*previous_inflation_and_claims =
+									previous_inflation_and_claims.safe_add(&new_inflation)?;
+							},
+							None => {
+								// REVIEW(benluelo): Is this an invariant we expect? In
+								// ProtocolStaking::transfer_reward assets can be added (and is
+								// currently the only way to add a new reward asset to a pool),
+								// but they are not added to all existing stakes so this
+								// invariant is not upheld
+								defensive!("stake.reductions should contain the same assets as reward_pool.rewards");
+							},
+						}
+					}
+
+					let fnft_asset_account =
+						T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+
+					Self::transfer_stake(
+						who,
+						amount,
+						stake.reward_pool_id,
+						&fnft_asset_account,
+						keep_alive,
+					)?;
+
+					Self::allocate_shares(
+						&stake.reward_pool_id,
+						&fnft_asset_account,
+						rewards_pool,
+						new_shares,
+					)?;
+
+					Self::deposit_event(Event::<T>::StakeAmountExtended {
+						amount,
+						fnft_collection_id,
+						fnft_instance_id,
+					});
+
+					stake.stake = stake.stake.safe_add(&amount)?;
+					stake.share = stake.share.safe_add(&new_shares)?;
+					TotalStakes::<T>::mutate(stake.reward_pool_id, |total_stake| {
+						*total_stake = total_stake.saturating_add(amount)
+					});
+					if restart_lock {
+						stake.lock.started_at = T::UnixTime::now().as_secs();
+					}
+
+					Self::update_voting_power(
+						&fnft_collection_id,
+						&fnft_instance_id,
+						Some(&previous_stake),
+						Some(stake),
+					);
+
+					Ok(())
+				})
+			})
+		}
+
 		fn transfer_stake(
 			who: &AccountIdOf<T>,
 			amount: <T as Config>::Balance,
@@ -1208,6 +1982,60 @@ pub mod pallet {
 			Ok(who)
 		}
 
+		/// Moves `amount` of `asset_id` locked in `merged_fnft_asset_account` to
+		/// `fnft_asset_account`, locking `total_amount` there.
+		pub(crate) fn merge_lock(
+			asset_id: T::AssetId,
+			merged_fnft_asset_account: &T::AccountId,
+			fnft_asset_account: &T::AccountId,
+			amount: T::Balance,
+			total_amount: T::Balance,
+		) -> DispatchResult {
+			T::Assets::remove_lock(T::LockId::get(), asset_id, merged_fnft_asset_account)?;
+			T::Assets::transfer(
+				asset_id,
+				merged_fnft_asset_account,
+				fnft_asset_account,
+				amount,
+				false, // not a user account, doesn't need to be kept alive
+			)?;
+			T::Assets::set_lock(T::LockId::get(), asset_id, fnft_asset_account, total_amount)
+		}
+
+		/// The lock of two merged positions: it ends with the later of the two locks, and its
+		/// unlock penalty is the average of both penalties weighted by stake.
+		pub(crate) fn merged_lock(
+			lock: &lock::Lock,
+			stake: T::Balance,
+			other_lock: &lock::Lock,
+			other_stake: T::Balance,
+		) -> lock::Lock {
+			let end_of = |lock: &lock::Lock| lock.started_at.saturating_add(lock.duration);
+			let mut merged_lock =
+				if end_of(other_lock) > end_of(lock) { *other_lock } else { *lock };
+
+			// move from the lower penalty towards the higher one by the stake share of the latter,
+			// so that equal penalties are kept as is
+			let (low, high, high_stake) = if lock.unlock_penalty <= other_lock.unlock_penalty {
+				(lock.unlock_penalty, other_lock.unlock_penalty, other_stake)
+			} else {
+				(other_lock.unlock_penalty, lock.unlock_penalty, stake)
+			};
+			let total_stake: u128 = stake.saturating_add(other_stake).into();
+			let increase = sp_runtime::helpers_128bit::multiply_by_rational_with_rounding(
+				high.deconstruct().saturating_sub(low.deconstruct()).into(),
+				high_stake.into(),
+				total_stake,
+				sp_runtime::Rounding::NearestPrefDown,
+			)
+			.unwrap_or_default();
+			merged_lock.unlock_penalty = low.saturating_add(Perbill::from_parts(
+				increase.try_into().unwrap_or(Perbill::ACCURACY),
+			));
+
+			merged_lock
+		}
+
 		pub(crate) fn split_lock(
 			asset_id: T::AssetId,
 			existing_fnft_asset_account: &T::AccountId,
@@ -1307,7 +2135,16 @@ pub mod pallet {
 			rewards_pool: &RewardPoolOf<T>,
 			duration_preset: DurationSeconds,
 		) -> Option<Validated<FixedU64, GeOne>> {
-			rewards_pool.lock.duration_multipliers.multiplier(duration_preset).copied()
+			rewards_pool.lock.duration_multipliers.multiplier(duration_preset)
+		}
+
+		/// Error of a lock duration without a reward multiplier in `rewards_pool`.
+		pub(crate) fn lock_duration_error(rewards_pool: &RewardPoolOf<T>) -> Error<T> {
+			match rewards_pool.lock.duration_multipliers {
+				DurationMultipliers::Presets(_) => Error::<T>::DurationPresetNotFound,
+				DurationMultipliers::Linear { .. } | DurationMultipliers::Curve(_) =>
+					Error::<T>::LockDurationOutOfRange,
+			}
 		}
 
 		pub(crate) fn boosted_amount(
@@ -1423,12 +2260,73 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		#[transactional]
+		fn slash(
+			pool_id: &Self::RewardPoolId,
+			ratio: Perbill,
+			beneficiary: &Self::AccountId,
+		) -> DispatchResult {
+			Self::do_slash(pool_id, ratio, beneficiary)
+		}
+
+		fn ensure_stakeable(
+			pool_id: &Self::RewardPoolId,
+			asset: Self::AssetId,
+			duration_preset: DurationSeconds,
+		) -> DispatchResult {
+			let rewards_pool =
+				RewardPools::<T>::try_get(pool_id).map_err(|_| Error::<T>::RewardsPoolNotFound)?;
+			// pools stake the asset they are identified by
+			ensure!(*pool_id == asset, Error::<T>::StakedAssetMismatch);
+			Self::reward_multiplier(&rewards_pool, duration_preset)
+				.ok_or_else(|| Self::lock_duration_error(&rewards_pool))?;
+
+			Ok(())
+		}
 	}
 }
+/// Accumulates the rewards in a pool following the emission schedule of the reward, if any.
+pub(crate) fn reward_accumulation_hook_reward_update_calculation<T: Config>(
+	pool_id: T::AssetId,
+	reward_asset_id: T::AssetId,
+	reward: &mut Reward<T::Balance>,
+	unstaked_shares: T::Balance,
+	total_shares: T::Balance,
+	now_seconds: u64,
+) {
+	let schedule = EmissionSchedules::<T>::get(pool_id, reward_asset_id);
+
+	if let Some(step_down) = schedule.as_ref().and_then(|schedule| schedule.step_down.as_ref()) {
+		emission::step_down_reward_rate::<T>(
+			pool_id,
+			reward_asset_id,
+			reward,
+			step_down,
+			unstaked_shares,
+			total_shares,
+			now_seconds,
+		);
+	}
+
+	accumulate_reward_and_report::<T>(
+		pool_id,
+		reward_asset_id,
+		reward,
+		unstaked_shares,
+		total_shares,
+		now_seconds,
+	);
+
+	if let Some(schedule) = schedule {
+		emission::end_emission_if_due::<T>(pool_id, reward_asset_id, reward, &schedule);
+	}
+}
+
 /// Accumulates the rewards in a pool, if the pot isn't empty. Emits the relevant events
 /// after accumulation. See [`accumulate_reward`] for more information about how the
 /// accumulation calculation is done.
-pub(crate) fn reward_accumulation_hook_reward_update_calculation<T: Config>(
+pub(crate) fn accumulate_reward_and_report<T: Config>(
 	pool_id: T::AssetId,
 	reward_asset_id: T::AssetId,
 	reward: &mut Reward<T::Balance>,
@@ -1528,9 +2426,7 @@ fn accumulate_pool_rewards<T: Config>(
 ) -> Weight {
 	// TODO(benluelo): Benchmark this
 
-	let pool_account = Pallet::<T>::pool_account_id(&pool_id);
-
-	let unstaked_shares: T::Balance = T::Assets::balance(reward_pool.share_asset_id, &pool_account);
+	let unstaked_shares = Pallet::<T>::unstaked_shares(&pool_id, reward_pool.share_asset_id);
 	let total_shares: T::Balance =
 		<T::Assets as FungiblesInspect<T::AccountId>>::total_issuance(reward_pool.share_asset_id);
 
@@ -1634,9 +2530,7 @@ fn update_rewards_pool<T: Config>(
 	RewardPools::<T>::try_mutate(pool_id, |pool| {
 		let pool = pool.as_mut().ok_or(Error::<T>::RewardsPoolNotFound)?;
 
-		let pool_account = Pallet::<T>::pool_account_id(&pool_id);
-
-		let unstaked_shares: T::Balance = T::Assets::balance(pool.share_asset_id, &pool_account);
+		let unstaked_shares = Pallet::<T>::unstaked_shares(&pool_id, pool.share_asset_id);
 		let total_shares: T::Balance =
 			<T::Assets as FungiblesInspect<T::AccountId>>::total_issuance(pool.share_asset_id);
 
@@ -1658,6 +2552,17 @@ fn update_rewards_pool<T: Config>(
 
 			reward.reward_rate = update.reward_rate.clone();
 
+			if let Some(schedule) = EmissionSchedules::<T>::get(pool_id, asset_id) {
+				emission::ensure_emission_funded::<T>(
+					pool_id,
+					asset_id,
+					pool.start_block,
+					reward,
+					&schedule,
+					NextRateStepDown::<T>::get(pool_id, asset_id),
+				)?;
+			}
+
 			updates.insert(asset_id, update.clone());
 		}
 
@@ -1851,7 +2756,7 @@ pub(crate) fn claim_of_stake<T: Config>(
 
 impl<T: Config> Pallet<T> {
 	/// Calculates the claimable amount(s) for a staked position, calling [`claim_of_stake`] for
-	/// each asset in the pool.
+	/// each asset in the pool. Pending slashes of the pool are applied to the position first.
 	///
 	/// # Errors
 	///
@@ -1866,6 +2771,9 @@ impl<T: Config> Pallet<T> {
 
 		let rewards_pool = RewardPools::<T>::try_get(stake.reward_pool_id)
 			.map_err(|_| ClaimableAmountError::RewardsPoolNotFound)?;
+		let stake =
+			Self::settled_stake(&fnft_collection_id, &fnft_instance_id, stake, &rewards_pool)
+				.map_err(ClaimableAmountError::ArithmeticError)?;
 
 		rewards_pool
 			.rewards
@@ -0,0 +1,90 @@
+//! Storage migrations of the staking rewards pallet.
+
+pub mod v1 {
+	use crate::{Config, Pallet, Stakes};
+	use frame_support::{
+		log,
+		traits::{
+			tokens::nonfungibles::Inspect, Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion,
+		},
+		weights::Weight,
+	};
+	use sp_std::marker::PhantomData;
+
+	/// Checkpoints the voting power of the positions staked in the
+	/// [`VotingPowerPoolId`](Config::VotingPowerPoolId) pool before voting power was tracked, so
+	/// that unstaking, splitting, merging or transferring them later removes a lock that was
+	/// added.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (mut stakes, mut checkpointed) = (0_u64, 0_u64);
+			for (fnft_collection_id, fnft_instance_id, stake) in Stakes::<T>::iter() {
+				stakes += 1;
+				let vote_lock = Pallet::<T>::vote_lock(
+					&stake,
+					Pallet::<T>::slash_checkpoint(&fnft_collection_id, &fnft_instance_id),
+				);
+				let owner = T::FinancialNft::owner(&fnft_collection_id, &fnft_instance_id);
+				if let (Some(vote_lock), Some(owner)) = (vote_lock, owner) {
+					checkpointed += 1;
+					Pallet::<T>::checkpoint_voting_power(&owner, None, Some(vote_lock));
+				}
+			}
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(
+				target: "staking-rewards",
+				"checkpointed the voting power of {} positions",
+				checkpointed
+			);
+
+			// a stake reads its slash checkpoint and owner, a checkpoint reads and writes the
+			// slope change, count and last checkpoint of both the owner and the total
+			T::DbWeight::get().reads_writes(1 + 3 * stakes + 7 * checkpointed, 1 + 6 * checkpointed)
+		}
+	}
+}
+
+pub mod v2 {
+	use crate::{Config, Pallet, Stakes, TotalStakes};
+	use frame_support::{
+		log,
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+	};
+	use sp_runtime::traits::{Saturating, Zero};
+	use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+	/// Initializes the [`TotalStakes`] of each pool from the stakes of its positions.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 2 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut total_stakes = BTreeMap::<T::AssetId, T::Balance>::new();
+			let mut stakes = 0_u64;
+			for stake in Stakes::<T>::iter_values() {
+				stakes += 1;
+				let total_stake =
+					total_stakes.entry(stake.reward_pool_id).or_insert_with(Zero::zero);
+				*total_stake = total_stake.saturating_add(stake.stake);
+			}
+			let pools = total_stakes.len() as u64;
+			for (pool_id, total_stake) in total_stakes {
+				TotalStakes::<T>::insert(pool_id, total_stake);
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+			log::info!(target: "staking-rewards", "initialized the total stake of {} pools", pools);
+
+			T::DbWeight::get().reads_writes(1 + stakes, 1 + pools)
+		}
+	}
+}
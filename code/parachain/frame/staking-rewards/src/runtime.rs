@@ -2,6 +2,7 @@ use composable_tests_helpers::test::currency::PICA;
 use composable_traits::{
 	account_proxy::ProxyType,
 	governance::{GovernanceRegistry, SignedRawOrigin},
+	time::{DurationSeconds, ONE_MINUTE},
 };
 use frame_support::pallet_prelude::*;
 use sp_core::{
@@ -21,6 +22,7 @@ use sp_core::sr25519;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentifyAccount, IdentityLookup, Verify},
+	Perbill,
 };
 
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -221,6 +223,7 @@ impl pallet_fnft::Config for Test {
 	type ProxyType = ProxyType;
 	type AccountProxy = AccountProxyWrapperInstance;
 	type ProxyTypeSelector = FnftAccountProxyType;
+	type TransferHook = StakingRewards;
 	type PalletId = FnftPalletId;
 	type WeightInfo = ();
 }
@@ -254,6 +257,15 @@ parameter_types! {
 	pub const StakingRewardsLockId: LockIdentifier = *b"stk_lock";
 	// REVIEW(benluelo): Use a better value for this?
 	pub const TreasuryAccountId: AccountId = sr25519::Public([10_u8; 32]);
+	pub const VotingPowerPoolId: CurrencyId = PICA::ID;
+	pub const VoteEscrowEpoch: DurationSeconds = ONE_MINUTE;
+	pub const MaxVoteEscrowEpochs: u32 = 120;
+	pub const MaxGauges: u32 = 10;
+	pub const GaugeEpoch: DurationSeconds = 10 * ONE_MINUTE;
+	pub const AutoCompoundBatchSize: u32 = 10;
+	pub const AutoCompoundPeriod: u64 = 10;
+	pub const CompoundBounty: Perbill = Perbill::from_percent(1);
+	pub const ExpectedBlockTime: DurationSeconds = MILLISECS_PER_BLOCK / 1_000;
 }
 
 impl crate::Config for Test {
@@ -276,6 +288,15 @@ impl crate::Config for Test {
 
 	type LockId = StakingRewardsLockId;
 	type TreasuryAccount = TreasuryAccountId;
+	type VotingPowerPoolId = VotingPowerPoolId;
+	type VoteEscrowEpoch = VoteEscrowEpoch;
+	type MaxVoteEscrowEpochs = MaxVoteEscrowEpochs;
+	type MaxGauges = MaxGauges;
+	type GaugeEpoch = GaugeEpoch;
+	type AutoCompoundBatchSize = AutoCompoundBatchSize;
+	type AutoCompoundPeriod = AutoCompoundPeriod;
+	type CompoundBounty = CompoundBounty;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
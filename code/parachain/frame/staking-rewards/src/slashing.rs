@@ -0,0 +1,301 @@
+//! Slashing of reward pools used as protocol backstop capital.
+//!
+//! [`ProtocolStaking::slash`] multiplies the [`PoolSlashFactors`] of the pool by the part of the
+//! stake it leaves, and immediately applies the slash to the totals of the pool: the slashed part
+//! of [`TotalStakes`] is owed to the beneficiary in [`SlashPayouts`], the slashed shares stop
+//! earning rewards through [`PendingSlashedShares`], and voting power is scaled by the slash
+//! factor when read. The positions of the pool are left untouched.
+//!
+//! A position is settled before any operation on it. Its stake and share are scaled by the slash
+//! factor of the pool relative to its [`SlashCheckpoints`], the slashed stake is sent to the slash
+//! account of the pool, and the slashed shares are returned to the pool account like the shares
+//! of an unstaked position. Unclaimed rewards shrink in the same proportion. Anyone can settle a
+//! position with [`Pallet::settle_slashes`], and beneficiaries withdraw what they are owed from
+//! the slash account with [`Pallet::withdraw_slashed`] as positions are settled.
+
+use crate::{
+	prelude::*, Config, Error, Event, Pallet, PendingSlashedShares, PoolSlashFactors, RewardPoolOf,
+	RewardPools, SlashCheckpoints, SlashPayouts, StakeOf, Stakes, TotalStakes,
+};
+use composable_support::math::safe::{SafeDiv, SafeMul, SafeSub};
+use composable_traits::fnft::FinancialNft;
+use frame_support::traits::{
+	fungibles::{Inspect as FungiblesInspect, Transfer},
+	tokens::nonfungibles::Inspect as NonFungiblesInspect,
+};
+use orml_traits::{GetByKey, MultiLockableCurrency};
+use sp_arithmetic::fixed_point::{FixedPointNumber, FixedU128};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, One, Saturating},
+	ArithmeticError, PerThing, Perbill, Rounding,
+};
+use sp_std::cmp;
+
+/// Scales `stake` by `slash_factor` relative to `slash_checkpoint`. Returns the slashed stake
+/// and share.
+///
+/// Slashed amounts below the existential deposit are left to the position, and remaining amounts
+/// below it are slashed entirely, so that every transfer of the settlement succeeds.
+pub(crate) fn apply_slash<T: Config>(
+	stake: &mut StakeOf<T>,
+	slash_checkpoint: FixedU128,
+	slash_factor: FixedU128,
+	rewards_pool: &RewardPoolOf<T>,
+) -> Result<(T::Balance, T::Balance), ArithmeticError> {
+	let slashed_amount = |amount: T::Balance, asset_id: &T::AssetId| {
+		let remaining: T::Balance = multiply_by_rational_with_rounding(
+			amount.into(),
+			slash_factor.into_inner(),
+			slash_checkpoint.into_inner(),
+			Rounding::Up,
+		)
+		.ok_or(ArithmeticError::Overflow)?
+		.into();
+		let slashed = amount.saturating_sub(remaining);
+
+		let existential_deposit = T::ExistentialDeposits::get(asset_id);
+		Ok::<_, ArithmeticError>(if slashed < existential_deposit {
+			Zero::zero()
+		} else if remaining < existential_deposit {
+			amount
+		} else {
+			slashed
+		})
+	};
+
+	let slashed_stake = slashed_amount(stake.stake, &stake.reward_pool_id)?;
+	let slashed_share = slashed_amount(stake.share, &rewards_pool.share_asset_id)?;
+
+	// unclaimed rewards shrink with the share
+	if !slashed_share.is_zero() {
+		for (_, reduction) in &mut stake.reductions {
+			let slashed_reduction = multiply_by_rational_with_rounding(
+				(*reduction).into(),
+				slashed_share.into(),
+				stake.share.into(),
+				Rounding::Down,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			*reduction = reduction.safe_sub(&slashed_reduction.into())?;
+		}
+	}
+
+	stake.stake = stake.stake.safe_sub(&slashed_stake)?;
+	stake.share = stake.share.safe_sub(&slashed_share)?;
+
+	// rounding must not leave a reduction above the rewards of the remaining share
+	let total_shares =
+		<T::Assets as FungiblesInspect<T::AccountId>>::total_issuance(rewards_pool.share_asset_id);
+	if !total_shares.is_zero() {
+		for (reward_asset_id, reward) in &rewards_pool.rewards {
+			if let Some(reduction) = stake.reductions.get_mut(reward_asset_id) {
+				let rewards =
+					reward.total_rewards.safe_mul(&stake.share)?.safe_div(&total_shares)?;
+				*reduction = cmp::min(*reduction, rewards);
+			}
+		}
+	}
+
+	Ok((slashed_stake, slashed_share))
+}
+
+/// Locks `amount` of `asset_id` held by `account`, replacing its previous lock.
+fn relock<T: Config>(
+	asset_id: T::AssetId,
+	account: &T::AccountId,
+	amount: T::Balance,
+) -> DispatchResult {
+	if amount.is_zero() {
+		T::Assets::remove_lock(T::LockId::get(), asset_id, account)
+	} else {
+		T::Assets::set_lock(T::LockId::get(), asset_id, account, amount)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub(crate) fn do_slash(
+		pool_id: &T::AssetId,
+		ratio: Perbill,
+		beneficiary: &T::AccountId,
+	) -> DispatchResult {
+		let rewards_pool = RewardPools::<T>::get(pool_id).ok_or(Error::<T>::RewardsPoolNotFound)?;
+		ensure!(!ratio.is_zero() && !ratio.is_one(), Error::<T>::InvalidSlashRatio);
+
+		let slash_factor = Self::slash_factor(pool_id).saturating_mul(FixedU128::from_rational(
+			ratio.left_from_one().deconstruct().into(),
+			Perbill::ACCURACY.into(),
+		));
+		ensure!(!slash_factor.is_zero(), Error::<T>::TooManySlashes);
+
+		let stake = TotalStakes::<T>::mutate(pool_id, |total_stake| {
+			let stake = ratio.mul_floor(*total_stake);
+			*total_stake = total_stake.saturating_sub(stake);
+			stake
+		});
+		SlashPayouts::<T>::mutate(pool_id, beneficiary, |payout| {
+			*payout = payout.saturating_add(stake)
+		});
+
+		let staked_shares = <T::Assets as FungiblesInspect<T::AccountId>>::total_issuance(
+			rewards_pool.share_asset_id,
+		)
+		.saturating_sub(Self::unstaked_shares(pool_id, rewards_pool.share_asset_id));
+		PendingSlashedShares::<T>::mutate(pool_id, |pending_shares| {
+			*pending_shares = pending_shares.saturating_add(ratio.mul_floor(staked_shares))
+		});
+
+		PoolSlashFactors::<T>::insert(pool_id, slash_factor);
+		if *pool_id == T::VotingPowerPoolId::get() {
+			Self::checkpoint_voting_power_slash(slash_factor);
+		}
+
+		Self::deposit_event(Event::<T>::PoolSlashed {
+			pool_id: *pool_id,
+			ratio,
+			beneficiary: beneficiary.clone(),
+			stake,
+		});
+
+		Ok(())
+	}
+
+	/// Applies the slashes of its pool recorded since the position was last settled. Returns
+	/// whether there was any.
+	pub(crate) fn settle_position(
+		fnft_collection_id: &T::AssetId,
+		fnft_instance_id: &T::FinancialNftInstanceId,
+	) -> Result<bool, DispatchError> {
+		let mut stake = Stakes::<T>::get(fnft_collection_id, fnft_instance_id)
+			.ok_or(Error::<T>::StakeNotFound)?;
+		let slash_factor = Self::slash_factor(&stake.reward_pool_id);
+		let slash_checkpoint = Self::slash_checkpoint(fnft_collection_id, fnft_instance_id);
+		if slash_factor >= slash_checkpoint {
+			return Ok(false)
+		}
+
+		let rewards_pool =
+			RewardPools::<T>::get(stake.reward_pool_id).ok_or(Error::<T>::RewardsPoolNotFound)?;
+		let previous_stake = stake.clone();
+		let (slashed_stake, slashed_share) =
+			apply_slash::<T>(&mut stake, slash_checkpoint, slash_factor, &rewards_pool)?;
+
+		let fnft_asset_account =
+			T::FinancialNft::asset_account(fnft_collection_id, fnft_instance_id);
+
+		relock::<T>(stake.reward_pool_id, &fnft_asset_account, stake.stake)?;
+		if !slashed_stake.is_zero() {
+			T::Assets::transfer(
+				stake.reward_pool_id,
+				&fnft_asset_account,
+				&Self::slash_account_id(&stake.reward_pool_id),
+				slashed_stake,
+				false, // not a user account, doesn't need to be kept alive
+			)?;
+		}
+
+		relock::<T>(rewards_pool.share_asset_id, &fnft_asset_account, stake.share)?;
+		if !slashed_share.is_zero() {
+			// not burned, to keep the share calculation of other positions unchanged
+			T::Assets::transfer(
+				rewards_pool.share_asset_id,
+				&fnft_asset_account,
+				&Self::pool_account_id(&stake.reward_pool_id),
+				slashed_share,
+				false, // not a user account, doesn't need to be kept alive
+			)?;
+		}
+		PendingSlashedShares::<T>::mutate(stake.reward_pool_id, |pending_shares| {
+			*pending_shares = pending_shares.saturating_sub(slashed_share)
+		});
+
+		// the slash is already part of the voting power, only rounding changes it here
+		if let Some(owner) = T::FinancialNft::owner(fnft_collection_id, fnft_instance_id) {
+			Self::checkpoint_voting_power(
+				&owner,
+				Self::vote_lock(&previous_stake, slash_checkpoint),
+				Self::vote_lock(&stake, slash_factor),
+			);
+		}
+		Stakes::<T>::insert(fnft_collection_id, fnft_instance_id, stake);
+		SlashCheckpoints::<T>::insert(fnft_collection_id, fnft_instance_id, slash_factor);
+
+		Self::deposit_event(Event::<T>::PositionSlashed {
+			fnft_collection_id: *fnft_collection_id,
+			fnft_instance_id: *fnft_instance_id,
+			stake: slashed_stake,
+		});
+
+		Ok(true)
+	}
+
+	/// The stake of a position once the pending slashes of its pool are applied.
+	pub(crate) fn settled_stake(
+		fnft_collection_id: &T::AssetId,
+		fnft_instance_id: &T::FinancialNftInstanceId,
+		mut stake: StakeOf<T>,
+		rewards_pool: &RewardPoolOf<T>,
+	) -> Result<StakeOf<T>, ArithmeticError> {
+		let slash_factor = Self::slash_factor(&stake.reward_pool_id);
+		let slash_checkpoint = Self::slash_checkpoint(fnft_collection_id, fnft_instance_id);
+		if slash_factor < slash_checkpoint {
+			apply_slash::<T>(&mut stake, slash_checkpoint, slash_factor, rewards_pool)?;
+		}
+
+		Ok(stake)
+	}
+
+	pub(crate) fn do_withdraw_slashed(
+		beneficiary: &T::AccountId,
+		pool_id: &T::AssetId,
+	) -> DispatchResult {
+		let owed = SlashPayouts::<T>::get(pool_id, beneficiary);
+		ensure!(!owed.is_zero(), Error::<T>::NoSlashedStakeOwed);
+
+		let slash_account = Self::slash_account_id(pool_id);
+		let amount = cmp::min(owed, T::Assets::reducible_balance(*pool_id, &slash_account, false));
+		ensure!(!amount.is_zero(), Error::<T>::SlashedStakeNotSettled);
+
+		T::Assets::transfer(*pool_id, &slash_account, beneficiary, amount, false)?;
+		SlashPayouts::<T>::mutate_exists(pool_id, beneficiary, |payout| {
+			*payout = payout
+				.map(|payout| payout.saturating_sub(amount))
+				.filter(|payout| !payout.is_zero());
+		});
+
+		Self::deposit_event(Event::<T>::SlashWithdrawn {
+			pool_id: *pool_id,
+			beneficiary: beneficiary.clone(),
+			amount,
+		});
+
+		Ok(())
+	}
+
+	/// Part of the stake and share of its positions left to a pool by its slashes.
+	pub(crate) fn slash_factor(pool_id: &T::AssetId) -> FixedU128 {
+		PoolSlashFactors::<T>::get(pool_id).unwrap_or_else(FixedU128::one)
+	}
+
+	/// Slash factor of its pool when a position was last settled.
+	pub(crate) fn slash_checkpoint(
+		fnft_collection_id: &T::AssetId,
+		fnft_instance_id: &T::FinancialNftInstanceId,
+	) -> FixedU128 {
+		SlashCheckpoints::<T>::get(fnft_collection_id, fnft_instance_id)
+			.unwrap_or_else(FixedU128::one)
+	}
+
+	/// Account holding the stake slashed from the positions of a pool until its beneficiaries
+	/// withdraw it.
+	pub(crate) fn slash_account_id(pool_id: &T::AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((b"slash", pool_id))
+	}
+
+	/// Shares of a pool that earn no rewards: those held by the pool account and those slashed
+	/// from positions that were not settled yet.
+	pub(crate) fn unstaked_shares(pool_id: &T::AssetId, share_asset_id: T::AssetId) -> T::Balance {
+		T::Assets::balance(share_asset_id, &Self::pool_account_id(pool_id))
+			.saturating_add(PendingSlashedShares::<T>::get(pool_id))
+	}
+}
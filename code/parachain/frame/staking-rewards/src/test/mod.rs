@@ -18,14 +18,14 @@ use composable_support::validation::TryIntoValidated;
 use composable_tests_helpers::test::{
 	block::{next_block, process_and_progress_blocks, process_and_progress_blocks_with},
 	currency::{BTC, PICA, USDT, XPICA},
-	helper::RuntimeTrait,
+	helper::{default_acceptable_computation_error, RuntimeTrait},
 };
 
 use crate::test::prelude::block_seconds;
 use composable_traits::{
 	fnft::{FinancialNft as FinancialNftT, FinancialNftProtocol},
 	staking::{
-		lock::{Lock, LockConfig},
+		lock::{DurationMultipliers, Lock, LockConfig},
 		ProtocolStaking, RewardConfig,
 		RewardPoolConfiguration::RewardRateBasedIncentive,
 		RewardRate, Stake,
@@ -52,8 +52,14 @@ use self::prelude::init_logger;
 
 pub(crate) mod prelude;
 
+mod test_compound;
+mod test_emission_schedule;
+mod test_gauge;
+mod test_merge;
 mod test_reward_accumulation_hook;
+mod test_slashing;
 mod test_update_reward_pools;
+mod test_vote_escrow;
 
 #[test]
 fn test_create_reward_pool() {
@@ -747,12 +753,7 @@ mod extend {
 					reward_pool_id: STAKED_ASSET::ID,
 					stake: staked_amount + extended_amount,
 					share: Pallet::<Test>::boosted_amount(
-						rewards_pool
-							.lock
-							.duration_multipliers
-							.multiplier(ONE_MINUTE)
-							.copied()
-							.unwrap(),
+						rewards_pool.lock.duration_multipliers.multiplier(ONE_MINUTE).unwrap(),
 						staked_amount + extended_amount
 					)
 					.expect("boosted amount calculation should not fail"),
@@ -863,12 +864,7 @@ mod extend {
 					reward_pool_id: STAKED_ASSET::ID,
 					stake: staked_amount + extended_amount,
 					share: Pallet::<Test>::boosted_amount(
-						rewards_pool
-							.lock
-							.duration_multipliers
-							.multiplier(ONE_MINUTE)
-							.copied()
-							.unwrap(),
+						rewards_pool.lock.duration_multipliers.multiplier(ONE_MINUTE).unwrap(),
 						staked_amount + extended_amount
 					)
 					.expect("boosted amount calculation should not fail"),
@@ -1093,6 +1089,39 @@ fn test_transfer_reward() {
 	});
 }
 
+#[test]
+fn test_ensure_stakeable() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			<StakingRewards as ProtocolStaking>::ensure_stakeable(&PICA::ID, PICA::ID, ONE_HOUR),
+			crate::Error::<Test>::RewardsPoolNotFound
+		);
+
+		assert_ok!(StakingRewards::create_reward_pool(
+			RuntimeOrigin::root(),
+			get_default_reward_pool()
+		));
+		assert_ok!(<StakingRewards as ProtocolStaking>::ensure_stakeable(
+			&PICA::ID,
+			PICA::ID,
+			ONE_HOUR
+		));
+		assert_noop!(
+			<StakingRewards as ProtocolStaking>::ensure_stakeable(&PICA::ID, BTC::ID, ONE_HOUR),
+			crate::Error::<Test>::StakedAssetMismatch
+		);
+		assert_noop!(
+			<StakingRewards as ProtocolStaking>::ensure_stakeable(
+				&PICA::ID,
+				PICA::ID,
+				ONE_HOUR + 1
+			),
+			crate::Error::<Test>::DurationPresetNotFound
+		);
+	});
+}
+
 #[test]
 fn test_split_position() {
 	new_test_ext().execute_with(|| {
@@ -1746,6 +1775,144 @@ fn duration_presets_are_required() {
 	});
 }
 
+#[test]
+fn linear_duration_multipliers_interpolate_between_bounds() {
+	new_test_ext().execute_with(|| {
+		next_block::<StakingRewards, Test>();
+
+		create_rewards_pool_and_assert::<Test>(RewardRateBasedIncentive {
+			owner: ALICE,
+			asset_id: PICA::ID,
+			start_block: 2,
+			reward_configs: default_reward_config(),
+			lock: LockConfig {
+				duration_multipliers: DurationMultipliers::Linear {
+					min: ONE_MINUTE,
+					max: ONE_HOUR,
+					min_multiplier: FixedU64::one().try_into_validated().expect(">= 1"),
+					max_multiplier: FixedU64::from_rational(2, 1)
+						.try_into_validated()
+						.expect(">= 1"),
+				},
+				unlock_penalty: Perbill::from_percent(5),
+			},
+			share_asset_id: XPICA::ID,
+			financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+			minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+		});
+
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		mint_assets([BOB], [PICA::ID], PICA::units(100));
+
+		// halfway between the bounds, not a preset
+		let duration = (ONE_MINUTE + ONE_HOUR) / 2;
+		let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), duration);
+
+		let stake = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+			.expect("stake expected");
+		assert_eq!(stake.lock.duration, duration);
+		assert_eq!(stake.share, PICA::units(15));
+
+		assert_noop!(
+			StakingRewards::stake(
+				RuntimeOrigin::signed(BOB),
+				PICA::ID,
+				PICA::units(10),
+				ONE_HOUR + 1
+			),
+			crate::Error::<Test>::LockDurationOutOfRange
+		);
+		assert_noop!(
+			StakingRewards::stake(
+				RuntimeOrigin::signed(BOB),
+				PICA::ID,
+				PICA::units(10),
+				ONE_MINUTE - 1
+			),
+			crate::Error::<Test>::LockDurationOutOfRange
+		);
+
+		let new_fnft_instance_id = split_and_assert::<Test>(
+			BOB,
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			Permill::from_rational(1_u32, 2_u32)
+				.try_into_validated()
+				.expect("valid split ratio"),
+		);
+		let new_stake = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, new_fnft_instance_id)
+			.expect("stake expected");
+		assert_eq!(new_stake.lock.duration, duration);
+		assert_eq!(new_stake.share, PICA::units(15) / 2);
+	});
+}
+
+#[test]
+fn linear_duration_multipliers_boost_extend_and_claimable_amount() {
+	new_test_ext().execute_with(|| {
+		next_block::<StakingRewards, Test>();
+
+		create_rewards_pool_and_assert::<Test>(RewardRateBasedIncentive {
+			owner: ALICE,
+			asset_id: PICA::ID,
+			start_block: 2,
+			reward_configs: default_reward_config(),
+			lock: LockConfig {
+				duration_multipliers: DurationMultipliers::Linear {
+					min: ONE_MINUTE,
+					max: ONE_HOUR,
+					min_multiplier: FixedU64::one().try_into_validated().expect(">= 1"),
+					max_multiplier: FixedU64::from_rational(2, 1)
+						.try_into_validated()
+						.expect(">= 1"),
+				},
+				unlock_penalty: Perbill::from_percent(5),
+			},
+			share_asset_id: XPICA::ID,
+			financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+			minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+		});
+		mint_assets([ALICE], [USDT::ID], USDT::units(1_001));
+		add_to_rewards_pot_and_assert::<Test>(ALICE, PICA::ID, USDT::ID, USDT::units(1_000), false);
+
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		mint_assets([BOB, CHARLIE], [PICA::ID], PICA::units(100));
+		let duration = (ONE_MINUTE + ONE_HOUR) / 2;
+		let bob_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), duration);
+		let charlie_id = stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(10), ONE_MINUTE);
+
+		// additional stake is boosted by the multiplier of the lock of the position
+		assert_ok!(StakingRewards::extend(
+			RuntimeOrigin::signed(BOB),
+			STAKING_FNFT_COLLECTION_ID,
+			bob_id,
+			PICA::units(10),
+		));
+		let stake =
+			Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, bob_id).expect("stake expected");
+		assert_eq!(stake.stake, PICA::units(20));
+		assert_eq!(stake.share, PICA::units(30));
+
+		let claimable = |fnft_instance_id| {
+			StakingRewards::claimable_amount(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+				.expect("stake expected")[&USDT::ID]
+		};
+		// rewards accrued before the first stake go to the first staker, so only compare what
+		// accrues from now on
+		let (bob_before, charlie_before) = (claimable(bob_id), claimable(charlie_id));
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+
+		let charlie_accrued = claimable(charlie_id) - charlie_before;
+		assert!(charlie_accrued > 0);
+		assert_ok!(default_acceptable_computation_error(
+			claimable(bob_id) - bob_before,
+			3 * charlie_accrued
+		));
+	});
+}
+
 mod stake_proptests {
 	use super::*;
 	use crate::Error;
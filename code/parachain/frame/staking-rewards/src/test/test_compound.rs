@@ -0,0 +1,268 @@
+use composable_tests_helpers::test::{
+	block::{next_block, process_and_progress_blocks, process_and_progress_blocks_with},
+	currency::{PICA, XPICA},
+	helper::RuntimeTrait,
+};
+use composable_traits::{
+	staking::{RewardConfig, RewardPoolConfiguration, RewardRate, RewardUpdate},
+	time::ONE_HOUR,
+};
+use frame_support::{
+	assert_noop, assert_ok, bounded_btree_map,
+	traits::{fungibles::Inspect, Hooks},
+	weights::Weight,
+};
+use sp_runtime::Perbill;
+
+use crate::{
+	runtime::{RuntimeOrigin, StakingRewards, System, Test, Tokens, ALICE, BOB, CHARLIE},
+	test::{
+		create_default_reward_pool, default_lock_config, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{
+		add_to_rewards_pot_and_assert, create_rewards_pool_and_assert, stake_and_assert,
+		unstake_and_assert,
+	},
+	AutoCompound, Error, Stakes,
+};
+
+const STAKE: u128 = PICA::units(10);
+
+/// Creates a PICA pool rewarding PICA and stakes for `BOB` at block 2.
+fn stake_in_compounding_pool() -> u64 {
+	next_block::<StakingRewards, Test>();
+
+	create_rewards_pool_and_assert::<Test>(RewardPoolConfiguration::RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: PICA::ID,
+		start_block: 2,
+		reward_configs: bounded_btree_map! {
+			PICA::ID => RewardConfig { reward_rate: RewardRate::per_second(PICA::units(1)) }
+		},
+		lock: default_lock_config(),
+		share_asset_id: XPICA::ID,
+		financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+		minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+	});
+	mint_assets([ALICE], [PICA::ID], PICA::units(10_000));
+	add_to_rewards_pot_and_assert::<Test>(ALICE, PICA::ID, PICA::ID, PICA::units(10_000), false);
+
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+
+	mint_assets([BOB], [PICA::ID], PICA::units(100));
+	stake_and_assert::<Test>(BOB, PICA::ID, STAKE, ONE_HOUR)
+}
+
+/// Progresses `blocks` blocks, giving each of them `idle_weight` of idle time.
+fn process_and_progress_idle_blocks(blocks: usize, idle_weight: Weight) {
+	process_and_progress_blocks_with::<StakingRewards, Test>(blocks, || {
+		StakingRewards::on_idle(System::block_number(), idle_weight);
+	});
+}
+
+fn staked_amount(fnft_instance_id: u64) -> u128 {
+	Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+		.expect("stake expected")
+		.stake
+}
+
+#[test]
+fn auto_compound_hook_compounds_due_positions() {
+	new_test_ext().execute_with(|| {
+		let fnft_instance_id = stake_in_compounding_pool();
+		let started_at = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+			.expect("stake expected")
+			.lock
+			.started_at;
+
+		Test::assert_extrinsic_event(
+			StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				true,
+			),
+			crate::Event::<Test>::AutoCompoundSet {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				enabled: true,
+			},
+		);
+
+		// due at block 12
+		process_and_progress_idle_blocks(9, Weight::MAX);
+		assert_eq!(staked_amount(fnft_instance_id), STAKE);
+
+		process_and_progress_idle_blocks(1, Weight::MAX);
+		let stake = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+			.expect("stake expected");
+		assert!(stake.stake > STAKE);
+		assert_eq!(stake.lock.started_at, started_at);
+		assert_eq!(
+			AutoCompound::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id),
+			Some(12)
+		);
+		assert!(System::events().iter().any(|record| matches!(
+			record.event,
+			crate::runtime::RuntimeEvent::StakingRewards(crate::Event::Compounded {
+				keeper: None,
+				bounty: 0,
+				..
+			})
+		)));
+
+		unstake_and_assert::<Test>(BOB, STAKING_FNFT_COLLECTION_ID, fnft_instance_id, true);
+		assert_eq!(AutoCompound::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id), None);
+	});
+}
+
+#[test]
+fn keeper_compound_pays_bounty() {
+	new_test_ext().execute_with(|| {
+		let fnft_instance_id = stake_in_compounding_pool();
+
+		assert_noop!(
+			StakingRewards::compound(
+				RuntimeOrigin::signed(CHARLIE),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id
+			),
+			Error::<Test>::AutoCompoundNotEnabled
+		);
+
+		assert_ok!(StakingRewards::set_auto_compound(
+			RuntimeOrigin::signed(BOB),
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			true,
+		));
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+
+		let rewards =
+			StakingRewards::claimable_amount(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+				.expect("claimable amount expected")[&PICA::ID];
+		let bounty = Perbill::from_percent(1).mul_floor(rewards);
+		assert!(bounty > 0);
+		let keeper_balance = Tokens::balance(PICA::ID, &CHARLIE);
+
+		Test::assert_extrinsic_event(
+			StakingRewards::compound(
+				RuntimeOrigin::signed(CHARLIE),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+			),
+			crate::Event::<Test>::Compounded {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				amount: rewards - bounty,
+				keeper: Some(CHARLIE),
+				bounty,
+			},
+		);
+		assert_eq!(Tokens::balance(PICA::ID, &CHARLIE), keeper_balance + bounty);
+		assert_eq!(staked_amount(fnft_instance_id), STAKE + rewards - bounty);
+
+		assert_noop!(
+			StakingRewards::compound(
+				RuntimeOrigin::signed(CHARLIE),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id
+			),
+			Error::<Test>::NothingToCompound
+		);
+	});
+}
+
+#[test]
+fn auto_compound_requires_owner_and_staked_asset_rewards() {
+	new_test_ext().execute_with(|| {
+		next_block::<StakingRewards, Test>();
+		// rewards USDT
+		create_default_reward_pool();
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		mint_assets([BOB], [PICA::ID], PICA::units(100));
+		let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, STAKE, ONE_HOUR);
+
+		assert_noop!(
+			StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(CHARLIE),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				true,
+			),
+			Error::<Test>::OnlyStakeOwnerCanInteractWithStake
+		);
+		assert_noop!(
+			StakingRewards::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				true,
+			),
+			Error::<Test>::AutoCompoundNotSupported
+		);
+	});
+}
+
+#[test]
+fn auto_compound_hook_stays_within_idle_weight() {
+	new_test_ext().execute_with(|| {
+		let fnft_instance_id = stake_in_compounding_pool();
+		assert_ok!(StakingRewards::set_auto_compound(
+			RuntimeOrigin::signed(BOB),
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			true,
+		));
+
+		process_and_progress_idle_blocks(10, Weight::zero());
+		assert_eq!(staked_amount(fnft_instance_id), STAKE);
+
+		process_and_progress_idle_blocks(1, Weight::MAX);
+		assert!(staked_amount(fnft_instance_id) > STAKE);
+	});
+}
+
+#[test]
+fn failed_auto_compound_emits_event_and_waits_a_period() {
+	new_test_ext().execute_with(|| {
+		let fnft_instance_id = stake_in_compounding_pool();
+		assert_ok!(StakingRewards::set_auto_compound(
+			RuntimeOrigin::signed(BOB),
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			true,
+		));
+
+		// nothing left to compound once the keeper compounded and the pool stopped rewarding
+		process_and_progress_idle_blocks(5, Weight::MAX);
+		assert_ok!(StakingRewards::compound(
+			RuntimeOrigin::signed(CHARLIE),
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+		));
+		assert_ok!(StakingRewards::update_rewards_pool(
+			RuntimeOrigin::root(),
+			PICA::ID,
+			bounded_btree_map! {
+				PICA::ID => RewardUpdate { reward_rate: RewardRate::per_second(0_u128) }
+			},
+		));
+		let compounded_at = System::block_number();
+		let stake = staked_amount(fnft_instance_id);
+
+		process_and_progress_idle_blocks(10, Weight::MAX);
+		Test::assert_event(crate::Event::<Test>::AutoCompoundFailed {
+			fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			error: Error::<Test>::NothingToCompound.into(),
+		});
+		assert_eq!(staked_amount(fnft_instance_id), stake);
+		assert_eq!(
+			AutoCompound::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id),
+			Some(compounded_at + 10)
+		);
+	});
+}
@@ -0,0 +1,217 @@
+use composable_tests_helpers::test::{
+	block::process_and_progress_blocks,
+	currency::{PICA, USDT},
+	helper::RuntimeTrait,
+};
+use composable_traits::staking::{EmissionSchedule, RewardRate, RewardRateStepDown, RewardUpdate};
+use frame_support::{assert_noop, assert_ok, bounded_btree_map, traits::fungibles::InspectHold};
+use runtime_api::RewardEmission;
+use sp_runtime::{DispatchError, PerThing, Perbill};
+
+use crate::{
+	emission::scheduled_emission,
+	runtime::{RuntimeOrigin, StakingRewards, Test, Tokens, CHARLIE},
+	test::{create_default_reward_pool, mint_assets, new_test_ext},
+	test_helpers::add_to_rewards_pot_and_assert,
+	EmissionSchedules, Error, NextRateStepDown, RewardPools,
+};
+
+/// Creates the default PICA pool, starting at block 2, with `pot` USDT rewards at 10 per second,
+/// and leaves the chain at block 1.
+fn create_pool_with_pot(pot: u128) {
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+	create_default_reward_pool();
+	mint_assets([CHARLIE], [USDT::ID], 10_000);
+	add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, pot, false);
+}
+
+fn set_emission_schedule(
+	schedule: Option<EmissionSchedule<u64>>,
+) -> frame_support::dispatch::DispatchResult {
+	StakingRewards::set_emission_schedule(RuntimeOrigin::root(), PICA::ID, USDT::ID, schedule)
+}
+
+fn halving(periods: u64) -> Option<RewardRateStepDown> {
+	Some(RewardRateStepDown { periods, ratio: Perbill::from_percent(50) })
+}
+
+/// Total rewards and reward rate amount of the USDT reward of the PICA pool.
+fn usdt_reward() -> (u128, u128) {
+	let pool = RewardPools::<Test>::get(PICA::ID).expect("pool expected");
+	let reward = pool.rewards.get(&USDT::ID).expect("reward expected");
+	(reward.total_rewards, reward.reward_rate.amount)
+}
+
+fn rewards_pot() -> u128 {
+	Tokens::balance_on_hold(USDT::ID, &StakingRewards::pool_account_id(&PICA::ID))
+}
+
+#[test]
+fn emission_ends_at_end_block() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_pot(1_000);
+
+		let schedule = EmissionSchedule { end_block: 12, step_down: None };
+		Test::assert_extrinsic_event(
+			set_emission_schedule(Some(schedule.clone())),
+			crate::Event::<Test>::EmissionScheduleSet {
+				pool_id: PICA::ID,
+				asset_id: USDT::ID,
+				schedule: Some(schedule),
+			},
+		);
+
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+		assert_eq!(usdt_reward(), (540, 10));
+
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+		Test::assert_event(crate::Event::<Test>::RewardEmissionEnded {
+			pool_id: PICA::ID,
+			asset_id: USDT::ID,
+		});
+		// 10 blocks of rewards, accumulated from the start of the pool at block 2
+		assert_eq!(usdt_reward(), (600, 0));
+		assert_eq!(EmissionSchedules::<Test>::get(PICA::ID, USDT::ID), None);
+
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+		assert_eq!(usdt_reward(), (600, 0));
+		assert_eq!(rewards_pot(), 400);
+	});
+}
+
+#[test]
+fn reward_rate_steps_down_until_emission_ends() {
+	new_test_ext().execute_with(|| {
+		create_pool_with_pot(1_000);
+
+		// the rate is halved every minute: 540 before the first step-down, then about 600 over the
+		// 8 full minutes left until block 100 and 1 for the remaining 54 seconds
+		let schedule = EmissionSchedule { end_block: 100, step_down: halving(60) };
+		assert_noop!(
+			set_emission_schedule(Some(schedule.clone())),
+			Error::<Test>::EmissionNotFunded
+		);
+
+		add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, 200, false);
+		assert_ok!(set_emission_schedule(Some(schedule)));
+		assert_eq!(NextRateStepDown::<Test>::get(PICA::ID, USDT::ID), Some(66));
+		assert_eq!(
+			StakingRewards::reward_emission(PICA::ID, USDT::ID),
+			Some(RewardEmission {
+				amount: 10,
+				period: 1,
+				rewards_pot: 1_200,
+				remaining_emission: Some(1_138),
+				end_block: Some(100),
+				next_step_down: Some(66),
+			})
+		);
+
+		process_and_progress_blocks::<StakingRewards, Test>(11);
+		Test::assert_event(crate::Event::<Test>::RewardRateSteppedDown {
+			pool_id: PICA::ID,
+			asset_id: USDT::ID,
+			amount: 5,
+		});
+		assert_eq!(usdt_reward(), (600, 5));
+
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+		assert_eq!(usdt_reward(), (900, 2));
+		assert_eq!(NextRateStepDown::<Test>::get(PICA::ID, USDT::ID), Some(186));
+
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+		assert_eq!(usdt_reward(), (1_080, 0));
+		assert_eq!(EmissionSchedules::<Test>::get(PICA::ID, USDT::ID), None);
+		assert_eq!(NextRateStepDown::<Test>::get(PICA::ID, USDT::ID), None);
+	});
+}
+
+#[test]
+fn emission_schedules_are_validated() {
+	new_test_ext().execute_with(|| {
+		let schedule = EmissionSchedule { end_block: 12, step_down: None };
+
+		assert_noop!(
+			StakingRewards::set_emission_schedule(
+				RuntimeOrigin::signed(CHARLIE),
+				PICA::ID,
+				USDT::ID,
+				Some(schedule.clone())
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			set_emission_schedule(Some(schedule.clone())),
+			Error::<Test>::RewardsPoolNotFound
+		);
+
+		create_pool_with_pot(1_000);
+
+		assert_noop!(
+			StakingRewards::set_emission_schedule(
+				RuntimeOrigin::root(),
+				PICA::ID,
+				PICA::ID,
+				Some(schedule.clone())
+			),
+			Error::<Test>::RewardAssetNotFound
+		);
+		// the pool starts at block 2
+		assert_noop!(
+			set_emission_schedule(Some(EmissionSchedule { end_block: 2, step_down: None })),
+			Error::<Test>::InvalidEmissionEndBlock
+		);
+		// shorter than a block
+		assert_noop!(
+			set_emission_schedule(Some(EmissionSchedule { end_block: 12, step_down: halving(5) })),
+			Error::<Test>::InvalidRewardRateStepDown
+		);
+		assert_noop!(
+			set_emission_schedule(Some(EmissionSchedule {
+				end_block: 12,
+				step_down: Some(RewardRateStepDown { periods: 60, ratio: Perbill::one() }),
+			})),
+			Error::<Test>::InvalidRewardRateStepDown
+		);
+		assert_noop!(
+			set_emission_schedule(Some(EmissionSchedule { end_block: 20, step_down: None })),
+			Error::<Test>::EmissionNotFunded
+		);
+
+		assert_ok!(set_emission_schedule(Some(schedule)));
+
+		// reward rate updates must be covered by the pot as well
+		let reward_updates = bounded_btree_map! {
+			USDT::ID => RewardUpdate { reward_rate: RewardRate::per_second(20_u128) }
+		};
+		assert_noop!(
+			StakingRewards::update_rewards_pool(
+				RuntimeOrigin::root(),
+				PICA::ID,
+				reward_updates.clone()
+			),
+			Error::<Test>::EmissionNotFunded
+		);
+
+		assert_ok!(set_emission_schedule(None));
+		assert_eq!(EmissionSchedules::<Test>::get(PICA::ID, USDT::ID), None);
+		assert_ok!(StakingRewards::update_rewards_pool(
+			RuntimeOrigin::root(),
+			PICA::ID,
+			reward_updates
+		));
+	});
+}
+
+#[test]
+fn scheduled_emission_steps_down_reward_rate() {
+	let halving = RewardRateStepDown { periods: 3, ratio: Perbill::from_percent(50) };
+
+	assert_eq!(scheduled_emission(8, 10, None), 80);
+	// not stepped down before the end
+	assert_eq!(scheduled_emission(8, 10, Some((10, &halving))), 80);
+	// 2 periods at 8, 3 at 4, 3 at 2 and 2 at 1
+	assert_eq!(scheduled_emission(8, 10, Some((2, &halving))), 36);
+	// stepped down right away
+	assert_eq!(scheduled_emission(8, 6, Some((0, &halving))), 18);
+}
@@ -0,0 +1,226 @@
+use composable_tests_helpers::test::{
+	block::{next_block, process_and_progress_blocks},
+	currency::{Currency, BTC, PICA, USDT},
+	helper::RuntimeTrait,
+};
+use composable_traits::{
+	staking::{vote_escrow::GaugeEmission, EmissionSchedule, RewardPoolConfiguration, RewardRate},
+	time::ONE_HOUR,
+};
+use frame_support::{assert_noop, assert_ok, bounded_btree_map, BoundedBTreeMap};
+use sp_runtime::Permill;
+
+use crate::{
+	runtime::{MaxGauges, RuntimeOrigin, StakingRewards, Test, ALICE, BOB, CHARLIE},
+	test::{
+		create_default_reward_pool, default_lock_config, default_reward_config, mint_assets,
+		new_test_ext, prelude::MINIMUM_STAKING_AMOUNT,
+	},
+	test_helpers::{
+		add_to_rewards_pot_and_assert, create_rewards_pool_and_assert, stake_and_assert,
+	},
+	CurrentGaugeEpoch, Error, GaugeWeights, PendingGaugeUpdates, RewardPools,
+};
+
+type XBTC = Currency<2001>;
+const BTC_FNFT_COLLECTION_ID: u128 = 2002;
+
+const EMISSION_RATE: u128 = 100;
+
+/// Creates the default PICA pool and a BTC pool, both rewarding USDT, registers them as gauges
+/// and sets the gauge emission. Leaves the chain at block 2.
+fn create_gauges() {
+	next_block::<StakingRewards, Test>();
+
+	create_default_reward_pool();
+	create_rewards_pool_and_assert::<Test>(RewardPoolConfiguration::RewardRateBasedIncentive {
+		owner: ALICE,
+		asset_id: BTC::ID,
+		start_block: 2,
+		reward_configs: default_reward_config(),
+		lock: default_lock_config(),
+		share_asset_id: XBTC::ID,
+		financial_nft_asset_id: BTC_FNFT_COLLECTION_ID,
+		minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+	});
+
+	for pool_id in [PICA::ID, BTC::ID] {
+		Test::assert_extrinsic_event(
+			StakingRewards::add_gauge(RuntimeOrigin::root(), pool_id),
+			crate::Event::<Test>::GaugeAdded { pool_id },
+		);
+	}
+
+	let emission = GaugeEmission {
+		reward_asset_id: USDT::ID,
+		reward_rate: RewardRate::per_second(EMISSION_RATE),
+	};
+	Test::assert_extrinsic_event(
+		StakingRewards::set_gauge_emission(RuntimeOrigin::root(), Some(emission.clone())),
+		crate::Event::<Test>::GaugeEmissionSet { emission: Some(emission) },
+	);
+
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+}
+
+fn reward_rate_of(pool_id: u128) -> u128 {
+	RewardPools::<Test>::get(pool_id)
+		.and_then(|pool| pool.rewards.get(&USDT::ID).map(|reward| reward.reward_rate.amount))
+		.expect("pool with USDT reward expected")
+}
+
+fn vote(
+	who: sp_core::sr25519::Public,
+	votes: BoundedBTreeMap<u128, Permill, MaxGauges>,
+) -> frame_support::dispatch::DispatchResult {
+	StakingRewards::vote_for_gauges(RuntimeOrigin::signed(who), votes)
+}
+
+#[test]
+fn gauge_votes_split_emission_at_epoch_start() {
+	new_test_ext().execute_with(|| {
+		create_gauges();
+
+		mint_assets([BOB, CHARLIE], [PICA::ID], PICA::units(100));
+		stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_HOUR);
+		stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(10), ONE_HOUR);
+
+		// epoch 0 started at block 1, before the stakes
+		assert_noop!(
+			vote(BOB, bounded_btree_map! { PICA::ID => Permill::from_percent(100) }),
+			Error::<Test>::NoVotingPower
+		);
+
+		// epoch 1 starts at block 100, 600s
+		process_and_progress_blocks::<StakingRewards, Test>(98);
+		assert_eq!(CurrentGaugeEpoch::<Test>::get().map(|epoch| epoch.start_block), Some(100));
+		assert_eq!(reward_rate_of(PICA::ID), 10);
+
+		assert_ok!(vote(BOB, bounded_btree_map! { PICA::ID => Permill::from_percent(100) }));
+		assert_ok!(vote(
+			CHARLIE,
+			bounded_btree_map! {
+				PICA::ID => Permill::from_percent(50),
+				BTC::ID => Permill::from_percent(50),
+			}
+		));
+
+		// epoch 2 starts at block 200
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+		assert_eq!(reward_rate_of(PICA::ID), EMISSION_RATE * 3 / 4);
+		assert_eq!(reward_rate_of(BTC::ID), EMISSION_RATE / 4);
+		assert_eq!(GaugeWeights::<Test>::get(1, PICA::ID), 0);
+	});
+}
+
+#[test]
+fn gauge_votes_are_replaced_within_an_epoch() {
+	new_test_ext().execute_with(|| {
+		create_gauges();
+
+		mint_assets([BOB], [PICA::ID], PICA::units(100));
+		stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(98);
+
+		assert_ok!(vote(BOB, bounded_btree_map! { PICA::ID => Permill::from_percent(100) }));
+		let gauge_weight = GaugeWeights::<Test>::get(1, PICA::ID);
+		assert!(gauge_weight > 0);
+
+		assert_ok!(vote(BOB, bounded_btree_map! { BTC::ID => Permill::from_percent(100) }));
+		assert_eq!(GaugeWeights::<Test>::get(1, PICA::ID), 0);
+		assert_eq!(GaugeWeights::<Test>::get(1, BTC::ID), gauge_weight);
+
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+		assert_eq!(reward_rate_of(PICA::ID), 0);
+		assert_eq!(reward_rate_of(BTC::ID), EMISSION_RATE);
+	});
+}
+
+#[test]
+fn gauge_votes_are_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			vote(BOB, bounded_btree_map! { PICA::ID => Permill::from_percent(100) }),
+			Error::<Test>::GaugeEpochNotStarted
+		);
+
+		create_gauges();
+
+		assert_noop!(
+			StakingRewards::add_gauge(RuntimeOrigin::root(), USDT::ID),
+			Error::<Test>::RewardsPoolNotFound
+		);
+		assert_noop!(
+			StakingRewards::add_gauge(RuntimeOrigin::root(), PICA::ID),
+			Error::<Test>::GaugeAlreadyExists
+		);
+		assert_noop!(
+			StakingRewards::add_gauge(RuntimeOrigin::signed(ALICE), PICA::ID),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_noop!(
+			vote(BOB, bounded_btree_map! { USDT::ID => Permill::from_percent(100) }),
+			Error::<Test>::GaugeNotFound
+		);
+		assert_noop!(
+			vote(
+				BOB,
+				bounded_btree_map! {
+					PICA::ID => Permill::from_percent(60),
+					BTC::ID => Permill::from_percent(60),
+				}
+			),
+			Error::<Test>::GaugeVotesExceedVotingPower
+		);
+
+		Test::assert_extrinsic_event(
+			StakingRewards::remove_gauge(RuntimeOrigin::root(), BTC::ID),
+			crate::Event::<Test>::GaugeRemoved { pool_id: BTC::ID },
+		);
+		assert_noop!(
+			StakingRewards::remove_gauge(RuntimeOrigin::root(), BTC::ID),
+			Error::<Test>::GaugeNotFound
+		);
+	});
+}
+
+#[test]
+fn failed_gauge_updates_are_retried() {
+	new_test_ext().execute_with(|| {
+		create_gauges();
+
+		// funds the current reward rate of 10 per second until the end of the emission, but not
+		// the rate BOB votes for
+		mint_assets([CHARLIE], [USDT::ID], 1_100_000);
+		add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, 100_000, false);
+		assert_ok!(StakingRewards::set_emission_schedule(
+			RuntimeOrigin::root(),
+			PICA::ID,
+			USDT::ID,
+			Some(EmissionSchedule { end_block: 1_000, step_down: None }),
+		));
+
+		mint_assets([BOB], [PICA::ID], PICA::units(100));
+		stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(98);
+		assert_ok!(vote(BOB, bounded_btree_map! { PICA::ID => Permill::from_percent(100) }));
+
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+		Test::assert_event(crate::Event::<Test>::GaugeUpdateFailed {
+			pool_id: PICA::ID,
+			epoch: 1,
+			error: Error::<Test>::EmissionNotFunded.into(),
+		});
+		assert_eq!(reward_rate_of(PICA::ID), 10);
+		assert!(PendingGaugeUpdates::<Test>::contains_key(PICA::ID));
+
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+		assert_eq!(reward_rate_of(PICA::ID), 10);
+
+		add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, 1_000_000, false);
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+		assert_eq!(reward_rate_of(PICA::ID), EMISSION_RATE);
+		assert!(!PendingGaugeUpdates::<Test>::contains_key(PICA::ID));
+	});
+}
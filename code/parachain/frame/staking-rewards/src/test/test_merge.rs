@@ -0,0 +1,176 @@
+use composable_support::validation::TryIntoValidated;
+use composable_tests_helpers::{
+	prop_assert_ok,
+	test::{
+		block::{next_block, process_and_progress_blocks},
+		currency::{PICA, USDT},
+		helper::RuntimeTrait,
+	},
+};
+use composable_traits::{
+	staking::lock::Lock,
+	time::{ONE_HOUR, ONE_MINUTE},
+};
+use frame_support::{assert_noop, traits::tokens::nonfungibles::Inspect};
+use proptest::prelude::*;
+use sp_arithmetic::{Perbill, Permill};
+
+use crate::{
+	runtime::{FinancialNft, RuntimeOrigin, StakingRewards, Test, BOB, CHARLIE},
+	test::{
+		create_default_reward_pool, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{add_to_rewards_pot_and_assert, split_and_assert, stake_and_assert},
+	Error, Stakes,
+};
+
+/// Creates the default PICA pool with funded USDT rewards and leaves the chain at block 2.
+fn create_funded_pool() {
+	next_block::<StakingRewards, Test>();
+	create_default_reward_pool();
+	mint_assets([CHARLIE], [USDT::ID], USDT::units(1_000));
+	add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, USDT::units(1_000), false);
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+	mint_assets([BOB], [PICA::ID], PICA::units(1_000));
+}
+
+fn merge(
+	fnft_instance_id: u64,
+	merged_fnft_instance_id: u64,
+) -> frame_support::dispatch::DispatchResult {
+	StakingRewards::merge(
+		RuntimeOrigin::signed(BOB),
+		STAKING_FNFT_COLLECTION_ID,
+		fnft_instance_id,
+		merged_fnft_instance_id,
+	)
+}
+
+fn claimable(fnft_instance_id: u64) -> u128 {
+	StakingRewards::claimable_amount(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+		.expect("claimable amount expected")
+		.get(&USDT::ID)
+		.copied()
+		.unwrap_or_default()
+}
+
+#[test]
+fn merge_combines_positions() {
+	new_test_ext().execute_with(|| {
+		create_funded_pool();
+
+		let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_MINUTE);
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+		let merged_fnft_instance_id =
+			stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(30), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+
+		let position = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+			.expect("stake expected");
+		let merged_position =
+			Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, merged_fnft_instance_id)
+				.expect("stake expected");
+		let claimable_before = claimable(fnft_instance_id) + claimable(merged_fnft_instance_id);
+
+		Test::assert_extrinsic_event(
+			merge(fnft_instance_id, merged_fnft_instance_id),
+			crate::Event::<Test>::MergedPositions {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				merged_fnft_instance_id,
+				stake: PICA::units(40),
+			},
+		);
+
+		let result = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+			.expect("stake expected");
+		assert_eq!(result.stake, position.stake + merged_position.stake);
+		assert_eq!(result.share, position.share + merged_position.share);
+		// the ONE_HOUR lock of the second position ends later
+		assert_eq!(result.lock, merged_position.lock);
+		// floor(a) + floor(b) <= floor(a + b) <= floor(a) + floor(b) + 1
+		let claimable_after = claimable(fnft_instance_id);
+		assert!(claimable_after >= claimable_before && claimable_after <= claimable_before + 1);
+
+		assert_eq!(Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, merged_fnft_instance_id), None);
+		assert_eq!(
+			FinancialNft::owner(&STAKING_FNFT_COLLECTION_ID, &merged_fnft_instance_id),
+			None
+		);
+	});
+}
+
+#[test]
+fn merged_lock_weights_unlock_penalty_by_stake() {
+	let lock = Lock { started_at: 0, duration: 100, unlock_penalty: Perbill::from_percent(10) };
+	let other_lock =
+		Lock { started_at: 50, duration: 100, unlock_penalty: Perbill::from_percent(20) };
+
+	let merged_lock = StakingRewards::merged_lock(&lock, 100, &other_lock, 300);
+
+	assert_eq!(merged_lock.started_at, 50);
+	assert_eq!(merged_lock.duration, 100);
+	// (10 + 60) / 400
+	assert_eq!(merged_lock.unlock_penalty, Perbill::from_rational(175_u32, 1_000));
+}
+
+#[test]
+fn merge_requires_two_owned_positions() {
+	new_test_ext().execute_with(|| {
+		create_funded_pool();
+		mint_assets([CHARLIE], [PICA::ID], PICA::units(1_000));
+
+		let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_HOUR);
+		let other_fnft_instance_id =
+			stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(10), ONE_HOUR);
+
+		assert_noop!(
+			merge(fnft_instance_id, fnft_instance_id),
+			Error::<Test>::CannotMergePositionWithItself
+		);
+		assert_noop!(
+			merge(fnft_instance_id, other_fnft_instance_id),
+			Error::<Test>::OnlyStakeOwnerCanInteractWithStake
+		);
+		assert_noop!(merge(fnft_instance_id, 42), Error::<Test>::FnftNotFound);
+	});
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+
+	#[test]
+	fn split_then_merge_preserves_claimable_amounts(
+		parts in MINIMUM_STAKING_AMOUNT..MINIMUM_STAKING_AMOUNT*99,
+		blocks in 1_usize..20,
+	) {
+		new_test_ext().execute_with(|| {
+			let ratio = Permill::from_rational(parts, MINIMUM_STAKING_AMOUNT*100);
+			create_funded_pool();
+
+			let fnft_instance_id =
+				stake_and_assert::<Test>(BOB, PICA::ID, 100 * MINIMUM_STAKING_AMOUNT, ONE_HOUR);
+			process_and_progress_blocks::<StakingRewards, Test>(blocks);
+
+			let position = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+				.expect("stake expected");
+			let claimable_before = claimable(fnft_instance_id);
+
+			let new_fnft_instance_id = split_and_assert::<Test>(
+				BOB,
+				STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id,
+				ratio.try_into_validated().expect("valid split ratio"),
+			);
+			prop_assert_ok!(merge(fnft_instance_id, new_fnft_instance_id));
+
+			let merged_position = Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+				.expect("stake expected");
+			prop_assert_eq!(merged_position, position);
+			prop_assert_eq!(claimable(fnft_instance_id), claimable_before);
+
+			Ok(())
+		})?;
+	}
+}
@@ -0,0 +1,336 @@
+use composable_tests_helpers::{
+	prop_assert_ok,
+	test::{
+		block::{next_block, process_and_progress_blocks},
+		currency::{PICA, USDT, XPICA},
+		helper::RuntimeTrait,
+	},
+};
+use composable_traits::{
+	fnft::FinancialNft as FinancialNftT,
+	staking::{vote_escrow::VotingPower, ProtocolStaking},
+	time::ONE_HOUR,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungibles::Inspect, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use proptest::prelude::*;
+use sp_arithmetic::fixed_point::{FixedPointNumber, FixedU128};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{One, Saturating, Zero},
+	Perbill, Rounding,
+};
+
+use crate::{
+	runtime::{FinancialNft, RuntimeOrigin, StakingRewards, Test, Tokens, BOB, CHARLIE, DAVE},
+	test::{
+		balance, create_default_reward_pool, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{add_to_rewards_pot_and_assert, stake_and_assert},
+	Error, PoolSlashFactors, SlashCheckpoints, SlashPayouts, Stakes, TotalStakes,
+};
+
+/// As configured in the test runtime.
+const EXISTENTIAL_DEPOSIT: u128 = 5;
+
+/// Creates the default PICA pool with funded USDT rewards and leaves the chain at block 2.
+fn create_funded_pool() {
+	next_block::<StakingRewards, Test>();
+	create_default_reward_pool();
+	mint_assets([BOB, CHARLIE], [USDT::ID], USDT::units(1_000));
+	add_to_rewards_pot_and_assert::<Test>(CHARLIE, PICA::ID, USDT::ID, USDT::units(1_000), false);
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+	mint_assets([BOB, CHARLIE], [PICA::ID], PICA::units(1_000));
+}
+
+fn slash(ratio: Perbill) -> frame_support::dispatch::DispatchResult {
+	<StakingRewards as ProtocolStaking>::slash(&PICA::ID, ratio, &DAVE)
+}
+
+fn settle_slashes(fnft_instance_id: u64) -> frame_support::dispatch::DispatchResult {
+	StakingRewards::settle_slashes(
+		RuntimeOrigin::signed(DAVE),
+		STAKING_FNFT_COLLECTION_ID,
+		fnft_instance_id,
+	)
+}
+
+fn withdraw_slashed() -> frame_support::dispatch::DispatchResult {
+	StakingRewards::withdraw_slashed(RuntimeOrigin::signed(DAVE), PICA::ID)
+}
+
+fn slash_account_balance() -> u128 {
+	balance(PICA::ID, &StakingRewards::slash_account_id(&PICA::ID))
+}
+
+fn stake_of(fnft_instance_id: u64) -> (u128, u128) {
+	let stake =
+		Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id).expect("stake expected");
+	(stake.stake, stake.share)
+}
+
+fn claimable(fnft_instance_id: u64) -> u128 {
+	StakingRewards::claimable_amount(STAKING_FNFT_COLLECTION_ID, fnft_instance_id)
+		.expect("claimable amount expected")
+		.get(&USDT::ID)
+		.copied()
+		.unwrap_or_default()
+}
+
+fn assert_claimable_near(fnft_instance_id: u64, expected: u128) {
+	let claimable = claimable(fnft_instance_id);
+	assert!(claimable.abs_diff(expected) <= 2, "claimable {claimable}, expected {expected}");
+}
+
+/// The staked and share assets held by the fNFT of a position are all locked and match the
+/// position.
+fn assert_position_assets(fnft_instance_id: u64) {
+	let (stake, share) = stake_of(fnft_instance_id);
+	let fnft_asset_account =
+		FinancialNft::asset_account(&STAKING_FNFT_COLLECTION_ID, &fnft_instance_id);
+
+	assert_eq!(balance(PICA::ID, &fnft_asset_account), stake);
+	assert_eq!(balance(XPICA::ID, &fnft_asset_account), share);
+	assert_eq!(Tokens::reducible_balance(PICA::ID, &fnft_asset_account, false), 0);
+	assert_eq!(Tokens::reducible_balance(XPICA::ID, &fnft_asset_account, false), 0);
+}
+
+#[test]
+fn slashes_are_applied_when_positions_are_settled() {
+	new_test_ext().execute_with(|| {
+		create_funded_pool();
+
+		let bob_fnft_instance_id =
+			stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(100), ONE_HOUR);
+		let charlie_fnft_instance_id =
+			stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(300), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(5);
+
+		let (_, bob_share) = stake_of(bob_fnft_instance_id);
+		let bob_claimable = claimable(bob_fnft_instance_id);
+		let total_shares = Tokens::total_issuance(XPICA::ID);
+
+		Test::assert_extrinsic_event(
+			slash(Perbill::from_percent(10)),
+			crate::Event::<Test>::PoolSlashed {
+				pool_id: PICA::ID,
+				ratio: Perbill::from_percent(10),
+				beneficiary: DAVE,
+				stake: PICA::units(40),
+			},
+		);
+
+		// the totals of the pool are slashed immediately, positions only once settled
+		assert_eq!(TotalStakes::<Test>::get(PICA::ID), PICA::units(360));
+		assert_eq!(SlashPayouts::<Test>::get(PICA::ID, DAVE), PICA::units(40));
+		assert_eq!(stake_of(bob_fnft_instance_id), (PICA::units(100), bob_share));
+		assert_claimable_near(bob_fnft_instance_id, Perbill::from_percent(90) * bob_claimable);
+		assert_noop!(withdraw_slashed(), Error::<Test>::SlashedStakeNotSettled);
+
+		Test::assert_extrinsic_event(
+			settle_slashes(bob_fnft_instance_id),
+			crate::Event::<Test>::PositionSlashed {
+				fnft_collection_id: STAKING_FNFT_COLLECTION_ID,
+				fnft_instance_id: bob_fnft_instance_id,
+				stake: PICA::units(10),
+			},
+		);
+		assert_eq!(
+			stake_of(bob_fnft_instance_id),
+			(PICA::units(90), bob_share - Perbill::from_percent(10).mul_floor(bob_share))
+		);
+		assert_eq!(slash_account_balance(), PICA::units(10));
+		assert_claimable_near(bob_fnft_instance_id, Perbill::from_percent(90) * bob_claimable);
+		assert_position_assets(bob_fnft_instance_id);
+		// slashed shares are returned to the pool account rather than burned
+		assert_eq!(Tokens::total_issuance(XPICA::ID), total_shares);
+		assert_noop!(settle_slashes(bob_fnft_instance_id), Error::<Test>::NoSlashesToSettle);
+
+		// beneficiaries withdraw what settled positions paid
+		Test::assert_extrinsic_event(
+			withdraw_slashed(),
+			crate::Event::<Test>::SlashWithdrawn {
+				pool_id: PICA::ID,
+				beneficiary: DAVE,
+				amount: PICA::units(10),
+			},
+		);
+		assert_eq!(balance(PICA::ID, &DAVE), PICA::units(10));
+		assert_eq!(SlashPayouts::<Test>::get(PICA::ID, DAVE), PICA::units(30));
+
+		// any operation on a position settles it first
+		assert_ok!(StakingRewards::claim(
+			RuntimeOrigin::signed(CHARLIE),
+			STAKING_FNFT_COLLECTION_ID,
+			charlie_fnft_instance_id
+		));
+		assert_eq!(stake_of(charlie_fnft_instance_id).0, PICA::units(270));
+		assert_position_assets(charlie_fnft_instance_id);
+		assert_ok!(withdraw_slashed());
+		assert_eq!(balance(PICA::ID, &DAVE), PICA::units(40));
+		assert!(!SlashPayouts::<Test>::contains_key(PICA::ID, DAVE));
+		assert_noop!(withdraw_slashed(), Error::<Test>::NoSlashedStakeOwed);
+
+		// positions created after a slash are not affected by it
+		let new_fnft_instance_id =
+			stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(100), ONE_HOUR);
+		assert_eq!(
+			SlashCheckpoints::<Test>::get(STAKING_FNFT_COLLECTION_ID, new_fnft_instance_id),
+			PoolSlashFactors::<Test>::get(PICA::ID)
+		);
+		assert_noop!(settle_slashes(new_fnft_instance_id), Error::<Test>::NoSlashesToSettle);
+		assert_eq!(TotalStakes::<Test>::get(PICA::ID), PICA::units(460));
+	});
+}
+
+#[test]
+fn slashes_reduce_voting_power_immediately() {
+	new_test_ext().execute_with(|| {
+		create_funded_pool();
+
+		let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(100), ONE_HOUR);
+		process_and_progress_blocks::<StakingRewards, Test>(1);
+
+		let voting_power = StakingRewards::voting_power(&BOB);
+		let voting_power_before_slash = StakingRewards::voting_power_at(&BOB, 2);
+		assert!(voting_power > 0);
+
+		assert_ok!(slash(Perbill::from_percent(50)));
+
+		assert_eq!(StakingRewards::voting_power(&BOB), voting_power / 2);
+		assert_eq!(StakingRewards::total_voting_power(), voting_power / 2);
+		assert_eq!(StakingRewards::voting_power_at(&BOB, 2), voting_power_before_slash);
+
+		// settling only changes voting power by the rounding of the share
+		assert_ok!(settle_slashes(fnft_instance_id));
+		let span = u128::from(ONE_HOUR);
+		assert!(StakingRewards::voting_power(&BOB).abs_diff(voting_power / 2) <= span);
+		assert_eq!(StakingRewards::total_voting_power(), StakingRewards::voting_power(&BOB));
+	});
+}
+
+#[test]
+fn slashes_are_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(slash(Perbill::from_percent(10)), Error::<Test>::RewardsPoolNotFound);
+
+		create_funded_pool();
+
+		assert_noop!(slash(Perbill::zero()), Error::<Test>::InvalidSlashRatio);
+		assert_noop!(slash(Perbill::one()), Error::<Test>::InvalidSlashRatio);
+		assert_noop!(settle_slashes(0), Error::<Test>::StakeNotFound);
+		assert_noop!(withdraw_slashed(), Error::<Test>::NoSlashedStakeOwed);
+
+		// the number of slashes is unbounded, only a slash factor too small to track is rejected
+		for _ in 0..100 {
+			assert_ok!(slash(Perbill::from_percent(1)));
+		}
+		assert_ok!(slash(Perbill::from_parts(999_999_999)));
+		assert_noop!(slash(Perbill::from_parts(999_999_999)), Error::<Test>::TooManySlashes);
+	});
+}
+
+#[test]
+fn migration_to_v2_initializes_total_stakes_from_positions() {
+	new_test_ext().execute_with(|| {
+		create_funded_pool();
+		stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(100), ONE_HOUR);
+		stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(300), ONE_HOUR);
+
+		// the positions predate the tracking of the total stake
+		TotalStakes::<Test>::remove(PICA::ID);
+		StorageVersion::new(1).put::<StakingRewards>();
+
+		crate::migrations::v2::MigrateToV2::<Test>::on_runtime_upgrade();
+		assert_eq!(StakingRewards::on_chain_storage_version(), 2);
+		assert_eq!(TotalStakes::<Test>::get(PICA::ID), PICA::units(400));
+	});
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+
+	#[test]
+	fn slashes_move_stake_to_beneficiary(
+		amounts in prop::collection::vec(MINIMUM_STAKING_AMOUNT..PICA::units(100), 1..4),
+		ratios in prop::collection::vec(1_u32..1_000_000_000, 1..5),
+	) {
+		new_test_ext().execute_with(|| {
+			create_funded_pool();
+			// withdrawals below the existential deposit would not create the account
+			mint_assets([DAVE], [PICA::ID], EXISTENTIAL_DEPOSIT);
+
+			let fnft_instance_ids = amounts
+				.iter()
+				.map(|amount| stake_and_assert::<Test>(BOB, PICA::ID, *amount, ONE_HOUR))
+				.collect::<Vec<_>>();
+			process_and_progress_blocks::<StakingRewards, Test>(2);
+			let total_shares = Tokens::total_issuance(XPICA::ID);
+
+			let mut slash_factor = FixedU128::one();
+			let mut slashes = 0_u128;
+			for ratio in &ratios {
+				let left = FixedU128::from_rational(
+					(1_000_000_000 - *ratio).into(),
+					1_000_000_000,
+				);
+				if slash_factor.saturating_mul(left).is_zero() {
+					prop_assert_eq!(
+						slash(Perbill::from_parts(*ratio)),
+						Err(Error::<Test>::TooManySlashes.into())
+					);
+				} else {
+					prop_assert_ok!(slash(Perbill::from_parts(*ratio)));
+					slash_factor = slash_factor.saturating_mul(left);
+					slashes += 1;
+				}
+			}
+
+			for (fnft_instance_id, amount) in fnft_instance_ids.iter().zip(&amounts) {
+				// settled through a claim, which must not fail on the reduced position
+				prop_assert_ok!(StakingRewards::claim(
+					RuntimeOrigin::signed(BOB),
+					STAKING_FNFT_COLLECTION_ID,
+					*fnft_instance_id
+				));
+
+				let remaining = multiply_by_rational_with_rounding(
+					*amount,
+					slash_factor.into_inner(),
+					FixedU128::DIV,
+					Rounding::Up,
+				)
+				.expect("remaining stake expected");
+				let expected = match amount - remaining {
+					slashed if slashed < EXISTENTIAL_DEPOSIT => *amount,
+					_ if remaining < EXISTENTIAL_DEPOSIT => 0,
+					_ => remaining,
+				};
+				prop_assert_eq!(stake_of(*fnft_instance_id).0, expected);
+				assert_position_assets(*fnft_instance_id);
+			}
+
+			let staked = fnft_instance_ids
+				.iter()
+				.map(|fnft_instance_id| stake_of(*fnft_instance_id).0)
+				.sum::<u128>();
+			// the total stake was slashed up front, only rounding separates it from the positions
+			let tolerance = slashes + (EXISTENTIAL_DEPOSIT + 1) * amounts.len() as u128;
+			prop_assert!(TotalStakes::<Test>::get(PICA::ID).abs_diff(staked) <= tolerance);
+
+			if SlashPayouts::<Test>::get(PICA::ID, DAVE) > 0 && slash_account_balance() > 0 {
+				prop_assert_ok!(withdraw_slashed());
+			}
+			prop_assert_eq!(
+				staked + slash_account_balance() + balance(PICA::ID, &DAVE) - EXISTENTIAL_DEPOSIT,
+				amounts.iter().sum::<u128>()
+			);
+			prop_assert_eq!(Tokens::total_issuance(XPICA::ID), total_shares);
+
+			Ok(())
+		})?;
+	}
+}
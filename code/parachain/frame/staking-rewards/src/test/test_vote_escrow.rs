@@ -0,0 +1,197 @@
+use composable_support::validation::TryIntoValidated;
+use composable_tests_helpers::test::{
+	block::{next_block, process_and_progress_blocks},
+	currency::{PICA, XPICA},
+};
+use composable_traits::{
+	staking::{
+		lock::LockConfig, vote_escrow::VotingPower,
+		RewardPoolConfiguration::RewardRateBasedIncentive,
+	},
+	time::ONE_HOUR,
+};
+use frame_support::{
+	assert_err, assert_noop, assert_ok, bounded_btree_map,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_arithmetic::{fixed_point::FixedU64, Perbill};
+
+use crate::{
+	runtime::{
+		FinancialNft, MaxVoteEscrowEpochs, RuntimeOrigin, StakingRewards, Test, VoteEscrowEpoch,
+		ALICE, BOB, CHARLIE,
+	},
+	test::{
+		create_default_reward_pool, default_reward_config, mint_assets, new_test_ext,
+		prelude::{MINIMUM_STAKING_AMOUNT, STAKING_FNFT_COLLECTION_ID},
+	},
+	test_helpers::{stake_and_assert, unstake_and_assert},
+	Stakes, TotalVotingPowerCheckpointCount, TotalVotingPowerCheckpoints,
+	TotalVotingPowerSlopeChanges, VotingPowerCheckpointCount, VotingPowerCheckpoints,
+	VotingPowerSlopeChanges,
+};
+
+/// Seconds between the start of a stake made at block 2 and the end of its vote lock.
+///
+/// The stake starts at 12s and the `ONE_HOUR` lock ends at 3612s, which is rounded down to the
+/// `ONE_MINUTE` vote escrow epoch.
+const VOTE_LOCK_SPAN: u128 = (ONE_HOUR - 12) as u128;
+const VOTE_LOCK_END: u128 = ONE_HOUR as u128;
+
+/// Creates the default PICA pool and stakes for `BOB` at block 2, returning the slope of the
+/// resulting vote lock.
+fn stake_for_voting_power() -> (u64, u128) {
+	next_block::<StakingRewards, Test>();
+	create_default_reward_pool();
+	process_and_progress_blocks::<StakingRewards, Test>(1);
+
+	mint_assets([BOB], [PICA::ID], PICA::units(100));
+	let fnft_instance_id = stake_and_assert::<Test>(BOB, PICA::ID, PICA::units(10), ONE_HOUR);
+
+	let stake =
+		Stakes::<Test>::get(STAKING_FNFT_COLLECTION_ID, fnft_instance_id).expect("stake expected");
+
+	(fnft_instance_id, stake.share / VOTE_LOCK_SPAN)
+}
+
+#[test]
+fn voting_power_decays_linearly_to_lock_expiry() {
+	new_test_ext().execute_with(|| {
+		let (_, slope) = stake_for_voting_power();
+
+		assert_eq!(StakingRewards::voting_power(&BOB), slope * VOTE_LOCK_SPAN);
+		assert_eq!(StakingRewards::total_voting_power(), slope * VOTE_LOCK_SPAN);
+
+		// block 102, 612s
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+		assert_eq!(StakingRewards::voting_power(&BOB), slope * (VOTE_LOCK_END - 612));
+
+		// expires at 3600s, block 600
+		process_and_progress_blocks::<StakingRewards, Test>(498);
+		assert_eq!(StakingRewards::voting_power(&BOB), 0);
+		assert_eq!(StakingRewards::total_voting_power(), 0);
+	});
+}
+
+#[test]
+fn voting_power_history_is_queryable() {
+	new_test_ext().execute_with(|| {
+		let (_, slope) = stake_for_voting_power();
+
+		process_and_progress_blocks::<StakingRewards, Test>(100);
+
+		assert_eq!(StakingRewards::voting_power_at(&BOB, 1), Ok(0));
+		assert_eq!(StakingRewards::voting_power_at(&BOB, 2), Ok(slope * VOTE_LOCK_SPAN));
+		// block 52, 312s
+		assert_eq!(StakingRewards::voting_power_at(&BOB, 52), Ok(slope * (VOTE_LOCK_END - 312)));
+		assert_eq!(StakingRewards::total_voting_power_at(52), Ok(slope * (VOTE_LOCK_END - 312)));
+
+		assert_err!(
+			StakingRewards::voting_power_at(&BOB, 103),
+			crate::Error::<Test>::BlockNumberInFuture
+		);
+	});
+}
+
+#[test]
+fn voting_power_follows_fnft_owner() {
+	new_test_ext().execute_with(|| {
+		let (fnft_instance_id, slope) = stake_for_voting_power();
+
+		// block 12, 72s
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+		assert_ok!(FinancialNft::transfer(
+			RuntimeOrigin::signed(BOB),
+			STAKING_FNFT_COLLECTION_ID,
+			fnft_instance_id,
+			CHARLIE,
+		));
+
+		assert_eq!(StakingRewards::voting_power(&BOB), 0);
+		assert_eq!(StakingRewards::voting_power(&CHARLIE), slope * (VOTE_LOCK_END - 72));
+		assert_eq!(StakingRewards::total_voting_power(), slope * (VOTE_LOCK_END - 72));
+
+		process_and_progress_blocks::<StakingRewards, Test>(10);
+		assert_eq!(StakingRewards::voting_power_at(&BOB, 11), Ok(slope * (VOTE_LOCK_END - 66)));
+		assert_eq!(StakingRewards::voting_power_at(&CHARLIE, 11), Ok(0));
+
+		unstake_and_assert::<Test>(CHARLIE, STAKING_FNFT_COLLECTION_ID, fnft_instance_id, true);
+
+		assert_eq!(StakingRewards::voting_power(&CHARLIE), 0);
+		assert_eq!(StakingRewards::total_voting_power(), 0);
+		assert_eq!(
+			StakingRewards::voting_power_at(&CHARLIE, 21),
+			Ok(slope * (VOTE_LOCK_END - 126))
+		);
+	});
+}
+
+#[test]
+fn voting_power_pool_rejects_locks_longer_than_max_vote_escrow_epochs() {
+	new_test_ext().execute_with(|| {
+		next_block::<StakingRewards, Test>();
+
+		let max_duration = VoteEscrowEpoch::get() * u64::from(MaxVoteEscrowEpochs::get());
+		let pool_with_max_duration = |max_duration| RewardRateBasedIncentive {
+			owner: ALICE,
+			asset_id: PICA::ID,
+			start_block: 2,
+			reward_configs: default_reward_config(),
+			lock: LockConfig {
+				duration_multipliers: bounded_btree_map! {
+					max_duration => FixedU64::from_rational(2, 1).try_into_validated().expect(">= 1"),
+				}
+				.into(),
+				unlock_penalty: Perbill::from_percent(5),
+			},
+			share_asset_id: XPICA::ID,
+			financial_nft_asset_id: STAKING_FNFT_COLLECTION_ID,
+			minimum_staking_amount: MINIMUM_STAKING_AMOUNT,
+		};
+
+		assert_noop!(
+			StakingRewards::create_reward_pool(
+				RuntimeOrigin::root(),
+				pool_with_max_duration(max_duration + 1)
+			),
+			crate::Error::<Test>::VoteEscrowLockTooLong
+		);
+		assert_ok!(StakingRewards::create_reward_pool(
+			RuntimeOrigin::root(),
+			pool_with_max_duration(max_duration)
+		));
+	});
+}
+
+#[test]
+fn migration_to_v1_checkpoints_positions_staked_before_voting_power() {
+	new_test_ext().execute_with(|| {
+		let (bob_fnft_instance_id, _) = stake_for_voting_power();
+		mint_assets([CHARLIE], [PICA::ID], PICA::units(100));
+		stake_and_assert::<Test>(CHARLIE, PICA::ID, PICA::units(20), ONE_HOUR);
+		let bob_voting_power = StakingRewards::voting_power(&BOB);
+		let charlie_voting_power = StakingRewards::voting_power(&CHARLIE);
+		assert_eq!(StakingRewards::total_voting_power(), bob_voting_power + charlie_voting_power);
+
+		// the positions predate the tracking of voting power
+		let _ = VotingPowerCheckpoints::<Test>::clear(u32::MAX, None);
+		let _ = VotingPowerCheckpointCount::<Test>::clear(u32::MAX, None);
+		let _ = VotingPowerSlopeChanges::<Test>::clear(u32::MAX, None);
+		let _ = TotalVotingPowerCheckpoints::<Test>::clear(u32::MAX, None);
+		let _ = TotalVotingPowerSlopeChanges::<Test>::clear(u32::MAX, None);
+		TotalVotingPowerCheckpointCount::<Test>::kill();
+		StorageVersion::new(0).put::<StakingRewards>();
+		assert_eq!(StakingRewards::total_voting_power(), 0);
+
+		crate::migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(StakingRewards::on_chain_storage_version(), 1);
+		assert_eq!(StakingRewards::voting_power(&BOB), bob_voting_power);
+		assert_eq!(StakingRewards::voting_power(&CHARLIE), charlie_voting_power);
+		assert_eq!(StakingRewards::total_voting_power(), bob_voting_power + charlie_voting_power);
+
+		// unstaking removes the lock added by the migration, leaving the others untouched
+		unstake_and_assert::<Test>(BOB, STAKING_FNFT_COLLECTION_ID, bob_fnft_instance_id, true);
+		assert_eq!(StakingRewards::voting_power(&BOB), 0);
+		assert_eq!(StakingRewards::total_voting_power(), charlie_voting_power);
+	});
+}
@@ -0,0 +1,404 @@
+//! Vote-escrowed voting power of staking positions.
+//!
+//! Each position in the [`Config::VotingPowerPoolId`] pool grants its fNFT owner voting power
+//! equal to its shares at the start of the lock, decaying linearly to zero at the end of the lock
+//! (rounded down to a multiple of [`Config::VoteEscrowEpoch`]). Per-account and total voting power
+//! are stored as checkpoints, together with the slope removed at every epoch boundary where locks
+//! expire, so that the power at any past block can be recomputed from storage.
+//!
+//! Slashing the pool scales every position alike, so voting power is stored without the slashes
+//! of the pool and scaled by its slash factor at the time it is read. This applies a slash to the
+//! voting power of every account and to the total at once, before positions are settled.
+
+use crate::{
+	prelude::*, AccountIdOf, BalanceOf, Config, Error, Pallet, StakeOf,
+	TotalVotingPowerCheckpointCount, TotalVotingPowerCheckpoints, TotalVotingPowerSlopeChanges,
+	VotingPowerCheckpointCount, VotingPowerCheckpointOf, VotingPowerCheckpoints,
+	VotingPowerSlashCount, VotingPowerSlashes, VotingPowerSlopeChanges, WeightInfo,
+};
+use composable_traits::{
+	fnft::FinancialNftTransferHook,
+	staking::vote_escrow::{VotingPower, VotingPowerCheckpoint},
+	time::{DurationSeconds, Timestamp},
+};
+use frame_support::traits::{tokens::nonfungibles::Inspect as NonFungiblesInspect, UnixTime};
+use sp_arithmetic::fixed_point::{FixedPointNumber, FixedU128};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{Saturating, UniqueSaturatedInto},
+	Rounding,
+};
+
+/// Contribution of a single position to its owner's voting power.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub(crate) struct VoteLock<Balance> {
+	/// Voting power lost per second.
+	slope: Balance,
+	/// Epoch boundary at which the voting power reaches zero.
+	end: Timestamp,
+}
+
+impl<T: Config> Pallet<T> {
+	/// The vote lock of `stake`, if it grants any voting power, without the slashes of the pool
+	/// up to `slash_checkpoint`.
+	pub(crate) fn vote_lock(
+		stake: &StakeOf<T>,
+		slash_checkpoint: FixedU128,
+	) -> Option<VoteLock<BalanceOf<T>>> {
+		if stake.reward_pool_id != T::VotingPowerPoolId::get() {
+			return None
+		}
+
+		// pool creation rejects longer locks, capped so that `advance` stays bounded regardless
+		let duration = stake.lock.duration.min(Self::max_vote_escrow_duration());
+		let end = Self::epoch_start(stake.lock.started_at.saturating_add(duration));
+		let span = end.checked_sub(stake.lock.started_at).filter(|span| *span > 0)?;
+		let share = multiply_by_rational_with_rounding(
+			stake.share.into(),
+			FixedU128::DIV,
+			slash_checkpoint.into_inner(),
+			Rounding::Down,
+		)?;
+		let slope = share.checked_div(u128::from(span))?;
+
+		(slope > 0).then(|| VoteLock { slope: slope.into(), end })
+	}
+
+	/// Moves the voting power of the owner of a settled position from `previous` to `current`.
+	pub(crate) fn update_voting_power(
+		fnft_collection_id: &T::AssetId,
+		fnft_instance_id: &T::FinancialNftInstanceId,
+		previous: Option<&StakeOf<T>>,
+		current: Option<&StakeOf<T>>,
+	) {
+		let vote_lock =
+			|stake: &StakeOf<T>| Self::vote_lock(stake, Self::slash_factor(&stake.reward_pool_id));
+		if let Some(owner) = T::FinancialNft::owner(fnft_collection_id, fnft_instance_id) {
+			Self::checkpoint_voting_power(
+				&owner,
+				previous.and_then(vote_lock),
+				current.and_then(vote_lock),
+			);
+		}
+	}
+
+	/// Records the slash factor of the voting power pool after a slash at the current block.
+	pub(crate) fn checkpoint_voting_power_slash(slash_factor: FixedU128) {
+		let block = frame_system::Pallet::<T>::block_number();
+		let count = VotingPowerSlashCount::<T>::get();
+		// several slashes in the same block overwrite each other, like checkpoints
+		let index = match count.checked_sub(1).and_then(VotingPowerSlashes::<T>::get) {
+			Some((last_block, _)) if last_block == block => count.saturating_sub(1),
+			_ => count,
+		};
+		VotingPowerSlashes::<T>::insert(index, (block, slash_factor));
+		VotingPowerSlashCount::<T>::put(index.saturating_add(1));
+	}
+
+	/// Slash factor of the voting power pool at a past `block`.
+	fn voting_power_slash_factor_at(block: T::BlockNumber) -> FixedU128 {
+		let (mut low, mut high) = (0_u32, VotingPowerSlashCount::<T>::get());
+		while low < high {
+			let middle = low + (high - low) / 2;
+			match VotingPowerSlashes::<T>::get(middle) {
+				Some((slashed_at, _)) if slashed_at <= block => low = middle + 1,
+				_ => high = middle,
+			}
+		}
+		low.checked_sub(1)
+			.and_then(VotingPowerSlashes::<T>::get)
+			.map_or_else(FixedU128::one, |(_, slash_factor)| slash_factor)
+	}
+
+	/// Replaces `old` with `new` in the voting power of `who` and in the total voting power,
+	/// writing a checkpoint for both at the current block.
+	pub(crate) fn checkpoint_voting_power(
+		who: &AccountIdOf<T>,
+		old: Option<VoteLock<BalanceOf<T>>>,
+		new: Option<VoteLock<BalanceOf<T>>>,
+	) {
+		let now = T::UnixTime::now().as_secs();
+		let block = frame_system::Pallet::<T>::block_number();
+
+		// expired locks no longer contribute anything, and their slope has already been removed
+		let old = old.filter(|lock| lock.end > now);
+		let new = new.filter(|lock| lock.end > now);
+		if old.is_none() && new.is_none() {
+			return
+		}
+
+		if let Some(lock) = old {
+			VotingPowerSlopeChanges::<T>::mutate(who, lock.end, |slope| {
+				*slope = slope.saturating_sub(lock.slope)
+			});
+			TotalVotingPowerSlopeChanges::<T>::mutate(lock.end, |slope| {
+				*slope = slope.saturating_sub(lock.slope)
+			});
+		}
+		if let Some(lock) = new {
+			VotingPowerSlopeChanges::<T>::mutate(who, lock.end, |slope| {
+				*slope = slope.saturating_add(lock.slope)
+			});
+			TotalVotingPowerSlopeChanges::<T>::mutate(lock.end, |slope| {
+				*slope = slope.saturating_add(lock.slope)
+			});
+		}
+
+		let count = VotingPowerCheckpointCount::<T>::get(who);
+		let last = count
+			.checked_sub(1)
+			.and_then(|index| VotingPowerCheckpoints::<T>::get(who, index));
+		let checkpoint = Self::next_checkpoint(last, now, block, old, new, |timestamp| {
+			VotingPowerSlopeChanges::<T>::get(who, timestamp)
+		});
+		let index = Self::checkpoint_index(last, count, block);
+		VotingPowerCheckpoints::<T>::insert(who, index, checkpoint);
+		VotingPowerCheckpointCount::<T>::insert(who, index.saturating_add(1));
+
+		let count = TotalVotingPowerCheckpointCount::<T>::get();
+		let last = count.checked_sub(1).and_then(TotalVotingPowerCheckpoints::<T>::get);
+		let checkpoint = Self::next_checkpoint(
+			last,
+			now,
+			block,
+			old,
+			new,
+			TotalVotingPowerSlopeChanges::<T>::get,
+		);
+		let index = Self::checkpoint_index(last, count, block);
+		TotalVotingPowerCheckpoints::<T>::insert(index, checkpoint);
+		TotalVotingPowerCheckpointCount::<T>::put(index.saturating_add(1));
+	}
+
+	/// Advances `last` to `now` and swaps the contribution of `old` for the one of `new`.
+	fn next_checkpoint(
+		last: Option<VotingPowerCheckpointOf<T>>,
+		now: Timestamp,
+		block: T::BlockNumber,
+		old: Option<VoteLock<BalanceOf<T>>>,
+		new: Option<VoteLock<BalanceOf<T>>>,
+		slope_change: impl Fn(Timestamp) -> BalanceOf<T>,
+	) -> VotingPowerCheckpointOf<T> {
+		let (mut bias, mut slope) = last
+			.map(|checkpoint| Self::advance(checkpoint, now, slope_change))
+			.unwrap_or_default();
+
+		if let Some(lock) = old {
+			bias = bias.saturating_sub(Self::bias_of(lock, now));
+			slope = slope.saturating_sub(lock.slope);
+		}
+		if let Some(lock) = new {
+			bias = bias.saturating_add(Self::bias_of(lock, now));
+			slope = slope.saturating_add(lock.slope);
+		}
+
+		VotingPowerCheckpoint { bias, slope, timestamp: now, block }
+	}
+
+	/// Several checkpoints in the same block overwrite each other, keeping checkpoint blocks
+	/// strictly increasing.
+	fn checkpoint_index(
+		last: Option<VotingPowerCheckpointOf<T>>,
+		count: u32,
+		block: T::BlockNumber,
+	) -> u32 {
+		match last {
+			Some(checkpoint) if checkpoint.block == block => count.saturating_sub(1),
+			_ => count,
+		}
+	}
+
+	/// Returns the `(bias, slope)` of `checkpoint` at `timestamp`, applying the slope changes of
+	/// every epoch boundary crossed on the way.
+	///
+	/// Every lock contributing to the slope of a checkpoint started before it and spans at most
+	/// [`Config::MaxVoteEscrowEpochs`], so the slope reaches zero after at most that many
+	/// boundaries.
+	pub(crate) fn advance(
+		checkpoint: VotingPowerCheckpointOf<T>,
+		timestamp: Timestamp,
+		slope_change: impl Fn(Timestamp) -> BalanceOf<T>,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		let VotingPowerCheckpoint { mut bias, mut slope, timestamp: mut current, .. } = checkpoint;
+
+		let mut boundaries = 0_u32;
+		while current < timestamp && !slope.is_zero() && boundaries <= T::MaxVoteEscrowEpochs::get()
+		{
+			boundaries += 1;
+			let boundary = Self::epoch_start(current).saturating_add(Self::epoch());
+			let until = boundary.min(timestamp);
+			bias = bias.saturating_sub(slope.saturating_mul(Self::seconds(until - current)));
+			if until == boundary {
+				slope = slope.saturating_sub(slope_change(boundary));
+			}
+			current = until;
+		}
+
+		(bias, slope)
+	}
+
+	/// Estimates the unix time of a past `block` by interpolating between the total voting power
+	/// checkpoints surrounding it.
+	fn timestamp_at(block: T::BlockNumber) -> Result<Timestamp, DispatchError> {
+		let now = T::UnixTime::now().as_secs();
+		let current_block = frame_system::Pallet::<T>::block_number();
+		ensure!(block <= current_block, Error::<T>::BlockNumberInFuture);
+		if block == current_block {
+			return Ok(now)
+		}
+
+		let count = TotalVotingPowerCheckpointCount::<T>::get();
+		let (index, checkpoint) =
+			match Self::find_checkpoint(count, block, TotalVotingPowerCheckpoints::<T>::get) {
+				Some(found) => found,
+				// no voting power existed before the first checkpoint
+				None => return Ok(now),
+			};
+		let (next_block, next_timestamp) =
+			TotalVotingPowerCheckpoints::<T>::get(index.saturating_add(1))
+				.map_or((current_block, now), |next| (next.block, next.timestamp));
+
+		let elapsed_blocks: u128 = block.saturating_sub(checkpoint.block).unique_saturated_into();
+		let total_blocks: u128 =
+			next_block.saturating_sub(checkpoint.block).unique_saturated_into();
+		let total_seconds = u128::from(next_timestamp.saturating_sub(checkpoint.timestamp));
+		let elapsed_seconds = total_seconds
+			.saturating_mul(elapsed_blocks)
+			.checked_div(total_blocks)
+			.unwrap_or_default();
+
+		Ok(checkpoint.timestamp.saturating_add(elapsed_seconds.unique_saturated_into()))
+	}
+
+	/// Binary search for the last checkpoint written at or before `block`.
+	fn find_checkpoint(
+		count: u32,
+		block: T::BlockNumber,
+		checkpoint: impl Fn(u32) -> Option<VotingPowerCheckpointOf<T>>,
+	) -> Option<(u32, VotingPowerCheckpointOf<T>)> {
+		let (mut low, mut high) = (0_u32, count);
+		while low < high {
+			let middle = low + (high - low) / 2;
+			match checkpoint(middle) {
+				Some(found) if found.block <= block => low = middle + 1,
+				_ => high = middle,
+			}
+		}
+		let index = low.checked_sub(1)?;
+		checkpoint(index).map(|found| (index, found))
+	}
+
+	fn bias_of(lock: VoteLock<BalanceOf<T>>, now: Timestamp) -> BalanceOf<T> {
+		lock.slope.saturating_mul(Self::seconds(lock.end.saturating_sub(now)))
+	}
+
+	fn epoch() -> Timestamp {
+		T::VoteEscrowEpoch::get().max(1)
+	}
+
+	/// The longest lock of the voting power pool.
+	pub(crate) fn max_vote_escrow_duration() -> DurationSeconds {
+		Self::epoch().saturating_mul(T::MaxVoteEscrowEpochs::get().into())
+	}
+
+	/// Worst case weight of walking the epochs of `walks` checkpoints with [`Self::advance`].
+	pub(crate) fn vote_escrow_weight(walks: u64) -> Weight {
+		T::WeightInfo::vote_escrow_advance(T::MaxVoteEscrowEpochs::get()).saturating_mul(walks)
+	}
+
+	fn epoch_start(timestamp: Timestamp) -> Timestamp {
+		timestamp - timestamp % Self::epoch()
+	}
+
+	fn seconds(seconds: u64) -> BalanceOf<T> {
+		u128::from(seconds).into()
+	}
+}
+
+impl<T: Config> VotingPower for Pallet<T> {
+	type AccountId = AccountIdOf<T>;
+	type Balance = BalanceOf<T>;
+	type BlockNumber = T::BlockNumber;
+
+	fn voting_power(who: &Self::AccountId) -> Self::Balance {
+		let count = VotingPowerCheckpointCount::<T>::get(who);
+		let voting_power = count
+			.checked_sub(1)
+			.and_then(|index| VotingPowerCheckpoints::<T>::get(who, index))
+			.map(|checkpoint| {
+				Self::advance(checkpoint, T::UnixTime::now().as_secs(), |timestamp| {
+					VotingPowerSlopeChanges::<T>::get(who, timestamp)
+				})
+				.0
+			})
+			.unwrap_or_default();
+		Self::slash_factor(&T::VotingPowerPoolId::get()).saturating_mul_int(voting_power)
+	}
+
+	fn voting_power_at(
+		who: &Self::AccountId,
+		block: Self::BlockNumber,
+	) -> Result<Self::Balance, DispatchError> {
+		let timestamp = Self::timestamp_at(block)?;
+		let count = VotingPowerCheckpointCount::<T>::get(who);
+		let voting_power = Self::find_checkpoint(count, block, |index| {
+			VotingPowerCheckpoints::<T>::get(who, index)
+		})
+		.map(|(_, checkpoint)| {
+			Self::advance(checkpoint, timestamp, |timestamp| {
+				VotingPowerSlopeChanges::<T>::get(who, timestamp)
+			})
+			.0
+		})
+		.unwrap_or_default();
+		Ok(Self::voting_power_slash_factor_at(block).saturating_mul_int(voting_power))
+	}
+
+	fn total_voting_power() -> Self::Balance {
+		let count = TotalVotingPowerCheckpointCount::<T>::get();
+		let total_voting_power = count
+			.checked_sub(1)
+			.and_then(TotalVotingPowerCheckpoints::<T>::get)
+			.map(|checkpoint| {
+				Self::advance(
+					checkpoint,
+					T::UnixTime::now().as_secs(),
+					TotalVotingPowerSlopeChanges::<T>::get,
+				)
+				.0
+			})
+			.unwrap_or_default();
+		Self::slash_factor(&T::VotingPowerPoolId::get()).saturating_mul_int(total_voting_power)
+	}
+
+	fn total_voting_power_at(block: Self::BlockNumber) -> Result<Self::Balance, DispatchError> {
+		let timestamp = Self::timestamp_at(block)?;
+		let count = TotalVotingPowerCheckpointCount::<T>::get();
+		let total_voting_power =
+			Self::find_checkpoint(count, block, TotalVotingPowerCheckpoints::<T>::get)
+				.map(|(_, checkpoint)| {
+					Self::advance(checkpoint, timestamp, TotalVotingPowerSlopeChanges::<T>::get).0
+				})
+				.unwrap_or_default();
+		Ok(Self::voting_power_slash_factor_at(block).saturating_mul_int(total_voting_power))
+	}
+}
+
+impl<T: Config> FinancialNftTransferHook<AccountIdOf<T>, T::AssetId, T::FinancialNftInstanceId>
+	for Pallet<T>
+{
+	fn on_transfer(
+		collection: &T::AssetId,
+		instance: &T::FinancialNftInstanceId,
+		from: &AccountIdOf<T>,
+		to: &AccountIdOf<T>,
+	) -> DispatchResult {
+		if let Some(lock) = crate::Stakes::<T>::get(collection, instance)
+			.and_then(|stake| Self::vote_lock(&stake, Self::slash_checkpoint(collection, instance)))
+		{
+			Self::checkpoint_voting_power(from, Some(lock), None);
+			Self::checkpoint_voting_power(to, None, Some(lock));
+		}
+		Ok(())
+	}
+}
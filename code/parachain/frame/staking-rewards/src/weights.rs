@@ -1,4 +1,4 @@
-use frame_support::dispatch::Weight;
+use frame_support::{dispatch::Weight, traits::Get, weights::constants::RocksDbWeight};
 
 pub trait WeightInfo {
 	fn create_reward_pool(r: u32) -> Weight;
@@ -11,6 +11,18 @@ pub trait WeightInfo {
 	fn update_rewards_pool(r: u32) -> Weight;
 	fn claim(r: u32) -> Weight;
 	fn add_to_rewards_pot() -> Weight;
+	fn add_gauge() -> Weight;
+	fn remove_gauge() -> Weight;
+	fn set_gauge_emission() -> Weight;
+	fn vote_for_gauges(g: u32) -> Weight;
+	fn apply_gauge_votes(g: u32) -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn compound(r: u32) -> Weight;
+	fn merge(r: u32) -> Weight;
+	fn settle_slashes() -> Weight;
+	fn withdraw_slashed() -> Weight;
+	fn set_emission_schedule() -> Weight;
+	fn vote_escrow_advance(e: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -53,4 +65,100 @@ impl WeightInfo for () {
 	fn add_to_rewards_pot() -> Weight {
 		Weight::from_ref_time(10_000)
 	}
+
+	// not benchmarked: reads the pool and the gauges, writes the gauges
+	fn add_gauge() -> Weight {
+		Weight::from_ref_time(25_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	// not benchmarked: reads and writes the gauges, queues the pending update
+	fn remove_gauge() -> Weight {
+		Weight::from_ref_time(25_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	// not benchmarked: writes the gauge emission
+	fn set_gauge_emission() -> Weight {
+		Weight::from_ref_time(20_000_000_u64).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	// not benchmarked: reads the voting power of the voter, reads and writes its votes and the
+	// weight of every gauge voted for
+	fn vote_for_gauges(g: u32) -> Weight {
+		Weight::from_ref_time(50_000_000_u64)
+			.saturating_add(Weight::from_ref_time(10_000_000_u64).saturating_mul(g as u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(g as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(g as u64)))
+	}
+
+	// not benchmarked: reads the gauges and their emission, updates the weight, pool and pending
+	// update of every gauge
+	fn apply_gauge_votes(g: u32) -> Weight {
+		Weight::from_ref_time(30_000_000_u64)
+			.saturating_add(Weight::from_ref_time(50_000_000_u64).saturating_mul(g as u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(g as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(g as u64)))
+	}
+
+	// not benchmarked: reads the position and its pool, writes the auto compound flag
+	fn set_auto_compound() -> Weight {
+		Weight::from_ref_time(35_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+
+	// not benchmarked: claims the rewards of the position and restakes them, checkpointing its
+	// voting power
+	fn compound(r: u32) -> Weight {
+		Weight::from_ref_time(240_000_000_u64)
+			.saturating_add(Weight::from_ref_time(15_000_000_u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(21_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(19_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(r as u64)))
+	}
+
+	// not benchmarked: reads and writes both positions, their locks and balances, burns one fNFT
+	fn merge(r: u32) -> Weight {
+		Weight::from_ref_time(175_000_000_u64)
+			.saturating_add(Weight::from_ref_time(5_000_000_u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(15_u64))
+	}
+
+	// not benchmarked: applies the slashes of the pool to the position, its lock and balances
+	fn settle_slashes() -> Weight {
+		Weight::from_ref_time(115_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+
+	// not benchmarked: reads and writes the payout and the balances of the slash account and
+	// beneficiary
+	fn withdraw_slashed() -> Weight {
+		Weight::from_ref_time(50_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+
+	// not benchmarked: reads the pool, the time and the reserves, writes the schedule and its next
+	// step
+	fn set_emission_schedule() -> Weight {
+		Weight::from_ref_time(40_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	// not benchmarked: one `VotingPowerSlopeChanges` read per epoch boundary walked
+	fn vote_escrow_advance(e: u32) -> Weight {
+		Weight::from_ref_time(5_000_000_u64)
+			.saturating_add(Weight::from_ref_time(5_000_000_u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(e as u64)))
+	}
 }
@@ -0,0 +1,220 @@
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+use core::{fmt::Display, str::FromStr};
+use jsonrpsee::{
+	core::{Error as RpcError, RpcResult},
+	proc_macros::rpc,
+	types::{error::CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, DispatchError};
+use sp_std::sync::Arc;
+use vault_runtime_api::VaultRuntimeApi;
+
+#[rpc(client, server)]
+pub trait VaultApi<BlockHash, VaultId, AccountId, Balance>
+where
+	VaultId: FromStr + Display,
+	AccountId: FromStr + Display,
+	Balance: FromStr + Display,
+{
+	#[method(name = "vault_previewDeposit")]
+	fn preview_deposit(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		asset_amount: SafeRpcWrapper<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+
+	#[method(name = "vault_previewWithdraw")]
+	fn preview_withdraw(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		lp_amount: SafeRpcWrapper<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+
+	#[method(name = "vault_convertToShares")]
+	fn convert_to_shares(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		asset_amount: SafeRpcWrapper<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+
+	#[method(name = "vault_convertToAssets")]
+	fn convert_to_assets(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		lp_amount: SafeRpcWrapper<Balance>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+
+	#[method(name = "vault_maxDeposit")]
+	fn max_deposit(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		account: SafeRpcWrapper<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+
+	#[method(name = "vault_maxWithdraw")]
+	fn max_withdraw(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		account: SafeRpcWrapper<AccountId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>>;
+}
+
+pub struct Vault<C, Block> {
+	client: Arc<C>,
+	_marker: sp_std::marker::PhantomData<Block>,
+}
+
+impl<C, M> Vault<C, M> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, VaultId, AccountId, Balance>
+	VaultApiServer<<Block as BlockT>::Hash, VaultId, AccountId, Balance>
+	for Vault<C, (Block, VaultId, AccountId, Balance)>
+where
+	Block: BlockT,
+	VaultId: Send + Sync + 'static + Codec + FromStr + Display,
+	AccountId: Send + Sync + 'static + Codec + FromStr + Display,
+	Balance: Send + Sync + 'static + Codec + FromStr + Display,
+	C: Send + Sync + 'static,
+	C: ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C::Api: VaultRuntimeApi<Block, VaultId, AccountId, Balance>,
+{
+	fn preview_deposit(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		asset_amount: SafeRpcWrapper<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.preview_deposit(&at, vault_id, asset_amount);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn preview_withdraw(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		lp_amount: SafeRpcWrapper<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.preview_withdraw(&at, vault_id, lp_amount);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn convert_to_shares(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		asset_amount: SafeRpcWrapper<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.convert_to_shares(&at, vault_id, asset_amount);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn convert_to_assets(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		lp_amount: SafeRpcWrapper<Balance>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.convert_to_assets(&at, vault_id, lp_amount);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn max_deposit(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		account: SafeRpcWrapper<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.max_deposit(&at, vault_id, account);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+
+	fn max_withdraw(
+		&self,
+		vault_id: SafeRpcWrapper<VaultId>,
+		account: SafeRpcWrapper<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Result<SafeRpcWrapper<Balance>, DispatchError>> {
+		let api = self.client.runtime_api();
+
+		let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+
+		// calling ../../runtime-api
+		let runtime_api_result = api.max_withdraw(&at, vault_id, account);
+		runtime_api_result.map_err(|e| {
+			RpcError::Call(CallError::Custom(ErrorObject::owned(
+				9876,
+				"Something wrong",
+				Some(format!("{:?}", e)),
+			)))
+		})
+	}
+}
@@ -0,0 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use composable_support::rpc_helpers::SafeRpcWrapper;
+use sp_runtime::DispatchError;
+
+// Vault Runtime API declaration. Implemented for each runtime at
+// `runtime/<runtime-name>/src/lib.rs`.
+sp_api::decl_runtime_apis! {
+	pub trait VaultRuntimeApi<VaultId, AccountId, Balance>
+	where
+		VaultId: Codec,
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Amount of LP tokens minted when depositing `asset_amount` into the vault.
+		fn preview_deposit(
+			vault_id: SafeRpcWrapper<VaultId>,
+			asset_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+
+		/// Amount of underlying assets returned when withdrawing `lp_amount` from the vault.
+		fn preview_withdraw(
+			vault_id: SafeRpcWrapper<VaultId>,
+			lp_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+
+		/// Amount of LP tokens the vault exchanges for `asset_amount`, ignoring any limit.
+		fn convert_to_shares(
+			vault_id: SafeRpcWrapper<VaultId>,
+			asset_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+
+		/// Amount of underlying assets the vault exchanges for `lp_amount`, ignoring any limit.
+		fn convert_to_assets(
+			vault_id: SafeRpcWrapper<VaultId>,
+			lp_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+
+		/// Maximum amount of underlying assets `account` can deposit into the vault.
+		fn max_deposit(
+			vault_id: SafeRpcWrapper<VaultId>,
+			account: SafeRpcWrapper<AccountId>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+
+		/// Maximum amount of LP tokens `account` can withdraw from the vault.
+		fn max_withdraw(
+			vault_id: SafeRpcWrapper<VaultId>,
+			account: SafeRpcWrapper<AccountId>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError>;
+	}
+}
@@ -4,7 +4,7 @@ use crate::Pallet as Vault;
 use codec::{Decode, Encode, MaxEncodedLen};
 use composable_support::validation::Validated;
 use composable_traits::vault::{CapabilityVault, Deposit, Vault as VaultTrait, VaultConfig};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_support::{
 	assert_ok,
 	traits::{fungible::Mutate as FungibleMutate, fungibles::Mutate as FungiblesMutate, Get},
@@ -128,6 +128,50 @@ benchmarks! {
 		}.into())
 	}
 
+	request_withdrawal {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		let (vault, _) = create_vault::<T>(A, caller.clone());
+		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * 2u32.into())?;
+		T::NativeCurrency::mint_into(&caller, amount * 2u32.into())?;
+		<Vault<T> as VaultTrait>::deposit(&vault, &caller, amount)?;
+	}: _(RawOrigin::Signed(caller.clone()), vault, amount)
+	verify {
+		assert_last_event::<T>(Event::WithdrawalRequested {
+			vault,
+			account: caller,
+			request: 0,
+			lp_amount: amount,
+			asset_amount: amount
+		}.into())
+	}
+
+	settle_withdrawals {
+		let n in 1 .. T::MaxWithdrawalSettlements::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		let (vault, _) = create_vault::<T>(A, caller.clone());
+		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * n.into())?;
+		T::NativeCurrency::mint_into(&caller, amount * 2u32.into())?;
+		<Vault<T> as VaultTrait>::deposit(&vault, &caller, amount * n.into())?;
+		for _ in 0..n {
+			Vault::<T>::request_withdrawal(RawOrigin::Signed(caller.clone()).into(), vault, amount)?;
+		}
+		System::<T>::set_block_number(System::<T>::block_number() + T::WithdrawalDelay::get());
+	}: _(RawOrigin::Signed(caller.clone()), vault)
+	verify {
+		assert_eq!(WithdrawalQueues::<T>::get(vault).head, n as u64);
+	}
+
+	withdraw_instant {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		let (vault, _) = create_vault::<T>(A, caller.clone());
+		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * 2u32.into())?;
+		T::NativeCurrency::mint_into(&caller, amount * 2u32.into())?;
+		<Vault<T> as VaultTrait>::deposit(&vault, &caller, amount)?;
+	}: _(RawOrigin::Signed(caller.clone()), vault, amount)
+
 	emergency_shutdown {
 		let caller: T::AccountId = whitelisted_caller();
 		let (vault, _) = create_vault::<T>(A, caller);
@@ -185,6 +229,34 @@ benchmarks! {
 		System::<T>::set_block_number(10_000_000u32.into());
 	}: _(RawOrigin::Signed(caller), vault, None)
 
+	rebalance_vault {
+		let s in 1 .. T::MaxStrategies::get() as u32;
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::CreationDeposit::get() * 10u32.into();
+		// only strategies handled by `T::Strategies` are harvested and rebalanced, the others are
+		// read and skipped
+		let share = Perquintill::from_rational(9_u64, 10_u64 * s as u64);
+		let strategies = (0..s)
+			.map(|i| (account("strategy", i, 0xCAFEBABE), share))
+			.collect();
+		let config = VaultConfig {
+			asset_id: recode_unwrap_u128(A),
+			manager: caller.clone(),
+			reserved: DEFAULT_RESERVE,
+			strategies,
+		};
+		let (vault, _) = Vault::<T>::do_create_vault(Deposit::Existential, Validated::new(config).unwrap())?;
+		T::Currency::mint_into(recode_unwrap_u128(A), &caller, amount * 2u32.into())?;
+		T::NativeCurrency::mint_into(&caller, amount * 2u32.into())?;
+		<Vault<T> as VaultTrait>::deposit(&vault, &caller, amount)?;
+		let now = System::<T>::block_number();
+	}: {
+		Vault::<T>::service_vault(&vault, now);
+	}
+	verify {
+		assert_eq!(LastRebalance::<T>::get(vault), Some(now));
+	}
+
 	delete_tombstoned {
 		let caller: T::AccountId = whitelisted_caller();
 		let amount = T::CreationDeposit::get() * 10u32.into();
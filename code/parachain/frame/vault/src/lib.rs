@@ -60,7 +60,7 @@ pub mod pallet {
 	use core::ops::AddAssign;
 
 	use crate::{
-		models::StrategyOverview,
+		models::{StrategyOverview, WithdrawalQueue, WithdrawalRequest},
 		rent::{self, Verdict},
 		traits::{CurrencyFactory, StrategicVault},
 		validation::{ValidateCreationDeposit, ValidateMaxStrategies},
@@ -73,7 +73,7 @@ pub mod pallet {
 		defi::Rate,
 		vault::{
 			CapabilityVault, Deposit, FundsAvailability, ReportableStrategicVault, Vault,
-			VaultConfig,
+			VaultConfig, VaultStrategy,
 		},
 	};
 	use frame_support::{
@@ -90,7 +90,9 @@ pub mod pallet {
 		transactional, PalletId,
 	};
 	use frame_system::{
-		ensure_root, ensure_signed, pallet_prelude::OriginFor, Config as SystemConfig,
+		ensure_root, ensure_signed,
+		pallet_prelude::{BlockNumberFor, OriginFor},
+		Config as SystemConfig,
 	};
 	use num_traits::{One, SaturatingSub};
 	use scale_info::TypeInfo;
@@ -103,7 +105,7 @@ pub mod pallet {
 		},
 		ArithmeticError, DispatchError, FixedPointNumber, Perquintill,
 	};
-	use sp_std::{cmp::Ordering, fmt::Debug};
+	use sp_std::{cmp::Ordering, fmt::Debug, vec::Vec};
 
 	#[allow(missing_docs)]
 	pub type AssetIdOf<T> =
@@ -218,6 +220,35 @@ pub mod pallet {
 		/// avoid name collisions with other pallets and vaults.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+
+		/// Registry of strategies which are harvested and rebalanced by the vault itself.
+		/// Strategies of a vault not handled by the registry have to be driven externally through
+		/// [`StrategicVault`].
+		type Strategies: VaultStrategy<
+			AccountId = Self::AccountId,
+			AssetId = Self::AssetId,
+			Balance = Self::Balance,
+			VaultId = Self::VaultId,
+		>;
+
+		/// The minimal number of blocks between two automated rebalances of the same vault.
+		#[pallet::constant]
+		type RebalanceInterval: Get<Self::BlockNumber>;
+
+		/// The maximum number of queued withdrawal requests of a vault settled at once.
+		#[pallet::constant]
+		type MaxWithdrawalSettlements: Get<u32>;
+
+		/// The number of blocks a queued withdrawal request is locked for before it can be
+		/// settled.
+		#[pallet::constant]
+		type WithdrawalDelay: Get<Self::BlockNumber>;
+
+		/// The share of the withdrawn assets kept by the vault when exiting through
+		/// [`withdraw_instant`](Pallet::withdraw_instant). It compensates the remaining LP holders
+		/// for funds recalled early from strategies.
+		#[pallet::constant]
+		type InstantExitFee: Get<Perquintill>;
 	}
 
 	#[pallet::pallet]
@@ -263,6 +294,45 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The block at which the strategies of a vault were last rebalanced in `on_idle`.
+	#[pallet::storage]
+	#[pallet::getter(fn last_rebalance)]
+	pub type LastRebalance<T: Config> =
+		StorageMap<_, Twox64Concat, T::VaultId, T::BlockNumber, OptionQuery>;
+
+	/// The vault from which the next automated rebalance continues.
+	#[pallet::storage]
+	#[allow(clippy::disallowed_types)]
+	pub type RebalanceCursor<T: Config> = StorageValue<_, T::VaultId, ValueQuery>;
+
+	/// Bounds of the withdrawal request queue of each vault.
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_queue)]
+	#[allow(clippy::disallowed_types)]
+	pub type WithdrawalQueues<T: Config> =
+		StorageMap<_, Twox64Concat, T::VaultId, WithdrawalQueue, ValueQuery>;
+
+	/// Withdrawal requests waiting to be settled, indexed by their position in the queue.
+	#[pallet::storage]
+	#[pallet::getter(fn withdrawal_request)]
+	pub type WithdrawalRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::VaultId,
+		Twox64Concat,
+		u64,
+		WithdrawalRequest<T::AccountId, T::Balance, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Sum of the assets owed to queued withdrawal requests. These assets are no longer managed on
+	/// behalf of the LP holders.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_withdrawals)]
+	#[allow(clippy::disallowed_types)]
+	pub type PendingWithdrawals<T: Config> =
+		StorageMap<_, Twox64Concat, T::VaultId, T::Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -303,6 +373,72 @@ pub mod pallet {
 			/// The ID of the vault.
 			vault: T::VaultId,
 		},
+		/// Emitted after a strategy driven by the vault was harvested and its balance reported.
+		StrategyHarvested {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account of the strategy.
+			strategy: T::AccountId,
+			/// The amount collected by the harvest.
+			harvested: T::Balance,
+			/// The balance of the strategy after the harvest.
+			balance: T::Balance,
+		},
+		/// Emitted after funds were moved between the vault and a strategy driven by the vault.
+		StrategyRebalanced {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account of the strategy.
+			strategy: T::AccountId,
+			/// The movement of funds which was performed.
+			action: FundsAvailability<T::Balance>,
+		},
+		/// Emitted when harvesting or rebalancing a strategy driven by the vault failed. Other
+		/// strategies are still processed.
+		StrategyRebalanceFailed {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account of the strategy.
+			strategy: T::AccountId,
+			error: DispatchError,
+		},
+		/// Emitted after LP tokens were burnt into a queued withdrawal request.
+		WithdrawalRequested {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account receiving the assets once settled.
+			account: T::AccountId,
+			/// The position of the request in the queue of the vault.
+			request: u64,
+			/// Amount of LP tokens burnt.
+			lp_amount: T::Balance,
+			/// Assets owed to the account.
+			asset_amount: T::Balance,
+		},
+		/// Emitted after a queued withdrawal request was paid out.
+		WithdrawalSettled {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account receiving the assets.
+			account: T::AccountId,
+			/// The position of the request in the queue of the vault.
+			request: u64,
+			/// Assets paid out.
+			asset_amount: T::Balance,
+		},
+		/// Emitted after a user exited immediately through the instant exit path.
+		InstantWithdrawn {
+			/// The ID of the vault.
+			vault: T::VaultId,
+			/// The account ID making the withdrawal.
+			account: T::AccountId,
+			/// Amount of LP tokens exchanged for the withdrawal.
+			lp_amount: T::Balance,
+			/// Assets received in exchange for the withdrawal.
+			asset_amount: T::Balance,
+			/// Assets kept by the vault as [`InstantExitFee`](Config::InstantExitFee).
+			fee: T::Balance,
+		},
 	}
 
 	#[allow(missing_docs)]
@@ -364,6 +500,49 @@ pub mod pallet {
 		TombstoneDurationNotExceeded,
 		/// Existentially funded vaults do not require extra funds.
 		InvalidAddSurcharge,
+		/// The vault could not be deleted, as it still owes assets to queued withdrawal requests.
+		PendingWithdrawalRequests,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Services vaults round robin, starting after the vault processed last, for as long as
+		/// there is weight left. Vaults due for a rebalance are rebalanced, then unlocked
+		/// withdrawal requests of every serviced vault are settled.
+		fn on_idle(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let per_vault = T::WeightInfo::rebalance_vault(T::MaxStrategies::get() as u32)
+				.saturating_add(T::WeightInfo::settle_withdrawals(
+					T::MaxWithdrawalSettlements::get(),
+				));
+			let vault_count: u128 = VaultCount::<T>::get().into();
+			let mut used = T::DbWeight::get().reads_writes(2, 1);
+			if vault_count == 0 {
+				return used
+			}
+			let start = RebalanceCursor::<T>::get();
+			let mut vault_id = start;
+			loop {
+				if used.saturating_add(per_vault).any_gt(remaining_weight) {
+					break
+				}
+				if Into::<u128>::into(vault_id) >= vault_count {
+					vault_id = One::one();
+				} else {
+					vault_id += One::one();
+				}
+				used = used.saturating_add(Self::service_vault(&vault_id, now));
+				let last = if start == T::VaultId::default() {
+					Into::<u128>::into(vault_id) == vault_count
+				} else {
+					vault_id == start
+				};
+				if last {
+					break
+				}
+			}
+			RebalanceCursor::<T>::put(vault_id);
+			used
+		}
 	}
 
 	#[pallet::call]
@@ -510,6 +689,10 @@ pub mod pallet {
 			Vaults::<T>::try_mutate_exists(dest, |v| -> DispatchResultWithPostInfo {
 				let vault = v.as_mut().ok_or(Error::<T>::VaultDoesNotExist)?;
 				ensure!(vault.capabilities.is_tombstoned(), Error::<T>::VaultNotTombstoned);
+				ensure!(
+					PendingWithdrawals::<T>::get(dest).is_zero(),
+					Error::<T>::PendingWithdrawalRequests
+				);
 
 				if !rent::evaluate_deletion::<T>(
 					<frame_system::Pallet<T>>::block_number(),
@@ -575,6 +758,97 @@ pub mod pallet {
 			Ok(().into())
 		}
 
+		/// Burns LP tokens into a withdrawal request, which is paid out once it has been locked for
+		/// [`WithdrawalDelay`](Config::WithdrawalDelay) and the vault holds enough liquidity.
+		/// Requests are settled in the order they were made, either in
+		/// `on_idle` or through [`settle_withdrawals`](Pallet::settle_withdrawals). Strategies
+		/// return the funds on the next rebalance, as the owed assets no longer count towards
+		/// their allocation.
+		///
+		/// # Emits
+		///  - Event::WithdrawalRequested
+		///
+		/// # Errors
+		///  - When the origin is not signed.
+		///  - When `lp_amount < MinimumWithdrawal`.
+		///  - When the vault has withdrawals halted.
+		#[transactional]
+		#[pallet::weight(<T as Config>::WeightInfo::request_withdrawal())]
+		pub fn request_withdrawal(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			lp_amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let to = ensure_signed(origin)?;
+			let (request, asset_amount) = Self::do_request_withdrawal(&vault, &to, lp_amount)?;
+			Self::deposit_event(Event::WithdrawalRequested {
+				vault,
+				account: to,
+				request,
+				lp_amount,
+				asset_amount,
+			});
+			Ok(().into())
+		}
+
+		/// Pays out unlocked withdrawal requests of the vault, oldest first, for as long as the
+		/// vault holds enough liquidity. At most
+		/// [`MaxWithdrawalSettlements`](Config::MaxWithdrawalSettlements) are settled.
+		///
+		/// # Emits
+		///  - Event::WithdrawalSettled
+		///
+		/// # Errors
+		///  - When the origin is not signed.
+		///  - When `vault` does not exist.
+		#[pallet::weight(
+			<T as Config>::WeightInfo::settle_withdrawals(T::MaxWithdrawalSettlements::get())
+		)]
+		pub fn settle_withdrawals(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let info = Self::vault_info(&vault)?;
+			let settled =
+				Self::do_settle_withdrawals(&vault, &info, T::MaxWithdrawalSettlements::get());
+			Ok(Some(<T as Config>::WeightInfo::settle_withdrawals(settled)).into())
+		}
+
+		/// Withdraws immediately, recalling funds from the strategies driven by the vault if its
+		/// own liquidity does not suffice. The vault keeps
+		/// [`InstantExitFee`](Config::InstantExitFee) of the withdrawn assets.
+		///
+		/// # Emits
+		///  - Event::InstantWithdrawn
+		///
+		/// # Errors
+		///  - When the origin is not signed.
+		///  - When `lp_amount < MinimumWithdrawal`.
+		///  - When the vault has withdrawals halted.
+		///  - When the strategies could not return enough funds.
+		#[transactional]
+		// recalling funds costs at most as much as rebalancing every strategy of the vault
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_instant().saturating_add(
+			<T as Config>::WeightInfo::rebalance_vault(T::MaxStrategies::get() as u32)
+		))]
+		pub fn withdraw_instant(
+			origin: OriginFor<T>,
+			vault: T::VaultId,
+			lp_amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let to = ensure_signed(origin)?;
+			let (asset_amount, fee) = Self::do_withdraw_instant(&vault, &to, lp_amount)?;
+			Self::deposit_event(Event::InstantWithdrawn {
+				vault,
+				account: to,
+				lp_amount,
+				asset_amount,
+				fee,
+			});
+			Ok(().into())
+		}
+
 		/// Stops a vault. To be used in case of severe protocol flaws.
 		///
 		/// # Emits
@@ -736,11 +1010,10 @@ pub mod pallet {
 
 			let lp_shares_value_amount = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
 
-			let vault_owned_amount =
-				T::Currency::balance(vault.asset_id, &Self::account_id(vault_id));
+			let vault_owned_amount = Self::free_liquidity(vault_id, &vault);
 
-			// TODO(hussein-aitlahcen): should we provide what we can to reduce the available
-			// liquidity in order to force strategies to rebalance?
+			// Withdrawals exceeding the liquidity of the vault have to go through
+			// `request_withdrawal` or `withdraw_instant`.
 			ensure!(lp_shares_value_amount <= vault_owned_amount, Error::<T>::NotEnoughLiquidity);
 
 			ensure!(
@@ -853,6 +1126,35 @@ pub mod pallet {
 			Ok(shares_amount)
 		}
 
+		/// Same as [`Self::do_amount_of_lp_token_for_added_liquidity`], but exchanges 1:1 while
+		/// the vault manages no assets, as [`Self::do_calculate_lp_tokens_to_mint`] does.
+		fn do_convert_to_shares(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			asset_amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let aum = Self::do_assets_under_management(vault_id, vault)?;
+			if aum.is_zero() {
+				Ok(asset_amount)
+			} else {
+				let total_lp_issuance = T::Currency::total_issuance(vault.lp_token_id);
+				Self::convert_and_multiply_by_rational(asset_amount, total_lp_issuance, aum)
+			}
+		}
+
+		/// Same as [`Self::do_lp_share_value`], but exchanges 1:1 while no LP tokens are issued.
+		fn do_convert_to_assets(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			lp_amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			if T::Currency::total_issuance(vault.lp_token_id).is_zero() {
+				Ok(lp_amount)
+			} else {
+				Self::do_lp_share_value(vault_id, vault, lp_amount)
+			}
+		}
+
 		fn convert_and_multiply_by_rational(
 			a: T::Balance,
 			b: T::Balance,
@@ -877,7 +1179,280 @@ pub mod pallet {
 			let owned = T::Currency::balance(vault.asset_id, &Self::account_id(vault_id));
 			let outstanding = CapitalStructure::<T>::iter_prefix_values(vault_id)
 				.fold(T::Balance::zero(), |sum, item| sum + item.balance);
-			Ok(owned + outstanding)
+			// assets owed to queued withdrawals no longer belong to the LP holders
+			Ok((owned + outstanding).saturating_sub(&PendingWithdrawals::<T>::get(vault_id)))
+		}
+
+		/// Assets held by the vault itself which are not owed to queued withdrawal requests.
+		fn free_liquidity(vault_id: &T::VaultId, vault: &VaultInfo<T>) -> T::Balance {
+			T::Currency::balance(vault.asset_id, &Self::account_id(vault_id))
+				.saturating_sub(&PendingWithdrawals::<T>::get(vault_id))
+		}
+
+		fn do_request_withdrawal(
+			vault_id: &T::VaultId,
+			to: &T::AccountId,
+			lp_amount: T::Balance,
+		) -> Result<(u64, T::Balance), DispatchError> {
+			ensure!(
+				lp_amount > T::MinimumWithdrawal::get(),
+				Error::<T>::AmountMustGteMinimumWithdrawal
+			);
+			let vault = Self::vault_info(vault_id)?;
+			ensure!(vault.capabilities.withdrawals_allowed(), Error::<T>::WithdrawalsHalted);
+
+			let asset_amount = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
+			T::Currency::burn_from(vault.lp_token_id, to, lp_amount)
+				.map_err(|_| Error::<T>::InsufficientLpTokens)?;
+
+			PendingWithdrawals::<T>::try_mutate(vault_id, |pending| -> DispatchResult {
+				*pending = pending.checked_add(&asset_amount).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+			let request = WithdrawalQueues::<T>::try_mutate(
+				vault_id,
+				|queue| -> Result<u64, DispatchError> {
+					let request = queue.tail;
+					queue.tail = request.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+					Ok(request)
+				},
+			)?;
+			WithdrawalRequests::<T>::insert(
+				vault_id,
+				request,
+				WithdrawalRequest {
+					account: to.clone(),
+					asset_amount,
+					requested_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+			// ask the strategies for the owed funds on the next `on_idle`
+			LastRebalance::<T>::remove(vault_id);
+			Ok((request, asset_amount))
+		}
+
+		/// Pays out up to `limit` queued withdrawal requests of the vault in FIFO order, stopping
+		/// at the first request which is still locked or which the vault does not hold enough
+		/// assets for. Returns the number of settled requests.
+		pub fn do_settle_withdrawals(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			limit: u32,
+		) -> u32 {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let account = Self::account_id(vault_id);
+			let mut queue = WithdrawalQueues::<T>::get(vault_id);
+			let mut settled = 0_u32;
+			while settled < limit && queue.head < queue.tail {
+				let request = match WithdrawalRequests::<T>::get(vault_id, queue.head) {
+					Some(request) => request,
+					None => {
+						queue.head = queue.head.saturating_add(1);
+						continue
+					},
+				};
+				// requests are queued in order, so all later ones are locked as well
+				if now < request.requested_at.saturating_add(T::WithdrawalDelay::get()) {
+					break
+				}
+				if T::Currency::transfer(
+					vault.asset_id,
+					&account,
+					&request.account,
+					request.asset_amount,
+					true,
+				)
+				.is_err()
+				{
+					break
+				}
+				PendingWithdrawals::<T>::mutate(vault_id, |pending| {
+					*pending = pending.saturating_sub(&request.asset_amount)
+				});
+				WithdrawalRequests::<T>::remove(vault_id, queue.head);
+				Self::deposit_event(Event::WithdrawalSettled {
+					vault: *vault_id,
+					account: request.account,
+					request: queue.head,
+					asset_amount: request.asset_amount,
+				});
+				queue.head = queue.head.saturating_add(1);
+				settled += 1;
+			}
+			WithdrawalQueues::<T>::insert(vault_id, queue);
+			settled
+		}
+
+		fn do_withdraw_instant(
+			vault_id: &T::VaultId,
+			to: &T::AccountId,
+			lp_amount: T::Balance,
+		) -> Result<(T::Balance, T::Balance), DispatchError> {
+			ensure!(
+				lp_amount > T::MinimumWithdrawal::get(),
+				Error::<T>::AmountMustGteMinimumWithdrawal
+			);
+			let vault = Self::vault_info(vault_id)?;
+			ensure!(vault.capabilities.withdrawals_allowed(), Error::<T>::WithdrawalsHalted);
+
+			let value = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
+			let fee = <T::Convert as Convert<u128, T::Balance>>::convert(
+				T::InstantExitFee::get()
+					.mul_ceil(<T::Convert as Convert<T::Balance, u128>>::convert(value)),
+			);
+			let asset_amount = value.saturating_sub(&fee);
+
+			let shortfall = asset_amount.saturating_sub(&Self::free_liquidity(vault_id, &vault));
+			if !shortfall.is_zero() {
+				Self::recall_from_strategies(vault_id, &vault, shortfall)?;
+			}
+			ensure!(
+				asset_amount <= Self::free_liquidity(vault_id, &vault),
+				Error::<T>::NotEnoughLiquidity
+			);
+
+			T::Currency::burn_from(vault.lp_token_id, to, lp_amount)
+				.map_err(|_| Error::<T>::InsufficientLpTokens)?;
+			T::Currency::transfer(
+				vault.asset_id,
+				&Self::account_id(vault_id),
+				to,
+				asset_amount,
+				true,
+			)
+			.map_err(|_| Error::<T>::TransferFromFailed)?;
+			Ok((asset_amount, fee))
+		}
+
+		/// Withdraws `amount` from the strategies driven by the vault back into the vault,
+		/// draining them one after the other.
+		fn recall_from_strategies(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let mut needed = amount;
+			let strategies: Vec<_> = CapitalStructure::<T>::iter_prefix(vault_id)
+				.filter(|(strategy, _)| T::Strategies::handles(strategy))
+				.collect();
+			for (strategy, overview) in strategies {
+				if needed.is_zero() {
+					break
+				}
+				let requested = needed.min(overview.balance);
+				if requested.is_zero() {
+					continue
+				}
+				let freed =
+					T::Strategies::withdraw(&strategy, vault_id, vault.asset_id, requested)?;
+				<Self as StrategicVault>::deposit(vault_id, &strategy, freed)?;
+				needed = needed.saturating_sub(&freed);
+			}
+			Ok(())
+		}
+
+		/// Rebalances the vault if [`RebalanceInterval`](Config::RebalanceInterval) passed since
+		/// its last rebalance, then settles its unlocked withdrawal requests.
+		fn service_vault(vault_id: &T::VaultId, now: T::BlockNumber) -> Weight {
+			let vault = match Vaults::<T>::get(vault_id) {
+				Some(vault) => vault,
+				None => return T::DbWeight::get().reads(1),
+			};
+			let due = LastRebalance::<T>::get(vault_id)
+				.map_or(true, |at| now.saturating_sub(at) >= T::RebalanceInterval::get());
+			let mut weight = T::DbWeight::get().reads(2);
+			if due {
+				let strategies = Self::do_rebalance_vault(vault_id, &vault);
+				LastRebalance::<T>::insert(vault_id, now);
+				weight = weight.saturating_add(T::WeightInfo::rebalance_vault(strategies));
+			}
+			let settled =
+				Self::do_settle_withdrawals(vault_id, &vault, T::MaxWithdrawalSettlements::get());
+			weight.saturating_add(T::WeightInfo::settle_withdrawals(settled))
+		}
+
+		/// Harvests all strategies of the vault which are handled by
+		/// [`Strategies`](Config::Strategies) and moves funds between the vault and the strategies
+		/// according to their allocation. Returns the number of strategies processed.
+		pub fn do_rebalance_vault(vault_id: &T::VaultId, vault: &VaultInfo<T>) -> u32 {
+			let strategies: Vec<_> = CapitalStructure::<T>::iter_key_prefix(vault_id)
+				.filter(|strategy| T::Strategies::handles(strategy))
+				.collect();
+			// harvest all first, so that allocations are computed on up to date assets under
+			// management
+			for strategy in strategies.iter() {
+				if let Err(error) = Self::harvest_strategy(vault_id, vault, strategy) {
+					Self::deposit_event(Event::StrategyRebalanceFailed {
+						vault: *vault_id,
+						strategy: strategy.clone(),
+						error,
+					});
+				}
+			}
+			for strategy in strategies.iter() {
+				if let Err(error) = Self::rebalance_strategy(vault_id, vault, strategy) {
+					Self::deposit_event(Event::StrategyRebalanceFailed {
+						vault: *vault_id,
+						strategy: strategy.clone(),
+						error,
+					});
+				}
+			}
+			strategies.len() as u32
+		}
+
+		/// Harvests the strategy and updates its balance, which changes the value of LP tokens.
+		#[transactional]
+		fn harvest_strategy(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			strategy: &T::AccountId,
+		) -> DispatchResult {
+			let harvested = T::Strategies::harvest(strategy, vault_id, vault.asset_id)?;
+			let balance = T::Strategies::report(strategy, vault_id, vault.asset_id)?;
+			<Self as ReportableStrategicVault>::update_strategy_report(
+				vault_id, strategy, &balance,
+			)?;
+			Self::deposit_event(Event::StrategyHarvested {
+				vault: *vault_id,
+				strategy: strategy.clone(),
+				harvested,
+				balance,
+			});
+			Ok(())
+		}
+
+		#[transactional]
+		fn rebalance_strategy(
+			vault_id: &T::VaultId,
+			vault: &VaultInfo<T>,
+			strategy: &T::AccountId,
+		) -> DispatchResult {
+			let action = <Self as StrategicVault>::available_funds(vault_id, strategy)?;
+			match action {
+				FundsAvailability::Withdrawable(amount) if !amount.is_zero() => {
+					<Self as StrategicVault>::withdraw(vault_id, strategy, amount)?;
+					T::Strategies::deposit(strategy, vault_id, vault.asset_id, amount)?;
+				},
+				FundsAvailability::Depositable(amount) if !amount.is_zero() => {
+					let freed =
+						T::Strategies::withdraw(strategy, vault_id, vault.asset_id, amount)?;
+					<Self as StrategicVault>::deposit(vault_id, strategy, freed)?;
+				},
+				FundsAvailability::MustLiquidate => {
+					let balance = CapitalStructure::<T>::get(vault_id, strategy).balance;
+					let freed =
+						T::Strategies::withdraw(strategy, vault_id, vault.asset_id, balance)?;
+					<Self as StrategicVault>::deposit(vault_id, strategy, freed)?;
+				},
+				_ => return Ok(()),
+			}
+			Self::deposit_event(Event::StrategyRebalanced {
+				vault: *vault_id,
+				strategy: strategy.clone(),
+				action,
+			});
+			Ok(())
 		}
 
 		/// Tries to fetch a stored [VaultInfo] through its index.
@@ -993,6 +1568,86 @@ pub mod pallet {
 				Self::do_amount_of_lp_token_for_added_liquidity(vault_id, &vault, asset_amount)?;
 			Ok(lp)
 		}
+
+		fn preview_deposit(
+			vault_id: &Self::VaultId,
+			asset_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			ensure!(
+				asset_amount > T::MinimumDeposit::get(),
+				Error::<T>::AmountMustGteMinimumDeposit
+			);
+			let vault = Self::vault_info(vault_id)?;
+			ensure!(vault.capabilities.deposits_allowed(), Error::<T>::DepositsHalted);
+			Self::do_calculate_lp_tokens_to_mint(vault_id, &vault, asset_amount)
+		}
+
+		fn preview_withdraw(
+			vault_id: &Self::VaultId,
+			lp_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			ensure!(
+				lp_amount > T::MinimumWithdrawal::get(),
+				Error::<T>::AmountMustGteMinimumWithdrawal
+			);
+			let vault = Self::vault_info(vault_id)?;
+			ensure!(vault.capabilities.withdrawals_allowed(), Error::<T>::WithdrawalsHalted);
+			let value = Self::do_lp_share_value(vault_id, &vault, lp_amount)?;
+			ensure!(
+				value <= Self::free_liquidity(vault_id, &vault),
+				Error::<T>::NotEnoughLiquidity
+			);
+			Ok(value)
+		}
+
+		fn convert_to_shares(
+			vault_id: &Self::VaultId,
+			asset_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+			Self::do_convert_to_shares(vault_id, &vault, asset_amount)
+		}
+
+		fn convert_to_assets(
+			vault_id: &Self::VaultId,
+			lp_amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+			Self::do_convert_to_assets(vault_id, &vault, lp_amount)
+		}
+
+		fn max_deposit(
+			vault_id: &Self::VaultId,
+			account: &Self::AccountId,
+		) -> Result<Self::Balance, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+			if !vault.capabilities.deposits_allowed() {
+				return Ok(T::Balance::zero())
+			}
+			// deposits keep the depositor alive
+			Ok(T::Currency::reducible_balance(vault.asset_id, account, true))
+		}
+
+		fn max_withdraw(
+			vault_id: &Self::VaultId,
+			account: &Self::AccountId,
+		) -> Result<Self::Balance, DispatchError> {
+			let vault = Self::vault_info(vault_id)?;
+			if !vault.capabilities.withdrawals_allowed() {
+				return Ok(T::Balance::zero())
+			}
+			let lp_balance = T::Currency::reducible_balance(vault.lp_token_id, account, false);
+			let vault_owned_amount = Self::free_liquidity(vault_id, &vault);
+			// shares are rounded down, so their value never exceeds what the vault holds
+			let withdrawable = Self::do_convert_to_shares(vault_id, &vault, vault_owned_amount)?;
+			let max = lp_balance.min(withdrawable);
+			// withdrawals not exceeding the minimum are declined
+			if max > T::MinimumWithdrawal::get() {
+				Ok(max)
+			} else {
+				Ok(T::Balance::zero())
+			}
+		}
 	}
 
 	impl<T: Config> StrategicVault for Pallet<T> {
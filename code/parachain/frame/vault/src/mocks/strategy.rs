@@ -6,17 +6,19 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use crate::traits::{FundsAvailability, ReportableStrategicVault, StrategicVault};
-	use composable_traits::vault::Vault;
+	use composable_traits::vault::{Vault, VaultStrategy};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::fungibles::{Inspect, Mutate, Transfer},
 		PalletId,
 	};
 	use frame_system::{ensure_root, pallet_prelude::OriginFor, Config as SystemConfig};
-	use sp_runtime::traits::AccountIdConversion;
+	use sp_runtime::traits::{AccountIdConversion, Zero};
 
 	type BalanceOf<T> =
 		<<T as Config>::Currency as Inspect<<T as SystemConfig>::AccountId>>::Balance;
+	type AssetIdOf<T> =
+		<<T as Config>::Currency as Inspect<<T as SystemConfig>::AccountId>>::AssetId;
 	type VaultIdOf<T> = <<T as Config>::Vault as Vault>::VaultId;
 	type ReportOf<T> = <<T as Config>::Vault as ReportableStrategicVault>::Report;
 
@@ -64,6 +66,53 @@ pub mod pallet {
 		}
 	}
 
+	/// Keeps funds idle on the pallet account, revenue is only generated through
+	/// `generate_revenue`.
+	impl<T: Config> VaultStrategy for Pallet<T> {
+		type AccountId = T::AccountId;
+		type AssetId = AssetIdOf<T>;
+		type Balance = BalanceOf<T>;
+		type VaultId = VaultIdOf<T>;
+
+		fn handles(strategy: &Self::AccountId) -> bool {
+			*strategy == Self::account_id()
+		}
+
+		fn deposit(
+			_strategy: &Self::AccountId,
+			_vault: &Self::VaultId,
+			_asset: Self::AssetId,
+			_amount: Self::Balance,
+		) -> DispatchResult {
+			Ok(())
+		}
+
+		fn withdraw(
+			_strategy: &Self::AccountId,
+			_vault: &Self::VaultId,
+			asset: Self::AssetId,
+			amount: Self::Balance,
+		) -> Result<Self::Balance, DispatchError> {
+			Ok(amount.min(T::Currency::balance(asset, &Self::account_id())))
+		}
+
+		fn harvest(
+			_strategy: &Self::AccountId,
+			_vault: &Self::VaultId,
+			_asset: Self::AssetId,
+		) -> Result<Self::Balance, DispatchError> {
+			Ok(Zero::zero())
+		}
+
+		fn report(
+			_strategy: &Self::AccountId,
+			_vault: &Self::VaultId,
+			asset: Self::AssetId,
+		) -> Result<Self::Balance, DispatchError> {
+			Ok(T::Currency::balance(asset, &Self::account_id()))
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Mints new tokens and sends them to self, mocking the generating of revenue through DeFi.
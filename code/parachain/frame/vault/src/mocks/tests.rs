@@ -12,6 +12,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{ConvertInto, IdentityLookup},
+	Perquintill,
 };
 
 pub type BlockNumber = u64;
@@ -86,8 +87,12 @@ parameter_types! {
 	// cspell:disable-next
 	pub const StrategyTestPalletID: PalletId = PalletId(*b"sest_pid");
 	pub const MinimumDeposit: Balance = 0;
-	pub const MinimumWithdrawal: Balance = 0;
+	pub static MinimumWithdrawal: Balance = 0;
 	pub const TombstoneDuration: BlockNumber = 10;
+	pub const RebalanceInterval: BlockNumber = 10;
+	pub const MaxWithdrawalSettlements: u32 = 10;
+	pub static WithdrawalDelay: BlockNumber = 0;
+	pub const InstantExitFee: Perquintill = Perquintill::from_percent(1);
 }
 
 impl pallet_vault::Config for Test {
@@ -108,6 +113,11 @@ impl pallet_vault::Config for Test {
 	type TombstoneDuration = TombstoneDuration;
 	type VaultId = u64;
 	type WeightInfo = ();
+	type Strategies = Strategy;
+	type RebalanceInterval = RebalanceInterval;
+	type MaxWithdrawalSettlements = MaxWithdrawalSettlements;
+	type WithdrawalDelay = WithdrawalDelay;
+	type InstantExitFee = InstantExitFee;
 }
 
 parameter_type_with_key! {
@@ -24,3 +24,23 @@ pub struct StrategyOverview<Balance> {
 	/// Sum of all deposited funds.
 	pub lifetime_deposited: Balance,
 }
+
+/// A withdrawal which is waiting for the vault to have enough liquidity to be paid out. The LP
+/// tokens of the request are burnt when it is queued, which fixes the amount owed.
+#[derive(Clone, Encode, Decode, MaxEncodedLen, Debug, PartialEq, Eq, TypeInfo)]
+pub struct WithdrawalRequest<AccountId, Balance, BlockNumber> {
+	/// The account receiving the assets once the request is settled.
+	pub account: AccountId,
+	/// The amount of underlying assets owed to `account`.
+	pub asset_amount: Balance,
+	/// The block at which the request was queued.
+	pub requested_at: BlockNumber,
+}
+
+/// Indices of the oldest unsettled and the next withdrawal request of a vault. Requests with an
+/// index in `head..tail` are pending.
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, Default, Debug, PartialEq, Eq, TypeInfo)]
+pub struct WithdrawalQueue {
+	pub head: u64,
+	pub tail: u64,
+}
@@ -3,11 +3,12 @@ use crate::{
 		currency_factory::MockCurrencyId,
 		tests::{
 			AccountId, Balance, Balances, BlockNumber, CreationDeposit, ExistentialDeposit,
-			ExtBuilder, RuntimeEvent, RuntimeOrigin, System, Test, Tokens, TombstoneDuration,
-			Vaults, ACCOUNT_FREE_START, ALICE, BOB, CHARLIE, MINIMUM_BALANCE,
+			ExtBuilder, MinimumWithdrawal, RebalanceInterval, RuntimeEvent, RuntimeOrigin,
+			Strategy, StrategyTestPalletID, System, Test, Tokens, TombstoneDuration, Vaults,
+			WithdrawalDelay, ACCOUNT_FREE_START, ALICE, BOB, CHARLIE, MINIMUM_BALANCE,
 		},
 	},
-	models::VaultInfo,
+	models::{VaultInfo, WithdrawalQueue},
 	*,
 };
 use composable_support::{math::safe::safe_multiply_by_rational, validation::Validated};
@@ -23,10 +24,16 @@ use composable_traits::{
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::fungibles::{Inspect, Mutate},
+	traits::{
+		fungibles::{Inspect, Mutate},
+		Hooks,
+	},
+	weights::Weight,
 };
 use proptest::prelude::*;
-use sp_runtime::{ArithmeticError, FixedPointNumber, Perbill, Perquintill};
+use sp_runtime::{
+	traits::AccountIdConversion, ArithmeticError, FixedPointNumber, Perbill, Perquintill,
+};
 
 const DEFAULT_STRATEGY_SHARE: Perquintill = Perquintill::from_percent(90);
 // dependent on the previous value, both should be changed
@@ -981,3 +988,281 @@ fn do_liquidate_strategy_successfully_liquidates_a_strategy_account(
 		assert_eq!(Tokens::balance(currency_id, &strategy_account_id), 0);
 	});
 }
+
+#[test]
+fn on_idle_allocates_and_harvests_registered_strategies() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = MockCurrencyId::A;
+		let strategy: AccountId = StrategyTestPalletID::get().into_account_truncating();
+		let (id, _) = create_vault(strategy, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+
+		Vaults::on_idle(1, Weight::MAX);
+		assert_eq!(Tokens::balance(asset_id, &strategy), 900);
+		assert_eq!(Tokens::balance(asset_id, &Vaults::account_id(&id)), 100);
+		assert_eq!(Vaults::last_rebalance(id), Some(1));
+
+		assert_ok!(Strategy::generate_revenue(RuntimeOrigin::root(), id, 100));
+		// not due yet, nothing moves
+		Vaults::on_idle(2, Weight::MAX);
+		assert_eq!(Tokens::balance(asset_id, &strategy), 1000);
+		assert_eq!(Vaults::lp_share_value(&id, 1000), Ok(1000));
+
+		let next = 1 + RebalanceInterval::get();
+		System::set_block_number(next);
+		Vaults::on_idle(next, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::StrategyHarvested {
+			vault: id,
+			strategy,
+			harvested: 0,
+			balance: 1000,
+		}));
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::StrategyRebalanced {
+			vault: id,
+			strategy,
+			action: FundsAvailability::Depositable(10),
+		}));
+		assert_eq!(Tokens::balance(asset_id, &strategy), 990);
+		assert_eq!(Tokens::balance(asset_id, &Vaults::account_id(&id)), 110);
+		assert_eq!(Vaults::lp_share_value(&id, 1000), Ok(1100));
+	});
+}
+
+#[test]
+fn on_idle_ignores_strategies_not_in_registry() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(BOB, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+
+		Vaults::on_idle(1, Weight::MAX);
+		assert_eq!(Tokens::balance(asset_id, &BOB), 0);
+		assert_eq!(Tokens::balance(asset_id, &Vaults::account_id(&id)), 1000);
+	});
+}
+
+#[test]
+fn previews_match_deposit_and_withdraw() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = MockCurrencyId::A;
+		let (id, vault) = create_vault(BOB, asset_id);
+		// empty vaults exchange 1:1
+		assert_eq!(Vaults::convert_to_shares(&id, 10), Ok(10));
+		assert_eq!(Vaults::convert_to_assets(&id, 10), Ok(10));
+
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_eq!(Vaults::max_deposit(&id, &ALICE), Ok(1000));
+		let previewed = Vaults::preview_deposit(&id, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+		assert_eq!(Tokens::balance(vault.lp_token_id, &ALICE), previewed);
+		assert_eq!(Vaults::max_deposit(&id, &ALICE), Ok(0));
+
+		// the vault earns 10%
+		Tokens::mint_into(asset_id, &Vaults::account_id(&id), 100).unwrap();
+		assert_eq!(Vaults::convert_to_assets(&id, 1000), Ok(1100));
+		assert_eq!(Vaults::convert_to_shares(&id, 1100), Ok(1000));
+		assert_eq!(Vaults::preview_deposit(&id, 110), Ok(100));
+		assert_eq!(Vaults::max_withdraw(&id, &ALICE), Ok(1000));
+
+		let previewed = Vaults::preview_withdraw(&id, 500).unwrap();
+		assert_eq!(previewed, 550);
+		assert_ok!(Vaults::withdraw(RuntimeOrigin::signed(ALICE), id, 500));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), previewed);
+	});
+}
+
+#[test]
+fn max_withdraw_is_bounded_by_vault_liquidity() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(BOB, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+
+		assert_ok!(<Vaults as StrategicVault>::withdraw(&id, &BOB, 600));
+		assert_eq!(Vaults::max_withdraw(&id, &ALICE), Ok(400));
+		assert_eq!(Vaults::preview_withdraw(&id, 400), Ok(400));
+		assert_noop!(Vaults::preview_withdraw(&id, 401), Error::<Test>::NotEnoughLiquidity);
+		// conversions ignore liquidity
+		assert_eq!(Vaults::convert_to_assets(&id, 1000), Ok(1000));
+	});
+}
+
+#[test]
+fn max_withdraw_respects_minimum_withdrawal() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(BOB, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+		assert_ok!(<Vaults as StrategicVault>::withdraw(&id, &BOB, 600));
+
+		MinimumWithdrawal::set(&399);
+		assert_eq!(Vaults::max_withdraw(&id, &ALICE), Ok(400));
+		MinimumWithdrawal::set(&400);
+		assert_eq!(Vaults::max_withdraw(&id, &ALICE), Ok(0));
+		assert_noop!(
+			Vaults::withdraw(RuntimeOrigin::signed(ALICE), id, 400),
+			Error::<Test>::AmountMustGteMinimumWithdrawal
+		);
+	});
+}
+
+#[test]
+fn previews_respect_vault_capabilities() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(BOB, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 500));
+
+		assert_ok!(Vaults::emergency_shutdown(RuntimeOrigin::root(), id));
+		assert_eq!(Vaults::max_deposit(&id, &ALICE), Ok(0));
+		assert_eq!(Vaults::max_withdraw(&id, &ALICE), Ok(0));
+		assert_noop!(Vaults::preview_deposit(&id, 100), Error::<Test>::DepositsHalted);
+		assert_noop!(Vaults::preview_withdraw(&id, 100), Error::<Test>::WithdrawalsHalted);
+		assert_eq!(Vaults::convert_to_assets(&id, 500), Ok(500));
+	});
+}
+
+#[test]
+fn request_withdrawal_is_settled_once_strategies_return_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = MockCurrencyId::A;
+		let strategy: AccountId = StrategyTestPalletID::get().into_account_truncating();
+		let (id, vault) = create_vault(strategy, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+		Vaults::on_idle(1, Weight::MAX);
+		assert_eq!(Tokens::balance(asset_id, &Vaults::account_id(&id)), 100);
+
+		assert_noop!(
+			Vaults::withdraw(RuntimeOrigin::signed(ALICE), id, 500),
+			Error::<Test>::NotEnoughLiquidity
+		);
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(ALICE), id, 500));
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::WithdrawalRequested {
+			vault: id,
+			account: ALICE,
+			request: 0,
+			lp_amount: 500,
+			asset_amount: 500,
+		}));
+		assert_eq!(Tokens::balance(vault.lp_token_id, &ALICE), 500);
+		assert_eq!(Vaults::pending_withdrawals(id), 500);
+		// owed assets are no longer managed for the remaining LP holders
+		assert_eq!(Vaults::convert_to_assets(&id, 500), Ok(500));
+		assert_eq!(Vaults::last_rebalance(id), None);
+
+		// the strategy returns what exceeds its allocation and the request is paid out
+		Vaults::on_idle(2, Weight::MAX);
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::StrategyRebalanced {
+			vault: id,
+			strategy,
+			action: FundsAvailability::Depositable(450),
+		}));
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::WithdrawalSettled {
+			vault: id,
+			account: ALICE,
+			request: 0,
+			asset_amount: 500,
+		}));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 500);
+		assert_eq!(Tokens::balance(asset_id, &Vaults::account_id(&id)), 50);
+		assert_eq!(Vaults::pending_withdrawals(id), 0);
+		assert_eq!(Vaults::withdrawal_request(id, 0), None);
+	});
+}
+
+#[test]
+fn withdrawal_requests_are_settled_in_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(CHARLIE, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 500).unwrap();
+		Tokens::mint_into(asset_id, &BOB, 500).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 500));
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(BOB), id, 500));
+		assert_ok!(<Vaults as StrategicVault>::withdraw(&id, &CHARLIE, 900));
+
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(ALICE), id, 400));
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(BOB), id, 100));
+		// the liquidity left is owed to the queue
+		assert_eq!(Vaults::max_withdraw(&id, &BOB), Ok(0));
+
+		// BOB's request could be paid, but ALICE's comes first
+		assert_ok!(Vaults::settle_withdrawals(RuntimeOrigin::signed(CHARLIE), id));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 0);
+		assert_eq!(Tokens::balance(asset_id, &BOB), 0);
+
+		assert_ok!(<Vaults as StrategicVault>::deposit(&id, &CHARLIE, 400));
+		assert_ok!(Vaults::settle_withdrawals(RuntimeOrigin::signed(CHARLIE), id));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 400);
+		assert_eq!(Tokens::balance(asset_id, &BOB), 100);
+		assert_eq!(Vaults::withdrawal_queue(id), WithdrawalQueue { head: 2, tail: 2 });
+		assert_eq!(Vaults::pending_withdrawals(id), 0);
+	});
+}
+
+#[test]
+fn withdrawal_requests_are_locked_for_withdrawal_delay() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		WithdrawalDelay::set(&5);
+		let asset_id = MockCurrencyId::A;
+		let (id, _) = create_vault(CHARLIE, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+		assert_ok!(Vaults::request_withdrawal(RuntimeOrigin::signed(ALICE), id, 400));
+
+		System::set_block_number(5);
+		assert_ok!(Vaults::settle_withdrawals(RuntimeOrigin::signed(CHARLIE), id));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 0);
+
+		// settled once unlocked, although no rebalance is due
+		Vaults::on_idle(5, Weight::MAX);
+		assert_eq!(Vaults::last_rebalance(id), Some(5));
+		System::set_block_number(6);
+		Vaults::on_idle(6, Weight::MAX);
+		assert_eq!(Vaults::last_rebalance(id), Some(5));
+		System::assert_has_event(RuntimeEvent::Vaults(crate::Event::WithdrawalSettled {
+			vault: id,
+			account: ALICE,
+			request: 0,
+			asset_amount: 400,
+		}));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 400);
+		assert_eq!(Vaults::withdrawal_queue(id), WithdrawalQueue { head: 1, tail: 1 });
+	});
+}
+
+#[test]
+fn withdraw_instant_recalls_funds_and_charges_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		let asset_id = MockCurrencyId::A;
+		let strategy: AccountId = StrategyTestPalletID::get().into_account_truncating();
+		let (id, _) = create_vault(strategy, asset_id);
+		Tokens::mint_into(asset_id, &ALICE, 1000).unwrap();
+		assert_ok!(Vaults::deposit(RuntimeOrigin::signed(ALICE), id, 1000));
+		Vaults::on_idle(1, Weight::MAX);
+
+		assert_ok!(Vaults::withdraw_instant(RuntimeOrigin::signed(ALICE), id, 500));
+		System::assert_last_event(RuntimeEvent::Vaults(crate::Event::InstantWithdrawn {
+			vault: id,
+			account: ALICE,
+			lp_amount: 500,
+			asset_amount: 495,
+			fee: 5,
+		}));
+		assert_eq!(Tokens::balance(asset_id, &ALICE), 495);
+		assert_eq!(Tokens::balance(asset_id, &strategy), 505);
+		// the fee stays with the remaining LP holders
+		assert_eq!(Vaults::convert_to_assets(&id, 500), Ok(505));
+	});
+}
@@ -18,6 +18,10 @@ pub trait WeightInfo {
 	fn add_surcharge() -> Weight;
 	fn claim_surcharge() -> Weight;
 	fn delete_tombstoned() -> Weight;
+	fn rebalance_vault(s: u32) -> Weight;
+	fn request_withdrawal() -> Weight;
+	fn settle_withdrawals(n: u32) -> Weight;
+	fn withdraw_instant() -> Weight;
 }
 
 pub struct SubstrateWeight<T>(PhantomData<T>);
@@ -86,6 +90,53 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault LastRebalance (r:1 w:1)
+	// Storage: Vault CapitalStructure (r:s w:s)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn rebalance_vault(s: u32) -> Weight {
+		Weight::from_ref_time(40_000_000_u64)
+			.saturating_add(Weight::from_ref_time(95_000_000_u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(s as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	// Storage: Vault PendingWithdrawals (r:1 w:1)
+	// Storage: Vault WithdrawalQueues (r:1 w:1)
+	// Storage: Vault WithdrawalRequests (r:0 w:1)
+	// Storage: Vault LastRebalance (r:0 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_ref_time(98_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: Vault WithdrawalQueues (r:1 w:1)
+	// Storage: Vault WithdrawalRequests (r:n w:n)
+	// Storage: Vault PendingWithdrawals (r:n w:n)
+	// Storage: Tokens Accounts (r:n w:n)
+	fn settle_withdrawals(n: u32) -> Weight {
+		Weight::from_ref_time(20_000_000_u64)
+			.saturating_add(Weight::from_ref_time(60_000_000_u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault PendingWithdrawals (r:1 w:0)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	fn withdraw_instant() -> Weight {
+		Weight::from_ref_time(120_000_000_u64)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 impl WeightInfo for () {
@@ -153,4 +204,47 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	fn rebalance_vault(s: u32) -> Weight {
+		Weight::from_ref_time(40_000_000_u64)
+			.saturating_add(Weight::from_ref_time(95_000_000_u64).saturating_mul(s as u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(s as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	// Storage: Vault PendingWithdrawals (r:1 w:1)
+	// Storage: Vault WithdrawalQueues (r:1 w:1)
+	// Storage: Vault WithdrawalRequests (r:0 w:1)
+	// Storage: Vault LastRebalance (r:0 w:1)
+	fn request_withdrawal() -> Weight {
+		Weight::from_ref_time(98_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: Vault WithdrawalQueues (r:1 w:1)
+	// Storage: Vault WithdrawalRequests (r:n w:n)
+	// Storage: Vault PendingWithdrawals (r:n w:n)
+	// Storage: Tokens Accounts (r:n w:n)
+	fn settle_withdrawals(n: u32) -> Weight {
+		Weight::from_ref_time(20_000_000_u64)
+			.saturating_add(Weight::from_ref_time(60_000_000_u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Vault Vaults (r:1 w:0)
+	// Storage: Vault PendingWithdrawals (r:1 w:0)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Vault CapitalStructure (r:2 w:0)
+	fn withdraw_instant() -> Weight {
+		Weight::from_ref_time(120_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
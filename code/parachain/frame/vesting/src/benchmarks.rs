@@ -9,7 +9,8 @@ use crate::{
 use codec::Decode;
 use composable_support::abstractions::utils::increment::Increment;
 use composable_traits::vesting::{
-	VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo, VestingWindow::BlockNumberBased,
+	VestingCurve, VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo,
+	VestingWindow::BlockNumberBased,
 };
 use frame_benchmarking::{account, benchmarks, vec, whitelisted_caller};
 use frame_support::traits::{fungibles::Mutate, Get};
@@ -56,7 +57,14 @@ where
 	T: Config,
 	BalanceOf<T>: From<u64>,
 {
-	VestingScheduleInfo { window: BlockNumberBased { start, period }, period_count, per_period }
+	VestingScheduleInfo {
+		window: BlockNumberBased { start, period },
+		period_count,
+		per_period,
+		cliff: 0,
+		curve: VestingCurve::Linear,
+		revoker: None,
+	}
 }
 
 fn vesting_schedule<T>(
@@ -75,6 +83,9 @@ where
 		period_count,
 		per_period,
 		already_claimed: Zero::zero(),
+		cliff: 0,
+		curve: VestingCurve::Linear,
+		revoker: None,
 	}
 }
 
@@ -164,5 +175,64 @@ benchmarks! {
 		}
 	}: _(RawOrigin::Signed(caller), dest_look_up, asset_id, VestingScheduleIdSet::All)
 
+	revoke {
+		let asset_id = asset::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let per_period = T::MinVestedTransfer::get();
+		let mut schedule_info = vesting_schedule_info::<T>(
+			START_BLOCK_NUMBER.into(),
+			PERIOD.into(),
+			PERIOD_COUNT,
+			per_period.into(),
+		);
+		schedule_info.revoker = Some(caller.clone());
+		let dest = create_account::<T>("dest", 1);
+		let beneficiary = create_account::<T>("beneficiary", 2);
+		fund_account::<T>(&caller, asset_id.clone(), FUNDING.into());
+		<Pallet<T> as VestedTransfer>::vested_transfer(asset_id.clone(), &caller, &dest, schedule_info).unwrap();
+		let vesting_schedule_id = VestingScheduleNonce::<T>::get();
+	}: _(
+		RawOrigin::Signed(caller),
+		T::Lookup::unlookup(dest),
+		asset_id,
+		vesting_schedule_id,
+		T::Lookup::unlookup(beneficiary)
+	)
+
+	wrap_into_fnft {
+		let asset_id = asset::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let source = create_account::<T>("source", 1);
+		let per_period = T::MinVestedTransfer::get();
+		let schedule_info = vesting_schedule_info::<T>(
+			START_BLOCK_NUMBER.into(),
+			PERIOD.into(),
+			PERIOD_COUNT,
+			per_period.into(),
+		);
+		fund_account::<T>(&source, asset_id.clone(), FUNDING.into());
+		<Pallet<T> as VestedTransfer>::vested_transfer(asset_id.clone(), &source, &caller, schedule_info).unwrap();
+		let vesting_schedule_id = VestingScheduleNonce::<T>::get();
+	}: _(RawOrigin::Signed(caller), asset_id, vesting_schedule_id)
+
+	claim_fnft {
+		let asset_id = asset::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		let source = create_account::<T>("source", 1);
+		let per_period = T::MinVestedTransfer::get();
+		let schedule_info = vesting_schedule_info::<T>(
+			START_BLOCK_NUMBER.into(),
+			PERIOD.into(),
+			PERIOD_COUNT,
+			per_period.into(),
+		);
+		fund_account::<T>(&source, asset_id.clone(), FUNDING.into());
+		<Pallet<T> as VestedTransfer>::vested_transfer(asset_id.clone(), &source, &caller, schedule_info).unwrap();
+		let vesting_schedule_id = VestingScheduleNonce::<T>::get();
+		let fnft_instance_id = Pallet::<T>::do_wrap_into_fnft(&caller, asset_id, vesting_schedule_id).unwrap();
+		// claim the whole schedule, burning the financial NFT
+		frame_system::Pallet::<T>::set_block_number((START_BLOCK_NUMBER + PERIOD * PERIOD_COUNT).into());
+	}: _(RawOrigin::Signed(caller), fnft_instance_id)
+
 	impl_benchmark_test_suite!(Vesting, crate::mock::ExtBuilder::build(), crate::mock::Runtime);
 }
@@ -15,6 +15,10 @@
 //! timestamps as well as block numbers for vesting schedules. All `VestingSchedule`s under
 //! an account could be queried in chain state.
 //!
+//! Nothing is released during the first `cliff` periods, the amount vested by then is released
+//! at once at the end of the cliff. The `curve` of a schedule changes how the vested amount is
+//! released: every period, every few periods, or following a custom table of cumulative shares.
+//!
 //! ## Interface
 //! - `VestedTransfer` - allowing a third party pallet to have this implementation as dependency to
 //!   execute vested transfers.
@@ -26,6 +30,17 @@
 //! - `claim_for` - Claim unlocked balances for a `target` account.
 //! - `update_vesting_schedules` - Update all vesting schedules under an account, `root` origin
 //!   required.
+//! - `revoke` - Revoke the unvested part of a vesting schedule, sending it to a `beneficiary`
+//!   account. Only the `revoker` of the schedule can revoke it.
+//! - `wrap_into_fnft` - Wrap a vesting schedule into a financial NFT, transferring the NFT
+//!   transfers the right to claim the schedule.
+//! - `claim_fnft` - Claim unlocked balances of a vesting schedule wrapped into a financial NFT.
+//!
+//! ### Financial NFTs
+//!
+//! A wrapped vesting schedule and its unclaimed balance are moved to the asset account of a
+//! financial NFT minted to the owner of the schedule. The schedule keeps vesting there, and only
+//! the owner of the NFT can claim it. The NFT is burnt once the schedule is fully claimed.
 
 #![cfg_attr(
 	not(test),
@@ -46,23 +61,31 @@ use composable_support::{
 	abstractions::utils::increment::Increment,
 	math::safe::{SafeAdd, SafeSub},
 };
-use composable_traits::vesting::{
-	VestedTransfer, VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo,
+use composable_traits::{
+	fnft::{FinancialNft, FinancialNftProtocol},
+	vesting::{VestedTransfer, VestingSchedule, VestingScheduleIdSet, VestingScheduleInfo},
 };
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{EnsureOrigin, Get, LockIdentifier, Time},
-	transactional, BoundedBTreeMap,
+	traits::{
+		tokens::nonfungibles::{
+			Create as NonFungiblesCreate, Inspect as NonFungiblesInspect,
+			Mutate as NonFungiblesMutate,
+		},
+		EnsureOrigin, Get, LockIdentifier, Time,
+	},
+	transactional, BoundedBTreeMap, PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::*};
 use orml_traits::{MultiCurrency, MultiLockableCurrency};
 use sp_runtime::{
-	traits::{BlockNumberProvider, One, StaticLookup, Zero},
+	traits::{AccountIdConversion, BlockNumberProvider, One, StaticLookup, Zero},
 	ArithmeticError, DispatchResult,
 };
 use sp_std::{collections::btree_map::BTreeMap, convert::TryInto, vec, vec::Vec};
 
+pub mod migrations;
 mod weights;
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -87,10 +110,12 @@ pub mod module {
 		},
 		math::safe::SafeAdd,
 	};
-	use composable_traits::vesting::{VestingSchedule, VestingScheduleInfo, VestingWindow};
+	use composable_traits::vesting::{
+		VestingCurve, VestingSchedule, VestingScheduleInfo, VestingWindow,
+	};
 	use frame_support::{traits::Time, BoundedBTreeMap};
 	use orml_traits::{MultiCurrency, MultiLockableCurrency};
-	use sp_runtime::traits::AtLeast32Bit;
+	use sp_runtime::traits::AtLeast32BitUnsigned;
 
 	use super::*;
 
@@ -106,9 +131,10 @@ pub mod module {
 		BlockNumberOf<T>,
 		MomentOf<T>,
 		BalanceOf<T>,
+		AccountIdOf<T>,
 	>;
 	pub(crate) type VestingScheduleInfoOf<T> =
-		VestingScheduleInfo<BlockNumberOf<T>, MomentOf<T>, BalanceOf<T>>;
+		VestingScheduleInfo<BlockNumberOf<T>, MomentOf<T>, BalanceOf<T>, AccountIdOf<T>>;
 	pub type ScheduledItem<T> = (
 		AssetIdOf<T>,
 		<T as frame_system::Config>::AccountId,
@@ -140,7 +166,7 @@ pub mod module {
 		type MaxVestingSchedules: Get<u32>;
 
 		/// Type of time
-		type Moment: AtLeast32Bit
+		type Moment: AtLeast32BitUnsigned
 			+ Parameter
 			+ Default
 			+ Copy
@@ -161,6 +187,29 @@ pub mod module {
 			+ FullCodec
 			+ MaxEncodedLen
 			+ TypeInfo;
+
+		/// Financial NFTs wrapping vesting schedules.
+		type FinancialNft: NonFungiblesMutate<AccountIdOf<Self>>
+			+ NonFungiblesCreate<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			> + FinancialNft<
+				AccountIdOf<Self>,
+				CollectionId = AssetIdOf<Self>,
+				ItemId = Self::FinancialNftInstanceId,
+			>;
+
+		/// The ID of a financial NFT instance.
+		type FinancialNftInstanceId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// The financial NFT collection of wrapped vesting schedules.
+		#[pallet::constant]
+		type FnftCollectionId: Get<AssetIdOf<Self>>;
+
+		/// The pallet ID, owning the financial NFT collection.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
 	}
 
 	#[pallet::error]
@@ -181,6 +230,18 @@ pub mod module {
 		TryingToSelfVest,
 		/// There is no vesting schedule with a given id
 		VestingScheduleNotFound,
+		/// The cliff is longer than the vesting schedule
+		InvalidVestingCliff,
+		/// The release curve does not fit the vesting schedule
+		InvalidVestingCurve,
+		/// The origin is not the revoker of the vesting schedule
+		NotScheduleRevoker,
+		/// The vesting schedule has nothing left to vest
+		NothingToRevoke,
+		/// There is no vesting schedule wrapped into the financial NFT
+		FnftNotFound,
+		/// Only the owner of the financial NFT can claim its vesting schedule
+		NotFnftOwner,
 	}
 
 	#[pallet::event]
@@ -207,6 +268,34 @@ pub mod module {
 		},
 		/// Updated vesting schedules.
 		VestingSchedulesUpdated { who: AccountIdOf<T> },
+		/// Revoked the unvested part of a vesting schedule.
+		VestingScheduleRevoked {
+			who: AccountIdOf<T>,
+			asset: AssetIdOf<T>,
+			vesting_schedule_id: T::VestingScheduleId,
+			revoker: AccountIdOf<T>,
+			beneficiary: AccountIdOf<T>,
+			/// Unvested amount sent to the beneficiary.
+			revoked_amount: BalanceOf<T>,
+			/// Vested amount left to claim by `who`.
+			claimable_amount: BalanceOf<T>,
+		},
+		/// Wrapped a vesting schedule into a financial NFT.
+		VestingScheduleWrapped {
+			who: AccountIdOf<T>,
+			asset: AssetIdOf<T>,
+			vesting_schedule_id: T::VestingScheduleId,
+			fnft_collection_id: AssetIdOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		},
+		/// Claimed a vesting schedule wrapped into a financial NFT.
+		FnftClaimed {
+			owner: AccountIdOf<T>,
+			fnft_collection_id: AssetIdOf<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+			asset: AssetIdOf<T>,
+			claimed_amount: BalanceOf<T>,
+		},
 	}
 
 	/// Vesting schedules of an account.
@@ -227,6 +316,17 @@ pub mod module {
 		ValueQuery,
 	>;
 
+	/// The asset and id of the vesting schedules wrapped into financial NFTs.
+	#[pallet::storage]
+	#[pallet::getter(fn fnft_vesting_schedule)]
+	pub type FnftVestingSchedules<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::FinancialNftInstanceId,
+		(AssetIdOf<T>, T::VestingScheduleId),
+		OptionQuery,
+	>;
+
 	/// Counter used to uniquely identify vesting schedules within this pallet.
 	#[pallet::storage]
 	#[pallet::getter(fn vesting_schedules_count)]
@@ -263,6 +363,9 @@ pub mod module {
 							period_count: *period_count,
 							per_period: *per_period,
 							already_claimed: BalanceOf::<T>::zero(),
+							cliff: 0,
+							curve: VestingCurve::Linear,
+							revoker: None,
 						},
 					)
 					.expect("Max vesting schedules exceeded");
@@ -290,7 +393,10 @@ pub mod module {
 		}
 	}
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -402,6 +508,79 @@ pub mod module {
 
 			Ok(())
 		}
+
+		/// Revoke the unvested part of a vesting schedule.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the `revoker` of the schedule.
+		///
+		/// The schedule is cut down to the amount vested so far, which `who` can still claim,
+		/// and the unvested balance is transferred to `beneficiary`. A revoked schedule cannot be
+		/// revoked again.
+		///
+		/// - `who`: The account the vesting schedule belongs to.
+		/// - `asset`: The asset associated with the vesting schedule.
+		/// - `vesting_schedule_id`: The id of the vesting schedule to revoke.
+		/// - `beneficiary`: The account receiving the unvested balance.
+		///
+		/// Emits `VestingScheduleRevoked`.
+		#[pallet::weight(<T as Config>::WeightInfo::revoke())]
+		pub fn revoke(
+			origin: OriginFor<T>,
+			who: <T::Lookup as StaticLookup>::Source,
+			asset: AssetIdOf<T>,
+			vesting_schedule_id: T::VestingScheduleId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let revoker = ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			Self::do_revoke(&revoker, &who, asset, vesting_schedule_id, &beneficiary)?;
+
+			Ok(())
+		}
+
+		/// Wrap a vesting schedule into a financial NFT.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the owner of the schedule.
+		///
+		/// The schedule and its unclaimed balance are moved to the asset account of a new
+		/// financial NFT minted to the caller. Whoever owns the NFT can claim the schedule.
+		///
+		/// - `asset`: The asset associated with the vesting schedule.
+		/// - `vesting_schedule_id`: The id of the vesting schedule to wrap.
+		///
+		/// Emits `VestingScheduleWrapped`.
+		#[pallet::weight(<T as Config>::WeightInfo::wrap_into_fnft())]
+		pub fn wrap_into_fnft(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T>,
+			vesting_schedule_id: T::VestingScheduleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_wrap_into_fnft(&who, asset, vesting_schedule_id)?;
+
+			Ok(())
+		}
+
+		/// Claim unlocked balances of a vesting schedule wrapped into a financial NFT.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the owner of the NFT.
+		///
+		/// The NFT is burnt once its vesting schedule is fully claimed or revoked.
+		///
+		/// - `fnft_instance_id`: The financial NFT wrapping the vesting schedule.
+		///
+		/// Emits `FnftClaimed`.
+		#[pallet::weight(<T as Config>::WeightInfo::claim_fnft())]
+		pub fn claim_fnft(
+			origin: OriginFor<T>,
+			fnft_instance_id: T::FinancialNftInstanceId,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_claim_fnft(&owner, fnft_instance_id)?;
+
+			Ok(())
+		}
 	}
 }
 
@@ -420,7 +599,12 @@ impl<T: Config> VestedTransfer for Pallet<T> {
 		asset: Self::AssetId,
 		from: &Self::AccountId,
 		to: &Self::AccountId,
-		schedule_info: VestingScheduleInfo<Self::BlockNumber, Self::Moment, Self::Balance>,
+		schedule_info: VestingScheduleInfo<
+			Self::BlockNumber,
+			Self::Moment,
+			Self::Balance,
+			Self::AccountId,
+		>,
 	) -> frame_support::dispatch::DispatchResult {
 		ensure!(from != to, Error::<T>::TryingToSelfVest);
 
@@ -454,14 +638,51 @@ impl<T: Config> VestedTransfer for Pallet<T> {
 
 		Ok(())
 	}
+
+	#[transactional]
+	fn claim(asset: Self::AssetId, who: &Self::AccountId) -> Result<Self::Balance, DispatchError> {
+		if <VestingSchedules<T>>::contains_key(who, asset) {
+			Self::do_claim(who, asset, VestingScheduleIdSet::All)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+}
+
+impl<T: Config> FinancialNftProtocol for Pallet<T> {
+	type ItemId = T::FinancialNftInstanceId;
+	type AssetId = AssetIdOf<T>;
+	type Balance = BalanceOf<T>;
+
+	fn collection_asset_ids() -> Vec<Self::AssetId> {
+		vec![T::FnftCollectionId::get()]
+	}
+
+	fn value_of(
+		collection: &Self::AssetId,
+		instance: &Self::ItemId,
+	) -> Result<Vec<(Self::AssetId, Self::Balance)>, DispatchError> {
+		ensure!(*collection == T::FnftCollectionId::get(), Error::<T>::FnftNotFound);
+		let (asset, vesting_schedule_id) =
+			FnftVestingSchedules::<T>::get(instance).ok_or(Error::<T>::FnftNotFound)?;
+		let fnft_account = T::FinancialNft::asset_account(collection, instance);
+		let unclaimed_amount = Self::unclaimed_balance(
+			&fnft_account,
+			asset,
+			VestingScheduleIdSet::One(vesting_schedule_id),
+		)?;
+
+		Ok(vec![(asset, unclaimed_amount)])
+	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Claims the unlocked balances of vesting schedules, returning the claimed balance.
 	fn do_claim(
 		who: &AccountIdOf<T>,
 		asset: AssetIdOf<T>,
 		vesting_schedule_ids: VestingScheduleIdSet<T::VestingScheduleId, T::MaxVestingSchedules>,
-	) -> Result<(), DispatchError> {
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let current_locked_amount = Self::unclaimed_balance(who, asset, VestingScheduleIdSet::All)?;
 		let (balance_to_claim, claimed_amount_per_schedule) =
 			Self::unlocked_claimable_balance(who, asset, vesting_schedule_ids.clone())?;
@@ -484,9 +705,174 @@ impl<T: Config> Pallet<T> {
 			claimed_amount_per_schedule,
 		});
 
+		Ok(balance_to_claim)
+	}
+
+	fn do_revoke(
+		revoker: &AccountIdOf<T>,
+		who: &AccountIdOf<T>,
+		asset: AssetIdOf<T>,
+		vesting_schedule_id: T::VestingScheduleId,
+		beneficiary: &AccountIdOf<T>,
+	) -> DispatchResult {
+		let (revoked_amount, claimable_amount) =
+			<VestingSchedules<T>>::try_mutate_exists(who, asset, |maybe_schedules| {
+				let schedules =
+					maybe_schedules.as_mut().ok_or(Error::<T>::VestingScheduleNotFound)?;
+				let schedule = schedules
+					.get_mut(&vesting_schedule_id)
+					.ok_or(Error::<T>::VestingScheduleNotFound)?;
+				ensure!(schedule.revoker.as_ref() == Some(revoker), Error::<T>::NotScheduleRevoker);
+
+				let revoked_amount = schedule
+					.revoke(frame_system::Pallet::<T>::current_block_number(), T::Time::now());
+				ensure!(!revoked_amount.is_zero(), Error::<T>::NothingToRevoke);
+
+				let claimable_amount =
+					schedule.total_amount()?.safe_sub(&schedule.already_claimed)?;
+				if claimable_amount.is_zero() {
+					schedules.remove(&vesting_schedule_id);
+				}
+				if schedules.is_empty() {
+					*maybe_schedules = None;
+				}
+
+				Ok::<_, DispatchError>((revoked_amount, claimable_amount))
+			})?;
+
+		Self::update_lock(who, asset)?;
+		T::Currency::transfer(asset, who, beneficiary, revoked_amount)?;
+
+		Self::deposit_event(Event::VestingScheduleRevoked {
+			who: who.clone(),
+			asset,
+			vesting_schedule_id,
+			revoker: revoker.clone(),
+			beneficiary: beneficiary.clone(),
+			revoked_amount,
+			claimable_amount,
+		});
+
+		Ok(())
+	}
+
+	fn do_wrap_into_fnft(
+		who: &AccountIdOf<T>,
+		asset: AssetIdOf<T>,
+		vesting_schedule_id: T::VestingScheduleId,
+	) -> Result<T::FinancialNftInstanceId, DispatchError> {
+		let schedule = <VestingSchedules<T>>::try_mutate_exists(who, asset, |maybe_schedules| {
+			let schedules = maybe_schedules.as_mut().ok_or(Error::<T>::VestingScheduleNotFound)?;
+			let schedule = schedules
+				.remove(&vesting_schedule_id)
+				.ok_or(Error::<T>::VestingScheduleNotFound)?;
+			if schedules.is_empty() {
+				*maybe_schedules = None;
+			}
+
+			Ok::<_, DispatchError>(schedule)
+		})?;
+		let unclaimed_amount = schedule.total_amount()?.safe_sub(&schedule.already_claimed)?;
+		Self::update_lock(who, asset)?;
+
+		let fnft_collection_id = T::FnftCollectionId::get();
+		T::FinancialNft::ensure_collection(
+			&fnft_collection_id,
+			&T::PalletId::get().into_account_truncating(),
+		)?;
+		let fnft_instance_id = T::FinancialNft::get_next_nft_id(&fnft_collection_id)?;
+		let fnft_account = T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+
+		T::Currency::transfer(asset, who, &fnft_account, unclaimed_amount)?;
+		<VestingSchedules<T>>::try_mutate(&fnft_account, asset, |schedules| {
+			schedules
+				.try_insert(vesting_schedule_id, schedule)
+				.map_err(|_| Error::<T>::MaxVestingSchedulesExceeded)
+		})?;
+		Self::update_lock(&fnft_account, asset)?;
+
+		T::FinancialNft::mint_into(&fnft_collection_id, &fnft_instance_id, who)?;
+		FnftVestingSchedules::<T>::insert(fnft_instance_id, (asset, vesting_schedule_id));
+
+		Self::deposit_event(Event::VestingScheduleWrapped {
+			who: who.clone(),
+			asset,
+			vesting_schedule_id,
+			fnft_collection_id,
+			fnft_instance_id,
+		});
+
+		Ok(fnft_instance_id)
+	}
+
+	fn do_claim_fnft(
+		owner: &AccountIdOf<T>,
+		fnft_instance_id: T::FinancialNftInstanceId,
+	) -> DispatchResult {
+		let fnft_collection_id = T::FnftCollectionId::get();
+		let (asset, vesting_schedule_id) =
+			FnftVestingSchedules::<T>::get(fnft_instance_id).ok_or(Error::<T>::FnftNotFound)?;
+		ensure!(
+			T::FinancialNft::owner(&fnft_collection_id, &fnft_instance_id).as_ref() == Some(owner),
+			Error::<T>::NotFnftOwner
+		);
+
+		let fnft_account = T::FinancialNft::asset_account(&fnft_collection_id, &fnft_instance_id);
+		let is_vesting = |account: &AccountIdOf<T>| {
+			<VestingSchedules<T>>::get(account, asset).contains_key(&vesting_schedule_id)
+		};
+
+		// the schedule is gone once revoked with nothing left to claim
+		if is_vesting(&fnft_account) {
+			Self::do_claim(&fnft_account, asset, VestingScheduleIdSet::One(vesting_schedule_id))?;
+		}
+		// anyone can `claim_for` the asset account, what they unlocked is forwarded as well
+		let claimed_amount = T::Currency::free_balance(asset, &fnft_account)
+			.safe_sub(&Self::total_unclaimed_balance(&fnft_account, asset)?)?;
+		if !claimed_amount.is_zero() {
+			T::Currency::transfer(asset, &fnft_account, owner, claimed_amount)?;
+		}
+
+		if !is_vesting(&fnft_account) {
+			T::FinancialNft::burn(&fnft_collection_id, &fnft_instance_id, Some(owner))?;
+			FnftVestingSchedules::<T>::remove(fnft_instance_id);
+		}
+
+		Self::deposit_event(Event::FnftClaimed {
+			owner: owner.clone(),
+			fnft_collection_id,
+			fnft_instance_id,
+			asset,
+			claimed_amount,
+		});
+
 		Ok(())
 	}
 
+	/// Locks the unclaimed balance of all the vesting schedules of an account, removing the lock
+	/// if there is none.
+	fn update_lock(who: &AccountIdOf<T>, asset: AssetIdOf<T>) -> DispatchResult {
+		let locked_amount = Self::total_unclaimed_balance(who, asset)?;
+
+		if locked_amount.is_zero() {
+			T::Currency::remove_lock(VESTING_LOCK_ID, asset, who)
+		} else {
+			T::Currency::set_lock(VESTING_LOCK_ID, asset, who, locked_amount)
+		}
+	}
+
+	/// The unclaimed balance of all the vesting schedules of an account.
+	fn total_unclaimed_balance(
+		who: &AccountIdOf<T>,
+		asset: AssetIdOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if <VestingSchedules<T>>::contains_key(who, asset) {
+			Self::unclaimed_balance(who, asset, VestingScheduleIdSet::All)
+		} else {
+			Ok(Zero::zero())
+		}
+	}
+
 	/// Claims all available balance
 	/// Returns total locked balance for a given account, asset and vesting schedules, based on
 	/// current block number
@@ -587,8 +973,8 @@ impl<T: Config> Pallet<T> {
 						.get_mut(id_to_claim)
 						.ok_or(Error::<T>::VestingScheduleNotFound)?;
 
-					// Total amount for vesting schedule
-					let total_amount = ensure_valid_vesting_schedule::<T>(schedule)?;
+					// Total amount for vesting schedule, revoked schedules may be below the minimum
+					let total_amount = schedule.total_amount()?;
 					// Currently locked amount
 					let locked_amount = schedule.locked_amount(
 						frame_system::Pallet::<T>::current_block_number(),
@@ -677,6 +1063,8 @@ fn ensure_valid_vesting_schedule<T: Config>(
 	ensure!(!schedule.is_zero_period(), Error::<T>::ZeroVestingPeriod);
 	ensure!(schedule.end().is_some(), ArithmeticError::Overflow);
 	ensure!(!schedule.period_count.is_zero(), Error::<T>::ZeroVestingPeriodCount);
+	ensure!(schedule.cliff <= schedule.period_count, Error::<T>::InvalidVestingCliff);
+	ensure!(schedule.curve.is_valid(schedule.period_count), Error::<T>::InvalidVestingCurve);
 
 	let total_total = schedule.total_amount()?;
 
@@ -0,0 +1,88 @@
+//! Storage migrations of the vesting pallet.
+
+pub mod v1 {
+	use crate::{
+		module::{BalanceOf, BlockNumberOf, MomentOf, VestingScheduleOf},
+		Config, Pallet, VestingSchedules,
+	};
+	use codec::{Decode, Encode, HasCompact};
+	use composable_traits::vesting::{VestingCurve, VestingSchedule, VestingWindow};
+	use frame_support::{
+		log,
+		traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+		weights::Weight,
+		BoundedBTreeMap,
+	};
+	use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+	/// Vesting schedule before schedules had a cliff, a release curve and a revoker.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldVestingSchedule<
+		VestingScheduleId,
+		BlockNumber,
+		Moment,
+		Balance: HasCompact,
+	> {
+		pub vesting_schedule_id: VestingScheduleId,
+		pub window: VestingWindow<BlockNumber, Moment>,
+		pub period_count: u32,
+		#[codec(compact)]
+		pub per_period: Balance,
+		pub already_claimed: Balance,
+	}
+
+	pub(crate) type OldVestingScheduleOf<T> = OldVestingSchedule<
+		<T as Config>::VestingScheduleId,
+		BlockNumberOf<T>,
+		MomentOf<T>,
+		BalanceOf<T>,
+	>;
+
+	/// Gives existing vesting schedules no cliff, a linear release curve and no revoker, which
+	/// keeps them vesting as before.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0_u64;
+			VestingSchedules::<T>::translate::<
+				BoundedBTreeMap<
+					T::VestingScheduleId,
+					OldVestingScheduleOf<T>,
+					T::MaxVestingSchedules,
+				>,
+				_,
+			>(|_, _, schedules| {
+				translated += 1;
+				let schedules = schedules
+					.into_iter()
+					.map(|(vesting_schedule_id, schedule)| {
+						(
+							vesting_schedule_id,
+							VestingSchedule {
+								vesting_schedule_id: schedule.vesting_schedule_id,
+								window: schedule.window,
+								period_count: schedule.period_count,
+								per_period: schedule.per_period,
+								already_claimed: schedule.already_claimed,
+								cliff: 0,
+								curve: VestingCurve::Linear,
+								revoker: None,
+							},
+						)
+					})
+					.collect::<BTreeMap<_, VestingScheduleOf<T>>>();
+				// same bound as before, cannot fail
+				schedules.try_into().ok()
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+			log::info!(target: "vesting", "migrated {} vesting schedule sets to v1", translated);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+	}
+}
@@ -3,10 +3,15 @@
 #![cfg(test)]
 
 use super::*;
-use composable_traits::vesting::VestingWindow::{BlockNumberBased, MomentBased};
+use composable_traits::{
+	account_proxy::{AccountProxyWrapper, ProxyType},
+	fnft::FnftAccountProxyTypeSelector,
+	vesting::VestingWindow::{BlockNumberBased, MomentBased},
+};
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{EnsureOrigin, Everything},
+	traits::{EnsureOrigin, Everything, InstanceFilter},
+	PalletId,
 };
 use frame_system::{EnsureRoot, RawOrigin};
 use orml_traits::parameter_type_with_key;
@@ -14,7 +19,7 @@ use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{IdentityLookup, TrailingZeroInput},
+	traits::{BlakeTwo256, IdentityLookup, TrailingZeroInput},
 };
 
 use crate as vesting;
@@ -48,6 +53,13 @@ pub const MILLISECS_PER_BLOCK: u64 = 6000;
 pub enum MockCurrencyId {
 	BTC,
 	ETH,
+	VESTING_FNFT,
+}
+
+impl From<MockCurrencyId> for u128 {
+	fn from(currency_id: MockCurrencyId) -> Self {
+		currency_id as u128
+	}
 }
 
 parameter_types! {
@@ -60,7 +72,7 @@ impl frame_system::Config for Runtime {
 	type Index = u64;
 	type BlockNumber = u64;
 	type Hash = H256;
-	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type Hashing = BlakeTwo256;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
@@ -159,9 +171,76 @@ impl orml_tokens::Config for Runtime {
 	type CurrencyHooks = CurrencyHooks;
 }
 
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+pub struct MockFnftAccountProxyType;
+impl FnftAccountProxyTypeSelector<ProxyType> for MockFnftAccountProxyType {
+	fn get_proxy_types() -> Vec<ProxyType> {
+		[ProxyType::Any, ProxyType::CancelProxy].into()
+	}
+}
+
+impl pallet_fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProperties = frame_support::traits::ConstU32<16>;
+	type FinancialNftCollectionId = MockCurrencyId;
+	type FinancialNftInstanceId = u64;
+	type ProxyType = ProxyType;
+	type AccountProxy = AccountProxyWrapper<Runtime>;
+	type ProxyTypeSelector = MockFnftAccountProxyType;
+	type TransferHook = ();
+	type PalletId = FnftPalletId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub MaxProxies: u32 = 4;
+	pub MaxPending: u32 = 32;
+	pub ProxyPrice: u32 = 0;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = ();
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ProxyPrice;
+	type ProxyDepositFactor = ProxyPrice;
+	type MaxProxies = MaxProxies;
+	type WeightInfo = ();
+	type MaxPending = MaxPending;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ProxyPrice;
+	type AnnouncementDepositFactor = ProxyPrice;
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, c: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			ProxyType::CancelProxy =>
+				matches!(c, RuntimeCall::Proxy(pallet_proxy::Call::reject_announcement { .. })),
+			_ => false,
+		}
+	}
+
+	fn is_superset(&self, o: &Self) -> bool {
+		match (self, o) {
+			(x, y) if x == y => true,
+			(ProxyType::Any, _) => true,
+			(_, ProxyType::Any) => false,
+			_ => false,
+		}
+	}
+}
+
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 3;
 	pub const MinVestedTransfer: u64 = 5;
+	pub const VestingFnftCollectionId: MockCurrencyId = MockCurrencyId::VESTING_FNFT;
+	pub const VestingPalletId: PalletId = PalletId(*b"pal_vest");
 }
 
 impl Config for Runtime {
@@ -175,6 +254,10 @@ impl Config for Runtime {
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = u64;
+	type FnftCollectionId = VestingFnftCollectionId;
+	type PalletId = VestingPalletId;
 }
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
@@ -190,6 +273,8 @@ construct_runtime!(
 		Vesting: vesting::{Pallet, Storage, Call, Event<T>, Config<T>},
 		Tokens: orml_tokens::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage},
+		Fnft: pallet_fnft,
+		Proxy: pallet_proxy,
 	}
 );
 
@@ -4,16 +4,17 @@
 
 use super::*;
 use composable_traits::vesting::{
-	VestingSchedule, VestingScheduleInfo,
+	VestingCurve, VestingSchedule, VestingScheduleInfo,
 	VestingWindow::{BlockNumberBased, MomentBased},
 };
 use frame_support::{
 	assert_noop, assert_ok,
 	error::BadOrigin,
-	traits::{fungibles::Mutate, TryCollect},
+	traits::{fungibles::Mutate, GetStorageVersion, OnRuntimeUpgrade, StorageVersion, TryCollect},
 };
 use mock::{RuntimeEvent, *};
 use orml_tokens::BalanceLock;
+use sp_runtime::Perbill;
 
 #[test]
 fn vesting_from_chain_spec_works() {
@@ -38,6 +39,9 @@ fn vesting_from_chain_spec_works() {
 					period_count: 1_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					cliff: 0,
+					curve: VestingCurve::Linear,
+					revoker: None,
 				},
 			),
 			(
@@ -60,6 +64,9 @@ fn vesting_from_chain_spec_works() {
 					period_count: 3_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					cliff: 0,
+					curve: VestingCurve::Linear,
+					revoker: None,
 				},
 			),
 			(
@@ -86,6 +93,9 @@ fn vesting_from_chain_spec_works() {
 					period_count: 3_u32,
 					per_period: 5_u64,
 					already_claimed: 0_u64,
+					cliff: 0,
+					curve: VestingCurve::Linear,
+					revoker: None,
 				},
 			),
 		]
@@ -156,6 +166,9 @@ fn vested_transfer_self_vest_ko() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -179,6 +192,9 @@ fn vested_transfer_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -213,6 +229,9 @@ fn vested_transfer_trait_emits_vesting_schedule_added_event() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
@@ -235,6 +254,37 @@ fn vested_transfer_trait_emits_vesting_schedule_added_event() {
 	});
 }
 
+#[test]
+fn vested_transfer_trait_claim_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Tokens::mint_into(MockCurrencyId::ETH, &ALICE, 100));
+		assert_eq!(<Vesting as VestedTransfer>::claim(MockCurrencyId::ETH, &BOB), Ok(0));
+
+		assert_ok!(<Vesting as VestedTransfer>::vested_transfer(
+			MockCurrencyId::ETH,
+			&ALICE,
+			&BOB,
+			VestingScheduleInfo {
+				window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+				period_count: 2_u32,
+				per_period: 50_u64,
+				cliff: 0,
+				curve: VestingCurve::Linear,
+				revoker: None,
+			},
+		));
+
+		System::set_block_number(11);
+		assert_eq!(<Vesting as VestedTransfer>::claim(MockCurrencyId::ETH, &BOB), Ok(50));
+		System::set_block_number(21);
+		assert_eq!(<Vesting as VestedTransfer>::claim(MockCurrencyId::ETH, &BOB), Ok(50));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::ETH));
+		assert_eq!(<Vesting as VestedTransfer>::claim(MockCurrencyId::ETH, &BOB), Ok(0));
+	});
+}
+
 #[test]
 fn vested_transfer_for_moment_based_schedule_works() {
 	ExtBuilder::build().execute_with(|| {
@@ -245,6 +295,9 @@ fn vested_transfer_for_moment_based_schedule_works() {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
 		assert_ok!(Vesting::vested_transfer(
@@ -276,6 +329,9 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -292,6 +348,9 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: BlockNumberBased { start: 10_u64, period: 13_u64 },
 			period_count: 1_u32,
 			per_period: 7_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -305,6 +364,9 @@ fn add_new_vesting_schedule_merges_with_current_locked_balance_and_until() {
 			window: MomentBased { start: 72000_u64, period: 5000_u64 },
 			period_count: 2_u32,
 			per_period: 7_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -328,6 +390,9 @@ fn cannot_use_fund_if_not_claimed() {
 			window: BlockNumberBased { start: 10_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 50_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -340,6 +405,9 @@ fn cannot_use_fund_if_not_claimed() {
 			window: MomentBased { start: 1000_u64, period: 5000_u64 },
 			period_count: 1_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -367,6 +435,9 @@ fn vested_transfer_fails_if_zero_period_count() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 0_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -388,6 +459,9 @@ fn vested_transfer_fails_if_zero_period() {
 			window: BlockNumberBased { start: 1_u64, period: 0_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -420,6 +494,9 @@ fn vested_transfer_fails_if_transfer_err() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -441,6 +518,9 @@ fn vested_transfer_fails_if_overflow() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: u64::MAX,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -457,6 +537,9 @@ fn vested_transfer_fails_if_overflow() {
 			window: BlockNumberBased { start: u64::MAX, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: 1_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -473,6 +556,9 @@ fn vested_transfer_fails_if_overflow() {
 			window: MomentBased { start: u64::MAX, period: 1_u64 },
 			period_count: 2_u32,
 			per_period: 1_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -494,6 +580,9 @@ fn vested_transfer_fails_if_bad_origin() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 1_u32,
 			per_period: 100_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -515,6 +604,9 @@ fn claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -609,6 +701,9 @@ fn claim_nonexistent_schedules() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -671,6 +766,9 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -685,6 +783,9 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -699,6 +800,9 @@ fn claim_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 3_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -833,6 +937,9 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -847,6 +954,9 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -861,6 +971,9 @@ fn claim_with_multiple_ids_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 3_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -945,6 +1058,9 @@ fn claim_for_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -959,6 +1075,9 @@ fn claim_for_with_id_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 15_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1029,6 +1148,9 @@ fn claim_for_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1082,6 +1204,9 @@ fn claim_for_works_moment_based() {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1127,6 +1252,9 @@ fn update_vesting_schedules_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1140,6 +1268,9 @@ fn update_vesting_schedules_works() {
 			window: MomentBased { start: 0_u64, period: 60000_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1153,11 +1284,17 @@ fn update_vesting_schedules_works() {
 			window: BlockNumberBased { start: 0_u64, period: 20_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		let updated_moment_based_schedule = VestingScheduleInfo {
 			window: MomentBased { start: 0_u64, period: 120000_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::update_vesting_schedules(
 			RuntimeOrigin::root(),
@@ -1216,6 +1353,9 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 0_u64, period: 1_u64 },
 			period_count: 100_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 
 		assert_ok!(Vesting::vested_transfer(
@@ -1231,6 +1371,9 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 0_u64, period: 1_u64 },
 			period_count: 50_u32,
 			per_period: 8_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 
 		assert_ok!(Vesting::vested_transfer(
@@ -1260,6 +1403,9 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 30_u64, period: 1_u64 },
 			period_count: 60_u32,
 			per_period: 5_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 
 		// Locks 200 * 2 = 400
@@ -1267,6 +1413,9 @@ fn update_vesting_schedules_does_not_break_locking_amount() {
 			window: BlockNumberBased { start: 40_u64, period: 1_u64 },
 			period_count: 200_u32,
 			per_period: 2_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 
 		// Unlocks all and locks 300 + 400 = 700
@@ -1307,6 +1456,9 @@ fn vested_transfer_check_for_min() {
 			window: BlockNumberBased { start: 1_u64, period: 1_u64 },
 			period_count: 1_u32,
 			per_period: 3_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_noop!(
 			Vesting::vested_transfer(
@@ -1328,6 +1480,9 @@ fn multiple_vesting_schedule_claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		let schedule = VestingSchedule::from_input(4_u128, schedule_input.clone());
 		assert_ok!(Vesting::vested_transfer(
@@ -1342,6 +1497,9 @@ fn multiple_vesting_schedule_claim_works() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 3_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		let schedule2 = VestingSchedule::from_input(5_u128, schedule2_input.clone());
 
@@ -1393,11 +1551,17 @@ fn exceeding_maximum_schedules_should_fail() {
 			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		let moment_schedule_input = VestingScheduleInfo {
 			window: MomentBased { start: 0_u64, period: 10_u64 },
 			period_count: 2_u32,
 			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
 		};
 		assert_ok!(Vesting::vested_transfer(
 			RuntimeOrigin::root(),
@@ -1449,3 +1613,675 @@ fn exceeding_maximum_schedules_should_fail() {
 		);
 	});
 }
+
+#[test]
+fn revoke_works() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		System::set_block_number(21);
+		assert_ok!(Vesting::revoke(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			MockCurrencyId::BTC,
+			4_u128,
+			CHARLIE,
+		));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingScheduleRevoked {
+			who: BOB,
+			asset: MockCurrencyId::BTC,
+			vesting_schedule_id: 4_u128,
+			revoker: ALICE,
+			beneficiary: CHARLIE,
+			revoked_amount: 20,
+			claimable_amount: 20,
+		}));
+
+		// the vested amount is left to claim, and can no longer be revoked
+		let schedule = Vesting::vesting_schedules(BOB, MockCurrencyId::BTC)
+			.get(&4_u128)
+			.cloned()
+			.unwrap();
+		assert_eq!(schedule.period_count, 2);
+		assert_eq!(schedule.revoker, None);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &BOB), 20);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &CHARLIE), 85);
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 1).is_err());
+
+		System::set_block_number(41);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 20));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+		assert_eq!(Tokens::locks(&BOB, MockCurrencyId::BTC), vec![]);
+	});
+}
+
+#[test]
+fn revoke_before_start_removes_schedule() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 10_u64, period: 10_u64 },
+			period_count: 2_u32,
+			per_period: 50_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		assert_ok!(Vesting::revoke(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			MockCurrencyId::BTC,
+			4_u128,
+			ALICE,
+		));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingScheduleRevoked {
+			who: BOB,
+			asset: MockCurrencyId::BTC,
+			vesting_schedule_id: 4_u128,
+			revoker: ALICE,
+			beneficiary: ALICE,
+			revoked_amount: 100,
+			claimable_amount: 0,
+		}));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+		assert_eq!(Tokens::locks(&BOB, MockCurrencyId::BTC), vec![]);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &ALICE), 100);
+	});
+}
+
+#[test]
+fn revoked_schedule_below_minimum_is_claimable() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 3_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		// 3 vested, below `MinVestedTransfer`
+		System::set_block_number(11);
+		assert_ok!(Vesting::revoke(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			MockCurrencyId::BTC,
+			4_u128,
+			ALICE,
+		));
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 3));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+	});
+}
+
+#[test]
+fn revoke_fails_if_not_allowed() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 2_u32,
+			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		assert_noop!(
+			Vesting::revoke(RuntimeOrigin::signed(BOB), BOB, MockCurrencyId::BTC, 4_u128, BOB),
+			Error::<Runtime>::NotScheduleRevoker
+		);
+		assert_noop!(
+			Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, MockCurrencyId::BTC, 5_u128, ALICE),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+		// genesis schedules are not revocable
+		assert_noop!(
+			Vesting::revoke(
+				RuntimeOrigin::signed(ALICE),
+				CHARLIE,
+				MockCurrencyId::BTC,
+				2_u128,
+				ALICE
+			),
+			Error::<Runtime>::NotScheduleRevoker
+		);
+
+		System::set_block_number(21);
+		assert_noop!(
+			Vesting::revoke(RuntimeOrigin::signed(ALICE), BOB, MockCurrencyId::BTC, 4_u128, ALICE),
+			Error::<Runtime>::NothingToRevoke
+		);
+	});
+}
+
+#[test]
+fn cliff_releases_vested_amount_at_once() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 10_u32,
+			per_period: 10_u64,
+			cliff: 4,
+			curve: VestingCurve::Linear,
+			revoker: None,
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		System::set_block_number(39);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 1).is_err());
+
+		System::set_block_number(40);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 40));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 41).is_err());
+
+		System::set_block_number(55);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 50));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 51).is_err());
+	});
+}
+
+#[test]
+fn step_curve_releases_every_few_periods() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+		Timestamp::set_timestamp(System::block_number() * MILLISECS_PER_BLOCK);
+
+		// monthly release of a daily vesting over 2 months
+		let day = 24 * 60 * 60 * 1_000;
+		let schedule_input = VestingScheduleInfo {
+			window: MomentBased { start: 0_u64, period: day },
+			period_count: 60_u32,
+			per_period: 1_u64,
+			cliff: 0,
+			curve: VestingCurve::Step { periods: 30 },
+			revoker: None,
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		Timestamp::set_timestamp(29 * day);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 1).is_err());
+
+		Timestamp::set_timestamp(59 * day);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 30));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 31).is_err());
+
+		Timestamp::set_timestamp(60 * day);
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 60));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+	});
+}
+
+#[test]
+fn custom_curve_releases_cumulative_shares() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let curve = VestingCurve::Custom(
+			vec![(0, Perbill::from_percent(10)), (2, Perbill::from_percent(60))]
+				.try_into()
+				.unwrap(),
+		);
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 25_u64,
+			cliff: 0,
+			curve,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 10));
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 11).is_err());
+
+		System::set_block_number(25);
+		assert_ok!(Vesting::revoke(
+			RuntimeOrigin::signed(ALICE),
+			BOB,
+			MockCurrencyId::BTC,
+			4_u128,
+			ALICE,
+		));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingScheduleRevoked {
+			who: BOB,
+			asset: MockCurrencyId::BTC,
+			vesting_schedule_id: 4_u128,
+			revoker: ALICE,
+			beneficiary: ALICE,
+			revoked_amount: 40,
+			claimable_amount: 50,
+		}));
+
+		assert_ok!(Vesting::claim(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &BOB, 60));
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+	});
+}
+
+#[test]
+fn vested_transfer_fails_for_invalid_cliff_or_curve() {
+	ExtBuilder::build().execute_with(|| {
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 10_u64,
+			cliff: 5,
+			curve: VestingCurve::Linear,
+			revoker: None,
+		};
+		assert_noop!(
+			Vesting::vested_transfer(
+				RuntimeOrigin::root(),
+				ALICE,
+				BOB,
+				MockCurrencyId::BTC,
+				schedule_input.clone(),
+			),
+			Error::<Runtime>::InvalidVestingCliff
+		);
+
+		let schedule_input = VestingScheduleInfo {
+			cliff: 0,
+			curve: VestingCurve::Step { periods: 0 },
+			..schedule_input
+		};
+		assert_noop!(
+			Vesting::vested_transfer(
+				RuntimeOrigin::root(),
+				ALICE,
+				BOB,
+				MockCurrencyId::BTC,
+				schedule_input.clone(),
+			),
+			Error::<Runtime>::InvalidVestingCurve
+		);
+
+		let schedule_input = VestingScheduleInfo {
+			curve: VestingCurve::Custom(
+				vec![(2, Perbill::from_percent(60)), (1, Perbill::from_percent(80))]
+					.try_into()
+					.unwrap(),
+			),
+			..schedule_input
+		};
+		assert_noop!(
+			Vesting::vested_transfer(
+				RuntimeOrigin::root(),
+				ALICE,
+				BOB,
+				MockCurrencyId::BTC,
+				schedule_input,
+			),
+			Error::<Runtime>::InvalidVestingCurve
+		);
+	});
+}
+
+#[test]
+fn wrapped_schedule_is_claimed_by_fnft_owner() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+
+		assert_ok!(Vesting::wrap_into_fnft(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			4_u128
+		));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::VestingScheduleWrapped {
+			who: BOB,
+			asset: MockCurrencyId::BTC,
+			vesting_schedule_id: 4_u128,
+			fnft_collection_id: MockCurrencyId::VESTING_FNFT,
+			fnft_instance_id: 0,
+		}));
+
+		// the schedule and its balance now belong to the asset account of the fNFT
+		let fnft_account = Fnft::asset_account(&MockCurrencyId::VESTING_FNFT, &0);
+		assert!(!VestingSchedules::<Runtime>::contains_key(BOB, MockCurrencyId::BTC));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &BOB), 0);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &fnft_account), 40);
+		assert!(Tokens::ensure_can_withdraw(MockCurrencyId::BTC, &fnft_account, 1).is_err());
+		assert_eq!(Fnft::owner(&MockCurrencyId::VESTING_FNFT, &0), Some(BOB));
+		assert_eq!(
+			Vesting::value_of(&MockCurrencyId::VESTING_FNFT, &0),
+			Ok(vec![(MockCurrencyId::BTC, 40)])
+		);
+
+		assert_ok!(Fnft::transfer(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::VESTING_FNFT,
+			0,
+			CHARLIE
+		));
+
+		System::set_block_number(21);
+		assert_noop!(
+			Vesting::claim_fnft(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::NotFnftOwner
+		);
+		assert_ok!(Vesting::claim_fnft(RuntimeOrigin::signed(CHARLIE), 0));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::FnftClaimed {
+			owner: CHARLIE,
+			fnft_collection_id: MockCurrencyId::VESTING_FNFT,
+			fnft_instance_id: 0,
+			asset: MockCurrencyId::BTC,
+			claimed_amount: 20,
+		}));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &CHARLIE), 85);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &fnft_account), 20);
+
+		// the fNFT is burnt once the schedule is fully claimed
+		System::set_block_number(41);
+		assert_ok!(Vesting::claim_fnft(RuntimeOrigin::signed(CHARLIE), 0));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &CHARLIE), 105);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &fnft_account), 0);
+		assert_eq!(Fnft::owner(&MockCurrencyId::VESTING_FNFT, &0), None);
+		assert_eq!(Vesting::fnft_vesting_schedule(0), None);
+		assert_noop!(
+			Vesting::claim_fnft(RuntimeOrigin::signed(CHARLIE), 0),
+			Error::<Runtime>::FnftNotFound
+		);
+	});
+}
+
+#[test]
+fn claim_fnft_forwards_balance_unlocked_by_claim_for() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 0_u64, period: 10_u64 },
+			period_count: 4_u32,
+			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: None,
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+		assert_ok!(Vesting::wrap_into_fnft(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			4_u128
+		));
+		let fnft_account = Fnft::asset_account(&MockCurrencyId::VESTING_FNFT, &0);
+
+		// anyone can unlock the vested balance of the asset account
+		System::set_block_number(21);
+		assert_ok!(Vesting::claim_for(
+			RuntimeOrigin::signed(CHARLIE),
+			fnft_account,
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert_ok!(Vesting::claim_fnft(RuntimeOrigin::signed(BOB), 0));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::FnftClaimed {
+			owner: BOB,
+			fnft_collection_id: MockCurrencyId::VESTING_FNFT,
+			fnft_instance_id: 0,
+			asset: MockCurrencyId::BTC,
+			claimed_amount: 20,
+		}));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &BOB), 20);
+
+		// even once the schedule is fully claimed by someone else
+		System::set_block_number(41);
+		assert_ok!(Vesting::claim_for(
+			RuntimeOrigin::signed(CHARLIE),
+			fnft_account,
+			MockCurrencyId::BTC,
+			VestingScheduleIdSet::All,
+		));
+		assert!(!VestingSchedules::<Runtime>::contains_key(fnft_account, MockCurrencyId::BTC));
+		assert_ok!(Vesting::claim_fnft(RuntimeOrigin::signed(BOB), 0));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &BOB), 40);
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &fnft_account), 0);
+		assert_eq!(Fnft::owner(&MockCurrencyId::VESTING_FNFT, &0), None);
+	});
+}
+
+#[test]
+fn revoked_wrapped_schedule_burns_fnft() {
+	ExtBuilder::build().execute_with(|| {
+		System::set_block_number(1);
+
+		let schedule_input = VestingScheduleInfo {
+			window: BlockNumberBased { start: 10_u64, period: 10_u64 },
+			period_count: 2_u32,
+			per_period: 10_u64,
+			cliff: 0,
+			curve: VestingCurve::Linear,
+			revoker: Some(ALICE),
+		};
+		assert_ok!(Vesting::vested_transfer(
+			RuntimeOrigin::root(),
+			ALICE,
+			BOB,
+			MockCurrencyId::BTC,
+			schedule_input,
+		));
+		assert_ok!(Vesting::wrap_into_fnft(
+			RuntimeOrigin::signed(BOB),
+			MockCurrencyId::BTC,
+			4_u128
+		));
+
+		// the revoker can still revoke the wrapped schedule
+		let fnft_account = Fnft::asset_account(&MockCurrencyId::VESTING_FNFT, &0);
+		assert_ok!(Vesting::revoke(
+			RuntimeOrigin::signed(ALICE),
+			fnft_account,
+			MockCurrencyId::BTC,
+			4_u128,
+			ALICE,
+		));
+		assert_eq!(Tokens::free_balance(MockCurrencyId::BTC, &ALICE), 100);
+
+		assert_ok!(Vesting::claim_fnft(RuntimeOrigin::signed(BOB), 0));
+		System::assert_last_event(RuntimeEvent::Vesting(crate::Event::FnftClaimed {
+			owner: BOB,
+			fnft_collection_id: MockCurrencyId::VESTING_FNFT,
+			fnft_instance_id: 0,
+			asset: MockCurrencyId::BTC,
+			claimed_amount: 0,
+		}));
+		assert_eq!(Fnft::owner(&MockCurrencyId::VESTING_FNFT, &0), None);
+		assert_eq!(Vesting::fnft_vesting_schedule(0), None);
+	});
+}
+
+#[test]
+fn wrap_into_fnft_fails_for_unknown_schedule() {
+	ExtBuilder::build().execute_with(|| {
+		assert_noop!(
+			Vesting::wrap_into_fnft(RuntimeOrigin::signed(CHARLIE), MockCurrencyId::BTC, 4_u128),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+		// schedules of other accounts can't be wrapped
+		assert_noop!(
+			Vesting::wrap_into_fnft(RuntimeOrigin::signed(BOB), MockCurrencyId::BTC, 1_u128),
+			Error::<Runtime>::VestingScheduleNotFound
+		);
+		assert_noop!(
+			Vesting::claim_fnft(RuntimeOrigin::signed(BOB), 0),
+			Error::<Runtime>::FnftNotFound
+		);
+	});
+}
+
+#[test]
+fn migration_to_v1_keeps_schedules_vesting_as_before() {
+	ExtBuilder::build().execute_with(|| {
+		let old_schedules: BoundedBTreeMap<u128, _, MaxVestingSchedule> = BTreeMap::from([(
+			1_u128,
+			migrations::v1::OldVestingSchedule {
+				vesting_schedule_id: 1_u128,
+				window: BlockNumberBased { start: 2_u64, period: 3_u64 },
+				period_count: 4_u32,
+				per_period: 5_u64,
+				already_claimed: 5_u64,
+			},
+		)])
+		.try_into()
+		.unwrap();
+		frame_support::storage::unhashed::put(
+			&VestingSchedules::<Runtime>::hashed_key_for(CHARLIE, MockCurrencyId::BTC),
+			&old_schedules,
+		);
+		StorageVersion::new(0).put::<Vesting>();
+
+		migrations::v1::MigrateToV1::<Runtime>::on_runtime_upgrade();
+
+		assert_eq!(Vesting::on_chain_storage_version(), 1);
+		assert_eq!(
+			Vesting::vesting_schedules(CHARLIE, MockCurrencyId::BTC).into_inner(),
+			BTreeMap::from([(
+				1_u128,
+				VestingSchedule {
+					vesting_schedule_id: 1_u128,
+					window: BlockNumberBased { start: 2_u64, period: 3_u64 },
+					period_count: 4_u32,
+					per_period: 5_u64,
+					already_claimed: 5_u64,
+					cliff: 0,
+					curve: VestingCurve::Linear,
+					revoker: None,
+				}
+			)])
+		);
+	});
+}
@@ -34,6 +34,9 @@ pub trait WeightInfo {
 	fn claim(i: u32, ) -> Weight;
 	fn update_vesting_schedules(i: u32, ) -> Weight;
 	fn claim_for(i: u32, ) -> Weight;
+	fn revoke() -> Weight;
+	fn wrap_into_fnft() -> Weight;
+	fn claim_fnft() -> Weight;
 }
 
 /// Default weights.
@@ -63,4 +66,25 @@ impl WeightInfo for () {
 			// Standard Error: 4_000
 			.saturating_add(Weight::from_ref_time(63_000_u64).saturating_mul(i as u64))
 	}
+	// not benchmarked: reads and writes the schedules, lock and balances of the beneficiary and
+	// the destination
+	fn revoke() -> Weight {
+		Weight::from_ref_time(115_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// not benchmarked: moves the schedule, its lock and funds to the asset account of a new fNFT
+	// and proxies it to the owner
+	fn wrap_into_fnft() -> Weight {
+		Weight::from_ref_time(200_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+	}
+	// not benchmarked: claims the schedule of the asset account and forwards the funds to the
+	// owner, burning the fNFT once nothing is left
+	fn claim_fnft() -> Weight {
+		Weight::from_ref_time(135_000_000_u64)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
 }
@@ -67,6 +67,7 @@ pub fn genesis_config(
 		council: Default::default(),
 		democracy: Default::default(),
 		treasury: Default::default(),
+		crowdloan_relayers: Default::default(),
 		relayer_xcm: Default::default(),
 		tokens: Default::default(),
 		transaction_payment: Default::default(),
@@ -71,6 +71,7 @@ pub fn genesis_config(
 		treasury: Default::default(),
 		technical_committee: Default::default(),
 		technical_committee_membership: Default::default(),
+		crowdloan_relayers: Default::default(),
 		relayer_xcm: Default::default(),
 		assets_registry: Default::default(),
 		tokens: Default::default(),
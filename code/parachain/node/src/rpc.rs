@@ -23,7 +23,8 @@ use crate::{
 		assets::ExtendWithAssetsApi, cosmwasm::ExtendWithCosmwasmApi,
 		crowdloan_rewards::ExtendWithCrowdloanRewardsApi, ibc::ExtendWithIbcApi,
 		lending::ExtendWithLendingApi, pablo::ExtendWithPabloApi,
-		staking_rewards::ExtendWithStakingRewardsApi, BaseHostRuntimeApis,
+		staking_rewards::ExtendWithStakingRewardsApi, vault::ExtendWithVaultApi,
+		BaseHostRuntimeApis,
 	},
 };
 
@@ -68,6 +69,7 @@ where
 			+ ExtendWithCrowdloanRewardsApi<RuntimeApi, Executor>
 			+ ExtendWithPabloApi<RuntimeApi, Executor>
 			+ ExtendWithLendingApi<RuntimeApi, Executor>
+			+ ExtendWithVaultApi<RuntimeApi, Executor>
 			+ ExtendWithCosmwasmApi<RuntimeApi, Executor>
 			+ ExtendWithIbcApi<RuntimeApi, Executor>,
 {
@@ -100,6 +102,11 @@ where
 		deps.clone(),
 	)?;
 
+	<FullClient<RuntimeApi, Executor> as ProvideRuntimeApi<OpaqueBlock>>::Api::extend_with_vault_api(
+		&mut io,
+		deps.clone(),
+	)?;
+
 	<FullClient<RuntimeApi, Executor> as ProvideRuntimeApi<OpaqueBlock>>::Api::extend_with_cosmwasm_api(
 		&mut io, deps.clone(),
 	)?;
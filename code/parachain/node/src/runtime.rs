@@ -16,6 +16,7 @@ use sp_session::SessionKeys;
 use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
 use staking_rewards_rpc::{StakingRewards, StakingRewardsApiServer};
 use substrate_frame_rpc_system::AccountNonceApi;
+use vault_rpc::{Vault, VaultApiServer};
 
 /// Consider this a trait alias.
 pub trait BaseHostRuntimeApis:
@@ -248,6 +249,24 @@ define_trait! {
 		}
 	}
 
+	mod vault {
+		pub trait ExtendWithVaultApi {
+			fn extend_with_vault_api(io, deps);
+		}
+
+		#[cfg(feature = "composable")]
+		impl for composable_runtime {}
+
+		impl for picasso_runtime {}
+
+		#[cfg(feature = "dali")]
+		impl for dali_runtime {
+			fn (io, deps) {
+				io.merge(Vault::new(deps.client).into_rpc())
+			}
+		}
+	}
+
 	mod cosmwasm {
 		pub trait ExtendWithCosmwasmApi {
 			fn extend_with_cosmwasm_api(io, deps);
@@ -24,7 +24,8 @@ use crate::{
 		assets::ExtendWithAssetsApi, cosmwasm::ExtendWithCosmwasmApi,
 		crowdloan_rewards::ExtendWithCrowdloanRewardsApi, ibc::ExtendWithIbcApi,
 		lending::ExtendWithLendingApi, pablo::ExtendWithPabloApi,
-		staking_rewards::ExtendWithStakingRewardsApi, BaseHostRuntimeApis,
+		staking_rewards::ExtendWithStakingRewardsApi, vault::ExtendWithVaultApi,
+		BaseHostRuntimeApis,
 	},
 };
 use sc_client_api::StateBackendFor;
@@ -291,6 +292,7 @@ where
 		+ ExtendWithCrowdloanRewardsApi<RuntimeApi, Executor>
 		+ ExtendWithPabloApi<RuntimeApi, Executor>
 		+ ExtendWithLendingApi<RuntimeApi, Executor>
+		+ ExtendWithVaultApi<RuntimeApi, Executor>
 		+ ExtendWithCosmwasmApi<RuntimeApi, Executor>
 		+ ExtendWithIbcApi<RuntimeApi, Executor>,
 	StateBackendFor<FullBackend, OpaqueBlock>: StateBackend<BlakeTwo256>,
@@ -731,6 +731,24 @@ parameter_types! {
 	  pub const VestingStep: Moment = (7 * DAYS as Moment) * (MILLISECS_PER_BLOCK as Moment);
 	  pub const Prefix: &'static [u8] = b"composable-";
 	  pub const LockCrowdloanRewards: bool = false;
+	  pub CrowdloanRelayerFee: Balance = 100 * CurrencyId::milli::<Balance>();
+	  pub const MaxRelayedCrowdloanClaimsPerBlock: u32 = 64;
+	  pub const MaxCrowdloanRounds: u32 = 16;
+	  pub const MaxCrowdloanRelayers: u32 = 16;
+}
+
+// Accounts allowed to relay crowdloan associations and be paid the `CrowdloanRelayerFee`.
+impl membership::Config<membership::Instance3> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrHalfCouncil;
+	type RemoveOrigin = EnsureRootOrHalfCouncil;
+	type SwapOrigin = EnsureRootOrHalfCouncil;
+	type ResetOrigin = EnsureRootOrHalfCouncil;
+	type PrimeOrigin = EnsureRootOrHalfCouncil;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = MaxCrowdloanRelayers;
+	type WeightInfo = weights::membership::WeightInfo<Runtime>;
 }
 
 impl crowdloan_rewards::Config for Runtime {
@@ -740,6 +758,7 @@ impl crowdloan_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRootOrHalfCouncil;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type RelayChainAccountId = [u8; 32];
+	type IdentityVerifier = composable_support::claims::identity::MultiChainVerifier<[u8; 32]>;
 	type InitialPayment = InitialPayment;
 	type OverFundedThreshold = OverFundedThreshold;
 	type VestingStep = VestingStep;
@@ -750,6 +769,10 @@ impl crowdloan_rewards::Config for Runtime {
 	type Time = Timestamp;
 	type LockId = CrowdloanRewardsLockId;
 	type LockByDefault = LockCrowdloanRewards;
+	type RelayerOrigin = frame_system::EnsureSignedBy<CrowdloanRelayers, AccountId>;
+	type RelayerFee = CrowdloanRelayerFee;
+	type MaxRelayedClaimsPerBlock = MaxRelayedCrowdloanClaimsPerBlock;
+	type MaxRounds = MaxCrowdloanRounds;
 }
 
 parameter_types! {
@@ -826,6 +849,7 @@ construct_runtime!(
 
 		CurrencyFactory: currency_factory = 53,
 		CrowdloanRewards: crowdloan_rewards = 56,
+		CrowdloanRelayers: membership::<Instance3> = 59,
 		Assets: assets = 57,
 		GovernanceRegistry: governance_registry = 58,
 	}
@@ -102,6 +102,8 @@ use composable_traits::{
 	account_proxy::{AccountProxyWrapper, ProxyType},
 	currency::{CurrencyFactory as CurrencyFactoryT, RangeId, Rational64},
 	fnft::FnftAccountProxyType,
+	time::{DurationSeconds, ONE_WEEK},
+	vault::Vault as VaultTrait,
 	xcm::assets::{RemoteAssetRegistryMutate, XcmAssetLocation},
 };
 use frame_support::{
@@ -752,6 +754,7 @@ impl pallet_fnft::Config for Runtime {
 	type ProxyType = ProxyType;
 	type AccountProxy = AccountProxyWrapperInstance;
 	type ProxyTypeSelector = FnftAccountProxyType;
+	type TransferHook = StakingRewards;
 	type PalletId = FnftPalletId;
 	type WeightInfo = weights::fnft::WeightInfo<Runtime>;
 }
@@ -780,6 +783,10 @@ parameter_types! {
 	pub const VaultMinimumWithdrawal: Balance = 10_000;
 	pub const VaultPalletId: PalletId = PalletId(*b"cubic___");
 	pub const TombstoneDuration: BlockNumber = DAYS * 7;
+	pub const VaultRebalanceInterval: BlockNumber = HOURS;
+	pub const VaultMaxWithdrawalSettlements: u32 = 50;
+	pub const VaultWithdrawalDelay: BlockNumber = HOURS;
+	pub const VaultInstantExitFee: Perquintill = Perquintill::from_perthousand(5);
 }
 
 impl vault::Config for Runtime {
@@ -800,6 +807,11 @@ impl vault::Config for Runtime {
 	type TombstoneDuration = TombstoneDuration;
 	type VaultId = u64;
 	type WeightInfo = weights::vault::WeightInfo<Runtime>;
+	type Strategies = composable_traits::vault::NoStrategies<AccountId, CurrencyId, Balance, u64>;
+	type RebalanceInterval = VaultRebalanceInterval;
+	type MaxWithdrawalSettlements = VaultMaxWithdrawalSettlements;
+	type WithdrawalDelay = VaultWithdrawalDelay;
+	type InstantExitFee = VaultInstantExitFee;
 }
 
 impl currency_factory::Config for Runtime {
@@ -842,6 +854,24 @@ parameter_types! {
 	  pub const VestingStep: Moment = 1;
 	  pub const Prefix: &'static [u8] = b"picasso-";
 	  pub const LockCrowdloanRewards: bool = true;
+	  pub CrowdloanRelayerFee: Balance = 100 * CurrencyId::milli::<Balance>();
+	  pub const MaxRelayedCrowdloanClaimsPerBlock: u32 = 64;
+	  pub const MaxCrowdloanRounds: u32 = 16;
+	  pub const MaxCrowdloanRelayers: u32 = 16;
+}
+
+// Accounts allowed to relay crowdloan associations and be paid the `CrowdloanRelayerFee`.
+impl membership::Config<membership::Instance3> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrHalfNativeCouncil;
+	type RemoveOrigin = EnsureRootOrHalfNativeCouncil;
+	type SwapOrigin = EnsureRootOrHalfNativeCouncil;
+	type ResetOrigin = EnsureRootOrHalfNativeCouncil;
+	type PrimeOrigin = EnsureRootOrHalfNativeCouncil;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = MaxCrowdloanRelayers;
+	type WeightInfo = weights::membership::WeightInfo<Runtime>;
 }
 
 impl crowdloan_rewards::Config for Runtime {
@@ -851,6 +881,8 @@ impl crowdloan_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRootOrHalfNativeCouncil;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type RelayChainAccountId = sp_runtime::AccountId32;
+	type IdentityVerifier =
+		composable_support::claims::identity::MultiChainVerifier<sp_runtime::AccountId32>;
 	type InitialPayment = InitialPayment;
 	type OverFundedThreshold = OverFundedThreshold;
 	type VestingStep = VestingStep;
@@ -861,6 +893,10 @@ impl crowdloan_rewards::Config for Runtime {
 	type Time = Timestamp;
 	type LockId = CrowdloanRewardsLockId;
 	type LockByDefault = LockCrowdloanRewards;
+	type RelayerOrigin = frame_system::EnsureSignedBy<CrowdloanRelayers, AccountId>;
+	type RelayerFee = CrowdloanRelayerFee;
+	type MaxRelayedClaimsPerBlock = MaxRelayedCrowdloanClaimsPerBlock;
+	type MaxRounds = MaxCrowdloanRounds;
 }
 
 parameter_types! {
@@ -868,6 +904,16 @@ parameter_types! {
 	pub const MaxStakingDurationPresets : u32 = 10;
 	pub const MaxRewardConfigsPerPool : u32 = 10;
 	pub const StakingRewardsLockId: LockIdentifier = *b"stk_lock";
+	pub const VotingPowerPoolId: CurrencyId = CurrencyId::PBLO;
+	pub const VoteEscrowEpoch: DurationSeconds = ONE_WEEK;
+	// four years
+	pub const MaxVoteEscrowEpochs: u32 = 4 * 52;
+	pub const MaxGauges: u32 = 32;
+	pub const GaugeEpoch: DurationSeconds = ONE_WEEK;
+	pub const AutoCompoundBatchSize: u32 = 20;
+	pub const AutoCompoundPeriod: BlockNumber = DAYS;
+	pub const CompoundBounty: Perbill = Perbill::from_perthousand(5);
+	pub const ExpectedBlockTime: DurationSeconds = (MILLISECS_PER_BLOCK / 1000) as u64;
 }
 
 impl pallet_staking_rewards::Config for Runtime {
@@ -889,6 +935,15 @@ impl pallet_staking_rewards::Config for Runtime {
 	type LockId = StakingRewardsLockId;
 	type TreasuryAccount = TreasuryAccount;
 	type ExistentialDeposits = MultiExistentialDeposits;
+	type VotingPowerPoolId = VotingPowerPoolId;
+	type VoteEscrowEpoch = VoteEscrowEpoch;
+	type MaxVoteEscrowEpochs = MaxVoteEscrowEpochs;
+	type MaxGauges = MaxGauges;
+	type GaugeEpoch = GaugeEpoch;
+	type AutoCompoundBatchSize = AutoCompoundBatchSize;
+	type AutoCompoundPeriod = AutoCompoundPeriod;
+	type CompoundBounty = CompoundBounty;
+	type ExpectedBlockTime = ExpectedBlockTime;
 }
 
 /// The calls we permit to be executed by extrinsics
@@ -916,6 +971,8 @@ impl call_filter::Config for Runtime {
 parameter_types! {
 	pub const MaxVestingSchedule: u32 = 100;
 	pub MinVestedTransfer: u64 = 10 * CurrencyId::unit::<u64>();
+	pub const VestingFnftCollectionId: CurrencyId = CurrencyId::VESTING_FNFT_COLLECTION;
+	pub const VestingPalletId: PalletId = PalletId(*b"pal_vest");
 }
 
 impl vesting::Config for Runtime {
@@ -929,6 +986,10 @@ impl vesting::Config for Runtime {
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FnftCollectionId = VestingFnftCollectionId;
+	type PalletId = VestingPalletId;
 }
 
 parameter_types! {
@@ -936,18 +997,27 @@ parameter_types! {
 	pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
 	pub MinReward: Balance = 100 * CurrencyId::unit::<Balance>();
 	pub Stake: Balance = 10 * CurrencyId::unit::<Balance>();
+	pub const BondFnftCollectionId: CurrencyId = CurrencyId::BOND_FNFT_COLLECTION;
 }
 
 impl bonded_finance::Config for Runtime {
 	type AdminOrigin = EnsureRootOrHalfNativeCouncil;
+	type AssetId = CurrencyId;
 	type BondOfferId = BondOfferId;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type Currency = Assets;
 	type RuntimeEvent = RuntimeEvent;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FnftCollectionId = BondFnftCollectionId;
 	type MinReward = MinReward;
 	type NativeCurrency = Balances;
+	type Oracle = Oracle;
+	type Pablo = Pablo;
 	type PalletId = BondedFinanceId;
+	type PoolId = PoolId;
 	type Stake = Stake;
+	type TreasuryAccount = TreasuryAccount;
 	type Vesting = Vesting;
 	type WeightInfo = weights::bonded_finance::WeightInfo<Runtime>;
 }
@@ -1396,6 +1466,7 @@ construct_runtime!(
 		GovernanceRegistry: governance_registry = 56,
 		Assets: assets = 57,
 		CrowdloanRewards: crowdloan_rewards = 58,
+		CrowdloanRelayers: membership::<Instance3> = 72,
 		Vesting: vesting = 59,
 		BondedFinance: bonded_finance = 60,
 		DutchAuction: dutch_auction = 61,
@@ -1474,6 +1545,7 @@ mod benches {
 			[multisig, Multisig]
 			[vault, Vault]
 			[vesting, Vesting]
+			[pallet_fnft, Fnft]
 			[oracle, Oracle]
 			[dutch_auction, DutchAuction]
 			[currency_factory, CurrencyFactory]
@@ -1645,6 +1717,61 @@ impl_runtime_apis! {
 				fnft_instance_id.0,
 			)
 		}
+
+		fn reward_emission(
+			pool_id: SafeRpcWrapper<CurrencyId>,
+			reward_asset_id: SafeRpcWrapper<CurrencyId>,
+		) -> Option<staking_rewards_runtime_api::RewardEmission<Balance>> {
+			StakingRewards::reward_emission(pool_id.0, reward_asset_id.0)
+		}
+	}
+
+	impl vault_runtime_api::VaultRuntimeApi<Block, u64, AccountId, Balance> for Runtime {
+		fn preview_deposit(
+			vault_id: SafeRpcWrapper<u64>,
+			asset_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::preview_deposit(&vault_id.0, asset_amount.0)
+				.map(SafeRpcWrapper)
+		}
+
+		fn preview_withdraw(
+			vault_id: SafeRpcWrapper<u64>,
+			lp_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::preview_withdraw(&vault_id.0, lp_amount.0)
+				.map(SafeRpcWrapper)
+		}
+
+		fn convert_to_shares(
+			vault_id: SafeRpcWrapper<u64>,
+			asset_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::convert_to_shares(&vault_id.0, asset_amount.0)
+				.map(SafeRpcWrapper)
+		}
+
+		fn convert_to_assets(
+			vault_id: SafeRpcWrapper<u64>,
+			lp_amount: SafeRpcWrapper<Balance>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::convert_to_assets(&vault_id.0, lp_amount.0)
+				.map(SafeRpcWrapper)
+		}
+
+		fn max_deposit(
+			vault_id: SafeRpcWrapper<u64>,
+			account: SafeRpcWrapper<AccountId>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::max_deposit(&vault_id.0, &account.0).map(SafeRpcWrapper)
+		}
+
+		fn max_withdraw(
+			vault_id: SafeRpcWrapper<u64>,
+			account: SafeRpcWrapper<AccountId>,
+		) -> Result<SafeRpcWrapper<Balance>, DispatchError> {
+			<Vault as VaultTrait>::max_withdraw(&vault_id.0, &account.0).map(SafeRpcWrapper)
+		}
 	}
 
 	impl cosmwasm_runtime_api::CosmwasmRuntimeApi<Block, AccountId, CurrencyId, Balance, Vec<u8>> for Runtime {
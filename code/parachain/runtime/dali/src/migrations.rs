@@ -9,6 +9,10 @@ pub type Migrations = (
 	scheduler::migration::v3::MigrateToV4<Runtime>,
 	democracy::migrations::v1::Migration<Runtime>,
 	multisig::migrations::v1::MigrateToV1<Runtime>,
+	vesting::migrations::v1::MigrateToV1<Runtime>,
+	bonded_finance::migrations::v1::MigrateToV1<Runtime>,
+	pallet_staking_rewards::migrations::v1::MigrateToV1<Runtime>,
+	pallet_staking_rewards::migrations::v2::MigrateToV2<Runtime>,
 );
 
 // Migration for scheduler pallet to move from a plain Call to a CallOrHash.
@@ -16,7 +16,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 10_005,
+	spec_version: 10_006,
 	impl_version: 3,
 	apis: crate::RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -60,6 +60,10 @@ impl<T: frame_system::Config> crowdloan_rewards::weights::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	// TODO: regenerate from `relay_associate` benchmark, the pallet default weight until then
+	fn relay_associate(x: u32, ) -> Weight {
+		<() as crowdloan_rewards::weights::WeightInfo>::relay_associate(x)
+	}
 	// Storage: CrowdloanRewards Associations (r:1 w:0)
 	// Storage: CrowdloanRewards VestingBlockStart (r:1 w:0)
 	// Storage: CrowdloanRewards Rewards (r:1 w:1)
@@ -75,4 +79,8 @@ impl<T: frame_system::Config> crowdloan_rewards::weights::WeightInfo for WeightI
 	fn unlock_rewards_for(_: u32) -> Weight {
     Weight::from_ref_time(10_000)
 	}
+	// TODO: regenerate from `create_round` benchmark, the pallet default weight until then
+	fn create_round() -> Weight {
+		<() as crowdloan_rewards::weights::WeightInfo>::create_round()
+	}
 }
@@ -88,4 +88,22 @@ impl<T: frame_system::Config> dutch_auction::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
 			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
+	// same as add_configuration, single admin insert
+	// TODO: regenerate from `add_xcm_callback_location` benchmark
+	// Storage: DutchAuction ParachainXcmCallbackLocation (r:0 w:1)
+	fn add_xcm_callback_location() -> Weight {
+		Weight::from_ref_time(28_036_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// same as xcm_sell, which does more work, plus removal of final status
+	// TODO: regenerate from `xcm_sell_status` benchmark
+	// Storage: DutchAuction XcmSellOrderStatuses (r:1 w:1)
+	// Storage: DutchAuction ParachainXcmCallbackLocation (r:1 w:0)
+	// Storage: ParachainInfo ParachainId (r:1 w:0)
+	// Storage: DutchAuction XcmSellOrders (r:0 w:1)
+	fn xcm_sell_status() -> Weight {
+		Weight::from_ref_time(105_430_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
@@ -8,7 +8,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `fnft`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_fnft::WeightInfo for WeightInfo<T> {
+	// TODO: regenerate from `transfer` benchmark, the pallet default weight until then
 	fn transfer() -> Weight {
-		Weight::from_ref_time(10_000)
+		<() as pallet_fnft::WeightInfo>::transfer()
 	}
 }
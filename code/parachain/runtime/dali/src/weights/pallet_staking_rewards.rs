@@ -163,4 +163,52 @@ impl<T: frame_system::Config> pallet_staking_rewards::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
+	// TODO: regenerate from `add_gauge` benchmark, the pallet default weight until then
+	fn add_gauge() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::add_gauge()
+	}
+	// TODO: regenerate from `remove_gauge` benchmark, the pallet default weight until then
+	fn remove_gauge() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::remove_gauge()
+	}
+	// TODO: regenerate from `set_gauge_emission` benchmark, the pallet default weight until then
+	fn set_gauge_emission() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::set_gauge_emission()
+	}
+	// TODO: regenerate from `vote_for_gauges` benchmark, the pallet default weight until then
+	fn vote_for_gauges(g: u32, ) -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::vote_for_gauges(g)
+	}
+	// TODO: regenerate from `apply_gauge_votes` benchmark, the pallet default weight until then
+	fn apply_gauge_votes(g: u32, ) -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::apply_gauge_votes(g)
+	}
+	// TODO: regenerate from `set_auto_compound` benchmark, the pallet default weight until then
+	fn set_auto_compound() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::set_auto_compound()
+	}
+	// TODO: regenerate from `compound` benchmark, the pallet default weight until then
+	fn compound(r: u32, ) -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::compound(r)
+	}
+	// TODO: regenerate from `merge` benchmark, the pallet default weight until then
+	fn merge(r: u32, ) -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::merge(r)
+	}
+	// TODO: regenerate from `settle_slashes` benchmark, the pallet default weight until then
+	fn settle_slashes() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::settle_slashes()
+	}
+	// TODO: regenerate from `withdraw_slashed` benchmark, the pallet default weight until then
+	fn withdraw_slashed() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::withdraw_slashed()
+	}
+	// TODO: regenerate from `set_emission_schedule` benchmark, the pallet default weight until then
+	fn set_emission_schedule() -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::set_emission_schedule()
+	}
+	// TODO: regenerate from `vote_escrow_advance` benchmark, the pallet default weight until then
+	fn vote_escrow_advance(e: u32, ) -> Weight {
+		<() as pallet_staking_rewards::WeightInfo>::vote_escrow_advance(e)
+	}
 }
@@ -100,4 +100,26 @@ impl<T: frame_system::Config> vault::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
+	// same as the pallet default weight
+	// TODO: regenerate from `rebalance_vault` benchmark
+	fn rebalance_vault(s: u32, ) -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(Weight::from_ref_time(95_000_000 as u64).saturating_mul(s as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(s as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(s as u64)))
+	}
+	// TODO: regenerate from `request_withdrawal` benchmark, the pallet default weight until then
+	fn request_withdrawal() -> Weight {
+		<() as vault::WeightInfo>::request_withdrawal()
+	}
+	// TODO: regenerate from `settle_withdrawals` benchmark, the pallet default weight until then
+	fn settle_withdrawals(n: u32, ) -> Weight {
+		<() as vault::WeightInfo>::settle_withdrawals(n)
+	}
+	// TODO: regenerate from `withdraw_instant` benchmark, the pallet default weight until then
+	fn withdraw_instant() -> Weight {
+		<() as vault::WeightInfo>::withdraw_instant()
+	}
 }
@@ -80,4 +80,16 @@ impl<T: frame_system::Config> vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// TODO: regenerate from `revoke` benchmark, the pallet default weight until then
+	fn revoke() -> Weight {
+		<() as vesting::WeightInfo>::revoke()
+	}
+	// TODO: regenerate from `wrap_into_fnft` benchmark, the pallet default weight until then
+	fn wrap_into_fnft() -> Weight {
+		<() as vesting::WeightInfo>::wrap_into_fnft()
+	}
+	// TODO: regenerate from `claim_fnft` benchmark, the pallet default weight until then
+	fn claim_fnft() -> Weight {
+		<() as vesting::WeightInfo>::claim_fnft()
+	}
 }
@@ -41,9 +41,9 @@ use common::{
 	governance::native::*,
 	rewards::StakingPot,
 	AccountId, AccountIndex, Address, Amount, AuraId, Balance, BlockNumber, BondOfferId,
-	ForeignAssetId, Hash, MaxStringSize, Moment, PoolId, ReservedDmpWeight, ReservedXcmpWeight,
-	Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT, MILLISECS_PER_BLOCK,
-	NORMAL_DISPATCH_RATIO, SLOT_DURATION,
+	FinancialNftInstanceId, ForeignAssetId, Hash, MaxStringSize, Moment, PoolId, ReservedDmpWeight,
+	ReservedXcmpWeight, Signature, AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT,
+	MILLISECS_PER_BLOCK, NORMAL_DISPATCH_RATIO, SLOT_DURATION,
 };
 use composable_support::rpc_helpers::SafeRpcWrapper;
 use composable_traits::{
@@ -61,7 +61,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, Zero},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	ApplyExtrinsicResult, DispatchError,
 };
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 #[cfg(feature = "std")]
@@ -129,7 +129,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	// The version of the runtime specification. A full node will not attempt to use its native
 	//   runtime in substitute for the on-chain Wasm runtime unless all of `spec_name`,
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
-	spec_version: 10_005,
+	spec_version: 10_006,
 	impl_version: 2,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -142,7 +142,10 @@ pub fn native_version() -> NativeVersion {
 	NativeVersion { runtime_version: VERSION, can_author_with: Default::default() }
 }
 
-use composable_traits::account_proxy::ProxyType;
+use composable_traits::{
+	account_proxy::{AccountProxyWrapper, ProxyType},
+	fnft::NoFnftAccountProxyType,
+};
 use orml_traits::{parameter_type_with_key, LockIdentifier};
 parameter_type_with_key! {
 	// Minimum amount an account has to hold to stay in state
@@ -681,6 +684,10 @@ parameter_types! {
 	pub const VestingStep: Moment = (DAYS as Moment) * (MILLISECS_PER_BLOCK as Moment);
 	pub const Prefix: &'static [u8] = b"picasso-";
 	pub const LockCrowdloanRewards: bool = true;
+	pub CrowdloanRelayerFee: Balance = 100 * CurrencyId::milli::<Balance>();
+	pub const MaxRelayedCrowdloanClaimsPerBlock: u32 = 64;
+	pub const MaxCrowdloanRounds: u32 = 16;
+	pub const MaxCrowdloanRelayers: u32 = 16;
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -732,6 +739,38 @@ impl proxy::Config for Runtime {
 	type AnnouncementDepositFactor = ProxyPrice;
 }
 
+parameter_types! {
+	pub const FnftPalletId: PalletId = PalletId(*b"pal_fnft");
+}
+
+impl pallet_fnft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxProperties = ConstU32<16>;
+	type FinancialNftCollectionId = CurrencyId;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type ProxyType = ProxyType;
+	type AccountProxy = AccountProxyWrapper<Runtime>;
+	// asset accounts would need native funds for the proxy deposits
+	type ProxyTypeSelector = NoFnftAccountProxyType;
+	type TransferHook = ();
+	type PalletId = FnftPalletId;
+	type WeightInfo = weights::fnft::WeightInfo<Runtime>;
+}
+
+// Accounts allowed to relay crowdloan associations and be paid the `CrowdloanRelayerFee`.
+impl membership::Config<membership::Instance3> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AddOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type RemoveOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type SwapOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type ResetOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type PrimeOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type MembershipInitialized = ();
+	type MembershipChanged = ();
+	type MaxMembers = MaxCrowdloanRelayers;
+	type WeightInfo = weights::membership::WeightInfo<Runtime>;
+}
+
 impl crowdloan_rewards::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -739,6 +778,8 @@ impl crowdloan_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRootOrTwoThirdNativeCouncil;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type RelayChainAccountId = sp_runtime::AccountId32;
+	type IdentityVerifier =
+		composable_support::claims::identity::MultiChainVerifier<sp_runtime::AccountId32>;
 	type InitialPayment = InitialPayment;
 	type OverFundedThreshold = OverFundedThreshold;
 	type VestingStep = VestingStep;
@@ -749,11 +790,17 @@ impl crowdloan_rewards::Config for Runtime {
 	type Time = Timestamp;
 	type LockId = CrowdloanRewardsLockId;
 	type LockByDefault = LockCrowdloanRewards;
+	type RelayerOrigin = frame_system::EnsureSignedBy<CrowdloanRelayers, AccountId>;
+	type RelayerFee = CrowdloanRelayerFee;
+	type MaxRelayedClaimsPerBlock = MaxRelayedCrowdloanClaimsPerBlock;
+	type MaxRounds = MaxCrowdloanRounds;
 }
 
 parameter_types! {
 	  pub const MaxVestingSchedule: u32 = 128;
 	  pub MinVestedTransfer: u64 = CurrencyId::milli::<u64>();
+	  pub const VestingFnftCollectionId: CurrencyId = CurrencyId::VESTING_FNFT_COLLECTION;
+	  pub const VestingPalletId: PalletId = PalletId(*b"pal_vest");
 }
 
 impl vesting::Config for Runtime {
@@ -767,6 +814,10 @@ impl vesting::Config for Runtime {
 	type Moment = Moment;
 	type Time = Timestamp;
 	type VestingScheduleId = u128;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FnftCollectionId = VestingFnftCollectionId;
+	type PalletId = VestingPalletId;
 }
 
 parameter_types! {
@@ -774,18 +825,28 @@ parameter_types! {
 	  pub const BondedFinanceId: PalletId = PalletId(*b"bondedfi");
 	  pub MinReward: Balance = 10 * CurrencyId::unit::<Balance>();
 	  pub Stake: Balance = 10 * CurrencyId::unit::<Balance>();
+	  pub const BondFnftCollectionId: CurrencyId = CurrencyId::BOND_FNFT_COLLECTION;
 }
 
 impl bonded_finance::Config for Runtime {
 	type AdminOrigin = EnsureRootOrTwoThirdNativeCouncil;
+	type AssetId = CurrencyId;
 	type BondOfferId = BondOfferId;
 	type Convert = sp_runtime::traits::ConvertInto;
 	type Currency = Assets;
 	type RuntimeEvent = RuntimeEvent;
+	type FinancialNft = Fnft;
+	type FinancialNftInstanceId = FinancialNftInstanceId;
+	type FnftCollectionId = BondFnftCollectionId;
 	type MinReward = MinReward;
 	type NativeCurrency = Balances;
+	// Picasso does not run an oracle yet, so offers paid with Pablo LP tokens cannot be bonded
+	type Oracle = composable_traits::oracle::NoPriceOracle<CurrencyId, Balance, BlockNumber>;
+	type Pablo = Pablo;
 	type PalletId = BondedFinanceId;
+	type PoolId = PoolId;
 	type Stake = Stake;
+	type TreasuryAccount = TreasuryAccount;
 	type Vesting = Vesting;
 	type WeightInfo = weights::bonded_finance::WeightInfo<Runtime>;
 }
@@ -866,10 +927,12 @@ construct_runtime!(
 		GovernanceRegistry: governance_registry = 54,
 		Assets: assets = 55,
 		CrowdloanRewards: crowdloan_rewards = 56,
+		CrowdloanRelayers: membership::<Instance3> = 74,
 		Vesting: vesting = 57,
 		BondedFinance: bonded_finance = 58,
 		AssetsRegistry: assets_registry = 59,
 		Pablo: pablo = 60,
+		Fnft: pallet_fnft = 67,
 
 		CallFilter: call_filter = 100,
 	}
@@ -932,6 +995,7 @@ mod benches {
 		[currency_factory, CurrencyFactory]
 		[bonded_finance, BondedFinance]
 		[vesting, Vesting]
+		[pallet_fnft, Fnft]
 		[assets_registry, AssetsRegistry]
 		[pablo, Pablo]
 	[democracy, Democracy]
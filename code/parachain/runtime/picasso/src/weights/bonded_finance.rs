@@ -39,17 +39,9 @@ impl<T: frame_system::Config> bonded_finance::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
-	// Storage: BondedFinance BondOffers (r:1 w:1)
-	// Storage: Tokens Accounts (r:4 w:4)
-	// Storage: System Account (r:2 w:2)
-	// Storage: Vesting VestingScheduleNonce (r:1 w:1)
-	// Storage: Vesting VestingSchedules (r:2 w:2)
-	// Storage: Tokens Locks (r:2 w:2)
+	// TODO: regenerate from `bond` benchmark, the pallet default weight until then
 	fn bond() -> Weight {
-		// Minimum execution time: 268_150 nanoseconds.
-		Weight::from_ref_time(272_121_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(12 as u64))
-			.saturating_add(T::DbWeight::get().writes(12 as u64))
+		<() as bonded_finance::WeightInfo>::bond()
 	}
 	// Storage: BondedFinance BondOffers (r:1 w:1)
 	// Storage: System Account (r:2 w:2)
@@ -59,4 +51,8 @@ impl<T: frame_system::Config> bonded_finance::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// TODO: regenerate from `claim` benchmark, the pallet default weight until then
+	fn claim() -> Weight {
+		<() as bonded_finance::WeightInfo>::claim()
+	}
 }
@@ -7,6 +7,7 @@ pub mod collator_selection;
 pub mod collective;
 pub mod crowdloan_rewards;
 pub mod currency_factory;
+pub mod fnft;
 pub mod frame_system;
 pub mod identity;
 pub mod indices;
@@ -202,6 +202,10 @@ impl CurrencyId {
 		pub const PICA_STAKE_FNFT_COLLECTION: CurrencyId = CurrencyId(2001, None);
 		/// PBLO Stake fNFT Collection
 		pub const PBLO_STAKE_FNFT_COLLECTION: CurrencyId = CurrencyId(2005, None);
+		/// Vesting Schedule fNFT Collection
+		pub const VESTING_FNFT_COLLECTION: CurrencyId = CurrencyId(2100, None);
+		/// Bond Position fNFT Collection
+		pub const BOND_FNFT_COLLECTION: CurrencyId = CurrencyId(2101, None);
 
 		// Non-Native Tokens (101 - 1000)
 		/// Karura KAR
@@ -0,0 +1,159 @@
+//! Builds the Merkle tree committing to the recipients of an Airdrop, and the proofs recipients
+//! submit alongside their claims.
+//!
+//! The input is a CSV file with one recipient per row:
+//!
+//! ```csv
+//! identity,amount,vesting_period,funded_claim
+//! relay:0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d,1000000000000,604800000,false
+//! ethereum:0x8ba1f109551bd432803012645ac136ddd64dba72,1000000000000,604800000,true
+//! ```
+//!
+//! Identities are one of `relay:<32 bytes>`, `ethereum:<20 bytes>`, `cosmos-secp256k1:<33
+//! bytes>`, `cosmos-secp256r1:<33 bytes>` or `ed25519:<32 bytes>`, hex encoded. Leaves are indexed
+//! in row order.
+
+use clap::Parser;
+use composable_support::types::{CosmosPublicKey, Ed25519PublicKey, EthereumAddress};
+use pallet_airdrop::{
+	merkle::{self, MerkleTree},
+	models::{Identity, MerkleLeaf},
+};
+use serde::Serialize;
+use sp_core::H256;
+use std::collections::BTreeSet;
+
+type Balance = u128;
+type Moment = u64;
+type RelayChainAccountId = [u8; 32];
+
+#[derive(Parser, Debug)]
+#[clap(version = "1.0", author = "Composable")]
+pub struct Opts {
+	/// Path of the CSV file listing the recipients.
+	#[clap(short, long)]
+	pub input: String,
+
+	/// Output path of the JSON file holding the root and the proofs.
+	#[clap(short, long)]
+	pub output: String,
+}
+
+#[derive(Serialize)]
+struct Distribution {
+	root: H256,
+	total_funds: String,
+	total_recipients: u32,
+	claims: Vec<Claim>,
+}
+
+#[derive(Serialize)]
+struct Claim {
+	identity: String,
+	index: u32,
+	total: String,
+	vesting_period: Moment,
+	funded_claim: bool,
+	path: Vec<H256>,
+}
+
+fn main() {
+	let opts = Opts::parse();
+	let input = std::fs::read_to_string(&opts.input).expect("Failed to read recipients");
+	let distribution = build_distribution(&input).unwrap_or_else(|error| panic!("{}", error));
+	let output =
+		serde_json::to_string_pretty(&distribution).expect("Failed to serialize distribution");
+	std::fs::write(&opts.output, output).expect("Failed to write distribution");
+}
+
+fn build_distribution(input: &str) -> Result<Distribution, String> {
+	let mut identities = BTreeSet::new();
+	let mut leaves = Vec::new();
+	let mut claims = Vec::new();
+
+	let rows = input
+		.lines()
+		.enumerate()
+		.map(|(line, row)| (line + 1, row.trim()))
+		.filter(|(_, row)| !row.is_empty() && !row.starts_with("identity,"));
+
+	for (line, row) in rows {
+		let [identity, total, vesting_period, funded_claim]: [&str; 4] = row
+			.split(',')
+			.map(str::trim)
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| format!("line {}: expected 4 columns", line))?;
+
+		let index = u32::try_from(leaves.len()).map_err(|_| "too many recipients".to_string())?;
+		let leaf = MerkleLeaf {
+			index,
+			identity: parse_identity(identity)
+				.map_err(|error| format!("line {}: {}", line, error))?,
+			total: total
+				.parse::<Balance>()
+				.map_err(|error| format!("line {}: invalid amount: {}", line, error))?,
+			vesting_period: vesting_period
+				.parse::<Moment>()
+				.map_err(|error| format!("line {}: invalid vesting period: {}", line, error))?,
+			funded_claim: funded_claim
+				.parse::<bool>()
+				.map_err(|error| format!("line {}: invalid funded claim: {}", line, error))?,
+		};
+
+		if !identities.insert(codec::Encode::encode(&leaf.identity)) {
+			return Err(format!("line {}: duplicate identity {}", line, identity))
+		}
+
+		leaves.push(merkle::leaf_hash(&leaf));
+		claims.push(Claim {
+			identity: identity.to_string(),
+			index,
+			total: leaf.total.to_string(),
+			vesting_period: leaf.vesting_period,
+			funded_claim: leaf.funded_claim,
+			path: Vec::new(),
+		});
+	}
+
+	let tree = MerkleTree::new(leaves);
+	let root = tree.root().ok_or_else(|| "no recipients".to_string())?;
+	let mut total_funds: Balance = 0;
+	for claim in claims.iter_mut() {
+		claim.path = tree.proof(claim.index as usize).expect("every leaf has a proof; qed");
+		total_funds = total_funds
+			.checked_add(claim.total.parse::<Balance>().expect("parsed above; qed"))
+			.ok_or_else(|| "total funds overflow".to_string())?;
+	}
+
+	Ok(Distribution {
+		root,
+		total_funds: total_funds.to_string(),
+		total_recipients: claims.len() as u32,
+		claims,
+	})
+}
+
+fn parse_identity(identity: &str) -> Result<Identity<RelayChainAccountId>, String> {
+	let (kind, key) = identity
+		.split_once(':')
+		.ok_or_else(|| format!("identity `{}` has no kind", identity))?;
+	let key = hex::decode(key.trim_start_matches("0x"))
+		.map_err(|error| format!("identity `{}` is not hex encoded: {}", identity, error))?;
+	let invalid_length = |_| format!("identity `{}` has an invalid length", identity);
+
+	match kind {
+		"relay" => Ok(Identity::RelayChain(key.try_into().map_err(invalid_length)?)),
+		"ethereum" =>
+			Ok(Identity::Ethereum(EthereumAddress(key.try_into().map_err(invalid_length)?))),
+		"cosmos-secp256k1" => Ok(Identity::Cosmos(CosmosPublicKey::Secp256k1(
+			key.try_into().map_err(invalid_length)?,
+		))),
+		"cosmos-secp256r1" => Ok(Identity::Cosmos(CosmosPublicKey::Secp256r1(
+			key.try_into().map_err(invalid_length)?,
+		))),
+		"ed25519" =>
+			Ok(Identity::Ed25519(Ed25519PublicKey(key.try_into().map_err(invalid_length)?))),
+		_ => Err(format!("identity `{}` has an unknown kind", identity)),
+	}
+}
@@ -53,6 +53,6 @@ export async function txBondedFinanceBondSuccessTest(
     api,
     wallet,
     api.events.bondedFinance.NewBond.is,
-    api.tx.bondedFinance.bond(offerId, nbOfBonds, true)
+    api.tx.bondedFinance.bond(offerId, nbOfBonds, null, true)
   );
 }
@@ -23,7 +23,7 @@ export function usePurchaseBond(offerId: BigNumber, amount: BigNumber) {
           try {
             await executor
               .execute(
-                parachainApi.tx.bondedFinance.bond(offerId.toNumber(), amount.toString(), false),
+                parachainApi.tx.bondedFinance.bond(offerId.toNumber(), amount.toString(), null, false),
                 selectedAccount.address,
                 parachainApi,
                 signer,
@@ -229,6 +229,7 @@ export async function purchaseBond({
           parachainApi.tx.bondedFinance.bond(
             offerId.toString(),
             bondInput.toString(),
+            null,
             true
           ),
           account.address,